@@ -47,10 +47,11 @@ macro_rules! assert_as_result {
                     Err(format!(
                         concat!(
                             "assertion failed: `assert!(condition)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert.html\n",
+                            "{}macro.assert.html\n",
                             " condition label: `{}`,\n",
                             " condition debug: `{:?}`,\n",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
                         a,
                     ))
@@ -29,8 +29,7 @@
 ///
 /// * If true, return `Ok(abs_diff)`.
 ///
-/// * Otherwise, return [`Err`] with a message and the values of the
-///   expressions with their debug representations.
+/// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro provides the same statements as [`assert_`](macro.assert_.html),
 /// except this macro returns a Result, rather than doing a panic.
@@ -57,7 +56,7 @@ macro_rules! assert_abs_diff_lt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_abs_diff_lt!(a, b, delta)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_abs_diff_lt.html\n",
+                                "{}macro.assert_abs_diff_lt.html\n",
                                 "       a label: `{}`,\n",
                                 "       a debug: `{:?}`,\n",
                                 "       b label: `{}`,\n",
@@ -67,6 +66,7 @@ macro_rules! assert_abs_diff_lt_as_result {
                                 "     | a - b |: `{:?}`,\n",
                                 " | a - b | < Δ: {}"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             a,
                             stringify!($b),
@@ -56,11 +56,12 @@ macro_rules! assert_all_as_result {
                     Err(format!(
                         concat!(
                             "assertion failed: `assert_all!(collection, predicate)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_all.html\n",
+                            "{}macro.assert_all.html\n",
                             " collection label: `{}`,\n",
                             " collection debug: `{:?}`,\n",
                             "        predicate: `{}`"
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($collection),
                         collection,
                         stringify!($predicate)
@@ -56,11 +56,12 @@ macro_rules! assert_any_as_result {
                     Err(format!(
                         concat!(
                             "assertion failed: `assert_any!(collection, predicate)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_any.html\n",
+                            "{}macro.assert_any.html\n",
                             " collection label: `{}`,\n",
                             " collection debug: `{:?}`,\n",
                             "        predicate: `{}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($collection),
                         collection,
                         stringify!($predicate)
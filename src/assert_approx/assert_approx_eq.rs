@@ -68,8 +68,7 @@
 ///
 /// * If true, return Result `Ok(abs_diff, approx)`.
 ///
-/// * When false, return [`Err`] with a message and the values of the
-///   expressions with their debug representations.
+/// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro provides the same statements as [`assert_`](macro.assert_.html), except this macro
 /// returns a Result, rather than doing a panic.
@@ -97,7 +96,7 @@ macro_rules! assert_approx_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_approx_eq!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_approx_eq.html\n",
+                                "{}macro.assert_approx_eq.html\n",
                                 "            a label: `{}`,\n",
                                 "            a debug: `{:?}`,\n",
                                 "            b label: `{}`,\n",
@@ -106,6 +105,7 @@ macro_rules! assert_approx_eq_as_result {
                                 "             approx: `{:?}`,\n",
                                 " | a - b | ≤ approx: false"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             a,
                             stringify!($b),
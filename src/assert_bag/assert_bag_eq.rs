@@ -56,7 +56,7 @@ macro_rules! assert_bag_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_bag_eq!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_bag_eq.html\n",
+                                "{}macro.assert_bag_eq.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -64,6 +64,7 @@ macro_rules! assert_bag_eq_as_result {
                                 "   a bag: `{:?}`,\n",
                                 "   b bag: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_collection),
                             a_collection,
                             stringify!($b_collection),
@@ -112,6 +113,19 @@ mod test_as_result {
             )
         );
     }
+
+    #[test]
+    fn success_with_owned_and_borrowed_inputs() {
+        let a: Vec<i32> = vec![1, 1, 2];
+        let b: Vec<i32> = vec![2, 1, 1];
+        assert!(assert_bag_eq_as_result!(a, b).is_ok());
+        assert!(assert_bag_eq_as_result!(&a, &b).is_ok());
+        assert!(assert_bag_eq_as_result!(a, [1, 2, 1]).is_ok());
+        assert!(assert_bag_eq_as_result!(&a[..], &b[..]).is_ok());
+        // The inputs are borrowed, not consumed.
+        assert_eq!(a, vec![1, 1, 2]);
+        assert_eq!(b, vec![2, 1, 1]);
+    }
 }
 
 /// Assert a bag is equal to another.
@@ -58,7 +58,7 @@ macro_rules! assert_bag_ne_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_bag_ne!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_bag_ne.html\n",
+                                "{}macro.assert_bag_ne.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -66,6 +66,7 @@ macro_rules! assert_bag_ne_as_result {
                                 "   a bag: `{:?}`,\n",
                                 "   b bag: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_collection),
                             a_collection,
                             stringify!($b_collection),
@@ -114,6 +115,19 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn success_with_owned_and_borrowed_inputs() {
+        let a: Vec<i32> = vec![1, 1, 2];
+        let b: Vec<i32> = vec![1, 2];
+        assert!(assert_bag_ne_as_result!(a, b).is_ok());
+        assert!(assert_bag_ne_as_result!(&a, &b).is_ok());
+        assert!(assert_bag_ne_as_result!(a, [1, 2]).is_ok());
+        assert!(assert_bag_ne_as_result!(&a[..], &b[..]).is_ok());
+        // The inputs are borrowed, not consumed.
+        assert_eq!(a, vec![1, 1, 2]);
+        assert_eq!(b, vec![1, 2]);
+    }
 }
 
 /// Assert a bag is not equal to another.
@@ -32,7 +32,7 @@
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
-/// This macro provides the same statements as [`assert_superbag_other`](macro.assert_superbag_other.html),
+/// This macro provides the same statements as [`assert_bag_superbag`](macro.assert_bag_superbag.html),
 /// except this macro returns a Result, rather than doing a panic.
 ///
 /// This macro is useful for runtime checks, such as checking parameters,
@@ -62,7 +62,7 @@ macro_rules! assert_bag_superbag_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_bag_superbag!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_bag_superbag.html\n",
+                                "{}macro.assert_bag_superbag.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -70,6 +70,7 @@ macro_rules! assert_bag_superbag_as_result {
                                 "   a bag: `{:?}`,\n",
                                 "   b bag: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_collection),
                             a_collection,
                             stringify!($b_collection),
@@ -138,6 +139,19 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn success_with_owned_and_borrowed_inputs() {
+        let a: Vec<i32> = vec![1, 1, 2];
+        let b: Vec<i32> = vec![1, 2];
+        assert!(assert_bag_superbag_as_result!(a, b).is_ok());
+        assert!(assert_bag_superbag_as_result!(&a, &b).is_ok());
+        assert!(assert_bag_superbag_as_result!(a, [1, 2]).is_ok());
+        assert!(assert_bag_superbag_as_result!(&a[..], &b[..]).is_ok());
+        // The inputs are borrowed, not consumed.
+        assert_eq!(a, vec![1, 1, 2]);
+        assert_eq!(b, vec![1, 2]);
+    }
 }
 
 /// Assert a bag is a superbag of another.
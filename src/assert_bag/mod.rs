@@ -2,8 +2,12 @@
 //!
 //! These macros help with comparison of bag parameters, such as comparison of
 //! two arrays or two vectors, where the item order does not matter, and the
-//! item count does matter. These macros convert their inputs into HashMap
-//! iterators.
+//! item count does matter. These macros convert their inputs into BTreeMap
+//! bags, where each key is an item and each value is the item count, so each
+//! item must implement `Ord`.
+//!
+//! These macros borrow their inputs, so a collection can be an owned `Vec`,
+//! a `&Vec`, an array, or a `&[T]` slice, and is still usable afterwards.
 //!
 //! For eq & ne:
 //!
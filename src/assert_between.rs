@@ -0,0 +1,297 @@
+//! Assert a value is between a lower bound and an upper bound, inclusive.
+//!
+//! Pseudocode:<br>
+//! lo ≤ x ≤ hi
+//!
+//! The bounds are inclusive. For exclusive bounds, use
+//! [`assert_between_exclusive`](macro@crate::assert_between_exclusive).
+//!
+//! This is useful when the bounds are separate variables, such as a dynamic
+//! minimum and maximum, rather than a range.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let lo: i32 = 10;
+//! let x: i32 = 15;
+//! let hi: i32 = 20;
+//! assert_between!(lo, x, hi);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_between`](macro@crate::assert_between)
+//! * [`assert_between_as_result`](macro@crate::assert_between_as_result)
+//! * [`debug_assert_between`](macro@crate::debug_assert_between)
+
+/// Assert a value is between a lower bound and an upper bound, inclusive.
+///
+/// Pseudocode:<br>
+/// lo ≤ x ≤ hi
+///
+/// * If true, return Result `Ok(x)`, which moves the value of x, so x can be
+///   a temporary, such as the result of a function call.
+///
+/// * Otherwise, return Result `Err(message)`, which says which bound failed.
+///
+/// This macro provides the same statements as [`assert_between`](macro.assert_between.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_between`](macro@crate::assert_between)
+/// * [`assert_between_as_result`](macro@crate::assert_between_as_result)
+/// * [`debug_assert_between`](macro@crate::debug_assert_between)
+///
+#[macro_export]
+macro_rules! assert_between_as_result {
+    ($lo:expr, $x:expr, $hi:expr $(,)?) => {{
+        match (&$lo, $x, &$hi) {
+            (lo, x, hi) => {
+                let failed_bound = if !(lo <= &x) {
+                    Some("lo ≤ x")
+                } else if !(&x <= hi) {
+                    Some("x ≤ hi")
+                } else {
+                    None
+                };
+                match failed_bound {
+                    None => Ok(x),
+                    Some(failed_bound) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_between!(lo, x, hi)`\n",
+                                    "{}macro.assert_between.html\n",
+                                    "     lo label: `{}`,\n",
+                                    "     lo debug: `{:?}`,\n",
+                                    "      x label: `{}`,\n",
+                                    "      x debug: `{:?}`,\n",
+                                    "     hi label: `{}`,\n",
+                                    "     hi debug: `{:?}`,\n",
+                                    " failed bound: `{}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($lo),
+                                lo,
+                                stringify!($x),
+                                x,
+                                stringify!($hi),
+                                hi,
+                                failed_bound
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let lo: i32 = 10;
+        let x: i32 = 15;
+        let hi: i32 = 20;
+        let result = assert_between_as_result!(lo, x, hi);
+        assert_eq!(result.unwrap(), 15);
+    }
+
+    #[test]
+    fn success_because_bounds_are_inclusive() {
+        let lo: i32 = 10;
+        let hi: i32 = 20;
+        assert_eq!(assert_between_as_result!(lo, 10, hi).unwrap(), 10);
+        assert_eq!(assert_between_as_result!(lo, 20, hi).unwrap(), 20);
+    }
+
+    #[test]
+    fn success_with_function_call() {
+        fn f() -> String {
+            String::from("m")
+        }
+        let lo = String::from("a");
+        let hi = String::from("z");
+        let x = assert_between_as_result!(lo, f(), hi).unwrap();
+        assert_eq!(x, "m");
+    }
+
+    #[test]
+    fn success_with_floats() {
+        let lo: f64 = -0.5;
+        let x: f64 = 0.25;
+        let hi: f64 = 0.5;
+        let result = assert_between_as_result!(lo, x, hi);
+        assert_eq!(result.unwrap(), 0.25);
+    }
+
+    #[test]
+    fn failure_because_lo() {
+        let lo: i32 = 10;
+        let x: i32 = 9;
+        let hi: i32 = 20;
+        let result = assert_between_as_result!(lo, x, hi);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_between!(lo, x, hi)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_between.html\n",
+                "     lo label: `lo`,\n",
+                "     lo debug: `10`,\n",
+                "      x label: `x`,\n",
+                "      x debug: `9`,\n",
+                "     hi label: `hi`,\n",
+                "     hi debug: `20`,\n",
+                " failed bound: `lo ≤ x`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_hi() {
+        let lo: i32 = 10;
+        let x: i32 = 21;
+        let hi: i32 = 20;
+        let result = assert_between_as_result!(lo, x, hi);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_between!(lo, x, hi)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_between.html\n",
+                "     lo label: `lo`,\n",
+                "     lo debug: `10`,\n",
+                "      x label: `x`,\n",
+                "      x debug: `21`,\n",
+                "     hi label: `hi`,\n",
+                "     hi debug: `20`,\n",
+                " failed bound: `x ≤ hi`"
+            )
+        );
+    }
+}
+
+/// Assert a value is between a lower bound and an upper bound, inclusive.
+///
+/// Pseudocode:<br>
+/// lo ≤ x ≤ hi
+///
+/// * If true, return `x`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let lo: i32 = 10;
+/// let x: i32 = 15;
+/// let hi: i32 = 20;
+/// assert_between!(lo, x, hi);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let lo: i32 = 10;
+/// let x: i32 = 21;
+/// let hi: i32 = 20;
+/// assert_between!(lo, x, hi);
+/// # });
+/// // assertion failed: `assert_between!(lo, x, hi)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_between.html
+/// //      lo label: `lo`,
+/// //      lo debug: `10`,
+/// //       x label: `x`,
+/// //       x debug: `21`,
+/// //      hi label: `hi`,
+/// //      hi debug: `20`,
+/// //  failed bound: `x ≤ hi`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_between!(lo, x, hi)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_between.html\n",
+/// #     "     lo label: `lo`,\n",
+/// #     "     lo debug: `10`,\n",
+/// #     "      x label: `x`,\n",
+/// #     "      x debug: `21`,\n",
+/// #     "     hi label: `hi`,\n",
+/// #     "     hi debug: `20`,\n",
+/// #     " failed bound: `x ≤ hi`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_between`](macro@crate::assert_between)
+/// * [`assert_between_as_result`](macro@crate::assert_between_as_result)
+/// * [`debug_assert_between`](macro@crate::debug_assert_between)
+///
+#[macro_export]
+macro_rules! assert_between {
+    ($lo:expr, $x:expr, $hi:expr $(,)?) => {{
+        match $crate::assert_between_as_result!($lo, $x, $hi) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($lo:expr, $x:expr, $hi:expr, $($message:tt)+) => {{
+        match $crate::assert_between_as_result!($lo, $x, $hi) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a value is between a lower bound and an upper bound, inclusive.
+///
+/// Pseudocode:<br>
+/// lo ≤ x ≤ hi
+///
+/// This macro provides the same statements as [`assert_between`](macro.assert_between.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_between`](macro@crate::assert_between)
+/// * [`assert_between_as_result`](macro@crate::assert_between_as_result)
+/// * [`debug_assert_between`](macro@crate::debug_assert_between)
+///
+#[macro_export]
+macro_rules! debug_assert_between {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_between!($($arg)*);
+        }
+    };
+}
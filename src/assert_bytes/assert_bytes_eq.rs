@@ -0,0 +1,246 @@
+//! Assert a byte slice is equal to another, and show a hexdump on failure.
+//!
+//! Pseudocode:<br>
+//! a[..] = b[..]
+//!
+//! The failure message shows both buffers as a side by side hexdump, with
+//! the offset, the hex, and the ASCII of each row, and marks the row that has
+//! the first differing offset. This is easier to read than decimal arrays,
+//! especially for binary data.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = b"alfa\n";
+//! let b = vec![b'a', b'l', b'f', b'a', b'\n'];
+//! assert_bytes_eq!(&a, &b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_bytes_eq`](macro@crate::assert_bytes_eq)
+//! * [`assert_bytes_eq_as_result`](macro@crate::assert_bytes_eq_as_result)
+//! * [`debug_assert_bytes_eq`](macro@crate::debug_assert_bytes_eq)
+
+/// Assert a byte slice is equal to another, and show a hexdump on failure.
+///
+/// Pseudocode:<br>
+/// a[..] = b[..]
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_bytes_eq`](macro.assert_bytes_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_bytes_eq`](macro@crate::assert_bytes_eq)
+/// * [`assert_bytes_eq_as_result`](macro@crate::assert_bytes_eq_as_result)
+/// * [`debug_assert_bytes_eq`](macro@crate::debug_assert_bytes_eq)
+///
+#[macro_export]
+macro_rules! assert_bytes_eq_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let (a_bytes, b_bytes): (&[u8], &[u8]) = (&a[..], &b[..]);
+                match $crate::assert_slice::first_difference(a_bytes, b_bytes) {
+                    None => Ok(()),
+                    Some(offset) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_bytes_eq!(a, b)`\n",
+                                    "{}macro.assert_bytes_eq.html\n",
+                                    "           a label: `{}`,\n",
+                                    "           b label: `{}`,\n",
+                                    "             a len: `{}`,\n",
+                                    "             b len: `{}`,\n",
+                                    " first diff offset: `{}`,\n",
+                                    "           hexdump:\n",
+                                    "{}"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                stringify!($b),
+                                a_bytes.len(),
+                                b_bytes.len(),
+                                offset,
+                                $crate::hexdump::hexdump_diff(a_bytes, b_bytes)
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = b"alfa\n";
+        let b = vec![b'a', b'l', b'f', b'a', b'\n'];
+        let result = assert_bytes_eq_as_result!(&a, &b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let a = b"alfa\n";
+        let b = b"bravo";
+        let result = assert_bytes_eq_as_result!(&a, &b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_bytes_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_bytes_eq.html\n",
+                "           a label: `&a`,\n",
+                "           b label: `&b`,\n",
+                "             a len: `5`,\n",
+                "             b len: `5`,\n",
+                " first diff offset: `0`,\n",
+                "           hexdump:\n",
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                ">00000000  61 6c 66 61 0a           alfa.     62 72 61 76 6f           bravo"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_len_differs() {
+        let a = b"alfa";
+        let b = b"alfa\n";
+        let result = assert_bytes_eq_as_result!(&a, &b);
+        let actual = result.unwrap_err();
+        assert!(actual.contains(" first diff offset: `4`,\n"));
+        assert!(actual.ends_with(">00000000  61 6c 66 61              alfa      61 6c 66 61 0a           alfa."));
+    }
+}
+
+/// Assert a byte slice is equal to another, and show a hexdump on failure.
+///
+/// Pseudocode:<br>
+/// a[..] = b[..]
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = b"alfa\n";
+/// let b = vec![b'a', b'l', b'f', b'a', b'\n'];
+/// assert_bytes_eq!(&a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = b"alfa\n";
+/// let b = b"bravo";
+/// assert_bytes_eq!(&a, &b);
+/// # });
+/// // assertion failed: `assert_bytes_eq!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_bytes_eq.html
+/// //            a label: `&a`,
+/// //            b label: `&b`,
+/// //              a len: `5`,
+/// //              b len: `5`,
+/// //  first diff offset: `0`,
+/// //            hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61 0a           alfa.     62 72 61 76 6f           bravo
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_bytes_eq!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_bytes_eq.html\n",
+/// #     "           a label: `&a`,\n",
+/// #     "           b label: `&b`,\n",
+/// #     "             a len: `5`,\n",
+/// #     "             b len: `5`,\n",
+/// #     " first diff offset: `0`,\n",
+/// #     "           hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 61 0a           alfa.     62 72 61 76 6f           bravo"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_bytes_eq`](macro@crate::assert_bytes_eq)
+/// * [`assert_bytes_eq_as_result`](macro@crate::assert_bytes_eq_as_result)
+/// * [`debug_assert_bytes_eq`](macro@crate::debug_assert_bytes_eq)
+///
+#[macro_export]
+macro_rules! assert_bytes_eq {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_bytes_eq_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_bytes_eq_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a byte slice is equal to another, and show a hexdump on failure.
+///
+/// Pseudocode:<br>
+/// a[..] = b[..]
+///
+/// This macro provides the same statements as [`assert_bytes_eq`](macro.assert_bytes_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_bytes_eq`](macro@crate::assert_bytes_eq)
+/// * [`assert_bytes_eq_as_result`](macro@crate::assert_bytes_eq_as_result)
+/// * [`debug_assert_bytes_eq`](macro@crate::debug_assert_bytes_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_bytes_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_bytes_eq!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,245 @@
+//! Assert bytes decode lossily to a string that is equal to an expected string.
+//!
+//! Pseudocode:<br>
+//! String::from_utf8_lossy(bytes) = expected
+//!
+//! This decodes with `String::from_utf8_lossy`, which replaces each invalid
+//! UTF-8 sequence with the replacement char `U+FFFD`, so this never panics on
+//! invalid UTF-8. This is useful for process output that is mostly UTF-8
+//! with occasional stray bytes.
+//!
+//! The failure message shows the lossy string, and the offset in the bytes of
+//! each invalid sequence, which is where each replacement char comes from.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let bytes = b"al\xFFa";
+//! assert_utf8_lossy_eq!(bytes, "al\u{FFFD}a");
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_utf8_lossy_eq`](macro@crate::assert_utf8_lossy_eq)
+//! * [`assert_utf8_lossy_eq_as_result`](macro@crate::assert_utf8_lossy_eq_as_result)
+//! * [`debug_assert_utf8_lossy_eq`](macro@crate::debug_assert_utf8_lossy_eq)
+
+/// Assert bytes decode lossily to a string that is equal to an expected string.
+///
+/// Pseudocode:<br>
+/// String::from_utf8_lossy(bytes) = expected
+///
+/// * If true, return Result `Ok(string)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The bytes can be any type that indexes as `[u8]`, such as `&[u8]`, an
+/// array, or `Vec<u8>`. The expected string can be any type that implements
+/// `AsRef<str>`, such as `&str` or `String`.
+///
+/// This macro provides the same statements as [`assert_utf8_lossy_eq`](macro.assert_utf8_lossy_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_utf8_lossy_eq`](macro@crate::assert_utf8_lossy_eq)
+/// * [`assert_utf8_lossy_eq_as_result`](macro@crate::assert_utf8_lossy_eq_as_result)
+/// * [`debug_assert_utf8_lossy_eq`](macro@crate::debug_assert_utf8_lossy_eq)
+///
+#[macro_export]
+macro_rules! assert_utf8_lossy_eq_as_result {
+    ($bytes:expr, $expected:expr $(,)?) => {{
+        match (&$bytes, &$expected) {
+            (bytes, expected) => {
+                let bytes: &[u8] = &bytes[..];
+                let string = String::from_utf8_lossy(bytes);
+                if string == AsRef::<str>::as_ref(expected) {
+                    Ok(string.into_owned())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_utf8_lossy_eq!(bytes, expected)`\n",
+                                "{}macro.assert_utf8_lossy_eq.html\n",
+                                "          bytes label: `{}`,\n",
+                                "       expected label: `{}`,\n",
+                                "       expected debug: `{:?}`,\n",
+                                "         lossy string: `{:?}`,\n",
+                                " invalid byte offsets: `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($bytes),
+                            stringify!($expected),
+                            expected,
+                            string,
+                            $crate::assert_bytes::invalid_utf8_offsets(bytes)
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let bytes = b"alfa";
+        let expected = "alfa";
+        let result = assert_utf8_lossy_eq_as_result!(bytes, expected);
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn success_with_invalid_utf8() {
+        let bytes = vec![b'a', b'l', 0xFF, b'a'];
+        let expected = String::from("al\u{FFFD}a");
+        let result = assert_utf8_lossy_eq_as_result!(bytes, expected);
+        assert_eq!(result.unwrap(), "al\u{FFFD}a");
+    }
+
+    #[test]
+    fn failure() {
+        let bytes = b"al\xFFa";
+        let expected = "alfa";
+        let result = assert_utf8_lossy_eq_as_result!(bytes, expected);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_utf8_lossy_eq!(bytes, expected)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_utf8_lossy_eq.html\n",
+                "          bytes label: `bytes`,\n",
+                "       expected label: `expected`,\n",
+                "       expected debug: `\"alfa\"`,\n",
+                "         lossy string: `\"al�a\"`,\n",
+                " invalid byte offsets: `[2]`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_with_incomplete_sequence_at_end() {
+        let bytes = b"alfa\xE2\x82";
+        let expected = "alfa";
+        let result = assert_utf8_lossy_eq_as_result!(bytes, expected);
+        assert!(result.unwrap_err().contains(" invalid byte offsets: `[4]`"));
+    }
+}
+
+/// Assert bytes decode lossily to a string that is equal to an expected string.
+///
+/// Pseudocode:<br>
+/// String::from_utf8_lossy(bytes) = expected
+///
+/// * If true, return `string`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let bytes = b"al\xFFa";
+/// assert_utf8_lossy_eq!(bytes, "al\u{FFFD}a");
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let bytes = b"al\xFFa";
+/// let expected = "alfa";
+/// assert_utf8_lossy_eq!(bytes, expected);
+/// # });
+/// // assertion failed: `assert_utf8_lossy_eq!(bytes, expected)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_utf8_lossy_eq.html
+/// //           bytes label: `bytes`,
+/// //        expected label: `expected`,
+/// //        expected debug: `\"alfa\"`,
+/// //          lossy string: `\"al�a\"`,
+/// //  invalid byte offsets: `[2]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_utf8_lossy_eq!(bytes, expected)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_utf8_lossy_eq.html\n",
+/// #     "          bytes label: `bytes`,\n",
+/// #     "       expected label: `expected`,\n",
+/// #     "       expected debug: `\"alfa\"`,\n",
+/// #     "         lossy string: `\"al�a\"`,\n",
+/// #     " invalid byte offsets: `[2]`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_utf8_lossy_eq`](macro@crate::assert_utf8_lossy_eq)
+/// * [`assert_utf8_lossy_eq_as_result`](macro@crate::assert_utf8_lossy_eq_as_result)
+/// * [`debug_assert_utf8_lossy_eq`](macro@crate::debug_assert_utf8_lossy_eq)
+///
+#[macro_export]
+macro_rules! assert_utf8_lossy_eq {
+    ($bytes:expr, $expected:expr $(,)?) => {{
+        match $crate::assert_utf8_lossy_eq_as_result!($bytes, $expected) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($bytes:expr, $expected:expr, $($message:tt)+) => {{
+        match $crate::assert_utf8_lossy_eq_as_result!($bytes, $expected) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert bytes decode lossily to a string that is equal to an expected string.
+///
+/// Pseudocode:<br>
+/// String::from_utf8_lossy(bytes) = expected
+///
+/// This macro provides the same statements as [`assert_utf8_lossy_eq`](macro.assert_utf8_lossy_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_utf8_lossy_eq`](macro@crate::assert_utf8_lossy_eq)
+/// * [`assert_utf8_lossy_eq_as_result`](macro@crate::assert_utf8_lossy_eq_as_result)
+/// * [`debug_assert_utf8_lossy_eq`](macro@crate::debug_assert_utf8_lossy_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_utf8_lossy_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_utf8_lossy_eq!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,45 @@
+//! Assert for comparing bytes.
+//!
+//! These macros help with comparison of byte slices, such as binary data,
+//! file contents, or command output. The failure message shows a hexdump.
+//!
+//! * [`assert_bytes_eq!(a, b)`](macro@crate::assert_bytes_eq) ≈ a[..] = b[..], with a hexdump
+//! * [`assert_utf8_lossy_eq!(bytes, expected)`](macro@crate::assert_utf8_lossy_eq) ≈ String::from_utf8_lossy(bytes) = expected
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = b"alfa";
+//! let b = vec![b'a', b'l', b'f', b'a'];
+//! assert_bytes_eq!(&a, &b);
+//! # }
+//! ```
+
+pub mod assert_bytes_eq;
+pub mod assert_utf8_lossy_eq;
+
+/// Find the offset of each invalid UTF-8 sequence in bytes.
+///
+/// Each offset matches one replacement char `U+FFFD` in the result of
+/// `String::from_utf8_lossy`, including an incomplete sequence at the end.
+///
+/// This is a helper for [`assert_utf8_lossy_eq`](macro@crate::assert_utf8_lossy_eq).
+#[doc(hidden)]
+pub fn invalid_utf8_offsets(bytes: &[u8]) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut start = 0;
+    while start < bytes.len() {
+        match ::std::str::from_utf8(&bytes[start..]) {
+            Ok(_) => break,
+            Err(err) => {
+                let offset = start + err.valid_up_to();
+                offsets.push(offset);
+                start = offset + err.error_len().unwrap_or(bytes.len() - offset);
+            }
+        }
+    }
+    offsets
+}
@@ -0,0 +1,240 @@
+//! Assert a string char count is less than an expression.
+//!
+//! Pseudocode:<br>
+//! s.chars().count() < n
+//!
+//! This counts chars, which are Unicode scalar values, rather than bytes.
+//! For example, `"café"` has 4 chars and 5 bytes, because `é` is 2 bytes
+//! in UTF-8, so `"café".len()` is 5.
+//!
+//! The failure message shows the char count and the byte length, and shows
+//! the string debug representation truncated to its first 64 chars.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let s = "café";
+//! let n: usize = 5;
+//! assert_char_count_lt!(s, n);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_char_count_lt`](macro@crate::assert_char_count_lt)
+//! * [`assert_char_count_lt_as_result`](macro@crate::assert_char_count_lt_as_result)
+//! * [`debug_assert_char_count_lt`](macro@crate::debug_assert_char_count_lt)
+
+/// Assert a string char count is less than an expression.
+///
+/// Pseudocode:<br>
+/// s.chars().count() < n
+///
+/// * If true, return Result `Ok(count)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_char_count_lt`](macro.assert_char_count_lt.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// This implementation uses [`::std::str::Chars`](https://doc.rust-lang.org/std/str/struct.Chars.html).
+///
+/// # Module macros
+///
+/// * [`assert_char_count_lt`](macro@crate::assert_char_count_lt)
+/// * [`assert_char_count_lt_as_result`](macro@crate::assert_char_count_lt_as_result)
+/// * [`debug_assert_char_count_lt`](macro@crate::debug_assert_char_count_lt)
+///
+#[macro_export]
+macro_rules! assert_char_count_lt_as_result {
+    ($s:expr, $n:expr $(,)?) => {{
+        match (&$s, &$n) {
+            (s, n) => {
+                let s_char_count = s.chars().count();
+                if s_char_count < *n {
+                    Ok(s_char_count)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_char_count_lt!(s, n)`\n",
+                                "{}macro.assert_char_count_lt.html\n",
+                                "      s label: `{}`,\n",
+                                "      s debug: `{}`,\n",
+                                " s char count: `{:?}`,\n",
+                                "   s byte len: `{:?}`,\n",
+                                "      n label: `{}`,\n",
+                                "      n debug: `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($s),
+                            $crate::assert_len::debug_truncated(s),
+                            s_char_count,
+                            s.len(),
+                            stringify!($n),
+                            n
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let s = "café";
+        let n: usize = 5;
+        let result = assert_char_count_lt_as_result!(s, n);
+        assert_eq!(result.unwrap(), 4);
+    }
+
+    #[test]
+    fn success_with_string() {
+        let s = String::from("café");
+        let n: usize = 5;
+        let result = assert_char_count_lt_as_result!(s, n);
+        assert_eq!(result.unwrap(), 4);
+    }
+
+    #[test]
+    fn failure() {
+        let s = "café";
+        let n: usize = 4;
+        let result = assert_char_count_lt_as_result!(s, n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_char_count_lt!(s, n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_char_count_lt.html\n",
+                "      s label: `s`,\n",
+                "      s debug: `\"café\"`,\n",
+                " s char count: `4`,\n",
+                "   s byte len: `5`,\n",
+                "      n label: `n`,\n",
+                "      n debug: `4`"
+            )
+        );
+    }
+}
+
+/// Assert a string char count is less than an expression.
+///
+/// Pseudocode:<br>
+/// s.chars().count() < n
+///
+/// * If true, return `count`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let s = "café";
+/// let n: usize = 5;
+/// assert_char_count_lt!(s, n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let s = "café";
+/// let n: usize = 4;
+/// assert_char_count_lt!(s, n);
+/// # });
+/// // assertion failed: `assert_char_count_lt!(s, n)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_char_count_lt.html
+/// //       s label: `s`,
+/// //       s debug: `\"café\"`,
+/// //  s char count: `4`,
+/// //    s byte len: `5`,
+/// //       n label: `n`,
+/// //       n debug: `4`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_char_count_lt!(s, n)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_char_count_lt.html\n",
+/// #     "      s label: `s`,\n",
+/// #     "      s debug: `\"café\"`,\n",
+/// #     " s char count: `4`,\n",
+/// #     "   s byte len: `5`,\n",
+/// #     "      n label: `n`,\n",
+/// #     "      n debug: `4`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_char_count_lt`](macro@crate::assert_char_count_lt)
+/// * [`assert_char_count_lt_as_result`](macro@crate::assert_char_count_lt_as_result)
+/// * [`debug_assert_char_count_lt`](macro@crate::debug_assert_char_count_lt)
+///
+#[macro_export]
+macro_rules! assert_char_count_lt {
+    ($s:expr, $n:expr $(,)?) => {{
+        match $crate::assert_char_count_lt_as_result!($s, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($s:expr, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_char_count_lt_as_result!($s, $n) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a string char count is less than an expression.
+///
+/// Pseudocode:<br>
+/// s.chars().count() < n
+///
+/// This macro provides the same statements as [`assert_char_count_lt`](macro.assert_char_count_lt.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_char_count_lt`](macro@crate::assert_char_count_lt)
+/// * [`assert_char_count_lt_as_result`](macro@crate::assert_char_count_lt_as_result)
+/// * [`debug_assert_char_count_lt`](macro@crate::debug_assert_char_count_lt)
+///
+#[macro_export]
+macro_rules! debug_assert_char_count_lt {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_char_count_lt!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,28 @@
+//! Assert for comparing string char counts.
+//!
+//! These macros help with strings that may contain multibyte text. The method
+//! `String::len` returns the number of bytes, which can be surprising, so
+//! these macros count chars, which are Unicode scalar values.
+//!
+//! * [`assert_char_count_eq!(s, n)`](macro@crate::assert_char_count_eq) ≈ s.chars().count() = n
+//! * [`assert_char_count_lt!(s, n)`](macro@crate::assert_char_count_lt) ≈ s.chars().count() < n
+//! * [`assert_char_count_le!(s, n)`](macro@crate::assert_char_count_le) ≈ s.chars().count() ≤ n
+//! * [`assert_char_count_gt!(s, n)`](macro@crate::assert_char_count_gt) ≈ s.chars().count() > n
+//! * [`assert_char_count_ge!(s, n)`](macro@crate::assert_char_count_ge) ≈ s.chars().count() ≥ n
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let s = "café";
+//! assert_char_count_eq!(s, 4);
+//! # }
+//! ```
+
+pub mod assert_char_count_eq;
+pub mod assert_char_count_ge;
+pub mod assert_char_count_gt;
+pub mod assert_char_count_le;
+pub mod assert_char_count_lt;
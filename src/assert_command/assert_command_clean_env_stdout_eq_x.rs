@@ -0,0 +1,316 @@
+//! Assert a command with a cleared environment has stdout equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (command with cleared environment ⇒ stdout) = expr
+//!
+//! This calls `command.env_clear()` before running the command, so the child
+//! process sees no inherited environment variables. This is useful for
+//! hermetic tests, such as on a CI server, where inherited variables for the
+//! locale or the terminal color can change the command output.
+//!
+//! The macro mutates the command, so it also clears any environment variable
+//! that the test set on the command beforehand.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let bytes = vec![b'a', b'l', b'f', b'a'];
+//! assert_command_clean_env_stdout_eq_x!(command, bytes);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_clean_env_stdout_eq_x`](macro@crate::assert_command_clean_env_stdout_eq_x)
+//! * [`assert_command_clean_env_stdout_eq_x_as_result`](macro@crate::assert_command_clean_env_stdout_eq_x_as_result)
+//! * [`debug_assert_command_clean_env_stdout_eq_x`](macro@crate::debug_assert_command_clean_env_stdout_eq_x)
+
+/// Assert a command with a cleared environment has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command with cleared environment ⇒ stdout) = expr
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_command_clean_env_stdout_eq_x`](macro.assert_command_clean_env_stdout_eq_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_clean_env_stdout_eq_x`](macro@crate::assert_command_clean_env_stdout_eq_x)
+/// * [`assert_command_clean_env_stdout_eq_x_as_result`](macro@crate::assert_command_clean_env_stdout_eq_x_as_result)
+/// * [`debug_assert_command_clean_env_stdout_eq_x`](macro@crate::debug_assert_command_clean_env_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_clean_env_stdout_eq_x_as_result {
+    ($command:expr, $expr:expr $(,)?) => {{
+        match (&mut $command, &$expr) {
+            (command, expr) => {
+                match command.env_clear().output() {
+                    Ok(output) => {
+                        let a = output.stdout;
+                        if a.eq(expr) {
+                            Ok(a)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_clean_env_stdout_eq_x!(command, expr)`\n",
+                                        "{}macro.assert_command_clean_env_stdout_eq_x.html\n",
+                                        " command label: `{}`,\n",
+                                        " command debug: `{:?}`,\n",
+                                        "   environment: `cleared`,\n",
+                                        "    expr label: `{}`,\n",
+                                        "    expr debug: `{:?}`,\n",
+                                        " command value: `{:?}`,\n",
+                                        "    expr value: `{:?}`,\n",
+                                        "       hexdump:\n{}"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($command),
+                                    command,
+                                    stringify!($expr),
+                                    expr,
+                                    a,
+                                    expr,
+                                    $crate::hexdump::hexdump_diff(&a, &expr[..])
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_clean_env_stdout_eq_x!(command, expr)`\n",
+                                    "{}macro.assert_command_clean_env_stdout_eq_x.html\n",
+                                    " command label: `{}`,\n",
+                                    " command debug: `{:?}`,\n",
+                                    "   environment: `cleared`,\n",
+                                    "    expr label: `{}`,\n",
+                                    "    expr debug: `{:?}`,\n",
+                                    " output is err: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($command),
+                                command,
+                                stringify!($expr),
+                                expr,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let expr = vec![b'a', b'l', b'f', b'a'];
+        let result = assert_command_clean_env_stdout_eq_x_as_result!(command, expr);
+        assert_eq!(result.unwrap(), vec![b'a', b'l', b'f', b'a']);
+    }
+
+    #[test]
+    fn success_because_child_sees_no_inherited_environment() {
+        let mut command = Command::new("env");
+        command.env("ASSERTABLES_TEST", "alfa");
+        let expr: Vec<u8> = vec![];
+        let result = assert_command_clean_env_stdout_eq_x_as_result!(command, expr);
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let expr = vec![b'z', b'z'];
+        let result = assert_command_clean_env_stdout_eq_x_as_result!(command, expr);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_clean_env_stdout_eq_x!(command, expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_clean_env_stdout_eq_x.html\n",
+                " command label: `command`,\n",
+                " command debug: `env -i \"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+                "   environment: `cleared`,\n",
+                "    expr label: `expr`,\n",
+                "    expr debug: `[122, 122]`,\n",
+                " command value: `[97, 108, 102, 97]`,\n",
+                "    expr value: `[122, 122]`,\n",
+                "       hexdump:\n",
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_command_cannot_spawn() {
+        let mut command = Command::new("bin/no-such-program");
+        let expr = vec![b'a'];
+        let result = assert_command_clean_env_stdout_eq_x_as_result!(command, expr);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("   environment: `cleared`,\n"));
+        assert!(actual.contains(" output is err: `"));
+    }
+
+    fn printf_alfa(count: &std::cell::Cell<i32>) -> Command {
+        count.set(count.get() + 1);
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        command
+    }
+
+    #[test]
+    fn evaluates_command_once() {
+        let count = std::cell::Cell::new(0);
+        let expr = vec![b'z', b'z'];
+        let result = assert_command_clean_env_stdout_eq_x_as_result!(printf_alfa(&count), expr);
+        assert!(result.is_err());
+        assert_eq!(count.get(), 1);
+    }
+}
+
+/// Assert a command with a cleared environment has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command with cleared environment ⇒ stdout) = expr
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let bytes = vec![b'a', b'l', b'f', b'a'];
+/// assert_command_clean_env_stdout_eq_x!(command, bytes);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let bytes = vec![b'z', b'z'];
+/// assert_command_clean_env_stdout_eq_x!(command, bytes);
+/// # });
+/// // assertion failed: `assert_command_clean_env_stdout_eq_x!(command, expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_clean_env_stdout_eq_x.html
+/// //  command label: `command`,
+/// //  command debug: `env -i \"bin/printf-stdout\" \"%s\" \"alfa\"`,
+/// //    environment: `cleared`,
+/// //     expr label: `bytes`,
+/// //     expr debug: `[122, 122]`,
+/// //  command value: `[97, 108, 102, 97]`,
+/// //     expr value: `[122, 122]`,
+/// //        hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61              alfa      7a 7a                    zz
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_clean_env_stdout_eq_x!(command, expr)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_clean_env_stdout_eq_x.html\n",
+/// #     " command label: `command`,\n",
+/// #     " command debug: `env -i \"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+/// #     "   environment: `cleared`,\n",
+/// #     "    expr label: `bytes`,\n",
+/// #     "    expr debug: `[122, 122]`,\n",
+/// #     " command value: `[97, 108, 102, 97]`,\n",
+/// #     "    expr value: `[122, 122]`,\n",
+/// #     "       hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_clean_env_stdout_eq_x`](macro@crate::assert_command_clean_env_stdout_eq_x)
+/// * [`assert_command_clean_env_stdout_eq_x_as_result`](macro@crate::assert_command_clean_env_stdout_eq_x_as_result)
+/// * [`debug_assert_command_clean_env_stdout_eq_x`](macro@crate::debug_assert_command_clean_env_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_clean_env_stdout_eq_x {
+    ($command:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_command_clean_env_stdout_eq_x_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_clean_env_stdout_eq_x_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command with a cleared environment has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command with cleared environment ⇒ stdout) = expr
+///
+/// This macro provides the same statements as [`assert_command_clean_env_stdout_eq_x`](macro.assert_command_clean_env_stdout_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_clean_env_stdout_eq_x`](macro@crate::assert_command_clean_env_stdout_eq_x)
+/// * [`assert_command_clean_env_stdout_eq_x_as_result`](macro@crate::assert_command_clean_env_stdout_eq_x_as_result)
+/// * [`debug_assert_command_clean_env_stdout_eq_x`](macro@crate::debug_assert_command_clean_env_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_command_clean_env_stdout_eq_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_clean_env_stdout_eq_x!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,307 @@
+//! Assert a command merged stdout and stderr string contains a given containee.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout and stderr merged ⇒ string) contains (containee)
+//!
+//! This runs the command with its stderr redirected into its stdout, like the
+//! shell `2>&1`, so both streams go to one pipe, in the order in which the
+//! command wrote them. This is useful for a tool that interleaves diagnostics
+//! and output. The separate-stream macros, such as
+//! [`assert_command_stdout_string_contains`](macro@crate::assert_command_stdout_string_contains),
+//! capture each stream on its own, so they cannot test the interleaving.
+//!
+//! The command must be a `::std::process::Command`, not a captured output.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("sh");
+//! command.args(["-c", "printf alfa; printf bravo >&2"]);
+//! let containee = "alfabravo";
+//! assert_command_merged_output_contains!(command, containee);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_merged_output_contains`](macro@crate::assert_command_merged_output_contains)
+//! * [`assert_command_merged_output_contains_as_result`](macro@crate::assert_command_merged_output_contains_as_result)
+//! * [`debug_assert_command_merged_output_contains`](macro@crate::debug_assert_command_merged_output_contains)
+
+/// Assert a command merged stdout and stderr string contains a given containee.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout and stderr merged ⇒ string) contains (containee)
+///
+/// * If true, return Result `Ok(command ⇒ stdout and stderr merged ⇒ string)`.
+///
+/// * Otherwise, return Result `Err(message)` with the merged string.
+///
+/// The containee can be any type that implements `AsRef<str>`, such as
+/// `&str` or `String`.
+///
+/// The merged bytes are decoded as UTF-8, with any invalid sequence replaced
+/// by `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// The command reads stdin from null, like `::std::process::Command::output`,
+/// so a command that reads stdin, such as `cat`, does not wait for input.
+/// The macro mutates the command, and afterwards the command stdin is
+/// `Stdio::null()`, and the command stdout and stderr are `Stdio::piped()`,
+/// which are the defaults of `output()`.
+///
+/// This macro provides the same statements as [`assert_command_merged_output_contains`](macro.assert_command_merged_output_contains.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_merged_output_contains`](macro@crate::assert_command_merged_output_contains)
+/// * [`assert_command_merged_output_contains_as_result`](macro@crate::assert_command_merged_output_contains_as_result)
+/// * [`debug_assert_command_merged_output_contains`](macro@crate::debug_assert_command_merged_output_contains)
+///
+#[macro_export]
+macro_rules! assert_command_merged_output_contains_as_result {
+    ($command:expr, $containee:expr $(,)?) => {{
+        match (&mut $command, &$containee) {
+            (command, containee) => {
+                match $crate::assert_command::merged_output(command) {
+                    Ok(bytes) => {
+                        let string = String::from_utf8_lossy(&bytes).into_owned();
+                        if string.contains(AsRef::<str>::as_ref(containee)) {
+                            Ok(string)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_merged_output_contains!(command, containee)`\n",
+                                        "{}macro.assert_command_merged_output_contains.html\n",
+                                        "   command label: `{}`,\n",
+                                        "   command debug: `{:?}`,\n",
+                                        " containee label: `{}`,\n",
+                                        " containee debug: `{:?}`,\n",
+                                        "   merged output: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($command),
+                                    command,
+                                    stringify!($containee),
+                                    containee,
+                                    string
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_merged_output_contains!(command, containee)`\n",
+                                    "{}macro.assert_command_merged_output_contains.html\n",
+                                    "   command label: `{}`,\n",
+                                    "   command debug: `{:?}`,\n",
+                                    " containee label: `{}`,\n",
+                                    " containee debug: `{:?}`,\n",
+                                    "   output is err: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($command),
+                                command,
+                                stringify!($containee),
+                                containee,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "printf alfa; printf bravo >&2; printf charlie"]);
+        let containee = "alfabravocharlie";
+        let result = assert_command_merged_output_contains_as_result!(command, containee);
+        assert_eq!(result.unwrap(), "alfabravocharlie");
+    }
+
+    #[test]
+    fn success_with_stderr_only() {
+        let mut command = Command::new("bin/printf-stderr");
+        command.args(["%s", "alfa"]);
+        let containee = "lf";
+        let result = assert_command_merged_output_contains_as_result!(command, containee);
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn success_with_command_reused() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let result = assert_command_merged_output_contains_as_result!(command, "alfa");
+        assert_eq!(result.unwrap(), "alfa");
+        let output = command.output().unwrap();
+        assert_eq!(output.stdout, b"alfa");
+    }
+
+    #[test]
+    fn success_with_command_that_reads_stdin() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "cat; printf alfa"]);
+        let result = assert_command_merged_output_contains_as_result!(command, "alfa");
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "printf alfa; printf bravo >&2"]);
+        let containee = "zz";
+        let result = assert_command_merged_output_contains_as_result!(command, containee);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_merged_output_contains!(command, containee)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_merged_output_contains.html\n",
+                "   command label: `command`,\n",
+                "   command debug: `\"sh\" \"-c\" \"printf alfa; printf bravo >&2\"`,\n",
+                " containee label: `containee`,\n",
+                " containee debug: `\"zz\"`,\n",
+                "   merged output: `\"alfabravo\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_command_cannot_spawn() {
+        let mut command = Command::new("bin/no-such-program");
+        let result = assert_command_merged_output_contains_as_result!(command, "alfa");
+        assert!(result.unwrap_err().contains("   output is err: `"));
+    }
+}
+
+/// Assert a command merged stdout and stderr string contains a given containee.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout and stderr merged ⇒ string) contains (containee)
+///
+/// * If true, return (command ⇒ stdout and stderr merged ⇒ string).
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new("sh");
+/// command.args(["-c", "printf alfa; printf bravo >&2"]);
+/// let containee = "alfabravo";
+/// assert_command_merged_output_contains!(command, containee);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("sh");
+/// command.args(["-c", "printf alfa; printf bravo >&2"]);
+/// let containee = "zz";
+/// assert_command_merged_output_contains!(command, containee);
+/// # });
+/// // assertion failed: `assert_command_merged_output_contains!(command, containee)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_merged_output_contains.html
+/// //    command label: `command`,
+/// //    command debug: `\"sh\" \"-c\" \"printf alfa; printf bravo >&2\"`,
+/// //  containee label: `containee`,
+/// //  containee debug: `\"zz\"`,
+/// //    merged output: `\"alfabravo\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_merged_output_contains!(command, containee)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_merged_output_contains.html\n",
+/// #     "   command label: `command`,\n",
+/// #     "   command debug: `\"sh\" \"-c\" \"printf alfa; printf bravo >&2\"`,\n",
+/// #     " containee label: `containee`,\n",
+/// #     " containee debug: `\"zz\"`,\n",
+/// #     "   merged output: `\"alfabravo\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_merged_output_contains`](macro@crate::assert_command_merged_output_contains)
+/// * [`assert_command_merged_output_contains_as_result`](macro@crate::assert_command_merged_output_contains_as_result)
+/// * [`debug_assert_command_merged_output_contains`](macro@crate::debug_assert_command_merged_output_contains)
+///
+#[macro_export]
+macro_rules! assert_command_merged_output_contains {
+    ($command:expr, $containee:expr $(,)?) => {{
+        match $crate::assert_command_merged_output_contains_as_result!($command, $containee) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $containee:expr, $($message:tt)+) => {{
+        match $crate::assert_command_merged_output_contains_as_result!($command, $containee) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command merged stdout and stderr string contains a given containee.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout and stderr merged ⇒ string) contains (containee)
+///
+/// This macro provides the same statements as [`assert_command_merged_output_contains`](macro.assert_command_merged_output_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_merged_output_contains`](macro@crate::assert_command_merged_output_contains)
+/// * [`assert_command_merged_output_contains_as_result`](macro@crate::assert_command_merged_output_contains_as_result)
+/// * [`debug_assert_command_merged_output_contains`](macro@crate::debug_assert_command_merged_output_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_command_merged_output_contains {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_merged_output_contains!($($arg)*);
+        }
+    };
+}
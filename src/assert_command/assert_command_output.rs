@@ -0,0 +1,242 @@
+//! Assert a command runs and capture its output, so it can be reused.
+//!
+//! Pseudocode:<br>
+//! command.output() is Ok(output) ⇒ output
+//!
+//! Each command macro runs its command each time, so passing the same command
+//! to many macros runs it many times. Instead, run the command once with this
+//! macro, then pass the returned `::std::process::Output` to the command macros
+//! in place of the command, to assert on its stdout and stderr many times.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let output = assert_command_output!(command);
+//! assert_command_stdout_eq_x!(output, vec![b'a', b'l', b'f', b'a']);
+//! assert_command_stderr_eq_x!(output, Vec::<u8>::new());
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_output`](macro@crate::assert_command_output)
+//! * [`assert_command_output_as_result`](macro@crate::assert_command_output_as_result)
+//! * [`debug_assert_command_output`](macro@crate::debug_assert_command_output)
+
+/// Assert a command runs and capture its output, so it can be reused.
+///
+/// Pseudocode:<br>
+/// command.output() is Ok(output) ⇒ output
+///
+/// * If true, return Result `Ok(output)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_command_output`](macro.assert_command_output.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_output`](macro@crate::assert_command_output)
+/// * [`assert_command_output_as_result`](macro@crate::assert_command_output_as_result)
+/// * [`debug_assert_command_output`](macro@crate::debug_assert_command_output)
+///
+#[macro_export]
+macro_rules! assert_command_output_as_result {
+    ($command:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::AssertCommandOnce as _;
+        match $command.output_once() {
+            (_, Ok(output)) => Ok(output),
+            (command, Err(err)) => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_command_output!(command)`\n",
+                            "{}macro.assert_command_output.html\n",
+                            " command label: `{}`,\n",
+                            " command debug: `{:?}`,\n",
+                            "    output err: `{:?}`",
+                        ),
+                        $crate::DOC_URL_BASE,
+                        stringify!($command),
+                        command,
+                        err
+                    )
+                )
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let result = assert_command_output_as_result!(command);
+        let output = result.unwrap();
+        assert_eq!(output.stdout, vec![b'a', b'l', b'f', b'a']);
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/no-such-program");
+        let result = assert_command_output_as_result!(command);
+        let actual = result.unwrap_err();
+        assert!(actual.starts_with(concat!(
+            "assertion failed: `assert_command_output!(command)`\n",
+            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_output.html\n",
+            " command label: `command`,\n",
+            " command debug: `\"bin/no-such-program\"`,\n",
+            "    output err: `",
+        )));
+    }
+
+    #[test]
+    fn reuse_output_runs_command_once() {
+        let path = std::env::temp_dir().join(format!(
+            "assertables-assert_command_output-{}.txt",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let mut command = Command::new("sh");
+        command.arg("-c").arg(format!("printf x >> '{}'; printf alfa", path.display()));
+        let output = assert_command_output_as_result!(command).unwrap();
+        assert_eq!(
+            crate::assert_command_stdout_eq_x_as_result!(output, vec![b'a', b'l', b'f', b'a']).unwrap(),
+            vec![b'a', b'l', b'f', b'a']
+        );
+        assert_eq!(
+            crate::assert_command_stdout_string_contains_as_result!(output, "lf").unwrap(),
+            "alfa"
+        );
+        assert_eq!(
+            crate::assert_command_stderr_eq_x_as_result!(output, Vec::<u8>::new()).unwrap(),
+            Vec::<u8>::new()
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "x");
+        let _ = std::fs::remove_file(&path);
+    }
+}
+
+/// Assert a command runs and capture its output, so it can be reused.
+///
+/// Pseudocode:<br>
+/// command.output() is Ok(output) ⇒ output
+///
+/// * If true, return `output`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let output = assert_command_output!(command);
+/// assert_command_stdout_eq_x!(output, vec![b'a', b'l', b'f', b'a']);
+/// assert_command_stderr_eq_x!(output, Vec::<u8>::new());
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/no-such-program");
+/// assert_command_output!(command);
+/// # });
+/// // assertion failed: `assert_command_output!(command)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_output.html
+/// //  command label: `command`,
+/// //  command debug: `\"bin/no-such-program\"`,
+/// //     output err: `Os { code: 2, kind: NotFound, message: \"No such file or directory\" }`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_output!(command)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_output.html\n",
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"bin/no-such-program\"`,\n",
+/// #     "    output err: `",
+/// # );
+/// # assert!(actual.starts_with(expect));
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_output`](macro@crate::assert_command_output)
+/// * [`assert_command_output_as_result`](macro@crate::assert_command_output_as_result)
+/// * [`debug_assert_command_output`](macro@crate::debug_assert_command_output)
+///
+#[macro_export]
+macro_rules! assert_command_output {
+    ($command:expr $(,)?) => {{
+        match $crate::assert_command_output_as_result!($command) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $($message:tt)+) => {{
+        match $crate::assert_command_output_as_result!($command) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command runs and capture its output, so it can be reused.
+///
+/// Pseudocode:<br>
+/// command.output() is Ok(output) ⇒ output
+///
+/// This macro provides the same statements as [`assert_command_output`](macro.assert_command_output.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_output`](macro@crate::assert_command_output)
+/// * [`assert_command_output_as_result`](macro@crate::assert_command_output_as_result)
+/// * [`debug_assert_command_output`](macro@crate::debug_assert_command_output)
+///
+#[macro_export]
+macro_rules! debug_assert_command_output {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_output!($($arg)*);
+        }
+    };
+}
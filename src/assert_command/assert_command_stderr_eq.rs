@@ -29,7 +29,7 @@
 /// Pseudocode:<br>
 /// (command1 ⇒ stderr) = (command2 ⇒ stderr)
 ///
-/// * If true, return Result `Ok(stderr)`.
+/// * If true, return Result `Ok((a_stderr, b_stderr))`.
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
@@ -48,56 +48,66 @@
 #[macro_export]
 macro_rules! assert_command_stderr_eq_as_result {
     ($a_command:expr, $b_command:expr $(,)?) => {{
-        match ($a_command.output(), $b_command.output()) {
-            (Ok(a), Ok(b)) => {
-                let a = a.stderr;
-                let b = b.stderr;
-                if a.eq(&b) {
-                    Ok((a, b))
-                } else {
-                    Err(
-                        format!(
-                            concat!(
-                                "assertion failed: `assert_command_stderr_eq!(a_command, b_command)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_eq.html\n",
-                                " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
-                                " b label: `{}`,\n",
-                                " b debug: `{:?}`,\n",
-                                "       a: `{:?}`,\n",
-                                "       b: `{:?}`"
-                            ),
-                            stringify!($a_command),
-                            $a_command,
-                            stringify!($b_command),
-                            $b_command,
-                            a,
-                            b
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match ($a_command.output_once(), $b_command.output_once()) {
+            ((a_command, a_command_output), (b_command, b_command_output)) => {
+                match (a_command_output, b_command_output) {
+                    (Ok(a), Ok(b)) => {
+                        let a = a.stderr;
+                        let b = b.stderr;
+                        if a.eq(&b) {
+                            Ok((a, b))
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_stderr_eq!(a_command, b_command)`\n",
+                                        "{}macro.assert_command_stderr_eq.html\n",
+                                        " a label: `{}`,\n",
+                                        " a debug: `{:?}`,\n",
+                                        " b label: `{}`,\n",
+                                        " b debug: `{:?}`,\n",
+                                        "       a: `{:?}`,\n",
+                                        "       b: `{:?}`,\n",
+                                        " hexdump:\n{}"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a_command),
+                                    a_command,
+                                    stringify!($b_command),
+                                    b_command,
+                                    a,
+                                    b,
+                                    $crate::hexdump::hexdump_diff(&a, &b)
+                                )
+                            )
+                        }
+                    },
+                    (a, b) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stderr_eq!(a_command, b_command)`\n",
+                                    "{}macro.assert_command_stderr_eq.html\n",
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " b label: `{}`,\n",
+                                    " b debug: `{:?}`,\n",
+                                    "       a: `{:?}`,\n",
+                                    "       b: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a_command),
+                                a_command,
+                                stringify!($b_command),
+                                b_command,
+                                a,
+                                b
+                            )
                         )
-                    )
+                    }
                 }
-            },
-            (a, b) => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_command_stderr_eq!(a_command, b_command)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_eq.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`,\n",
-                            "       a: `{:?}`,\n",
-                            "       b: `{:?}`"
-                        ),
-                        stringify!($a_command),
-                        $a_command,
-                        stringify!($b_command),
-                        $b_command,
-                        a,
-                        b
-                    )
-                )
             }
         }
     }};
@@ -137,7 +147,10 @@ mod tests {
             " b label: `b`,\n",
             " b debug: `\"bin/printf-stderr\" \"%s\" \"zz\"`,\n",
             "       a: `[97, 108, 102, 97]`,\n",
-            "       b: `[122, 122]`"
+            "       b: `[122, 122]`,\n",
+            " hexdump:\n",
+            " offset    a hex                    a ascii   b hex                    b ascii\n",
+            ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
         );
         assert_eq!(actual, expect);
     }
@@ -158,7 +171,10 @@ mod tests {
             " b label: `b`,\n",
             " b debug: `\"bin/printf-stderr\" \"%s\" \"aa\"`,\n",
             "       a: `[97, 108, 102, 97]`,\n",
-            "       b: `[97, 97]`"
+            "       b: `[97, 97]`,\n",
+            " hexdump:\n",
+            " offset    a hex                    a ascii   b hex                    b ascii\n",
+            ">00000000  61 6c 66 61              alfa      61 61                    aa"
         );
         assert_eq!(actual, expect);
     }
@@ -169,7 +185,7 @@ mod tests {
 /// Pseudocode:<br>
 /// (command1 ⇒ stderr) = (command2 ⇒ stderr)
 ///
-/// * If true, return `()`.
+/// * If true, return `(a_stderr, b_stderr)`.
 ///
 /// * Otherwise, call [`panic!`] with a message and the values of the
 ///   expressions with their debug representations.
@@ -203,7 +219,10 @@ mod tests {
 /// //  b label: `b`,
 /// //  b debug: `\"bin/printf-stderr\" \"%s\" \"zz\"`,
 /// //        a: `[97, 108, 102, 97]`,
-/// //        b: `[122, 122]`
+/// //        b: `[122, 122]`,
+/// //  hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61              alfa      7a 7a                    zz
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let expect = concat!(
 /// #     "assertion failed: `assert_command_stderr_eq!(a_command, b_command)`\n",
@@ -213,7 +232,10 @@ mod tests {
 /// #     " b label: `b`,\n",
 /// #     " b debug: `\"bin/printf-stderr\" \"%s\" \"zz\"`,\n",
 /// #     "       a: `[97, 108, 102, 97]`,\n",
-/// #     "       b: `[122, 122]`"
+/// #     "       b: `[122, 122]`,\n",
+/// #     " hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
 /// # );
 /// # assert_eq!(actual, expect);
 /// # }
@@ -47,55 +47,65 @@
 #[macro_export]
 macro_rules! assert_command_stderr_eq_x_as_result {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
-        match (/*&$command,*/ &$b_expr) {
-            b => {
-                match $a_command.output() {
-                    Ok(a) => {
-                        let a = a.stderr;
-                        if a.eq(&$b_expr) {
-                            Ok(a)
-                        } else {
-                            Err(
-                                format!(
-                                    concat!(
-                                        "assertion failed: `assert_command_stderr_eq_x!(command, expr)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_eq_x.html\n",
-                                        " command label: `{}`,\n",
-                                        " command debug: `{:?}`,\n",
-                                        "    expr label: `{}`,\n",
-                                        "    expr debug: `{:?}`,\n",
-                                        " command value: `{:?}`,\n",
-                                        "    expr value: `{:?}`"
-                                    ),
-                                    stringify!($a_command),
-                                    $a_command,
-                                    stringify!($b_expr),
-                                    $b_expr,
-                                    a,
-                                    b
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $a_command.output_once() {
+            (a_command, a_command_output) => {
+                match (/*&$command,*/ &$b_expr) {
+                    b => {
+                        match a_command_output {
+                            Ok(a) => {
+                                let a = a.stderr;
+                                if a.eq(&$b_expr) {
+                                    Ok(a)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stderr_eq_x!(command, expr)`\n",
+                                                "{}macro.assert_command_stderr_eq_x.html\n",
+                                                " command label: `{}`,\n",
+                                                " command debug: `{:?}`,\n",
+                                                "    expr label: `{}`,\n",
+                                                "    expr debug: `{:?}`,\n",
+                                                " command value: `{:?}`,\n",
+                                                "    expr value: `{:?}`,\n",
+                                                "       hexdump:\n{}"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($a_command),
+                                            a_command,
+                                            stringify!($b_expr),
+                                            $b_expr,
+                                            a,
+                                            b,
+                                            $crate::hexdump::hexdump_diff(&a, &b[..])
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stderr_eq_x!(command, expr)`\n",
+                                            "{}macro.assert_command_stderr_eq_x.html\n",
+                                            "  command label: `{}`,\n",
+                                            "  command debug: `{:?}`,\n",
+                                            "     expr label: `{}`,\n",
+                                            "     expr debug: `{:?}`,\n",
+                                            "  output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($a_command),
+                                        a_command,
+                                        stringify!($b_expr),
+                                        b,
+                                        err
+                                    )
                                 )
-                            )
+                            }
                         }
-                    },
-                    Err(err) => {
-                        Err(
-                            format!(
-                                concat!(
-                                    "assertion failed: `assert_command_stderr_eq_x!(command, expr)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_eq_x.html\n",
-                                    "  command label: `{}`,\n",
-                                    "  command debug: `{:?}`,\n",
-                                    "     expr label: `{}`,\n",
-                                    "     expr debug: `{:?}`,\n",
-                                    "  output is err: `{:?}`"
-                                ),
-                                stringify!($a_command),
-                                $a_command,
-                                stringify!($b_expr),
-                                b,
-                                err
-                            )
-                        )
                     }
                 }
             }
@@ -132,7 +142,10 @@ mod tests {
             "    expr label: `b`,\n",
             "    expr debug: `[97, 97]`,\n",
             " command value: `[97, 108, 102, 97]`,\n",
-            "    expr value: `[97, 97]`"
+            "    expr value: `[97, 97]`,\n",
+            "       hexdump:\n",
+            " offset    a hex                    a ascii   b hex                    b ascii\n",
+            ">00000000  61 6c 66 61              alfa      61 61                    aa"
         );
         assert_eq!(actual, expect);
     }
@@ -152,7 +165,10 @@ mod tests {
             "    expr label: `b`,\n",
             "    expr debug: `[122, 122]`,\n",
             " command value: `[97, 108, 102, 97]`,\n",
-            "    expr value: `[122, 122]`"
+            "    expr value: `[122, 122]`,\n",
+            "       hexdump:\n",
+            " offset    a hex                    a ascii   b hex                    b ascii\n",
+            ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
         );
         assert_eq!(actual, expect);
     }
@@ -195,7 +211,10 @@ mod tests {
 /// //     expr label: `bytes`,
 /// //     expr debug: `[122, 122]`,
 /// //  command value: `[97, 108, 102, 97]`,
-/// //     expr value: `[122, 122]`
+/// //     expr value: `[122, 122]`,
+/// //        hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61              alfa      7a 7a                    zz
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let expect = concat!(
 /// #     "assertion failed: `assert_command_stderr_eq_x!(command, expr)`\n",
@@ -205,7 +224,10 @@ mod tests {
 /// #     "    expr label: `bytes`,\n",
 /// #     "    expr debug: `[122, 122]`,\n",
 /// #     " command value: `[97, 108, 102, 97]`,\n",
-/// #     "    expr value: `[122, 122]`"
+/// #     "    expr value: `[122, 122]`,\n",
+/// #     "       hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
 /// # );
 /// # assert_eq!(actual, expect);
 /// # }
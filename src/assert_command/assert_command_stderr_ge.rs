@@ -29,7 +29,7 @@
 /// Pseudocode:<br>
 /// (command1 ⇒ stderr) = (command2 ⇒ stderr)
 ///
-/// * If true, return Result `Ok(stderr)`.
+/// * If true, return Result `Ok((a_stderr, b_stderr))`.
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
@@ -48,56 +48,64 @@
 #[macro_export]
 macro_rules! assert_command_stderr_ge_as_result {
     ($a_command:expr, $b_command:expr $(,)?) => {{
-        match ($a_command.output(), $b_command.output()) {
-            (Ok(a), Ok(b)) => {
-                let a = a.stderr;
-                let b = b.stderr;
-                if a.ge(&b) {
-                    Ok((a, b))
-                } else {
-                    Err(
-                        format!(
-                            concat!(
-                                "assertion failed: `assert_command_stderr_ge!(a_command, b_command)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_ge.html\n",
-                                " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
-                                " b label: `{}`,\n",
-                                " b debug: `{:?}`,\n",
-                                "       a: `{:?}`,\n",
-                                "       b: `{:?}`"
-                            ),
-                            stringify!($a_command),
-                            $a_command,
-                            stringify!($b_command),
-                            $b_command,
-                            a,
-                            b
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match ($a_command.output_once(), $b_command.output_once()) {
+            ((a_command, a_command_output), (b_command, b_command_output)) => {
+                match (a_command_output, b_command_output) {
+                    (Ok(a), Ok(b)) => {
+                        let a = a.stderr;
+                        let b = b.stderr;
+                        if a.ge(&b) {
+                            Ok((a, b))
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_stderr_ge!(a_command, b_command)`\n",
+                                        "{}macro.assert_command_stderr_ge.html\n",
+                                        " a label: `{}`,\n",
+                                        " a debug: `{:?}`,\n",
+                                        " b label: `{}`,\n",
+                                        " b debug: `{:?}`,\n",
+                                        "       a: `{:?}`,\n",
+                                        "       b: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a_command),
+                                    a_command,
+                                    stringify!($b_command),
+                                    b_command,
+                                    a,
+                                    b
+                                )
+                            )
+                        }
+                    },
+                    (a, b) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stderr_ge!(a_command, b_command)`\n",
+                                    "{}macro.assert_command_stderr_ge.html\n",
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " b label: `{}`,\n",
+                                    " b debug: `{:?}`,\n",
+                                    "       a: `{:?}`,\n",
+                                    "       b: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a_command),
+                                a_command,
+                                stringify!($b_command),
+                                b_command,
+                                a,
+                                b
+                            )
                         )
-                    )
+                    }
                 }
-            },
-            (a, b) => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_command_stderr_ge!(a_command, b_command)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_ge.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`,\n",
-                            "       a: `{:?}`,\n",
-                            "       b: `{:?}`"
-                        ),
-                        stringify!($a_command),
-                        $a_command,
-                        stringify!($b_command),
-                        $b_command,
-                        a,
-                        b
-                    )
-                )
             }
         }
     }};
@@ -161,7 +169,7 @@ mod tests {
 /// Pseudocode:<br>
 /// (command1 ⇒ stderr) = (command2 ⇒ stderr)
 ///
-/// * If true, return `()`.
+/// * If true, return `(a_stderr, b_stderr)`.
 ///
 /// * Otherwise, call [`panic!`] with a message and the values of the
 ///   expressions with their debug representations.
@@ -47,55 +47,63 @@
 #[macro_export]
 macro_rules! assert_command_stderr_ge_x_as_result {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
-        match (/*&$command,*/ &$b_expr) {
-            b => {
-                match $a_command.output() {
-                    Ok(a) => {
-                        let a = a.stderr;
-                        if a.ge(&$b_expr) {
-                            Ok(a)
-                        } else {
-                            Err(
-                                format!(
-                                    concat!(
-                                        "assertion failed: `assert_command_stderr_ge_x!(command, expr)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_ge_x.html\n",
-                                        " command label: `{}`,\n",
-                                        " command debug: `{:?}`,\n",
-                                        "    expr label: `{}`,\n",
-                                        "    expr debug: `{:?}`,\n",
-                                        " command value: `{:?}`,\n",
-                                        "    expr value: `{:?}`"
-                                    ),
-                                    stringify!($a_command),
-                                    $a_command,
-                                    stringify!($b_expr),
-                                    $b_expr,
-                                    a,
-                                    b
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $a_command.output_once() {
+            (a_command, a_command_output) => {
+                match (/*&$command,*/ &$b_expr) {
+                    b => {
+                        match a_command_output {
+                            Ok(a) => {
+                                let a = a.stderr;
+                                if a.ge(&$b_expr) {
+                                    Ok(a)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stderr_ge_x!(command, expr)`\n",
+                                                "{}macro.assert_command_stderr_ge_x.html\n",
+                                                " command label: `{}`,\n",
+                                                " command debug: `{:?}`,\n",
+                                                "    expr label: `{}`,\n",
+                                                "    expr debug: `{:?}`,\n",
+                                                " command value: `{:?}`,\n",
+                                                "    expr value: `{:?}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($a_command),
+                                            a_command,
+                                            stringify!($b_expr),
+                                            $b_expr,
+                                            a,
+                                            b
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stderr_ge_x!(command, expr)`\n",
+                                            "{}macro.assert_command_stderr_ge_x.html\n",
+                                            "  command label: `{}`,\n",
+                                            "  command debug: `{:?}`,\n",
+                                            "     expr label: `{}`,\n",
+                                            "     expr debug: `{:?}`,\n",
+                                            "  output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($a_command),
+                                        a_command,
+                                        stringify!($b_expr),
+                                        b,
+                                        err
+                                    )
                                 )
-                            )
+                            }
                         }
-                    },
-                    Err(err) => {
-                        Err(
-                            format!(
-                                concat!(
-                                    "assertion failed: `assert_command_stderr_ge_x!(command, expr)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_ge_x.html\n",
-                                    "  command label: `{}`,\n",
-                                    "  command debug: `{:?}`,\n",
-                                    "     expr label: `{}`,\n",
-                                    "     expr debug: `{:?}`,\n",
-                                    "  output is err: `{:?}`"
-                                ),
-                                stringify!($a_command),
-                                $a_command,
-                                stringify!($b_expr),
-                                b,
-                                err
-                            )
-                        )
                     }
                 }
             }
@@ -0,0 +1,272 @@
+//! Assert a command stderr string line count is greater than an expression.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stderr ⇒ string ⇒ lines ⇒ count) > n
+//!
+//! This runs the command once, decodes its stderr as UTF-8, then counts the
+//! lines. This is useful to bound the number of warning lines that a tool
+//! writes to stderr.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stderr");
+//! command.args(["%s", "alfa\nbravo\n"]);
+//! assert_command_stderr_line_count_gt!(command, 1);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stderr_line_count_gt`](macro@crate::assert_command_stderr_line_count_gt)
+//! * [`assert_command_stderr_line_count_gt_as_result`](macro@crate::assert_command_stderr_line_count_gt_as_result)
+//! * [`debug_assert_command_stderr_line_count_gt`](macro@crate::debug_assert_command_stderr_line_count_gt)
+
+/// Assert a command stderr string line count is greater than an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stderr ⇒ string ⇒ lines ⇒ count) > n
+///
+/// * If true, return Result `Ok(count)`.
+///
+/// * Otherwise, return Result `Err(message)` with the count and the first lines of stderr.
+///
+/// The stderr is decoded as UTF-8, with any invalid sequence replaced by
+/// `U+FFFD REPLACEMENT CHARACTER`. The lines are counted like `str::lines`,
+/// so a final line ending does not add an empty line, and an empty stderr
+/// has zero lines.
+///
+/// This macro provides the same statements as [`assert_command_stderr_line_count_gt`](macro.assert_command_stderr_line_count_gt.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stderr_line_count_gt`](macro@crate::assert_command_stderr_line_count_gt)
+/// * [`assert_command_stderr_line_count_gt_as_result`](macro@crate::assert_command_stderr_line_count_gt_as_result)
+/// * [`debug_assert_command_stderr_line_count_gt`](macro@crate::debug_assert_command_stderr_line_count_gt)
+///
+#[macro_export]
+macro_rules! assert_command_stderr_line_count_gt_as_result {
+    ($command:expr, $n:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (&$n) {
+                    n => {
+                        match command_output {
+                            Ok(output) => {
+                                let string = String::from_utf8_lossy(&output.stderr).into_owned();
+                                let count = string.lines().count();
+                                if count > *n {
+                                    Ok(count)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stderr_line_count_gt!(command, n)`\n",
+                                                "{}macro.assert_command_stderr_line_count_gt.html\n",
+                                                "      command label: `{}`,\n",
+                                                "      command debug: `{:?}`,\n",
+                                                "            n label: `{}`,\n",
+                                                "            n debug: `{:?}`,\n",
+                                                "  stderr line count: `{}`,\n",
+                                                " stderr first lines: `{:?}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($n),
+                                            n,
+                                            count,
+                                            string.lines().take(3).collect::<Vec<&str>>()
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stderr_line_count_gt!(command, n)`\n",
+                                            "{}macro.assert_command_stderr_line_count_gt.html\n",
+                                            "      command label: `{}`,\n",
+                                            "      command debug: `{:?}`,\n",
+                                            "            n label: `{}`,\n",
+                                            "            n debug: `{:?}`,\n",
+                                            "      output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($n),
+                                        n,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stderr");
+        command.args(["%s", "alfa\nbravo\n"]);
+        let n = 1;
+        let result = assert_command_stderr_line_count_gt_as_result!(command, n);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stderr");
+        command.args(["%s", "alfa\nbravo\n"]);
+        let n = 2;
+        let result = assert_command_stderr_line_count_gt_as_result!(command, n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stderr_line_count_gt!(command, n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_line_count_gt.html\n",
+                "      command label: `command`,\n",
+                "      command debug: `\"bin/printf-stderr\" \"%s\" \"alfa\\nbravo\\n\"`,\n",
+                "            n label: `n`,\n",
+                "            n debug: `2`,\n",
+                "  stderr line count: `2`,\n",
+                " stderr first lines: `[\"alfa\", \"bravo\"]`"
+            )
+        );
+    }
+}
+
+/// Assert a command stderr string line count is greater than an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stderr ⇒ string ⇒ lines ⇒ count) > n
+///
+/// * If true, return `count`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stderr");
+/// command.args(["%s", "alfa\nbravo\n"]);
+/// let n = 1;
+/// assert_command_stderr_line_count_gt!(command, n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stderr");
+/// command.args(["%s", "alfa\nbravo\n"]);
+/// let n = 2;
+/// assert_command_stderr_line_count_gt!(command, n);
+/// # });
+/// // assertion failed: `assert_command_stderr_line_count_gt!(command, n)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_line_count_gt.html
+/// //       command label: `command`,
+/// //       command debug: `\"bin/printf-stderr\" \"%s\" \"alfa\nbravo\n\"`,
+/// //             n label: `n`,
+/// //             n debug: `2`,
+/// //   stderr line count: `2`,
+/// //  stderr first lines: `[\"alfa\", \"bravo\"]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stderr_line_count_gt!(command, n)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_line_count_gt.html\n",
+/// #     "      command label: `command`,\n",
+/// #     "      command debug: `\"bin/printf-stderr\" \"%s\" \"alfa\\nbravo\\n\"`,\n",
+/// #     "            n label: `n`,\n",
+/// #     "            n debug: `2`,\n",
+/// #     "  stderr line count: `2`,\n",
+/// #     " stderr first lines: `[\"alfa\", \"bravo\"]`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stderr_line_count_gt`](macro@crate::assert_command_stderr_line_count_gt)
+/// * [`assert_command_stderr_line_count_gt_as_result`](macro@crate::assert_command_stderr_line_count_gt_as_result)
+/// * [`debug_assert_command_stderr_line_count_gt`](macro@crate::debug_assert_command_stderr_line_count_gt)
+///
+#[macro_export]
+macro_rules! assert_command_stderr_line_count_gt {
+    ($command:expr, $n:expr $(,)?) => {{
+        match $crate::assert_command_stderr_line_count_gt_as_result!($command, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stderr_line_count_gt_as_result!($command, $n) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command stderr string line count is greater than an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stderr ⇒ string ⇒ lines ⇒ count) > n
+///
+/// This macro provides the same statements as [`assert_command_stderr_line_count_gt`](macro.assert_command_stderr_line_count_gt.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stderr_line_count_gt`](macro@crate::assert_command_stderr_line_count_gt)
+/// * [`assert_command_stderr_line_count_gt_as_result`](macro@crate::assert_command_stderr_line_count_gt_as_result)
+/// * [`debug_assert_command_stderr_line_count_gt`](macro@crate::debug_assert_command_stderr_line_count_gt)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stderr_line_count_gt {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stderr_line_count_gt!($($arg)*);
+        }
+    };
+}
@@ -47,53 +47,61 @@
 #[macro_export]
 macro_rules! assert_command_stderr_string_contains_as_result {
     ($command:expr, $containee:expr $(,)?) => {{
-        match (/*&$command,*/ &$containee) {
-            containee => {
-                match $command.output() {
-                    Ok(output) => {
-                        let string = String::from_utf8(output.stderr).unwrap();
-                        if string.contains($containee) {
-                            Ok(string)
-                        } else {
-                            Err(
-                                format!(
-                                    concat!(
-                                        "assertion failed: `assert_command_stderr_string_contains!(command, containee)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_string_contains.html\n",
-                                        "   command label: `{}`,\n",
-                                        "   command debug: `{:?}`,\n",
-                                        " containee label: `{}`,\n",
-                                        " containee debug: `{:?}`,\n",
-                                        "          string: `{:?}`"
-                                    ),
-                                    stringify!($command),
-                                    $command,
-                                    stringify!($containee),
-                                    containee,
-                                    string
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (/*&$command,*/ &$containee) {
+                    containee => {
+                        match command_output {
+                            Ok(output) => {
+                                let string = String::from_utf8(output.stderr).unwrap();
+                                if string.contains($containee) {
+                                    Ok(string)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stderr_string_contains!(command, containee)`\n",
+                                                "{}macro.assert_command_stderr_string_contains.html\n",
+                                                "   command label: `{}`,\n",
+                                                "   command debug: `{:?}`,\n",
+                                                " containee label: `{}`,\n",
+                                                " containee debug: `{:?}`,\n",
+                                                "          string: `{:?}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($containee),
+                                            containee,
+                                            string
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stderr_string_contains!(command, containee)`\n",
+                                            "{}macro.assert_command_stderr_string_contains.html\n",
+                                            "   command label: `{}`,\n",
+                                            "   command debug: `{:?}`,\n",
+                                            " containee label: `{}`,\n",
+                                            " containee debug: `{:?}`,\n",
+                                            "      output err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($containee),
+                                        containee,
+                                        err
+                                    )
                                 )
-                            )
+                            }
                         }
-                    },
-                    Err(err) => {
-                        Err(
-                            format!(
-                                concat!(
-                                    "assertion failed: `assert_command_stderr_string_contains!(command, containee)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_string_contains.html\n",
-                                    "   command label: `{}`,\n",
-                                    "   command debug: `{:?}`,\n",
-                                    " containee label: `{}`,\n",
-                                    " containee debug: `{:?}`,\n",
-                                    "      output err: `{:?}`"
-                                ),
-                                stringify!($command),
-                                $command,
-                                stringify!($containee),
-                                containee,
-                                err
-                            )
-                        )
                     }
                 }
             }
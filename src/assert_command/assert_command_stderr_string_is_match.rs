@@ -48,55 +48,63 @@
 #[macro_export]
 macro_rules! assert_command_stderr_string_is_match_as_result {
     ($command:expr, $matcher:expr $(,)?) => {{
-        match (/*&$command,*/ &$matcher) {
-            matcher => {
-                match $command.output() {
-                    Ok(output) => {
-                        let string = String::from_utf8(output.stderr).unwrap();
-                        if $matcher.is_match(&string) {
-                            Ok(string)
-                        } else {
-                            Err(
-                                format!(
-                                    concat!(
-                                        "assertion failed: `assert_command_stderr_string_is_match!(command, matcher)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_string_is_match.html\n",
-                                        " command label: `{}`,\n",
-                                        " command debug: `{:?}`,\n",
-                                        " matcher label: `{}`,\n",
-                                        " matcher debug: `{:?}`,\n",
-                                        " command value: `{:?}`,\n",
-                                        " matcher value: `{:?}`"
-                                    ),
-                                    stringify!($command),
-                                    $command,
-                                    stringify!($matcher),
-                                    matcher,
-                                    string,
-                                    matcher
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (/*&$command,*/ &$matcher) {
+                    matcher => {
+                        match command_output {
+                            Ok(output) => {
+                                let string = String::from_utf8(output.stderr).unwrap();
+                                if $matcher.is_match(&string) {
+                                    Ok(string)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stderr_string_is_match!(command, matcher)`\n",
+                                                "{}macro.assert_command_stderr_string_is_match.html\n",
+                                                " command label: `{}`,\n",
+                                                " command debug: `{:?}`,\n",
+                                                " matcher label: `{}`,\n",
+                                                " matcher debug: `{:?}`,\n",
+                                                " command value: `{:?}`,\n",
+                                                " matcher value: `{:?}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($matcher),
+                                            matcher,
+                                            string,
+                                            matcher
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stderr_string_is_match!(command, matcher)`\n",
+                                            "{}macro.assert_command_stderr_string_is_match.html\n",
+                                            "  command label: `{}`,\n",
+                                            "  command debug: `{:?}`,\n",
+                                            "  matcher label: `{}`,\n",
+                                            "  matcher debug: `{:?}`,\n",
+                                            "  output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($matcher),
+                                        matcher,
+                                        err
+                                    )
                                 )
-                            )
+                            }
                         }
-                    },
-                    Err(err) => {
-                        Err(
-                            format!(
-                                concat!(
-                                    "assertion failed: `assert_command_stderr_string_is_match!(command, matcher)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stderr_string_is_match.html\n",
-                                    "  command label: `{}`,\n",
-                                    "  command debug: `{:?}`,\n",
-                                    "  matcher label: `{}`,\n",
-                                    "  matcher debug: `{:?}`,\n",
-                                    "  output is err: `{:?}`"
-                                ),
-                                stringify!($command),
-                                $command,
-                                stringify!($matcher),
-                                matcher,
-                                err
-                            )
-                        )
                     }
                 }
             }
@@ -0,0 +1,368 @@
+//! Assert a command with stdin from a file has stdout equal to an expression.
+//!
+//! Pseudocode:<br>
+//! command with stdin file ⇒ stdout = expr
+//!
+//! This opens the file at `stdin_path`, and passes the file to the command
+//! as its standard input, so the file is not loaded into memory. This is
+//! useful for testing a filter program with fixture input files.
+//!
+//! The failure message shows the stdin path, and distinguishes a file that
+//! cannot open from a command that cannot run.
+//!
+//! The macro mutates the command, because the standard library has no way to
+//! read or restore the stdin of a command. After the command runs, the macro
+//! sets the command stdin to `Stdio::null()`, so the command does not keep the
+//! file open, and a later run of the command gets an empty stdin.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("cat");
+//! let stdin_path = "alfa.txt";
+//! let expr = vec![b'a', b'l', b'f', b'a', b'\n'];
+//! assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdin_file_stdout_eq_x`](macro@crate::assert_command_stdin_file_stdout_eq_x)
+//! * [`assert_command_stdin_file_stdout_eq_x_as_result`](macro@crate::assert_command_stdin_file_stdout_eq_x_as_result)
+//! * [`debug_assert_command_stdin_file_stdout_eq_x`](macro@crate::debug_assert_command_stdin_file_stdout_eq_x)
+
+/// Assert a command with stdin from a file has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// command with stdin file ⇒ stdout = expr
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * If the stdin path cannot open, return Result `Err(message)` with the open error.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_command_stdin_file_stdout_eq_x`](macro.assert_command_stdin_file_stdout_eq_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdin_file_stdout_eq_x`](macro@crate::assert_command_stdin_file_stdout_eq_x)
+/// * [`assert_command_stdin_file_stdout_eq_x_as_result`](macro@crate::assert_command_stdin_file_stdout_eq_x_as_result)
+/// * [`debug_assert_command_stdin_file_stdout_eq_x`](macro@crate::debug_assert_command_stdin_file_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdin_file_stdout_eq_x_as_result {
+    ($command:expr, $stdin_path:expr, $expr:expr $(,)?) => {{
+        match (&mut $command, &$stdin_path, &$expr) {
+            (command, stdin_path, expr) => {
+                match ::std::fs::File::open(stdin_path) {
+                    Ok(stdin_file) => {
+                        let output = command.stdin(::std::process::Stdio::from(stdin_file)).output();
+                        command.stdin(::std::process::Stdio::null());
+                        match output {
+                            Ok(output) => {
+                                let a = output.stdout;
+                                if a.eq(expr) {
+                                    Ok(a)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr)`\n",
+                                                "{}macro.assert_command_stdin_file_stdout_eq_x.html\n",
+                                                "    command label: `{}`,\n",
+                                                "    command debug: `{:?}`,\n",
+                                                " stdin_path label: `{}`,\n",
+                                                " stdin_path debug: `{:?}`,\n",
+                                                "       expr label: `{}`,\n",
+                                                "       expr debug: `{:?}`,\n",
+                                                "    command value: `{:?}`,\n",
+                                                "       expr value: `{:?}`,\n",
+                                                "          hexdump:\n{}"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($stdin_path),
+                                            stdin_path,
+                                            stringify!($expr),
+                                            expr,
+                                            a,
+                                            expr,
+                                            $crate::hexdump::hexdump_diff(&a, &expr[..])
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr)`\n",
+                                            "{}macro.assert_command_stdin_file_stdout_eq_x.html\n",
+                                            "    command label: `{}`,\n",
+                                            "    command debug: `{:?}`,\n",
+                                            " stdin_path label: `{}`,\n",
+                                            " stdin_path debug: `{:?}`,\n",
+                                            "       expr label: `{}`,\n",
+                                            "       expr debug: `{:?}`,\n",
+                                            "    output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($stdin_path),
+                                        stdin_path,
+                                        stringify!($expr),
+                                        expr,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr)`\n",
+                                    "{}macro.assert_command_stdin_file_stdout_eq_x.html\n",
+                                    "    command label: `{}`,\n",
+                                    "    command debug: `{:?}`,\n",
+                                    " stdin_path label: `{}`,\n",
+                                    " stdin_path debug: `{:?}`,\n",
+                                    "       expr label: `{}`,\n",
+                                    "       expr debug: `{:?}`,\n",
+                                    "   stdin open err: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($command),
+                                command,
+                                stringify!($stdin_path),
+                                stdin_path,
+                                stringify!($expr),
+                                expr,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("cat");
+        let stdin_path = "alfa.txt";
+        let expr = vec![b'a', b'l', b'f', b'a', b'\n'];
+        let result = assert_command_stdin_file_stdout_eq_x_as_result!(command, stdin_path, expr);
+        assert_eq!(result.unwrap(), vec![b'a', b'l', b'f', b'a', b'\n']);
+    }
+
+    #[test]
+    fn success_then_command_stdin_is_null() {
+        let mut command = Command::new("cat");
+        let stdin_path = "alfa.txt";
+        let expr = vec![b'a', b'l', b'f', b'a', b'\n'];
+        let result = assert_command_stdin_file_stdout_eq_x_as_result!(command, stdin_path, expr);
+        assert!(result.is_ok());
+        let output = command.output().unwrap();
+        assert_eq!(output.stdout, Vec::<u8>::new());
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("cat");
+        let stdin_path = "alfa.txt";
+        let expr = vec![b'b', b'r', b'a', b'v', b'o'];
+        let result = assert_command_stdin_file_stdout_eq_x_as_result!(command, stdin_path, expr);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdin_file_stdout_eq_x.html\n",
+                "    command label: `command`,\n",
+                "    command debug: `\"cat\"`,\n",
+                " stdin_path label: `stdin_path`,\n",
+                " stdin_path debug: `\"alfa.txt\"`,\n",
+                "       expr label: `expr`,\n",
+                "       expr debug: `[98, 114, 97, 118, 111]`,\n",
+                "    command value: `[97, 108, 102, 97, 10]`,\n",
+                "       expr value: `[98, 114, 97, 118, 111]`,\n",
+                "          hexdump:\n",
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                ">00000000  61 6c 66 61 0a           alfa.     62 72 61 76 6f           bravo"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_stdin_path_cannot_open() {
+        let mut command = Command::new("cat");
+        let stdin_path = "no-such-file.txt";
+        let expr = vec![b'a'];
+        let result = assert_command_stdin_file_stdout_eq_x_as_result!(command, stdin_path, expr);
+        let actual = result.unwrap_err();
+        assert!(actual.starts_with(concat!(
+            "assertion failed: `assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr)`\n",
+            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdin_file_stdout_eq_x.html\n",
+            "    command label: `command`,\n",
+            "    command debug: `\"cat\"`,\n",
+            " stdin_path label: `stdin_path`,\n",
+            " stdin_path debug: `\"no-such-file.txt\"`,\n",
+            "       expr label: `expr`,\n",
+            "       expr debug: `[97]`,\n",
+            "   stdin open err: `"
+        )));
+    }
+
+    #[test]
+    fn failure_because_command_cannot_spawn() {
+        let mut command = Command::new("bin/no-such-program");
+        let stdin_path = "alfa.txt";
+        let expr = vec![b'a'];
+        let result = assert_command_stdin_file_stdout_eq_x_as_result!(command, stdin_path, expr);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("    output is err: `"));
+    }
+}
+
+/// Assert a command with stdin from a file has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// command with stdin file ⇒ stdout = expr
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new("cat");
+/// let stdin_path = "alfa.txt";
+/// let expr = vec![b'a', b'l', b'f', b'a', b'\n'];
+/// assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("cat");
+/// let stdin_path = "alfa.txt";
+/// let expr = vec![b'b', b'r', b'a', b'v', b'o'];
+/// assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr);
+/// # });
+/// // assertion failed: `assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdin_file_stdout_eq_x.html
+/// //     command label: `command`,
+/// //     command debug: `\"cat\"`,
+/// //  stdin_path label: `stdin_path`,
+/// //  stdin_path debug: `\"alfa.txt\"`,
+/// //        expr label: `expr`,
+/// //        expr debug: `[98, 114, 97, 118, 111]`,
+/// //     command value: `[97, 108, 102, 97, 10]`,
+/// //        expr value: `[98, 114, 97, 118, 111]`,
+/// //           hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61 0a           alfa.     62 72 61 76 6f           bravo
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdin_file_stdout_eq_x.html\n",
+/// #     "    command label: `command`,\n",
+/// #     "    command debug: `\"cat\"`,\n",
+/// #     " stdin_path label: `stdin_path`,\n",
+/// #     " stdin_path debug: `\"alfa.txt\"`,\n",
+/// #     "       expr label: `expr`,\n",
+/// #     "       expr debug: `[98, 114, 97, 118, 111]`,\n",
+/// #     "    command value: `[97, 108, 102, 97, 10]`,\n",
+/// #     "       expr value: `[98, 114, 97, 118, 111]`,\n",
+/// #     "          hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 61 0a           alfa.     62 72 61 76 6f           bravo"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdin_file_stdout_eq_x`](macro@crate::assert_command_stdin_file_stdout_eq_x)
+/// * [`assert_command_stdin_file_stdout_eq_x_as_result`](macro@crate::assert_command_stdin_file_stdout_eq_x_as_result)
+/// * [`debug_assert_command_stdin_file_stdout_eq_x`](macro@crate::debug_assert_command_stdin_file_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdin_file_stdout_eq_x {
+    ($command:expr, $stdin_path:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_command_stdin_file_stdout_eq_x_as_result!($command, $stdin_path, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $stdin_path:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdin_file_stdout_eq_x_as_result!($command, $stdin_path, $expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command with stdin from a file has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// command with stdin file ⇒ stdout = expr
+///
+/// This macro provides the same statements as [`assert_command_stdin_file_stdout_eq_x`](macro.assert_command_stdin_file_stdout_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdin_file_stdout_eq_x`](macro@crate::assert_command_stdin_file_stdout_eq_x)
+/// * [`assert_command_stdin_file_stdout_eq_x_as_result`](macro@crate::assert_command_stdin_file_stdout_eq_x_as_result)
+/// * [`debug_assert_command_stdin_file_stdout_eq_x`](macro@crate::debug_assert_command_stdin_file_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdin_file_stdout_eq_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdin_file_stdout_eq_x!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,308 @@
+//! Assert a command stdout and stderr are equal to another command stdout and stderr.
+//!
+//! Pseudocode:<br>
+//! (command1 ⇒ stdout) = (command2 ⇒ stdout) ∧ (command1 ⇒ stderr) = (command2 ⇒ stderr)
+//!
+//! This runs each command once, then compares both streams, so two commands
+//! need two runs, rather than the four runs of
+//! [`assert_command_stdout_eq`](macro@crate::assert_command_stdout_eq) plus
+//! [`assert_command_stderr_eq`](macro@crate::assert_command_stderr_eq).
+//! This is useful for checking that two commands are equivalent, such as an
+//! old tool and its replacement.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut a = Command::new("bin/printf-stdout");
+//! a.args(["%s", "alfa"]);
+//! let mut b = Command::new("bin/printf-stdout");
+//! b.args(["%s%s", "al", "fa"]);
+//! assert_command_stdout_and_stderr_eq!(a, b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_and_stderr_eq`](macro@crate::assert_command_stdout_and_stderr_eq)
+//! * [`assert_command_stdout_and_stderr_eq_as_result`](macro@crate::assert_command_stdout_and_stderr_eq_as_result)
+//! * [`debug_assert_command_stdout_and_stderr_eq`](macro@crate::debug_assert_command_stdout_and_stderr_eq)
+
+/// Assert a command stdout and stderr are equal to another command stdout and stderr.
+///
+/// Pseudocode:<br>
+/// (command1 ⇒ stdout) = (command2 ⇒ stdout) ∧ (command1 ⇒ stderr) = (command2 ⇒ stderr)
+///
+/// * If true, return Result `Ok((a_output, b_output))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message names the first diverging stream, checking stdout before
+/// stderr. Then the message has one section for stdout and one section for
+/// stderr: an equal stream says so, and a different stream shows the first
+/// differing byte offset and a hexdump.
+///
+/// This macro provides the same statements as [`assert_command_stdout_and_stderr_eq`](macro.assert_command_stdout_and_stderr_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_and_stderr_eq`](macro@crate::assert_command_stdout_and_stderr_eq)
+/// * [`assert_command_stdout_and_stderr_eq_as_result`](macro@crate::assert_command_stdout_and_stderr_eq_as_result)
+/// * [`debug_assert_command_stdout_and_stderr_eq`](macro@crate::debug_assert_command_stdout_and_stderr_eq)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_and_stderr_eq_as_result {
+    ($a_command:expr, $b_command:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match ($a_command.output_once(), $b_command.output_once()) {
+            ((a_command, a_command_output), (b_command, b_command_output)) => {
+                match (a_command_output, b_command_output) {
+                    (Ok(a), Ok(b)) => {
+                        let stdout_eq = a.stdout.eq(&b.stdout);
+                        let stderr_eq = a.stderr.eq(&b.stderr);
+                        if stdout_eq && stderr_eq {
+                            Ok((a, b))
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_stdout_and_stderr_eq!(a_command, b_command)`\n",
+                                        "{}macro.assert_command_stdout_and_stderr_eq.html\n",
+                                        "         a label: `{}`,\n",
+                                        "         a debug: `{:?}`,\n",
+                                        "         b label: `{}`,\n",
+                                        "         b debug: `{:?}`,\n",
+                                        " first diverging: `{}`,\n",
+                                        "{}\n",
+                                        "{}"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a_command),
+                                    a_command,
+                                    stringify!($b_command),
+                                    b_command,
+                                    if stdout_eq { "stderr" } else { "stdout" },
+                                    $crate::assert_command::stream_diff("stdout", &a.stdout, &b.stdout),
+                                    $crate::assert_command::stream_diff("stderr", &a.stderr, &b.stderr)
+                                )
+                            )
+                        }
+                    },
+                    (a, b) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdout_and_stderr_eq!(a_command, b_command)`\n",
+                                    "{}macro.assert_command_stdout_and_stderr_eq.html\n",
+                                    "         a label: `{}`,\n",
+                                    "         a debug: `{:?}`,\n",
+                                    "         b label: `{}`,\n",
+                                    "         b debug: `{:?}`,\n",
+                                    "               a: `{:?}`,\n",
+                                    "               b: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a_command),
+                                a_command,
+                                stringify!($b_command),
+                                b_command,
+                                a,
+                                b
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut a = Command::new("bin/printf-stderr");
+        a.args(["%s", "alfa"]);
+        let mut b = Command::new("bin/printf-stderr");
+        b.args(["%s%s", "al", "fa"]);
+        let result = assert_command_stdout_and_stderr_eq_as_result!(a, b);
+        let (a_output, b_output) = result.unwrap();
+        assert_eq!(a_output.stderr, b"alfa");
+        assert_eq!(b_output.stderr, b"alfa");
+    }
+
+    #[test]
+    fn failure_because_stdout() {
+        let mut a = Command::new("bin/printf-stdout");
+        a.args(["%s", "alfa"]);
+        let mut b = Command::new("bin/printf-stdout");
+        b.args(["%s", "alfz"]);
+        let result = assert_command_stdout_and_stderr_eq_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stdout_and_stderr_eq!(a_command, b_command)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_and_stderr_eq.html\n",
+                "         a label: `a`,\n",
+                "         a debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+                "         b label: `b`,\n",
+                "         b debug: `\"bin/printf-stdout\" \"%s\" \"alfz\"`,\n",
+                " first diverging: `stdout`,\n",
+                "   stdout offset: `3`,\n",
+                "  stdout hexdump:\n",
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                ">00000000  61 6c 66 61              alfa      61 6c 66 7a              alfz\n",
+                "     stderr diff: `equal`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_stderr() {
+        let mut a = Command::new("bin/printf-stderr");
+        a.args(["%s", "alfa"]);
+        let mut b = Command::new("bin/printf-stderr");
+        b.args(["%s", "al"]);
+        let result = assert_command_stdout_and_stderr_eq_as_result!(a, b);
+        let actual = result.unwrap_err();
+        assert!(actual.contains(" first diverging: `stderr`,\n"));
+        assert!(actual.contains("     stdout diff: `equal`\n"));
+        assert!(actual.contains("   stderr offset: `2`,\n"));
+    }
+}
+
+/// Assert a command stdout and stderr are equal to another command stdout and stderr.
+///
+/// Pseudocode:<br>
+/// (command1 ⇒ stdout) = (command2 ⇒ stdout) ∧ (command1 ⇒ stderr) = (command2 ⇒ stderr)
+///
+/// * If true, return `(a_output, b_output)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut a = Command::new("bin/printf-stdout");
+/// a.args(["%s", "alfa"]);
+/// let mut b = Command::new("bin/printf-stdout");
+/// b.args(["%s%s", "al", "fa"]);
+/// assert_command_stdout_and_stderr_eq!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut a = Command::new("bin/printf-stdout");
+/// a.args(["%s", "alfa"]);
+/// let mut b = Command::new("bin/printf-stdout");
+/// b.args(["%s", "alfz"]);
+/// assert_command_stdout_and_stderr_eq!(a, b);
+/// # });
+/// // assertion failed: `assert_command_stdout_and_stderr_eq!(a_command, b_command)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_and_stderr_eq.html
+/// //          a label: `a`,
+/// //          a debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,
+/// //          b label: `b`,
+/// //          b debug: `\"bin/printf-stdout\" \"%s\" \"alfz\"`,
+/// //  first diverging: `stdout`,
+/// //    stdout offset: `3`,
+/// //   stdout hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61              alfa      61 6c 66 7a              alfz
+/// //      stderr diff: `equal`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdout_and_stderr_eq!(a_command, b_command)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_and_stderr_eq.html\n",
+/// #     "         a label: `a`,\n",
+/// #     "         a debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+/// #     "         b label: `b`,\n",
+/// #     "         b debug: `\"bin/printf-stdout\" \"%s\" \"alfz\"`,\n",
+/// #     " first diverging: `stdout`,\n",
+/// #     "   stdout offset: `3`,\n",
+/// #     "  stdout hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 61              alfa      61 6c 66 7a              alfz\n",
+/// #     "     stderr diff: `equal`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_and_stderr_eq`](macro@crate::assert_command_stdout_and_stderr_eq)
+/// * [`assert_command_stdout_and_stderr_eq_as_result`](macro@crate::assert_command_stdout_and_stderr_eq_as_result)
+/// * [`debug_assert_command_stdout_and_stderr_eq`](macro@crate::debug_assert_command_stdout_and_stderr_eq)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_and_stderr_eq {
+    ($a_command:expr, $b_command:expr $(,)?) => {{
+        match $crate::assert_command_stdout_and_stderr_eq_as_result!($a_command, $b_command) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a_command:expr, $b_command:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_and_stderr_eq_as_result!($a_command, $b_command) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command stdout and stderr are equal to another command stdout and stderr.
+///
+/// Pseudocode:<br>
+/// (command1 ⇒ stdout) = (command2 ⇒ stdout) ∧ (command1 ⇒ stderr) = (command2 ⇒ stderr)
+///
+/// This macro provides the same statements as [`assert_command_stdout_and_stderr_eq`](macro.assert_command_stdout_and_stderr_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_and_stderr_eq`](macro@crate::assert_command_stdout_and_stderr_eq)
+/// * [`assert_command_stdout_and_stderr_eq_as_result`](macro@crate::assert_command_stdout_and_stderr_eq_as_result)
+/// * [`debug_assert_command_stdout_and_stderr_eq`](macro@crate::debug_assert_command_stdout_and_stderr_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_and_stderr_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_and_stderr_eq!($($arg)*);
+        }
+    };
+}
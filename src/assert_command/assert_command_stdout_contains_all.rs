@@ -0,0 +1,292 @@
+//! Assert a command stdout string contains every one of several containees.
+//!
+//! Pseudocode:<br>
+//! containees.iter().all(|containee| (command ⇒ stdout ⇒ string).contains(containee))
+//!
+//! This runs the command once, which matters when the command has side
+//! effects, then checks every containee. The failure message shows every
+//! missing containee, not only the first.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let containees = ["al", "fa"];
+//! assert_command_stdout_contains_all!(command, &containees);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_contains_all`](macro@crate::assert_command_stdout_contains_all)
+//! * [`assert_command_stdout_contains_all_as_result`](macro@crate::assert_command_stdout_contains_all_as_result)
+//! * [`debug_assert_command_stdout_contains_all`](macro@crate::debug_assert_command_stdout_contains_all)
+
+/// Assert a command stdout string contains every one of several containees.
+///
+/// Pseudocode:<br>
+/// containees.iter().all(|containee| (command ⇒ stdout ⇒ string).contains(containee))
+///
+/// * If true, return Result `Ok(command ⇒ stdout ⇒ string)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The stdout is decoded as UTF-8, with any invalid sequence replaced by
+/// `U+FFFD REPLACEMENT CHARACTER`. Each containee can be any type that
+/// implements `AsRef<str>`, such as `&str` or `String`.
+///
+/// This macro provides the same statements as [`assert_command_stdout_contains_all`](macro.assert_command_stdout_contains_all.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_contains_all`](macro@crate::assert_command_stdout_contains_all)
+/// * [`assert_command_stdout_contains_all_as_result`](macro@crate::assert_command_stdout_contains_all_as_result)
+/// * [`debug_assert_command_stdout_contains_all`](macro@crate::debug_assert_command_stdout_contains_all)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_contains_all_as_result {
+    ($command:expr, $containees:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (&$containees) {
+                    containees => {
+                        match command_output {
+                            Ok(output) => {
+                                let string = String::from_utf8_lossy(&output.stdout).into_owned();
+                                let missing: Vec<_> = containees
+                                    .iter()
+                                    .filter(|containee| !string.contains(AsRef::<str>::as_ref(containee)))
+                                    .collect();
+                                if missing.is_empty() {
+                                    Ok(string)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stdout_contains_all!(command, containees)`\n",
+                                                "{}macro.assert_command_stdout_contains_all.html\n",
+                                                "    command label: `{}`,\n",
+                                                "    command debug: `{:?}`,\n",
+                                                " containees label: `{}`,\n",
+                                                " containees debug: `{:?}`,\n",
+                                                "           string: `{:?}`,\n",
+                                                "          missing: `{:?}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($containees),
+                                            containees,
+                                            string,
+                                            missing
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_contains_all!(command, containees)`\n",
+                                            "{}macro.assert_command_stdout_contains_all.html\n",
+                                            "    command label: `{}`,\n",
+                                            "    command debug: `{:?}`,\n",
+                                            " containees label: `{}`,\n",
+                                            " containees debug: `{:?}`,\n",
+                                            "       output err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($containees),
+                                        containees,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut a = Command::new("bin/printf-stdout");
+        a.args(["%s", "alfa"]);
+        let b = ["al", "fa"];
+        let result = assert_command_stdout_contains_all_as_result!(a, &b);
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn success_with_strings() {
+        let mut a = Command::new("bin/printf-stdout");
+        a.args(["%s", "alfa"]);
+        let b = vec![String::from("al"), String::from("fa")];
+        let result = assert_command_stdout_contains_all_as_result!(a, b);
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn failure_shows_every_missing_containee() {
+        let mut a = Command::new("bin/printf-stdout");
+        a.args(["%s", "alfa"]);
+        let b = ["al", "xx", "fa", "zz"];
+        let result = assert_command_stdout_contains_all_as_result!(a, &b);
+        let actual = result.unwrap_err();
+        let expect = concat!(
+            "assertion failed: `assert_command_stdout_contains_all!(command, containees)`\n",
+            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_contains_all.html\n",
+            "    command label: `a`,\n",
+            "    command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+            " containees label: `&b`,\n",
+            " containees debug: `[\"al\", \"xx\", \"fa\", \"zz\"]`,\n",
+            "           string: `\"alfa\"`,\n",
+            "          missing: `[\"xx\", \"zz\"]`"
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn failure_because_command_cannot_spawn() {
+        let mut a = Command::new("bin/no-such-program");
+        let b = ["al"];
+        let result = assert_command_stdout_contains_all_as_result!(a, &b);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("       output err: `"));
+    }
+}
+
+/// Assert a command stdout string contains every one of several containees.
+///
+/// Pseudocode:<br>
+/// containees.iter().all(|containee| (command ⇒ stdout ⇒ string).contains(containee))
+///
+/// * If true, return (command ⇒ stdout ⇒ string).
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let containees = ["al", "fa"];
+/// assert_command_stdout_contains_all!(command, &containees);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let containees = ["al", "xx", "zz"];
+/// assert_command_stdout_contains_all!(command, &containees);
+/// # });
+/// // assertion failed: `assert_command_stdout_contains_all!(command, containees)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_contains_all.html
+/// //     command label: `command`,
+/// //     command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,
+/// //  containees label: `&containees`,
+/// //  containees debug: `[\"al\", \"xx\", \"zz\"]`,
+/// //            string: `\"alfa\"`,
+/// //           missing: `[\"xx\", \"zz\"]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdout_contains_all!(command, containees)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_contains_all.html\n",
+/// #     "    command label: `command`,\n",
+/// #     "    command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+/// #     " containees label: `&containees`,\n",
+/// #     " containees debug: `[\"al\", \"xx\", \"zz\"]`,\n",
+/// #     "           string: `\"alfa\"`,\n",
+/// #     "          missing: `[\"xx\", \"zz\"]`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_contains_all`](macro@crate::assert_command_stdout_contains_all)
+/// * [`assert_command_stdout_contains_all_as_result`](macro@crate::assert_command_stdout_contains_all_as_result)
+/// * [`debug_assert_command_stdout_contains_all`](macro@crate::debug_assert_command_stdout_contains_all)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_contains_all {
+    ($command:expr, $containees:expr $(,)?) => {{
+        match $crate::assert_command_stdout_contains_all_as_result!($command, $containees) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $containees:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_contains_all_as_result!($command, $containees) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command stdout string contains every one of several containees.
+///
+/// Pseudocode:<br>
+/// containees.iter().all(|containee| (command ⇒ stdout ⇒ string).contains(containee))
+///
+/// This macro provides the same statements as [`assert_command_stdout_contains_all`](macro.assert_command_stdout_contains_all.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_contains_all`](macro@crate::assert_command_stdout_contains_all)
+/// * [`assert_command_stdout_contains_all_as_result`](macro@crate::assert_command_stdout_contains_all_as_result)
+/// * [`debug_assert_command_stdout_contains_all`](macro@crate::debug_assert_command_stdout_contains_all)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_contains_all {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_contains_all!($($arg)*);
+        }
+    };
+}
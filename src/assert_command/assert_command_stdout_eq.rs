@@ -29,7 +29,7 @@
 /// Pseudocode:<br>
 /// (command1 ⇒ stdout) = (command2 ⇒ stdout)
 ///
-/// * If true, return Result `Ok(stdout)`.
+/// * If true, return Result `Ok((a_stdout, b_stdout))`.
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
@@ -48,56 +48,66 @@
 #[macro_export]
 macro_rules! assert_command_stdout_eq_as_result {
     ($a_command:expr, $b_command:expr $(,)?) => {{
-        match ($a_command.output(), $b_command.output()) {
-            (Ok(a), Ok(b)) => {
-                let a = a.stdout;
-                let b = b.stdout;
-                if a.eq(&b) {
-                    Ok((a, b))
-                } else {
-                    Err(
-                        format!(
-                            concat!(
-                                "assertion failed: `assert_command_stdout_eq!(a_command, b_command)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq.html\n",
-                                " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
-                                " b label: `{}`,\n",
-                                " b debug: `{:?}`,\n",
-                                "       a: `{:?}`,\n",
-                                "       b: `{:?}`"
-                            ),
-                            stringify!($a_command),
-                            $a_command,
-                            stringify!($b_command),
-                            $b_command,
-                            a,
-                            b
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match ($a_command.output_once(), $b_command.output_once()) {
+            ((a_command, a_command_output), (b_command, b_command_output)) => {
+                match (a_command_output, b_command_output) {
+                    (Ok(a), Ok(b)) => {
+                        let a = a.stdout;
+                        let b = b.stdout;
+                        if a.eq(&b) {
+                            Ok((a, b))
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_stdout_eq!(a_command, b_command)`\n",
+                                        "{}macro.assert_command_stdout_eq.html\n",
+                                        " a label: `{}`,\n",
+                                        " a debug: `{:?}`,\n",
+                                        " b label: `{}`,\n",
+                                        " b debug: `{:?}`,\n",
+                                        "       a: `{:?}`,\n",
+                                        "       b: `{:?}`,\n",
+                                        " hexdump:\n{}"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a_command),
+                                    a_command,
+                                    stringify!($b_command),
+                                    b_command,
+                                    a,
+                                    b,
+                                    $crate::hexdump::hexdump_diff(&a, &b)
+                                )
+                            )
+                        }
+                    },
+                    (a, b) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdout_eq!(a_command, b_command)`\n",
+                                    "{}macro.assert_command_stdout_eq.html\n",
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " b label: `{}`,\n",
+                                    " b debug: `{:?}`,\n",
+                                    "       a: `{:?}`,\n",
+                                    "       b: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a_command),
+                                a_command,
+                                stringify!($b_command),
+                                b_command,
+                                a,
+                                b
+                            )
                         )
-                    )
+                    }
                 }
-            },
-            (a, b) => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_command_stdout_eq!(a_command, b_command)`\n",
-                            "https://docs.rs/assertables/8.18/assert_command_stdout_eq/assertables/macro.assert_command_stdout_eq.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`,\n",
-                            "       a: `{:?}`,\n",
-                            "       b: `{:?}`"
-                        ),
-                        stringify!($a_command),
-                        $a_command,
-                        stringify!($b_command),
-                        $b_command,
-                        a,
-                        b
-                    )
-                )
             }
         }
     }};
@@ -137,7 +147,10 @@ mod tests {
             " b label: `b`,\n",
             " b debug: `\"bin/printf-stdout\" \"%s\" \"zz\"`,\n",
             "       a: `[97, 108, 102, 97]`,\n",
-            "       b: `[122, 122]`"
+            "       b: `[122, 122]`,\n",
+            " hexdump:\n",
+            " offset    a hex                    a ascii   b hex                    b ascii\n",
+            ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
         );
         assert_eq!(actual, expect);
     }
@@ -158,10 +171,28 @@ mod tests {
             " b label: `b`,\n",
             " b debug: `\"bin/printf-stdout\" \"%s\" \"aa\"`,\n",
             "       a: `[97, 108, 102, 97]`,\n",
-            "       b: `[97, 97]`"
+            "       b: `[97, 97]`,\n",
+            " hexdump:\n",
+            " offset    a hex                    a ascii   b hex                    b ascii\n",
+            ">00000000  61 6c 66 61              alfa      61 61                    aa"
         );
         assert_eq!(actual, expect);
     }
+
+    fn printf_alfa(count: &std::cell::Cell<i32>) -> Command {
+        count.set(count.get() + 1);
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        command
+    }
+
+    #[test]
+    fn evaluates_each_command_once() {
+        let count = std::cell::Cell::new(0);
+        let result = assert_command_stdout_eq_as_result!(printf_alfa(&count), printf_alfa(&count));
+        assert!(result.is_ok());
+        assert_eq!(count.get(), 2);
+    }
 }
 
 /// Assert a command stdout string is equal to another.
@@ -203,7 +234,10 @@ mod tests {
 /// //  b label: `b`,
 /// //  b debug: `\"bin/printf-stdout\" \"%s\" \"zz\"`,
 /// //        a: `[97, 108, 102, 97]`,
-/// //        b: `[122, 122]`
+/// //        b: `[122, 122]`,
+/// //  hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61              alfa      7a 7a                    zz
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let expect = concat!(
 /// #     "assertion failed: `assert_command_stdout_eq!(a_command, b_command)`\n",
@@ -213,7 +247,10 @@ mod tests {
 /// #     " b label: `b`,\n",
 /// #     " b debug: `\"bin/printf-stdout\" \"%s\" \"zz\"`,\n",
 /// #     "       a: `[97, 108, 102, 97]`,\n",
-/// #     "       b: `[122, 122]`"
+/// #     "       b: `[122, 122]`,\n",
+/// #     " hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
 /// # );
 /// # assert_eq!(actual, expect);
 /// # }
@@ -0,0 +1,306 @@
+//! Assert a command stdout is equal to bytes, such as a byte string or a string.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout) = (expr ⇒ as_ref bytes)
+//!
+//! This compares the raw stdout bytes, without decoding, so it works for
+//! stdout that is not valid UTF-8, such as binary output. The expression can
+//! be any type that implements `AsRef<[u8]>`, such as `&[u8]`, a byte string
+//! literal `b"…"`, `Vec<u8>`, `&str`, or `String`. A string compares as its
+//! UTF-8 bytes.
+//!
+//! The failure message shows the first differing byte offset, in hex, then a
+//! hexdump of both sides, with the marker `>` on the row of that offset.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! assert_command_stdout_eq_bytes_x!(command, b"alfa");
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_eq_bytes_x`](macro@crate::assert_command_stdout_eq_bytes_x)
+//! * [`assert_command_stdout_eq_bytes_x_as_result`](macro@crate::assert_command_stdout_eq_bytes_x_as_result)
+//! * [`debug_assert_command_stdout_eq_bytes_x`](macro@crate::debug_assert_command_stdout_eq_bytes_x)
+
+/// Assert a command stdout is equal to bytes, such as a byte string or a string.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout) = (expr ⇒ as_ref bytes)
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The expression can be any type that implements `AsRef<[u8]>`. Unlike the
+/// string macros, such as [`assert_command_stdout_trim_eq_x`](macro@crate::assert_command_stdout_trim_eq_x),
+/// this macro does not decode stdout, so it can not fail on invalid UTF-8.
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_bytes_x`](macro.assert_command_stdout_eq_bytes_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_bytes_x`](macro@crate::assert_command_stdout_eq_bytes_x)
+/// * [`assert_command_stdout_eq_bytes_x_as_result`](macro@crate::assert_command_stdout_eq_bytes_x_as_result)
+/// * [`debug_assert_command_stdout_eq_bytes_x`](macro@crate::debug_assert_command_stdout_eq_bytes_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_bytes_x_as_result {
+    ($command:expr, $bytes:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (&$bytes) {
+                    bytes => {
+                        let b: &[u8] = AsRef::<[u8]>::as_ref(bytes);
+                        match command_output {
+                            Ok(output) => {
+                                let a = output.stdout;
+                                match $crate::assert_slice::first_difference(&a, b) {
+                                    None => Ok(a),
+                                    Some(offset) => {
+                                        Err(
+                                            format!(
+                                                concat!(
+                                                    "assertion failed: `assert_command_stdout_eq_bytes_x!(command, bytes)`\n",
+                                                    "{}macro.assert_command_stdout_eq_bytes_x.html\n",
+                                                    "    command label: `{}`,\n",
+                                                    "    command debug: `{:?}`,\n",
+                                                    "      bytes label: `{}`,\n",
+                                                    "      bytes debug: `{:?}`,\n",
+                                                    " first difference: `{:#010x}`,\n",
+                                                    "          hexdump:\n{}"
+                                                ),
+                                                $crate::DOC_URL_BASE,
+                                                stringify!($command),
+                                                command,
+                                                stringify!($bytes),
+                                                bytes,
+                                                offset,
+                                                $crate::hexdump::hexdump_diff(&a, b)
+                                            )
+                                        )
+                                    }
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_eq_bytes_x!(command, bytes)`\n",
+                                            "{}macro.assert_command_stdout_eq_bytes_x.html\n",
+                                            "    command label: `{}`,\n",
+                                            "    command debug: `{:?}`,\n",
+                                            "      bytes label: `{}`,\n",
+                                            "      bytes debug: `{:?}`,\n",
+                                            "    output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($bytes),
+                                        bytes,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success_with_byte_string() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let result = assert_command_stdout_eq_bytes_x_as_result!(command, b"alfa");
+        assert_eq!(result.unwrap(), b"alfa");
+    }
+
+    #[test]
+    fn success_with_str() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let result = assert_command_stdout_eq_bytes_x_as_result!(command, "alfa");
+        assert_eq!(result.unwrap(), b"alfa");
+    }
+
+    #[test]
+    fn success_with_invalid_utf8() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["\\377\\376"]);
+        let bytes: &[u8] = &[0xff, 0xfe];
+        let result = assert_command_stdout_eq_bytes_x_as_result!(command, bytes);
+        assert_eq!(result.unwrap(), vec![0xff, 0xfe]);
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let bytes = b"alfz";
+        let result = assert_command_stdout_eq_bytes_x_as_result!(command, bytes);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stdout_eq_bytes_x!(command, bytes)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_bytes_x.html\n",
+                "    command label: `command`,\n",
+                "    command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+                "      bytes label: `bytes`,\n",
+                "      bytes debug: `[97, 108, 102, 122]`,\n",
+                " first difference: `0x00000003`,\n",
+                "          hexdump:\n",
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                ">00000000  61 6c 66 61              alfa      61 6c 66 7a              alfz"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_prefix() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let result = assert_command_stdout_eq_bytes_x_as_result!(command, "alfa\n");
+        assert!(result.unwrap_err().contains(" first difference: `0x00000004`,\n"));
+    }
+}
+
+/// Assert a command stdout is equal to bytes, such as a byte string or a string.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout) = (expr ⇒ as_ref bytes)
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// assert_command_stdout_eq_bytes_x!(command, b"alfa");
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let bytes = b"alfz";
+/// assert_command_stdout_eq_bytes_x!(command, bytes);
+/// # });
+/// // assertion failed: `assert_command_stdout_eq_bytes_x!(command, bytes)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_bytes_x.html
+/// //     command label: `command`,
+/// //     command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,
+/// //       bytes label: `bytes`,
+/// //       bytes debug: `[97, 108, 102, 122]`,
+/// //  first difference: `0x00000003`,
+/// //           hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61              alfa      61 6c 66 7a              alfz
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdout_eq_bytes_x!(command, bytes)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_bytes_x.html\n",
+/// #     "    command label: `command`,\n",
+/// #     "    command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+/// #     "      bytes label: `bytes`,\n",
+/// #     "      bytes debug: `[97, 108, 102, 122]`,\n",
+/// #     " first difference: `0x00000003`,\n",
+/// #     "          hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 61              alfa      61 6c 66 7a              alfz"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_bytes_x`](macro@crate::assert_command_stdout_eq_bytes_x)
+/// * [`assert_command_stdout_eq_bytes_x_as_result`](macro@crate::assert_command_stdout_eq_bytes_x_as_result)
+/// * [`debug_assert_command_stdout_eq_bytes_x`](macro@crate::debug_assert_command_stdout_eq_bytes_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_bytes_x {
+    ($command:expr, $bytes:expr $(,)?) => {{
+        match $crate::assert_command_stdout_eq_bytes_x_as_result!($command, $bytes) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $bytes:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_eq_bytes_x_as_result!($command, $bytes) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command stdout is equal to bytes, such as a byte string or a string.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout) = (expr ⇒ as_ref bytes)
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_bytes_x`](macro.assert_command_stdout_eq_bytes_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_bytes_x`](macro@crate::assert_command_stdout_eq_bytes_x)
+/// * [`assert_command_stdout_eq_bytes_x_as_result`](macro@crate::assert_command_stdout_eq_bytes_x_as_result)
+/// * [`debug_assert_command_stdout_eq_bytes_x`](macro@crate::debug_assert_command_stdout_eq_bytes_x)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_eq_bytes_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_eq_bytes_x!($($arg)*);
+        }
+    };
+}
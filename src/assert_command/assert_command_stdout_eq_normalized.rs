@@ -0,0 +1,329 @@
+//! Assert a command stdout string is equal to an expression, after normalizing both.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout ⇒ string ⇒ normalized) = (expr ⇒ normalized)
+//!
+//! The normalization is exactly this, and nothing more:
+//!
+//! * Convert each line ending `\r\n` or `\r` to `\n`.
+//!
+//! * Remove the trailing whitespace of each line, as defined by `char::is_whitespace`.
+//!
+//! The normalization keeps leading whitespace, blank lines, and a final line
+//! ending, so `"alfa\n"` is not equal to `"alfa"`. This is useful for
+//! cross-platform golden comparisons, where CRLF versus LF or trailing spaces
+//! are not meaningful.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa  \r\nbravo\t\r\n"]);
+//! let expr = "alfa\nbravo\n";
+//! assert_command_stdout_eq_normalized!(command, expr);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_eq_normalized`](macro@crate::assert_command_stdout_eq_normalized)
+//! * [`assert_command_stdout_eq_normalized_as_result`](macro@crate::assert_command_stdout_eq_normalized_as_result)
+//! * [`debug_assert_command_stdout_eq_normalized`](macro@crate::debug_assert_command_stdout_eq_normalized)
+
+/// Assert a command stdout string is equal to an expression, after normalizing both.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ normalized) = (expr ⇒ normalized)
+///
+/// * If true, return Result `Ok(normalized stdout string)`.
+///
+/// * Otherwise, return Result `Err(message)` with the normalized forms and the
+///   first line that differs. A line that is missing on one side shows as `None`.
+///
+/// The stdout is decoded as UTF-8, with any invalid sequence replaced by
+/// `U+FFFD REPLACEMENT CHARACTER`. The expression can be any type that
+/// implements `AsRef<str>`, such as `&str` or `String`.
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_normalized`](macro.assert_command_stdout_eq_normalized.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_normalized`](macro@crate::assert_command_stdout_eq_normalized)
+/// * [`assert_command_stdout_eq_normalized_as_result`](macro@crate::assert_command_stdout_eq_normalized_as_result)
+/// * [`debug_assert_command_stdout_eq_normalized`](macro@crate::debug_assert_command_stdout_eq_normalized)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_normalized_as_result {
+    ($command:expr, $expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (&$expr) {
+                    expr => {
+                        match command_output {
+                            Ok(output) => {
+                                let a = $crate::assert_command::normalize_lines(&String::from_utf8_lossy(&output.stdout));
+                                let b = $crate::assert_command::normalize_lines(AsRef::<str>::as_ref(expr));
+                                if a == b {
+                                    Ok(a)
+                                } else {
+                                    let a_lines: Vec<&str> = a.split('\n').collect();
+                                    let b_lines: Vec<&str> = b.split('\n').collect();
+                                    let index = (0..a_lines.len().max(b_lines.len()))
+                                        .find(|&i| a_lines.get(i) != b_lines.get(i))
+                                        .unwrap_or(0);
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stdout_eq_normalized!(command, expr)`\n",
+                                                "{}macro.assert_command_stdout_eq_normalized.html\n",
+                                                "      command label: `{}`,\n",
+                                                "      command debug: `{:?}`,\n",
+                                                "         expr label: `{}`,\n",
+                                                "         expr debug: `{:?}`,\n",
+                                                " command normalized: `{:?}`,\n",
+                                                "    expr normalized: `{:?}`,\n",
+                                                "        line number: `{}`,\n",
+                                                "       command line: `{:?}`,\n",
+                                                "          expr line: `{:?}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($expr),
+                                            expr,
+                                            a,
+                                            b,
+                                            index + 1,
+                                            a_lines.get(index),
+                                            b_lines.get(index)
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_eq_normalized!(command, expr)`\n",
+                                            "{}macro.assert_command_stdout_eq_normalized.html\n",
+                                            "      command label: `{}`,\n",
+                                            "      command debug: `{:?}`,\n",
+                                            "         expr label: `{}`,\n",
+                                            "         expr debug: `{:?}`,\n",
+                                            "      output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($expr),
+                                        expr,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa  \r\nbravo\t\r\n"]);
+        let expr = "alfa\nbravo\n";
+        let result = assert_command_stdout_eq_normalized_as_result!(command, expr);
+        assert_eq!(result.unwrap(), "alfa\nbravo\n");
+    }
+
+    #[test]
+    fn success_because_expr_is_normalized() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa\nbravo\n"]);
+        let expr = String::from("alfa \r\nbravo \r\n");
+        let result = assert_command_stdout_eq_normalized_as_result!(command, expr);
+        assert_eq!(result.unwrap(), "alfa\nbravo\n");
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa \nbravo\n"]);
+        let expr = "alfa\ncharlie\n";
+        let result = assert_command_stdout_eq_normalized_as_result!(command, expr);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stdout_eq_normalized!(command, expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_normalized.html\n",
+                "      command label: `command`,\n",
+                "      command debug: `\"bin/printf-stdout\" \"%s\" \"alfa \\nbravo\\n\"`,\n",
+                "         expr label: `expr`,\n",
+                "         expr debug: `\"alfa\\ncharlie\\n\"`,\n",
+                " command normalized: `\"alfa\\nbravo\\n\"`,\n",
+                "    expr normalized: `\"alfa\\ncharlie\\n\"`,\n",
+                "        line number: `2`,\n",
+                "       command line: `Some(\"bravo\")`,\n",
+                "          expr line: `Some(\"charlie\")`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_leading_whitespace_is_kept() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", " alfa"]);
+        let expr = "alfa";
+        let result = assert_command_stdout_eq_normalized_as_result!(command, expr);
+        assert!(result.unwrap_err().contains("        line number: `1`,\n"));
+    }
+
+    #[test]
+    fn failure_because_missing_line() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let expr = "alfa\nbravo";
+        let result = assert_command_stdout_eq_normalized_as_result!(command, expr);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("        line number: `2`,\n"));
+        assert!(actual.contains("       command line: `None`,\n"));
+    }
+}
+
+/// Assert a command stdout string is equal to an expression, after normalizing both.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ normalized) = (expr ⇒ normalized)
+///
+/// * If true, return the normalized stdout string.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa  \r\nbravo\t\r\n"]);
+/// let expr = "alfa\nbravo\n";
+/// assert_command_stdout_eq_normalized!(command, expr);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa \nbravo\n"]);
+/// let expr = "alfa\ncharlie\n";
+/// assert_command_stdout_eq_normalized!(command, expr);
+/// # });
+/// // assertion failed: `assert_command_stdout_eq_normalized!(command, expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_normalized.html
+/// //       command label: `command`,
+/// //       command debug: `\"bin/printf-stdout\" \"%s\" \"alfa \\nbravo\\n\"`,
+/// //          expr label: `expr`,
+/// //          expr debug: `\"alfa\\ncharlie\\n\"`,
+/// //  command normalized: `\"alfa\\nbravo\\n\"`,
+/// //     expr normalized: `\"alfa\\ncharlie\\n\"`,
+/// //         line number: `2`,
+/// //        command line: `Some(\"bravo\")`,
+/// //           expr line: `Some(\"charlie\")`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdout_eq_normalized!(command, expr)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_normalized.html\n",
+/// #     "      command label: `command`,\n",
+/// #     "      command debug: `\"bin/printf-stdout\" \"%s\" \"alfa \\nbravo\\n\"`,\n",
+/// #     "         expr label: `expr`,\n",
+/// #     "         expr debug: `\"alfa\\ncharlie\\n\"`,\n",
+/// #     " command normalized: `\"alfa\\nbravo\\n\"`,\n",
+/// #     "    expr normalized: `\"alfa\\ncharlie\\n\"`,\n",
+/// #     "        line number: `2`,\n",
+/// #     "       command line: `Some(\"bravo\")`,\n",
+/// #     "          expr line: `Some(\"charlie\")`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_normalized`](macro@crate::assert_command_stdout_eq_normalized)
+/// * [`assert_command_stdout_eq_normalized_as_result`](macro@crate::assert_command_stdout_eq_normalized_as_result)
+/// * [`debug_assert_command_stdout_eq_normalized`](macro@crate::debug_assert_command_stdout_eq_normalized)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_normalized {
+    ($command:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_command_stdout_eq_normalized_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_eq_normalized_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command stdout string is equal to an expression, after normalizing both.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ normalized) = (expr ⇒ normalized)
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_normalized`](macro.assert_command_stdout_eq_normalized.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_normalized`](macro@crate::assert_command_stdout_eq_normalized)
+/// * [`assert_command_stdout_eq_normalized_as_result`](macro@crate::assert_command_stdout_eq_normalized_as_result)
+/// * [`debug_assert_command_stdout_eq_normalized`](macro@crate::debug_assert_command_stdout_eq_normalized)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_eq_normalized {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_eq_normalized!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,327 @@
+//! Assert a command succeeds and its stdout is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ status ⇒ success) ∧ (command ⇒ stdout) = (expr into bytes)
+//!
+//! This runs the command once, then checks the exit status, then checks the
+//! stdout. This fits the usual intent of a command test: it should succeed
+//! and print the expected output.
+//!
+//! When the command does not succeed, the message leads with the status and
+//! the stderr tail, rather than a stdout diff, because the stdout of a failed
+//! command is often misleading.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let expr = b"alfa";
+//! assert_command_stdout_eq_x_and_success!(command, expr);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_eq_x_and_success`](macro@crate::assert_command_stdout_eq_x_and_success)
+//! * [`assert_command_stdout_eq_x_and_success_as_result`](macro@crate::assert_command_stdout_eq_x_and_success_as_result)
+//! * [`debug_assert_command_stdout_eq_x_and_success`](macro@crate::debug_assert_command_stdout_eq_x_and_success)
+
+/// Assert a command succeeds and its stdout is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ status ⇒ success) ∧ (command ⇒ stdout) = (expr into bytes)
+///
+/// * If true, return Result `Ok(output)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The output is the `::std::process::Output` of the command, so a test can
+/// check more, such as its stderr. The expression can be any type that a
+/// `Vec<u8>` can equal and slice, such as a byte string or a byte vector.
+///
+/// The stderr tail is the last 10 lines of stderr, decoded as UTF-8, with
+/// any invalid sequence replaced by `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_x_and_success`](macro.assert_command_stdout_eq_x_and_success.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_and_success`](macro@crate::assert_command_stdout_eq_x_and_success)
+/// * [`assert_command_stdout_eq_x_and_success_as_result`](macro@crate::assert_command_stdout_eq_x_and_success_as_result)
+/// * [`debug_assert_command_stdout_eq_x_and_success`](macro@crate::debug_assert_command_stdout_eq_x_and_success)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_x_and_success_as_result {
+    ($command:expr, $expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (&$expr) {
+                    expr => {
+                        match command_output {
+                            Ok(output) => {
+                                if !output.status.success() {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stdout_eq_x_and_success!(command, expr)`\n",
+                                                "{}macro.assert_command_stdout_eq_x_and_success.html\n",
+                                                " command label: `{}`,\n",
+                                                " command debug: `{:?}`,\n",
+                                                "    expr label: `{}`,\n",
+                                                "    expr debug: `{:?}`,\n",
+                                                "        status: `{}`,\n",
+                                                "   stderr tail: `{:?}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($expr),
+                                            expr,
+                                            output.status,
+                                            $crate::assert_command::tail_lines(&output.stderr, 10)
+                                        )
+                                    )
+                                } else if output.stdout == *expr {
+                                    Ok(output)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stdout_eq_x_and_success!(command, expr)`\n",
+                                                "{}macro.assert_command_stdout_eq_x_and_success.html\n",
+                                                " command label: `{}`,\n",
+                                                " command debug: `{:?}`,\n",
+                                                "    expr label: `{}`,\n",
+                                                "    expr debug: `{:?}`,\n",
+                                                "        status: `{}`,\n",
+                                                " command value: `{:?}`,\n",
+                                                "    expr value: `{:?}`,\n",
+                                                "       hexdump:\n{}"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($expr),
+                                            expr,
+                                            output.status,
+                                            output.stdout,
+                                            expr,
+                                            $crate::hexdump::hexdump_diff(&output.stdout, &expr[..])
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_eq_x_and_success!(command, expr)`\n",
+                                            "{}macro.assert_command_stdout_eq_x_and_success.html\n",
+                                            " command label: `{}`,\n",
+                                            " command debug: `{:?}`,\n",
+                                            "    expr label: `{}`,\n",
+                                            "    expr debug: `{:?}`,\n",
+                                            " output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($expr),
+                                        expr,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let expr = b"alfa";
+        let result = assert_command_stdout_eq_x_and_success_as_result!(command, expr);
+        let output = result.unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"alfa");
+    }
+
+    #[test]
+    fn success_with_vec() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let expr = vec![b'a', b'l', b'f', b'a'];
+        let result = assert_command_stdout_eq_x_and_success_as_result!(command, expr);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn failure_because_status() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "printf alfa; printf 'oops\\n' >&2; exit 3"]);
+        let expr = b"alfa";
+        let result = assert_command_stdout_eq_x_and_success_as_result!(command, expr);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stdout_eq_x_and_success!(command, expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_x_and_success.html\n",
+                " command label: `command`,\n",
+                " command debug: `\"sh\" \"-c\" \"printf alfa; printf 'oops\\\\n' >&2; exit 3\"`,\n",
+                "    expr label: `expr`,\n",
+                "    expr debug: `[97, 108, 102, 97]`,\n",
+                "        status: `exit status: 3`,\n",
+                "   stderr tail: `\"oops\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_stdout() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let expr = b"zz";
+        let result = assert_command_stdout_eq_x_and_success_as_result!(command, expr);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("        status: `exit status: 0`,\n"));
+        assert!(actual.contains(" command value: `[97, 108, 102, 97]`,\n"));
+        assert!(actual.contains("    expr value: `[122, 122]`,\n"));
+        assert!(actual.contains("       hexdump:\n"));
+    }
+}
+
+/// Assert a command succeeds and its stdout is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ status ⇒ success) ∧ (command ⇒ stdout) = (expr into bytes)
+///
+/// * If true, return `output`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let expr = b"alfa";
+/// assert_command_stdout_eq_x_and_success!(command, expr);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/exit-with-arg");
+/// command.arg("3");
+/// let expr = b"";
+/// assert_command_stdout_eq_x_and_success!(command, expr);
+/// # });
+/// // assertion failed: `assert_command_stdout_eq_x_and_success!(command, expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_x_and_success.html
+/// //  command label: `command`,
+/// //  command debug: `\"bin/exit-with-arg\" \"3\"`,
+/// //     expr label: `expr`,
+/// //     expr debug: `[]`,
+/// //         status: `exit status: 3`,
+/// //    stderr tail: `\"\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdout_eq_x_and_success!(command, expr)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_x_and_success.html\n",
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"bin/exit-with-arg\" \"3\"`,\n",
+/// #     "    expr label: `expr`,\n",
+/// #     "    expr debug: `[]`,\n",
+/// #     "        status: `exit status: 3`,\n",
+/// #     "   stderr tail: `\"\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_and_success`](macro@crate::assert_command_stdout_eq_x_and_success)
+/// * [`assert_command_stdout_eq_x_and_success_as_result`](macro@crate::assert_command_stdout_eq_x_and_success_as_result)
+/// * [`debug_assert_command_stdout_eq_x_and_success`](macro@crate::debug_assert_command_stdout_eq_x_and_success)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_x_and_success {
+    ($command:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_command_stdout_eq_x_and_success_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_eq_x_and_success_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command succeeds and its stdout is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ status ⇒ success) ∧ (command ⇒ stdout) = (expr into bytes)
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_x_and_success`](macro.assert_command_stdout_eq_x_and_success.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_and_success`](macro@crate::assert_command_stdout_eq_x_and_success)
+/// * [`assert_command_stdout_eq_x_and_success_as_result`](macro@crate::assert_command_stdout_eq_x_and_success_as_result)
+/// * [`debug_assert_command_stdout_eq_x_and_success`](macro@crate::debug_assert_command_stdout_eq_x_and_success)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_eq_x_and_success {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_eq_x_and_success!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,309 @@
+//! Assert a command stdout string is equal to an expression, ignoring CRLF versus LF line endings.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout ⇒ string ⇒ \r\n to \n) = (expr ⇒ \r\n to \n)
+//!
+//! This converts each `\r\n` to `\n` in the stdout and in the expression, then
+//! compares them. It changes nothing else: a lone `\r`, trailing spaces, and a
+//! final line ending all still count. This fits a tool that prints CRLF on
+//! Windows and LF elsewhere.
+//!
+//! For a broader normalization, which also removes trailing whitespace, see
+//! [`assert_command_stdout_eq_normalized`](macro@crate::assert_command_stdout_eq_normalized).
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa\r\nbravo\r\n"]);
+//! let expr = "alfa\nbravo\n";
+//! assert_command_stdout_eq_x_crlf_insensitive!(command, expr);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_eq_x_crlf_insensitive`](macro@crate::assert_command_stdout_eq_x_crlf_insensitive)
+//! * [`assert_command_stdout_eq_x_crlf_insensitive_as_result`](macro@crate::assert_command_stdout_eq_x_crlf_insensitive_as_result)
+//! * [`debug_assert_command_stdout_eq_x_crlf_insensitive`](macro@crate::debug_assert_command_stdout_eq_x_crlf_insensitive)
+
+/// Assert a command stdout string is equal to an expression, ignoring CRLF versus LF line endings.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ \r\n to \n) = (expr ⇒ \r\n to \n)
+///
+/// * If true, return Result `Ok(normalized stdout string)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The stdout is decoded as UTF-8, with any invalid sequence replaced by
+/// `U+FFFD REPLACEMENT CHARACTER`. The expression can be any type that
+/// implements `AsRef<str>`, such as `&str` or `String`.
+///
+/// The message shows both normalized values, and notes the normalization.
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_x_crlf_insensitive`](macro.assert_command_stdout_eq_x_crlf_insensitive.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_crlf_insensitive`](macro@crate::assert_command_stdout_eq_x_crlf_insensitive)
+/// * [`assert_command_stdout_eq_x_crlf_insensitive_as_result`](macro@crate::assert_command_stdout_eq_x_crlf_insensitive_as_result)
+/// * [`debug_assert_command_stdout_eq_x_crlf_insensitive`](macro@crate::debug_assert_command_stdout_eq_x_crlf_insensitive)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_x_crlf_insensitive_as_result {
+    ($command:expr, $expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (&$expr) {
+                    expr => {
+                        match command_output {
+                            Ok(output) => {
+                                let a = String::from_utf8_lossy(&output.stdout).replace("\r\n", "\n");
+                                let b = AsRef::<str>::as_ref(expr).replace("\r\n", "\n");
+                                if a == b {
+                                    Ok(a)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stdout_eq_x_crlf_insensitive!(command, expr)`\n",
+                                                "{}macro.assert_command_stdout_eq_x_crlf_insensitive.html\n",
+                                                "      command label: `{}`,\n",
+                                                "      command debug: `{:?}`,\n",
+                                                "         expr label: `{}`,\n",
+                                                "         expr debug: `{:?}`,\n",
+                                                "      normalization: `\\r\\n ⇒ \\n`,\n",
+                                                " command normalized: `{:?}`,\n",
+                                                "    expr normalized: `{:?}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($expr),
+                                            expr,
+                                            a,
+                                            b
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_eq_x_crlf_insensitive!(command, expr)`\n",
+                                            "{}macro.assert_command_stdout_eq_x_crlf_insensitive.html\n",
+                                            "      command label: `{}`,\n",
+                                            "      command debug: `{:?}`,\n",
+                                            "         expr label: `{}`,\n",
+                                            "         expr debug: `{:?}`,\n",
+                                            "      output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($expr),
+                                        expr,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa\r\nbravo\r\n"]);
+        let expr = "alfa\nbravo\n";
+        let result = assert_command_stdout_eq_x_crlf_insensitive_as_result!(command, expr);
+        assert_eq!(result.unwrap(), "alfa\nbravo\n");
+    }
+
+    #[test]
+    fn success_because_expr_is_normalized() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa\nbravo\n"]);
+        let expr = String::from("alfa\r\nbravo\r\n");
+        let result = assert_command_stdout_eq_x_crlf_insensitive_as_result!(command, expr);
+        assert_eq!(result.unwrap(), "alfa\nbravo\n");
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa\r\nbravo\r\n"]);
+        let expr = "alfa\ncharlie\n";
+        let result = assert_command_stdout_eq_x_crlf_insensitive_as_result!(command, expr);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stdout_eq_x_crlf_insensitive!(command, expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_x_crlf_insensitive.html\n",
+                "      command label: `command`,\n",
+                "      command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\\r\\nbravo\\r\\n\"`,\n",
+                "         expr label: `expr`,\n",
+                "         expr debug: `\"alfa\\ncharlie\\n\"`,\n",
+                "      normalization: `\\r\\n ⇒ \\n`,\n",
+                " command normalized: `\"alfa\\nbravo\\n\"`,\n",
+                "    expr normalized: `\"alfa\\ncharlie\\n\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_trailing_space() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa \r\n"]);
+        let expr = "alfa\n";
+        let result = assert_command_stdout_eq_x_crlf_insensitive_as_result!(command, expr);
+        assert!(result.unwrap_err().ends_with(" command normalized: `\"alfa \\n\"`,\n    expr normalized: `\"alfa\\n\"`"));
+    }
+
+    #[test]
+    fn failure_because_lone_carriage_return() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa\rbravo"]);
+        let expr = "alfa\nbravo";
+        let result = assert_command_stdout_eq_x_crlf_insensitive_as_result!(command, expr);
+        assert!(result.is_err());
+    }
+}
+
+/// Assert a command stdout string is equal to an expression, ignoring CRLF versus LF line endings.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ \r\n to \n) = (expr ⇒ \r\n to \n)
+///
+/// * If true, return `normalized stdout string`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa\r\nbravo\r\n"]);
+/// let expr = "alfa\nbravo\n";
+/// assert_command_stdout_eq_x_crlf_insensitive!(command, expr);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa\r\nbravo\r\n"]);
+/// let expr = "alfa\ncharlie\n";
+/// assert_command_stdout_eq_x_crlf_insensitive!(command, expr);
+/// # });
+/// // assertion failed: `assert_command_stdout_eq_x_crlf_insensitive!(command, expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_x_crlf_insensitive.html
+/// //       command label: `command`,
+/// //       command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\\r\\nbravo\\r\\n\"`,
+/// //          expr label: `expr`,
+/// //          expr debug: `\"alfa\\ncharlie\\n\"`,
+/// //       normalization: `\\r\\n ⇒ \\n`,
+/// //  command normalized: `\"alfa\\nbravo\\n\"`,
+/// //     expr normalized: `\"alfa\\ncharlie\\n\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdout_eq_x_crlf_insensitive!(command, expr)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_x_crlf_insensitive.html\n",
+/// #     "      command label: `command`,\n",
+/// #     "      command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\\r\\nbravo\\r\\n\"`,\n",
+/// #     "         expr label: `expr`,\n",
+/// #     "         expr debug: `\"alfa\\ncharlie\\n\"`,\n",
+/// #     "      normalization: `\\r\\n ⇒ \\n`,\n",
+/// #     " command normalized: `\"alfa\\nbravo\\n\"`,\n",
+/// #     "    expr normalized: `\"alfa\\ncharlie\\n\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_crlf_insensitive`](macro@crate::assert_command_stdout_eq_x_crlf_insensitive)
+/// * [`assert_command_stdout_eq_x_crlf_insensitive_as_result`](macro@crate::assert_command_stdout_eq_x_crlf_insensitive_as_result)
+/// * [`debug_assert_command_stdout_eq_x_crlf_insensitive`](macro@crate::debug_assert_command_stdout_eq_x_crlf_insensitive)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_x_crlf_insensitive {
+    ($command:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_command_stdout_eq_x_crlf_insensitive_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_eq_x_crlf_insensitive_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command stdout string is equal to an expression, ignoring CRLF versus LF line endings.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ \r\n to \n) = (expr ⇒ \r\n to \n)
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_x_crlf_insensitive`](macro.assert_command_stdout_eq_x_crlf_insensitive.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_crlf_insensitive`](macro@crate::assert_command_stdout_eq_x_crlf_insensitive)
+/// * [`assert_command_stdout_eq_x_crlf_insensitive_as_result`](macro@crate::assert_command_stdout_eq_x_crlf_insensitive_as_result)
+/// * [`debug_assert_command_stdout_eq_x_crlf_insensitive`](macro@crate::debug_assert_command_stdout_eq_x_crlf_insensitive)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_eq_x_crlf_insensitive {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_eq_x_crlf_insensitive!($($arg)*);
+        }
+    };
+}
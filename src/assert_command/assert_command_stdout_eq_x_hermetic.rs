@@ -0,0 +1,309 @@
+//! Assert a command in a hermetic environment has stdout equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (command with cleared environment, LANG=C, TZ=UTC, new temp dir ⇒ stdout) = expr
+//!
+//! This bundles the usual knobs for a reproducible command run, such as for
+//! golden tests of a command line interface on a CI server:
+//!
+//! * Clear the inherited environment, with `command.env_clear()`.
+//!
+//! * Set the locale `LANG=C` and the timezone `TZ=UTC`.
+//!
+//! * Set the current directory to a new empty directory in the system
+//!   temporary directory, and remove the directory after the run.
+//!
+//! The macro mutates the command. Because the current directory changes,
+//! the command program path should be absolute, or be a program name that
+//! the system finds via `PATH`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new(concat!(env!("CARGO_MANIFEST_DIR"), "/bin/printf-stdout"));
+//! command.args(["%s", "alfa"]);
+//! let bytes = vec![b'a', b'l', b'f', b'a'];
+//! assert_command_stdout_eq_x_hermetic!(command, bytes);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_eq_x_hermetic`](macro@crate::assert_command_stdout_eq_x_hermetic)
+//! * [`assert_command_stdout_eq_x_hermetic_as_result`](macro@crate::assert_command_stdout_eq_x_hermetic_as_result)
+//! * [`debug_assert_command_stdout_eq_x_hermetic`](macro@crate::debug_assert_command_stdout_eq_x_hermetic)
+
+/// Assert a command in a hermetic environment has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command with cleared environment, LANG=C, TZ=UTC, new temp dir ⇒ stdout) = expr
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message shows the environment that the macro forced, and the command
+/// debug shows the current directory.
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_x_hermetic`](macro.assert_command_stdout_eq_x_hermetic.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_hermetic`](macro@crate::assert_command_stdout_eq_x_hermetic)
+/// * [`assert_command_stdout_eq_x_hermetic_as_result`](macro@crate::assert_command_stdout_eq_x_hermetic_as_result)
+/// * [`debug_assert_command_stdout_eq_x_hermetic`](macro@crate::debug_assert_command_stdout_eq_x_hermetic)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_x_hermetic_as_result {
+    ($command:expr, $expr:expr $(,)?) => {{
+        match (&mut $command, &$expr) {
+            (command, expr) => {
+                match $crate::assert_command::hermetic_output(command) {
+                    Ok(output) => {
+                        let a = output.stdout;
+                        if a.eq(expr) {
+                            Ok(a)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_stdout_eq_x_hermetic!(command, expr)`\n",
+                                        "{}macro.assert_command_stdout_eq_x_hermetic.html\n",
+                                        " command label: `{}`,\n",
+                                        " command debug: `{:?}`,\n",
+                                        "   environment: `{}`,\n",
+                                        "    expr label: `{}`,\n",
+                                        "    expr debug: `{:?}`,\n",
+                                        " command value: `{:?}`,\n",
+                                        "    expr value: `{:?}`,\n",
+                                        "       hexdump:\n{}"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($command),
+                                    command,
+                                    $crate::assert_command::hermetic_env_description(),
+                                    stringify!($expr),
+                                    expr,
+                                    a,
+                                    expr,
+                                    $crate::hexdump::hexdump_diff(&a, &expr[..])
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdout_eq_x_hermetic!(command, expr)`\n",
+                                    "{}macro.assert_command_stdout_eq_x_hermetic.html\n",
+                                    " command label: `{}`,\n",
+                                    " command debug: `{:?}`,\n",
+                                    "   environment: `{}`,\n",
+                                    "    expr label: `{}`,\n",
+                                    "    expr debug: `{:?}`,\n",
+                                    " output is err: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($command),
+                                command,
+                                $crate::assert_command::hermetic_env_description(),
+                                stringify!($expr),
+                                expr,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    const PRINTF_STDOUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/bin/printf-stdout");
+
+    #[test]
+    fn success() {
+        let mut command = Command::new(PRINTF_STDOUT);
+        command.args(["%s", "alfa"]);
+        let expr = vec![b'a', b'l', b'f', b'a'];
+        let result = assert_command_stdout_eq_x_hermetic_as_result!(command, expr);
+        assert_eq!(result.unwrap(), vec![b'a', b'l', b'f', b'a']);
+    }
+
+    #[test]
+    fn success_because_child_sees_only_forced_environment() {
+        let mut command = Command::new("env");
+        command.env("ASSERTABLES_TEST", "alfa");
+        let expr = b"LANG=C\nTZ=UTC\n".to_vec();
+        let result = assert_command_stdout_eq_x_hermetic_as_result!(command, expr);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn success_because_child_runs_in_new_empty_dir() {
+        let mut command = Command::new("ls");
+        command.arg("-A");
+        let expr: Vec<u8> = vec![];
+        let result = assert_command_stdout_eq_x_hermetic_as_result!(command, expr);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new(PRINTF_STDOUT);
+        command.args(["%s", "alfa"]);
+        let expr = vec![b'z', b'z'];
+        let result = assert_command_stdout_eq_x_hermetic_as_result!(command, expr);
+        let actual = result.unwrap_err();
+        assert!(actual.starts_with(concat!(
+            "assertion failed: `assert_command_stdout_eq_x_hermetic!(command, expr)`\n",
+            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_x_hermetic.html\n",
+            " command label: `command`,\n",
+            " command debug: `cd ",
+        )));
+        assert!(actual.ends_with(concat!(
+            "   environment: `cleared, then LANG=C TZ=UTC`,\n",
+            "    expr label: `expr`,\n",
+            "    expr debug: `[122, 122]`,\n",
+            " command value: `[97, 108, 102, 97]`,\n",
+            "    expr value: `[122, 122]`,\n",
+            "       hexdump:\n",
+            " offset    a hex                    a ascii   b hex                    b ascii\n",
+            ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
+        )));
+    }
+
+    #[test]
+    fn failure_because_command_cannot_spawn() {
+        let mut command = Command::new("bin/no-such-program");
+        let expr = vec![b'a'];
+        let result = assert_command_stdout_eq_x_hermetic_as_result!(command, expr);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("   environment: `cleared, then LANG=C TZ=UTC`,\n"));
+        assert!(actual.contains(" output is err: `"));
+    }
+}
+
+/// Assert a command in a hermetic environment has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command with cleared environment, LANG=C, TZ=UTC, new temp dir ⇒ stdout) = expr
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new(concat!(env!("CARGO_MANIFEST_DIR"), "/bin/printf-stdout"));
+/// command.args(["%s", "alfa"]);
+/// let bytes = vec![b'a', b'l', b'f', b'a'];
+/// assert_command_stdout_eq_x_hermetic!(command, bytes);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new(concat!(env!("CARGO_MANIFEST_DIR"), "/bin/printf-stdout"));
+/// command.args(["%s", "alfa"]);
+/// let bytes = vec![b'z', b'z'];
+/// assert_command_stdout_eq_x_hermetic!(command, bytes);
+/// # });
+/// // assertion failed: `assert_command_stdout_eq_x_hermetic!(command, expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_eq_x_hermetic.html
+/// //  command label: `command`,
+/// //  command debug: `cd \"/tmp/assertables-hermetic-…\" && env -i LANG=\"C\" TZ=\"UTC\" \"…/bin/printf-stdout\" \"%s\" \"alfa\"`,
+/// //    environment: `cleared, then LANG=C TZ=UTC`,
+/// //     expr label: `bytes`,
+/// //     expr debug: `[122, 122]`,
+/// //  command value: `[97, 108, 102, 97]`,
+/// //     expr value: `[122, 122]`,
+/// //        hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61              alfa      7a 7a                    zz
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # assert!(actual.contains("   environment: `cleared, then LANG=C TZ=UTC`,\n"));
+/// # assert!(actual.ends_with(">00000000  61 6c 66 61              alfa      7a 7a                    zz"));
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_hermetic`](macro@crate::assert_command_stdout_eq_x_hermetic)
+/// * [`assert_command_stdout_eq_x_hermetic_as_result`](macro@crate::assert_command_stdout_eq_x_hermetic_as_result)
+/// * [`debug_assert_command_stdout_eq_x_hermetic`](macro@crate::debug_assert_command_stdout_eq_x_hermetic)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_x_hermetic {
+    ($command:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_command_stdout_eq_x_hermetic_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_eq_x_hermetic_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command in a hermetic environment has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command with cleared environment, LANG=C, TZ=UTC, new temp dir ⇒ stdout) = expr
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_x_hermetic`](macro.assert_command_stdout_eq_x_hermetic.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_hermetic`](macro@crate::assert_command_stdout_eq_x_hermetic)
+/// * [`assert_command_stdout_eq_x_hermetic_as_result`](macro@crate::assert_command_stdout_eq_x_hermetic_as_result)
+/// * [`debug_assert_command_stdout_eq_x_hermetic`](macro@crate::debug_assert_command_stdout_eq_x_hermetic)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_eq_x_hermetic {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_eq_x_hermetic!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,326 @@
+//! Assert a command stdout JSON contains every part of a subset JSON string.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout ⇒ JSON) contains (subset ⇒ JSON)
+//!
+//! This runs the command once, decodes its stdout as UTF-8, parses the
+//! stdout and the subset as JSON, then compares them the same way as
+//! [`assert_json_contains`](macro@crate::assert_json_contains). Extra keys and
+//! extra array elements in stdout are ignored, and whitespace does not matter,
+//! so a subset can skip volatile fields, such as timestamps and ids.
+//!
+//! The failure message shows the JSON pointer of the first part of the
+//! subset that is not met.
+//!
+//! This macro needs the crate feature `json`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", r#"{"id":7,"at":"2024-10-15T00:00:00Z","name":"alfa"}"#]);
+//! let subset = r#"{ "name": "alfa" }"#;
+//! assert_command_stdout_json_contains!(command, subset);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_json_contains`](macro@crate::assert_command_stdout_json_contains)
+//! * [`assert_command_stdout_json_contains_as_result`](macro@crate::assert_command_stdout_json_contains_as_result)
+//! * [`debug_assert_command_stdout_json_contains`](macro@crate::debug_assert_command_stdout_json_contains)
+
+/// Assert a command stdout JSON contains every part of a subset JSON string.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ JSON) contains (subset ⇒ JSON)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The subset can be any type that implements `AsRef<str>`, such as `&str`
+/// or `String`. If the command fails to run, or its stdout is not UTF-8,
+/// or either string is not JSON, then the message shows the error.
+///
+/// This macro provides the same statements as [`assert_command_stdout_json_contains`](macro.assert_command_stdout_json_contains.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_json_contains`](macro@crate::assert_command_stdout_json_contains)
+/// * [`assert_command_stdout_json_contains_as_result`](macro@crate::assert_command_stdout_json_contains_as_result)
+/// * [`debug_assert_command_stdout_json_contains`](macro@crate::debug_assert_command_stdout_json_contains)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_json_contains_as_result {
+    ($command:expr, $subset:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (&$subset) {
+                    subset => {
+                        let checked = match command_output {
+                            Ok(output) => {
+                                match String::from_utf8(output.stdout) {
+                                    Ok(stdout) => {
+                                        match (
+                                            $crate::assert_json::parse(&stdout),
+                                            $crate::assert_json::parse(AsRef::<str>::as_ref(subset)),
+                                        ) {
+                                            (Ok(stdout_value), Ok(subset_value)) => {
+                                                Ok(($crate::assert_json::json_contains_pointer(&stdout_value, &subset_value), stdout))
+                                            },
+                                            (Err(err), _) => Err(format!("stdout is not JSON: {}", err)),
+                                            (_, Err(err)) => Err(format!("subset is not JSON: {}", err)),
+                                        }
+                                    },
+                                    Err(err) => Err(format!("stdout is not UTF-8: {}", err)),
+                                }
+                            },
+                            Err(err) => Err(format!("output err: {:?}", err)),
+                        };
+                        match checked {
+                            Ok((None, _)) => Ok(()),
+                            Ok((Some((pointer, value)), stdout)) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_json_contains!(command, subset)`\n",
+                                            "{}macro.assert_command_stdout_json_contains.html\n",
+                                            " command label: `{}`,\n",
+                                            " command debug: `{:?}`,\n",
+                                            "  subset label: `{}`,\n",
+                                            "  subset debug: `{:?}`,\n",
+                                            "        stdout: `{:?}`,\n",
+                                            "       pointer: `{}`,\n",
+                                            "  subset value: `{}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($subset),
+                                        subset,
+                                        stdout,
+                                        pointer,
+                                        value
+                                    )
+                                )
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_json_contains!(command, subset)`\n",
+                                            "{}macro.assert_command_stdout_json_contains.html\n",
+                                            " command label: `{}`,\n",
+                                            " command debug: `{:?}`,\n",
+                                            "  subset label: `{}`,\n",
+                                            "  subset debug: `{:?}`,\n",
+                                            "           err: `{}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($subset),
+                                        subset,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", r#"{"id":7,"at":"2024-10-15T00:00:00Z","name":"alfa"}"#]);
+        let subset = r#"{"name": "alfa"}"#;
+        let result = assert_command_stdout_json_contains_as_result!(command, subset);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_pretty_subset_and_array() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", r#"{"users":[{"id":1,"admin":true},{"id":2,"admin":false}]}"#]);
+        let subset = String::from("{\n  \"users\": [\n    { \"id\": 2 }\n  ]\n}\n");
+        let result = assert_command_stdout_json_contains_as_result!(command, subset);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", r#"{"id":7,"at":"2024-10-15T00:00:00Z","name":"alfa"}"#]);
+        let subset = r#"{"name": "bravo"}"#;
+        let result = assert_command_stdout_json_contains_as_result!(command, subset);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stdout_json_contains!(command, subset)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_json_contains.html\n",
+                " command label: `command`,\n",
+                " command debug: `\"bin/printf-stdout\" \"%s\" \"{\\\"id\\\":7,\\\"at\\\":\\\"2024-10-15T00:00:00Z\\\",\\\"name\\\":\\\"alfa\\\"}\"`,\n",
+                "  subset label: `subset`,\n",
+                "  subset debug: `\"{\\\"name\\\": \\\"bravo\\\"}\"`,\n",
+                "        stdout: `\"{\\\"id\\\":7,\\\"at\\\":\\\"2024-10-15T00:00:00Z\\\",\\\"name\\\":\\\"alfa\\\"}\"`,\n",
+                "       pointer: `/name`,\n",
+                "  subset value: `\"bravo\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_stdout_is_not_json() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let subset = r#"{"name": "alfa"}"#;
+        let result = assert_command_stdout_json_contains_as_result!(command, subset);
+        assert!(result.unwrap_err().contains("           err: `stdout is not JSON: "));
+    }
+
+    #[test]
+    fn failure_because_subset_is_not_json() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "{}"]);
+        let subset = "{";
+        let result = assert_command_stdout_json_contains_as_result!(command, subset);
+        assert!(result.unwrap_err().contains("           err: `subset is not JSON: "));
+    }
+}
+
+/// Assert a command stdout JSON contains every part of a subset JSON string.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ JSON) contains (subset ⇒ JSON)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", r#"{"id":7,"at":"2024-10-15T00:00:00Z","name":"alfa"}"#]);
+/// let subset = r#"{ "name": "alfa" }"#;
+/// assert_command_stdout_json_contains!(command, subset);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", r#"{"id":7,"at":"2024-10-15T00:00:00Z","name":"alfa"}"#]);
+/// let subset = r#"{"name": "bravo"}"#;
+/// assert_command_stdout_json_contains!(command, subset);
+/// # });
+/// // assertion failed: `assert_command_stdout_json_contains!(command, subset)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_json_contains.html
+/// //  command label: `command`,
+/// //  command debug: `\"bin/printf-stdout\" \"%s\" \"{\\\"id\\\":7,\\\"at\\\":\\\"2024-10-15T00:00:00Z\\\",\\\"name\\\":\\\"alfa\\\"}\"`,
+/// //   subset label: `subset`,
+/// //   subset debug: `\"{\\\"name\\\": \\\"bravo\\\"}\"`,
+/// //         stdout: `\"{\\\"id\\\":7,\\\"at\\\":\\\"2024-10-15T00:00:00Z\\\",\\\"name\\\":\\\"alfa\\\"}\"`,
+/// //        pointer: `/name`,
+/// //   subset value: `\"bravo\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdout_json_contains!(command, subset)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_json_contains.html\n",
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"bin/printf-stdout\" \"%s\" \"{\\\"id\\\":7,\\\"at\\\":\\\"2024-10-15T00:00:00Z\\\",\\\"name\\\":\\\"alfa\\\"}\"`,\n",
+/// #     "  subset label: `subset`,\n",
+/// #     "  subset debug: `\"{\\\"name\\\": \\\"bravo\\\"}\"`,\n",
+/// #     "        stdout: `\"{\\\"id\\\":7,\\\"at\\\":\\\"2024-10-15T00:00:00Z\\\",\\\"name\\\":\\\"alfa\\\"}\"`,\n",
+/// #     "       pointer: `/name`,\n",
+/// #     "  subset value: `\"bravo\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_json_contains`](macro@crate::assert_command_stdout_json_contains)
+/// * [`assert_command_stdout_json_contains_as_result`](macro@crate::assert_command_stdout_json_contains_as_result)
+/// * [`debug_assert_command_stdout_json_contains`](macro@crate::debug_assert_command_stdout_json_contains)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_json_contains {
+    ($command:expr, $subset:expr $(,)?) => {{
+        match $crate::assert_command_stdout_json_contains_as_result!($command, $subset) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $subset:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_json_contains_as_result!($command, $subset) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command stdout JSON contains every part of a subset JSON string.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ JSON) contains (subset ⇒ JSON)
+///
+/// This macro provides the same statements as [`assert_command_stdout_json_contains`](macro.assert_command_stdout_json_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_json_contains`](macro@crate::assert_command_stdout_json_contains)
+/// * [`assert_command_stdout_json_contains_as_result`](macro@crate::assert_command_stdout_json_contains_as_result)
+/// * [`debug_assert_command_stdout_json_contains`](macro@crate::debug_assert_command_stdout_json_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_json_contains {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_json_contains!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,297 @@
+//! Assert any non-empty line of a command stdout string is a match to a regex.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout ⇒ string ⇒ non-empty lines).any(|line| matcher.is_match(line))
+//!
+//! This runs the command once, then checks each non-empty line. This is
+//! useful for log output, where at least one line must conform to a format.
+//! The failure message shows the count of non-empty lines that were checked.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//! use regex::Regex;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa 1\nbravo 2\n"]);
+//! let matcher = Regex::new(r"^[a-z]+ \d+$").unwrap();
+//! assert_command_stdout_lines_any_match!(command, &matcher);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_lines_any_match`](macro@crate::assert_command_stdout_lines_any_match)
+//! * [`assert_command_stdout_lines_any_match_as_result`](macro@crate::assert_command_stdout_lines_any_match_as_result)
+//! * [`debug_assert_command_stdout_lines_any_match`](macro@crate::debug_assert_command_stdout_lines_any_match)
+
+/// Assert any non-empty line of a command stdout string is a match to a regex.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ non-empty lines).any(|line| matcher.is_match(line))
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The stdout is decoded as UTF-8, with any invalid sequence replaced by
+/// `U+FFFD REPLACEMENT CHARACTER`. If the stdout has no non-empty line, then
+/// this is false.
+///
+/// This macro provides the same statements as [`assert_command_stdout_lines_any_match`](macro.assert_command_stdout_lines_any_match.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_lines_any_match`](macro@crate::assert_command_stdout_lines_any_match)
+/// * [`assert_command_stdout_lines_any_match_as_result`](macro@crate::assert_command_stdout_lines_any_match_as_result)
+/// * [`debug_assert_command_stdout_lines_any_match`](macro@crate::debug_assert_command_stdout_lines_any_match)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_lines_any_match_as_result {
+    ($command:expr, $matcher:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (&$matcher) {
+                    matcher => {
+                        match command_output {
+                            Ok(output) => {
+                                let string = String::from_utf8_lossy(&output.stdout).into_owned();
+                                let lines = string.lines().filter(|line| !line.is_empty());
+                                if lines.clone().any(|line| matcher.is_match(line)) {
+                                    Ok(())
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stdout_lines_any_match!(command, matcher)`\n",
+                                                "{}macro.assert_command_stdout_lines_any_match.html\n",
+                                                " command label: `{}`,\n",
+                                                " command debug: `{:?}`,\n",
+                                                " matcher label: `{}`,\n",
+                                                " matcher debug: `{:?}`,\n",
+                                                " command value: `{:?}`,\n",
+                                                " lines checked: `{}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($matcher),
+                                            matcher,
+                                            string,
+                                            lines.count()
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_lines_any_match!(command, matcher)`\n",
+                                            "{}macro.assert_command_stdout_lines_any_match.html\n",
+                                            " command label: `{}`,\n",
+                                            " command debug: `{:?}`,\n",
+                                            " matcher label: `{}`,\n",
+                                            " matcher debug: `{:?}`,\n",
+                                            " output is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($matcher),
+                                        matcher,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use regex::Regex;
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa
+bravo 2
+"]);
+        let matcher = Regex::new(r"^[a-z]+ \d+$").unwrap();
+        let result = assert_command_stdout_lines_any_match_as_result!(command, matcher);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa
+
+bravo
+"]);
+        let matcher = Regex::new(r"^[a-z]+ \d+$").unwrap();
+        let result = assert_command_stdout_lines_any_match_as_result!(command, matcher);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stdout_lines_any_match!(command, matcher)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_lines_any_match.html\n",
+                " command label: `command`,\n",
+                " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\\n\\nbravo\\n\"`,\n",
+                " matcher label: `matcher`,\n",
+                " matcher debug: `Regex(\"^[a-z]+ \\\\d+$\")`,\n",
+                " command value: `\"alfa\\n\\nbravo\\n\"`,\n",
+                " lines checked: `2`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_stdout_is_empty() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", ""]);
+        let matcher = Regex::new(r".*").unwrap();
+        let result = assert_command_stdout_lines_any_match_as_result!(command, matcher);
+        assert!(result.unwrap_err().ends_with(" lines checked: `0`"));
+    }
+
+    #[test]
+    fn failure_because_command_cannot_spawn() {
+        let mut command = Command::new("bin/no-such-program");
+        let matcher = Regex::new(r"^[a-z]+ \d+$").unwrap();
+        let result = assert_command_stdout_lines_any_match_as_result!(command, matcher);
+        assert!(result.unwrap_err().contains(" output is err: `"));
+    }
+}
+
+/// Assert any non-empty line of a command stdout string is a match to a regex.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ non-empty lines).any(|line| matcher.is_match(line))
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+/// use regex::Regex;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa 1\nbravo 2\n"]);
+/// let matcher = Regex::new(r"^[a-z]+ \d+$").unwrap();
+/// assert_command_stdout_lines_any_match!(command, &matcher);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa\nbravo\n"]);
+/// let matcher = Regex::new(r"^[a-z]+ \d+$").unwrap();
+/// assert_command_stdout_lines_any_match!(command, &matcher);
+/// # });
+/// // assertion failed: `assert_command_stdout_lines_any_match!(command, matcher)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_lines_any_match.html
+/// //  command label: `command`,
+/// //  command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\\nbravo\\n\"`,
+/// //  matcher label: `&matcher`,
+/// //  matcher debug: `Regex(\"^[a-z]+ \\\\d+$\")`,
+/// //  command value: `\"alfa\\nbravo\\n\"`,
+/// //  lines checked: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdout_lines_any_match!(command, matcher)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_lines_any_match.html\n",
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\\nbravo\\n\"`,\n",
+/// #     " matcher label: `&matcher`,\n",
+/// #     " matcher debug: `Regex(\"^[a-z]+ \\\\d+$\")`,\n",
+/// #     " command value: `\"alfa\\nbravo\\n\"`,\n",
+/// #     " lines checked: `2`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_lines_any_match`](macro@crate::assert_command_stdout_lines_any_match)
+/// * [`assert_command_stdout_lines_any_match_as_result`](macro@crate::assert_command_stdout_lines_any_match_as_result)
+/// * [`debug_assert_command_stdout_lines_any_match`](macro@crate::debug_assert_command_stdout_lines_any_match)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_lines_any_match {
+    ($command:expr, $matcher:expr $(,)?) => {{
+        match $crate::assert_command_stdout_lines_any_match_as_result!($command, $matcher) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $matcher:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_lines_any_match_as_result!($command, $matcher) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert any non-empty line of a command stdout string is a match to a regex.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ non-empty lines).any(|line| matcher.is_match(line))
+///
+/// This macro provides the same statements as [`assert_command_stdout_lines_any_match`](macro.assert_command_stdout_lines_any_match.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_lines_any_match`](macro@crate::assert_command_stdout_lines_any_match)
+/// * [`assert_command_stdout_lines_any_match_as_result`](macro@crate::assert_command_stdout_lines_any_match_as_result)
+/// * [`debug_assert_command_stdout_lines_any_match`](macro@crate::debug_assert_command_stdout_lines_any_match)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_lines_any_match {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_lines_any_match!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,276 @@
+//! Assert a command stdout string trimmed is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout ⇒ string ⇒ trim) = expr
+//!
+//! Command output often ends with a trailing newline. This macro trims the
+//! stdout string before comparing, and compares the expression as-is.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s\n", "alfa"]);
+//! let expr = "alfa";
+//! assert_command_stdout_trim_eq_x!(command, expr);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_trim_eq_x`](macro@crate::assert_command_stdout_trim_eq_x)
+//! * [`assert_command_stdout_trim_eq_x_as_result`](macro@crate::assert_command_stdout_trim_eq_x_as_result)
+//! * [`debug_assert_command_stdout_trim_eq_x`](macro@crate::debug_assert_command_stdout_trim_eq_x)
+
+/// Assert a command stdout string trimmed is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ trim) = expr
+///
+/// * If true, return Result `Ok(command ⇒ stdout ⇒ string ⇒ trim)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_command_stdout_trim_eq_x`](macro.assert_command_stdout_trim_eq_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_trim_eq_x`](macro@crate::assert_command_stdout_trim_eq_x)
+/// * [`assert_command_stdout_trim_eq_x_as_result`](macro@crate::assert_command_stdout_trim_eq_x_as_result)
+/// * [`debug_assert_command_stdout_trim_eq_x`](macro@crate::debug_assert_command_stdout_trim_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_trim_eq_x_as_result {
+    ($command:expr, $b_expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::{AssertCommandOnce as _, AssertOutputOnce as _};
+        match $command.output_once() {
+            (command, command_output) => {
+                match (&$b_expr) {
+                    b_expr => {
+                        match command_output {
+                            Ok(output) => {
+                                let string = String::from_utf8(output.stdout).unwrap();
+                                let string_trim = string.trim();
+                                if string_trim == *b_expr {
+                                    Ok(String::from(string_trim))
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stdout_trim_eq_x!(command, expr)`\n",
+                                                "{}macro.assert_command_stdout_trim_eq_x.html\n",
+                                                " command label: `{}`,\n",
+                                                " command debug: `{:?}`,\n",
+                                                "    expr label: `{}`,\n",
+                                                "    expr debug: `{:?}`,\n",
+                                                "        string: `{:?}`,\n",
+                                                " string.trim(): `{:?}`",
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($command),
+                                            command,
+                                            stringify!($b_expr),
+                                            b_expr,
+                                            string,
+                                            string_trim
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_trim_eq_x!(command, expr)`\n",
+                                            "{}macro.assert_command_stdout_trim_eq_x.html\n",
+                                            " command label: `{}`,\n",
+                                            " command debug: `{:?}`,\n",
+                                            "    expr label: `{}`,\n",
+                                            "    expr debug: `{:?}`,\n",
+                                            "    output err: `{:?}`",
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($command),
+                                        command,
+                                        stringify!($b_expr),
+                                        b_expr,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn eq() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s\n", "alfa"]);
+        let expr = "alfa";
+        let result = assert_command_stdout_trim_eq_x_as_result!(command, expr);
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn eq_with_string() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s\n", "alfa"]);
+        let expr = String::from("alfa");
+        let result = assert_command_stdout_trim_eq_x_as_result!(command, expr);
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn ne() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s\n", "alfa"]);
+        let expr = "zz";
+        let result = assert_command_stdout_trim_eq_x_as_result!(command, expr);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_stdout_trim_eq_x!(command, expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_trim_eq_x.html\n",
+                " command label: `command`,\n",
+                " command debug: `\"bin/printf-stdout\" \"%s\\n\" \"alfa\"`,\n",
+                "    expr label: `expr`,\n",
+                "    expr debug: `\"zz\"`,\n",
+                "        string: `\"alfa\\n\"`,\n",
+                " string.trim(): `\"alfa\"`",
+            )
+        );
+    }
+}
+
+/// Assert a command stdout string trimmed is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ trim) = expr
+///
+/// * If true, return `(command ⇒ stdout ⇒ string ⇒ trim)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s\n", "alfa"]);
+/// let expr = "alfa";
+/// assert_command_stdout_trim_eq_x!(command, expr);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s\n", "alfa"]);
+/// let expr = "zz";
+/// assert_command_stdout_trim_eq_x!(command, expr);
+/// # });
+/// // assertion failed: `assert_command_stdout_trim_eq_x!(command, expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_trim_eq_x.html
+/// //  command label: `command`,
+/// //  command debug: `\"bin/printf-stdout\" \"%s\\n\" \"alfa\"`,
+/// //     expr label: `expr`,
+/// //     expr debug: `\"zz\"`,
+/// //         string: `\"alfa\\n\"`,
+/// //  string.trim(): `\"alfa\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_stdout_trim_eq_x!(command, expr)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_stdout_trim_eq_x.html\n",
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"bin/printf-stdout\" \"%s\\n\" \"alfa\"`,\n",
+/// #     "    expr label: `expr`,\n",
+/// #     "    expr debug: `\"zz\"`,\n",
+/// #     "        string: `\"alfa\\n\"`,\n",
+/// #     " string.trim(): `\"alfa\"`",
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_trim_eq_x`](macro@crate::assert_command_stdout_trim_eq_x)
+/// * [`assert_command_stdout_trim_eq_x_as_result`](macro@crate::assert_command_stdout_trim_eq_x_as_result)
+/// * [`debug_assert_command_stdout_trim_eq_x`](macro@crate::debug_assert_command_stdout_trim_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_trim_eq_x {
+    ($command:expr, $b_expr:expr $(,)?) => {{
+        match $crate::assert_command_stdout_trim_eq_x_as_result!($command, $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $b_expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_trim_eq_x_as_result!($command, $b_expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command stdout string trimmed is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string ⇒ trim) = expr
+///
+/// This macro provides the same statements as [`assert_command_stdout_trim_eq_x`](macro.assert_command_stdout_trim_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_trim_eq_x`](macro@crate::assert_command_stdout_trim_eq_x)
+/// * [`assert_command_stdout_trim_eq_x_as_result`](macro@crate::assert_command_stdout_trim_eq_x_as_result)
+/// * [`debug_assert_command_stdout_trim_eq_x`](macro@crate::debug_assert_command_stdout_trim_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_trim_eq_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_stdout_trim_eq_x!($($arg)*);
+        }
+    };
+}
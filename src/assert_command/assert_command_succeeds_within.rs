@@ -0,0 +1,341 @@
+//! Assert a command succeeds within a time limit.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ spawn ⇒ exit before duration) ∧ (status is success)
+//!
+//! This is the assertion for "this tool should finish quickly and succeed".
+//! The macro spawns the command, and if the command does not exit within the
+//! duration, then the macro kills the command, and reports the elapsed time.
+//! If the command exits with a failure status, then the macro reports the
+//! status and the last 10 lines of stderr.
+//!
+//! The macro sets the command stdout and stderr to `Stdio::piped()`, and
+//! leaves the command stdin as is.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//! use std::time::Duration;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let output = assert_command_succeeds_within!(command, Duration::from_secs(10));
+//! assert_eq!(output.stdout, b"alfa");
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_succeeds_within`](macro@crate::assert_command_succeeds_within)
+//! * [`assert_command_succeeds_within_as_result`](macro@crate::assert_command_succeeds_within_as_result)
+//! * [`debug_assert_command_succeeds_within`](macro@crate::debug_assert_command_succeeds_within)
+
+/// Assert a command succeeds within a time limit.
+///
+/// Pseudocode:<br>
+/// (command ⇒ spawn ⇒ exit before duration) ∧ (status is success)
+///
+/// * If true, return Result `Ok(output)`, where output is a `::std::process::Output`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The duration is a `::std::time::Duration`. The macro polls the command for
+/// exit about every 10 milliseconds, so the elapsed time can be slightly more
+/// than the duration.
+///
+/// The command reads stdin from null, like `::std::process::Command::output`,
+/// so a command that reads stdin, such as `cat`, does not wait for input.
+///
+/// This macro provides the same statements as [`assert_command_succeeds_within`](macro.assert_command_succeeds_within.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_succeeds_within`](macro@crate::assert_command_succeeds_within)
+/// * [`assert_command_succeeds_within_as_result`](macro@crate::assert_command_succeeds_within_as_result)
+/// * [`debug_assert_command_succeeds_within`](macro@crate::debug_assert_command_succeeds_within)
+///
+#[macro_export]
+macro_rules! assert_command_succeeds_within_as_result {
+    ($command:expr, $duration:expr $(,)?) => {{
+        match (&mut $command, &$duration) {
+            (command, duration) => {
+                match $crate::assert_command::output_within(command, *duration) {
+                    Ok($crate::assert_command::OutputWithin::Finished(output)) => {
+                        if output.status.success() {
+                            Ok(output)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_succeeds_within!(command, duration)`\n",
+                                        "{}macro.assert_command_succeeds_within.html\n",
+                                        "  command label: `{}`,\n",
+                                        "  command debug: `{:?}`,\n",
+                                        " duration label: `{}`,\n",
+                                        " duration debug: `{:?}`,\n",
+                                        "         status: `{}`,\n",
+                                        "    stderr tail: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($command),
+                                    command,
+                                    stringify!($duration),
+                                    duration,
+                                    output.status,
+                                    $crate::assert_command::tail_lines(&output.stderr, 10)
+                                )
+                            )
+                        }
+                    },
+                    Ok($crate::assert_command::OutputWithin::TimedOut(elapsed)) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_succeeds_within!(command, duration)`\n",
+                                    "{}macro.assert_command_succeeds_within.html\n",
+                                    "  command label: `{}`,\n",
+                                    "  command debug: `{:?}`,\n",
+                                    " duration label: `{}`,\n",
+                                    " duration debug: `{:?}`,\n",
+                                    "        elapsed: `{:?}`,\n",
+                                    "         status: `killed after timeout`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($command),
+                                command,
+                                stringify!($duration),
+                                duration,
+                                elapsed
+                            )
+                        )
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_succeeds_within!(command, duration)`\n",
+                                    "{}macro.assert_command_succeeds_within.html\n",
+                                    "  command label: `{}`,\n",
+                                    "  command debug: `{:?}`,\n",
+                                    " duration label: `{}`,\n",
+                                    " duration debug: `{:?}`,\n",
+                                    "  output is err: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($command),
+                                command,
+                                stringify!($duration),
+                                duration,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let duration = Duration::from_secs(10);
+        let result = assert_command_succeeds_within_as_result!(command, duration);
+        let output = result.unwrap();
+        assert_eq!(output.stdout, b"alfa");
+        assert!(output.stderr.is_empty());
+    }
+
+    #[test]
+    fn success_with_command_that_reads_stdin() {
+        let mut command = Command::new("cat");
+        let duration = Duration::from_secs(10);
+        let result = assert_command_succeeds_within_as_result!(command, duration);
+        let output = result.unwrap();
+        assert!(output.stdout.is_empty());
+    }
+
+    #[test]
+    fn success_with_large_output() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "i=0; while [ $i -lt 20000 ]; do echo alfa; echo bravo >&2; i=$((i+1)); done"]);
+        let duration = Duration::from_secs(30);
+        let result = assert_command_succeeds_within_as_result!(command, duration);
+        let output = result.unwrap();
+        assert_eq!(output.stdout.len(), 20000 * 5);
+        assert_eq!(output.stderr.len(), 20000 * 6);
+    }
+
+    #[test]
+    fn failure_because_status() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "printf 'alfa\\nbravo\\n' >&2; exit 2"]);
+        let duration = Duration::from_secs(10);
+        let result = assert_command_succeeds_within_as_result!(command, duration);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_command_succeeds_within!(command, duration)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_succeeds_within.html\n",
+                "  command label: `command`,\n",
+                "  command debug: `\"sh\" \"-c\" \"printf 'alfa\\\\nbravo\\\\n' >&2; exit 2\"`,\n",
+                " duration label: `duration`,\n",
+                " duration debug: `10s`,\n",
+                "         status: `exit status: 2`,\n",
+                "    stderr tail: `\"alfa\\nbravo\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_timeout() {
+        let mut command = Command::new("sleep");
+        command.arg("10");
+        let duration = Duration::from_millis(100);
+        let start = Instant::now();
+        let result = assert_command_succeeds_within_as_result!(command, duration);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let actual = result.unwrap_err();
+        assert!(actual.contains(" duration debug: `100ms`,\n"));
+        assert!(actual.contains("        elapsed: `"));
+        assert!(actual.ends_with("         status: `killed after timeout`"));
+    }
+
+    #[test]
+    fn failure_because_command_cannot_spawn() {
+        let mut command = Command::new("bin/no-such-program");
+        let duration = Duration::from_secs(10);
+        let result = assert_command_succeeds_within_as_result!(command, duration);
+        assert!(result.unwrap_err().contains("  output is err: `"));
+    }
+}
+
+/// Assert a command succeeds within a time limit.
+///
+/// Pseudocode:<br>
+/// (command ⇒ spawn ⇒ exit before duration) ∧ (status is success)
+///
+/// * If true, return `output`, where output is a `::std::process::Output`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+/// use std::time::Duration;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let duration = Duration::from_secs(10);
+/// let output = assert_command_succeeds_within!(command, duration);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/exit-with-arg");
+/// command.arg("1");
+/// let duration = Duration::from_secs(10);
+/// assert_command_succeeds_within!(command, duration);
+/// # });
+/// // assertion failed: `assert_command_succeeds_within!(command, duration)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_succeeds_within.html
+/// //   command label: `command`,
+/// //   command debug: `\"bin/exit-with-arg\" \"1\"`,
+/// //  duration label: `duration`,
+/// //  duration debug: `10s`,
+/// //          status: `exit status: 1`,
+/// //     stderr tail: `\"\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_command_succeeds_within!(command, duration)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_command_succeeds_within.html\n",
+/// #     "  command label: `command`,\n",
+/// #     "  command debug: `\"bin/exit-with-arg\" \"1\"`,\n",
+/// #     " duration label: `duration`,\n",
+/// #     " duration debug: `10s`,\n",
+/// #     "         status: `exit status: 1`,\n",
+/// #     "    stderr tail: `\"\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_succeeds_within`](macro@crate::assert_command_succeeds_within)
+/// * [`assert_command_succeeds_within_as_result`](macro@crate::assert_command_succeeds_within_as_result)
+/// * [`debug_assert_command_succeeds_within`](macro@crate::debug_assert_command_succeeds_within)
+///
+#[macro_export]
+macro_rules! assert_command_succeeds_within {
+    ($command:expr, $duration:expr $(,)?) => {{
+        match $crate::assert_command_succeeds_within_as_result!($command, $duration) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($command:expr, $duration:expr, $($message:tt)+) => {{
+        match $crate::assert_command_succeeds_within_as_result!($command, $duration) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command succeeds within a time limit.
+///
+/// Pseudocode:<br>
+/// (command ⇒ spawn ⇒ exit before duration) ∧ (status is success)
+///
+/// This macro provides the same statements as [`assert_command_succeeds_within`](macro.assert_command_succeeds_within.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_succeeds_within`](macro@crate::assert_command_succeeds_within)
+/// * [`assert_command_succeeds_within_as_result`](macro@crate::assert_command_succeeds_within_as_result)
+/// * [`debug_assert_command_succeeds_within`](macro@crate::debug_assert_command_succeeds_within)
+///
+#[macro_export]
+macro_rules! debug_assert_command_succeeds_within {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_command_succeeds_within!($($arg)*);
+        }
+    };
+}
@@ -5,6 +5,27 @@
 //!
 //! These macros have corresponding the macros in the module [`assert_program_args`](module@crate::assert_program_args).
 //!
+//! ## Command output
+//!
+//! Run a command once, then reuse its output:
+//!
+//! * [`assert_command_output!(command)`](macro@crate::assert_command_output) ≈ command.output() is Ok ⇒ output
+//!
+//! Each command macro runs its command each time. To run a command once and
+//! make many assertions, capture its `::std::process::Output` with
+//! [`assert_command_output`](macro@crate::assert_command_output), then pass
+//! the output to the command macros in place of the command.
+//! The macros in the module [`assert_output`](module@crate::assert_output)
+//! also take an output, and borrow it rather than clone it.
+//!
+//! ## Command success within a time limit
+//!
+//! * [`assert_command_succeeds_within!(command, duration)`](macro@crate::assert_command_succeeds_within) ≈ command exits before duration ∧ status is success ⇒ output
+//!
+//! ## Command success and standard output
+//!
+//! * [`assert_command_stdout_eq_x_and_success!(command, expr)`](macro@crate::assert_command_stdout_eq_x_and_success) ≈ command status is success ∧ command stdout = expr ⇒ output
+//!
 //! ## Command standard output
 //!
 //! Compare command standard output to another command standard output:
@@ -24,11 +45,46 @@
 //! * [`assert_command_stdout_le_x!(command, expr)`](macro@crate::assert_command_stdout_le_x) ≈ command stdout ≤ expr
 //! * [`assert_command_stdout_gt_x!(command, expr)`](macro@crate::assert_command_stdout_gt_x) ≈ command stdout > expr
 //! * [`assert_command_stdout_ge_x!(command, expr)`](macro@crate::assert_command_stdout_ge_x) ≈ command stdout ≥ expr
+//! * [`assert_command_stdout_eq_bytes_x!(command, bytes)`](macro@crate::assert_command_stdout_eq_bytes_x) ≈ command stdout = bytes.as_ref(), for a byte string or a string
 //!
 //! Assert command standard output as a string:
 //!
 //! * [`assert_command_stdout_string_contains!(command, containee)`](macro@crate::assert_command_stdout_string_contains) ≈ command stdout string contains containee
+//! * [`assert_command_stdout_contains_all!(command, containees)`](macro@crate::assert_command_stdout_contains_all) ≈ command stdout string contains every containee
 //! * [`assert_command_stdout_string_is_match!(command, matcher)`](macro@crate::assert_command_stdout_string_is_match) ≈ command stdout string is a matcher match
+//! * [`assert_command_stdout_trim_eq_x!(command, expr)`](macro@crate::assert_command_stdout_trim_eq_x) ≈ command stdout string trim = expr
+//! * [`assert_command_stdout_eq_normalized!(command, expr)`](macro@crate::assert_command_stdout_eq_normalized) ≈ command stdout string normalized = expr normalized
+//! * [`assert_command_stdout_eq_x_crlf_insensitive!(command, expr)`](macro@crate::assert_command_stdout_eq_x_crlf_insensitive) ≈ command stdout string with CRLF as LF = expr with CRLF as LF
+//! * [`assert_command_stdout_lines_all_match!(command, matcher)`](macro@crate::assert_command_stdout_lines_all_match) ≈ command stdout non-empty lines all match matcher
+//! * [`assert_command_stdout_lines_any_match!(command, matcher)`](macro@crate::assert_command_stdout_lines_any_match) ≈ command stdout non-empty lines any match matcher
+//!
+//! Assert command standard output as JSON, with the crate feature `json`:
+//!
+//! * [`assert_command_stdout_json_contains!(command, subset)`](macro@crate::assert_command_stdout_json_contains) ≈ command stdout JSON contains every part of subset JSON
+//!
+//! Compare command standard output to a file:
+//!
+//! * [`assert_command_stdout_eq_file!(command, path)`](macro@crate::assert_command_stdout_eq_file) ≈ command stdout = std::fs::read(path)
+//!
+//! Compare command standard output, with standard input from a file, to an expression:
+//!
+//! * [`assert_command_stdin_file_stdout_eq_x!(command, stdin_path, expr)`](macro@crate::assert_command_stdin_file_stdout_eq_x) ≈ command with stdin file ⇒ stdout = expr
+//!
+//! Compare command standard output, with a cleared environment, to an expression:
+//!
+//! * [`assert_command_clean_env_stdout_eq_x!(command, expr)`](macro@crate::assert_command_clean_env_stdout_eq_x) ≈ command with cleared environment ⇒ stdout = expr
+//!
+//! Compare command standard output, with a hermetic environment and a new empty current directory, to an expression:
+//!
+//! * [`assert_command_stdout_eq_x_hermetic!(command, expr)`](macro@crate::assert_command_stdout_eq_x_hermetic) ≈ command with LANG=C, TZ=UTC, temp dir ⇒ stdout = expr
+//!
+//! Compare command standard output and standard error to another command, running each command once:
+//!
+//! * [`assert_command_stdout_and_stderr_eq!(command1, command2)`](macro@crate::assert_command_stdout_and_stderr_eq) ≈ command1 stdout = command2 stdout ∧ command1 stderr = command2 stderr
+//!
+//! Assert command standard output and standard error, merged like `2>&1`:
+//!
+//! * [`assert_command_merged_output_contains!(command, containee)`](macro@crate::assert_command_merged_output_contains) ≈ command stdout and stderr merged string contains containee
 //!
 //! ## Command standard error
 //!
@@ -55,6 +111,15 @@
 //! * [`assert_command_stderr_string_contains!(command, containee)`](macro@crate::assert_command_stderr_string_contains) ≈ command stderr string contains containee
 //! * [`assert_command_stderr_string_is_match!(command, matcher)`](macro@crate::assert_command_stderr_string_is_match) ≈ command stderr string is a matcher match
 //!
+//! Compare command standard error line count to an expression:
+//!
+//! * [`assert_command_stderr_line_count_eq!(command, n)`](macro@crate::assert_command_stderr_line_count_eq) ≈ command stderr string lines count = n
+//! * [`assert_command_stderr_line_count_ne!(command, n)`](macro@crate::assert_command_stderr_line_count_ne) ≈ command stderr string lines count ≠ n
+//! * [`assert_command_stderr_line_count_lt!(command, n)`](macro@crate::assert_command_stderr_line_count_lt) ≈ command stderr string lines count < n
+//! * [`assert_command_stderr_line_count_le!(command, n)`](macro@crate::assert_command_stderr_line_count_le) ≈ command stderr string lines count ≤ n
+//! * [`assert_command_stderr_line_count_gt!(command, n)`](macro@crate::assert_command_stderr_line_count_gt) ≈ command stderr string lines count > n
+//! * [`assert_command_stderr_line_count_ge!(command, n)`](macro@crate::assert_command_stderr_line_count_ge) ≈ command stderr string lines count ≥ n
+//!
 //! # Example
 //!
 //! ```rust
@@ -70,6 +135,15 @@
 //! # }
 //! ```
 
+// Capture output
+pub mod assert_command_output;
+
+// Success within a time limit
+pub mod assert_command_succeeds_within;
+
+// Success and stdout
+pub mod assert_command_stdout_eq_x_and_success;
+
 // Compare another
 pub mod assert_command_stdout_eq;
 pub mod assert_command_stdout_ge;
@@ -79,6 +153,7 @@ pub mod assert_command_stdout_lt;
 pub mod assert_command_stdout_ne;
 
 // Compare expression
+pub mod assert_command_stdout_eq_bytes_x;
 pub mod assert_command_stdout_eq_x;
 pub mod assert_command_stdout_ge_x;
 pub mod assert_command_stdout_gt_x;
@@ -88,9 +163,37 @@ pub mod assert_command_stdout_ne_x;
 
 // stdout string
 pub mod assert_command_stdout_contains;
+pub mod assert_command_stdout_contains_all;
+pub mod assert_command_stdout_eq_normalized;
+pub mod assert_command_stdout_eq_x_crlf_insensitive;
 pub mod assert_command_stdout_is_match;
+pub mod assert_command_stdout_lines_all_match;
+pub mod assert_command_stdout_lines_any_match;
 pub mod assert_command_stdout_string_contains;
 pub mod assert_command_stdout_string_is_match;
+pub mod assert_command_stdout_trim_eq_x;
+
+// stdout JSON
+#[cfg(feature = "json")]
+pub mod assert_command_stdout_json_contains;
+
+// stdout file
+pub mod assert_command_stdout_eq_file;
+
+// stdin file
+pub mod assert_command_stdin_file_stdout_eq_x;
+
+// clean environment
+pub mod assert_command_clean_env_stdout_eq_x;
+
+// hermetic environment
+pub mod assert_command_stdout_eq_x_hermetic;
+
+// stdout and stderr both
+pub mod assert_command_stdout_and_stderr_eq;
+
+// stdout and stderr merged
+pub mod assert_command_merged_output_contains;
 
 // stderr
 pub mod assert_command_stderr_eq;
@@ -113,3 +216,251 @@ pub mod assert_command_stderr_contains;
 pub mod assert_command_stderr_is_match;
 pub mod assert_command_stderr_string_contains;
 pub mod assert_command_stderr_string_is_match;
+
+// stderr line count
+pub mod assert_command_stderr_line_count_eq;
+pub mod assert_command_stderr_line_count_ge;
+pub mod assert_command_stderr_line_count_gt;
+pub mod assert_command_stderr_line_count_le;
+pub mod assert_command_stderr_line_count_lt;
+pub mod assert_command_stderr_line_count_ne;
+
+/// Run a command once, and return the command with its output.
+///
+/// The command macros call `.output_once()` on each command argument, so the
+/// argument expression is evaluated once, even when the message shows its
+/// debug representation, and a command built inline runs once. The method
+/// borrows the command as mutable, which `::std::process::Command::output`
+/// needs, and the sibling trait [`AssertOutputOnce`] borrows an output as
+/// immutable, so a captured output does not need to be mutable.
+#[doc(hidden)]
+pub trait AssertCommandOnce {
+    fn output_once(&mut self) -> (&Self, ::std::io::Result<::std::process::Output>);
+}
+
+impl AssertCommandOnce for ::std::process::Command {
+    fn output_once(&mut self) -> (&Self, ::std::io::Result<::std::process::Output>) {
+        let output = self.output();
+        (self, output)
+    }
+}
+
+/// Reuse an output that is already captured, and return it with a clone.
+///
+/// This is the sibling of [`AssertCommandOnce`] for a `::std::process::Output`.
+#[doc(hidden)]
+pub trait AssertOutputOnce {
+    fn output_once(&self) -> (&Self, ::std::io::Result<::std::process::Output>);
+}
+
+impl AssertOutputOnce for ::std::process::Output {
+    fn output_once(&self) -> (&Self, ::std::io::Result<::std::process::Output>) {
+        (self, Ok(self.clone()))
+    }
+}
+
+/// Normalize a string for a line-by-line comparison.
+///
+/// * Convert each line ending `\r\n` or `\r` to `\n`.
+///
+/// * Remove the trailing whitespace of each line, as defined by `char::is_whitespace`.
+///
+/// This is a helper for [`assert_command_stdout_eq_normalized`](macro@crate::assert_command_stdout_eq_normalized).
+#[doc(hidden)]
+pub fn normalize_lines(string: &str) -> String {
+    string
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .split('\n')
+        .map(str::trim_end)
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+/// Run a command with its stderr redirected into its stdout, like `2>&1`,
+/// and return the merged bytes.
+///
+/// The command writes both streams into one new file in the system temporary
+/// directory, via two handles that share one file offset, so the bytes keep
+/// the order in which the command wrote them. After the command runs, this
+/// reads the file and removes it. This uses only `::std::fs::File` rather than
+/// `::std::io::pipe`, so it needs no newer Rust than the rest of the crate.
+///
+/// The command reads stdin from null, like `::std::process::Command::output`,
+/// so a command that reads stdin, such as `cat`, does not wait for input.
+///
+/// The standard library has no way to read or unset the stdio of a command,
+/// so after the command runs, this sets the command stdin to `Stdio::null()`,
+/// and the command stdout and stderr to `Stdio::piped()`, which are what
+/// `output()` uses by default, and which release the file handles. Any stdio
+/// that the caller set is replaced.
+///
+/// This is a helper for [`assert_command_merged_output_contains`](macro@crate::assert_command_merged_output_contains).
+#[doc(hidden)]
+pub fn merged_output(command: &mut ::std::process::Command) -> ::std::io::Result<Vec<u8>> {
+    static COUNT: ::std::sync::atomic::AtomicUsize = ::std::sync::atomic::AtomicUsize::new(0);
+    let path = ::std::env::temp_dir().join(format!(
+        "assertables-merged-{}-{}",
+        ::std::process::id(),
+        COUNT.fetch_add(1, ::std::sync::atomic::Ordering::Relaxed)
+    ));
+    let status = ::std::fs::File::options()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .and_then(|file| file.try_clone().map(|file_clone| (file, file_clone)))
+        .and_then(|(file, file_clone)| {
+            command
+                .stdin(::std::process::Stdio::null())
+                .stdout(file)
+                .stderr(file_clone)
+                .status()
+        });
+    command
+        .stdin(::std::process::Stdio::null())
+        .stdout(::std::process::Stdio::piped())
+        .stderr(::std::process::Stdio::piped());
+    let bytes = status.and_then(|_| ::std::fs::read(&path));
+    let _ = ::std::fs::remove_file(&path);
+    bytes
+}
+
+/// The result of running a command with a time limit.
+#[doc(hidden)]
+#[derive(Debug)]
+pub enum OutputWithin {
+    /// The command finished within the time limit.
+    Finished(::std::process::Output),
+    /// The command did not finish within the time limit, so it was killed.
+    /// This has the elapsed time when the command was killed.
+    TimedOut(::std::time::Duration),
+}
+
+/// Run a command with a time limit, and capture its output.
+///
+/// This spawns the command with stdin null, and stdout and stderr piped,
+/// which are the defaults of `::std::process::Command::output`, so a command
+/// that reads stdin, such as `cat`, gets end of file rather than blocking.
+/// Then this reads both pipes in threads, so a command that writes a lot
+/// cannot fill a pipe and block. This polls the child for exit until the time
+/// limit. If the time limit expires, then this kills the child and waits for
+/// it, so there is no zombie process.
+///
+/// The standard library has no way to read or unset the stdio of a command,
+/// so this leaves the command with these settings, and it replaces any stdin
+/// that the caller set.
+///
+/// This is a helper for [`assert_command_succeeds_within`](macro@crate::assert_command_succeeds_within).
+#[doc(hidden)]
+pub fn output_within(
+    command: &mut ::std::process::Command,
+    duration: ::std::time::Duration,
+) -> ::std::io::Result<OutputWithin> {
+    use ::std::io::Read;
+    let start = ::std::time::Instant::now();
+    let mut child = command
+        .stdin(::std::process::Stdio::null())
+        .stdout(::std::process::Stdio::piped())
+        .stderr(::std::process::Stdio::piped())
+        .spawn()?;
+    let read_all = |pipe: Option<Box<dyn Read + Send>>| {
+        ::std::thread::spawn(move || {
+            let mut bytes = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut bytes);
+            }
+            bytes
+        })
+    };
+    let stdout = read_all(child.stdout.take().map(|x| Box::new(x) as Box<dyn Read + Send>));
+    let stderr = read_all(child.stderr.take().map(|x| Box::new(x) as Box<dyn Read + Send>));
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(OutputWithin::Finished(::std::process::Output {
+                status,
+                stdout: stdout.join().unwrap_or_default(),
+                stderr: stderr.join().unwrap_or_default(),
+            }));
+        }
+        let elapsed = start.elapsed();
+        if elapsed >= duration {
+            // Kill and reap the child. Do not join the reader threads, because
+            // a grandchild process can keep a pipe open after the child exits.
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(OutputWithin::TimedOut(elapsed));
+        }
+        ::std::thread::sleep((duration - elapsed).min(::std::time::Duration::from_millis(10)));
+    }
+}
+
+/// Return the last lines of bytes, decoded as UTF-8, such as for a stderr tail.
+#[doc(hidden)]
+pub fn tail_lines(bytes: &[u8], count: usize) -> String {
+    let string = String::from_utf8_lossy(bytes);
+    let lines: Vec<&str> = string.lines().collect();
+    lines[lines.len().saturating_sub(count)..].join("\n")
+}
+
+/// Describe how one output stream of two commands differs, for a message.
+///
+/// * If the bytes are equal, return one line that says so.
+///
+/// * Otherwise, return a line with the first differing offset, then a
+///   hexdump of both streams.
+///
+/// This is a helper for [`assert_command_stdout_and_stderr_eq`](macro@crate::assert_command_stdout_and_stderr_eq).
+#[doc(hidden)]
+pub fn stream_diff(name: &str, a: &[u8], b: &[u8]) -> String {
+    match crate::assert_slice::first_difference(a, b) {
+        None => format!("{:>16}: `equal`", format!("{} diff", name)),
+        Some(offset) => format!(
+            "{:>16}: `{}`,\n{:>16}:\n{}",
+            format!("{} offset", name),
+            offset,
+            format!("{} hexdump", name),
+            crate::hexdump::hexdump_diff(a, b)
+        ),
+    }
+}
+
+/// The environment variables that a hermetic command run sets, after it
+/// clears the inherited environment.
+#[doc(hidden)]
+pub const HERMETIC_ENV: [(&str, &str); 2] = [("LANG", "C"), ("TZ", "UTC")];
+
+/// Describe the hermetic environment, for a message.
+#[doc(hidden)]
+pub fn hermetic_env_description() -> String {
+    let vars: Vec<String> = HERMETIC_ENV.iter().map(|(key, value)| format!("{}={}", key, value)).collect();
+    format!("cleared, then {}", vars.join(" "))
+}
+
+/// Run a command in a hermetic way, and capture its output.
+///
+/// This clears the inherited environment, sets the variables of
+/// [`HERMETIC_ENV`], and sets the current directory to a new empty
+/// directory in the system temporary directory. After the command runs,
+/// this removes the directory.
+///
+/// Because the current directory changes, the command program path should
+/// be absolute, or be a program name that the system finds via `PATH`.
+///
+/// This is a helper for [`assert_command_stdout_eq_x_hermetic`](macro@crate::assert_command_stdout_eq_x_hermetic).
+#[doc(hidden)]
+pub fn hermetic_output(command: &mut ::std::process::Command) -> ::std::io::Result<::std::process::Output> {
+    static COUNT: ::std::sync::atomic::AtomicUsize = ::std::sync::atomic::AtomicUsize::new(0);
+    let dir = ::std::env::temp_dir().join(format!(
+        "assertables-hermetic-{}-{}",
+        ::std::process::id(),
+        COUNT.fetch_add(1, ::std::sync::atomic::Ordering::Relaxed)
+    ));
+    ::std::fs::create_dir_all(&dir)?;
+    let output = command
+        .env_clear()
+        .envs(HERMETIC_ENV)
+        .current_dir(&dir)
+        .output();
+    let _ = ::std::fs::remove_dir_all(&dir);
+    output
+}
@@ -3,6 +3,13 @@
 //! Pseudocode:<br>
 //! a.contains(b)
 //!
+//! For an ordered set, such as a `BTreeSet`, the failure message also shows
+//! the nearest present elements below and above the containee.
+//!
+//! For a string, the containee can be any pattern that `str::contains`
+//! accepts, such as a substring, a char, a slice of chars that matches any
+//! of the chars, or a closure that matches a char.
+//!
 //! # Example
 //!
 //! ```rust
@@ -14,6 +21,10 @@
 //! let b: &str = "lf";
 //! assert_contains!(a, b);
 //!
+//! // String contains any of these chars
+//! let a: &str = "alfa";
+//! assert_contains!(a, &['a', 'e', 'i'][..]);
+//!
 //! // Range contains value
 //! let a = 1..3;
 //! let b = 2;
@@ -41,6 +52,10 @@
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
+/// For a string, the containee can be any pattern that `str::contains` accepts.
+/// A closure pattern does not implement `Debug`, so the message shows
+/// `(no Debug)` as its debug representation, after its label.
+///
 /// This macro provides the same statements as [`assert_contains`](macro.assert_contains.html),
 /// except this macro returns a Result, rather than doing a panic.
 ///
@@ -61,20 +76,26 @@ macro_rules! assert_contains_as_result {
                 if container.contains($containee) {
                     Ok(())
                 } else {
+                    #[allow(unused_imports)]
+                    use $crate::assert_contains::{DebugFallback as _, DebugPreferred as _, NearestFallback as _, NearestOrdered as _};
+                    let nearest = (&$crate::assert_contains::NearestProbe(container, containee)).nearest();
+                    let containee_debug = (&$crate::assert_contains::DebugProbe(containee)).debug_string();
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_contains!(container, containee)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_contains.html\n",
+                                "{}macro.assert_contains.html\n",
                                 " container label: `{}`,\n",
                                 " container debug: `{:?}`,\n",
                                 " containee label: `{}`,\n",
-                                " containee debug: `{:?}`",
+                                " containee debug: `{}`{}",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($container),
                             container,
                             stringify!($containee),
-                            containee,
+                            containee_debug,
+                            nearest.unwrap_or_default(),
                         )
                     )
                 }
@@ -114,6 +135,59 @@ mod tests {
         }
     }
 
+    mod char_slice {
+
+        #[test]
+        fn success() {
+            let a = "alfa";
+            let result = assert_contains_as_result!(a, &['e', 'f', 'i'][..]);
+            assert_eq!(result.unwrap(), ());
+        }
+
+        #[test]
+        fn failure() {
+            let a = "alfa";
+            let b = ['e', 'i', 'o'];
+            let result = assert_contains_as_result!(a, &b[..]);
+            let actual = result.unwrap_err();
+            let expect = concat!(
+                "assertion failed: `assert_contains!(container, containee)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_contains.html\n",
+                " container label: `a`,\n",
+                " container debug: `\"alfa\"`,\n",
+                " containee label: `&b[..]`,\n",
+                " containee debug: `['e', 'i', 'o']`"
+            );
+            assert_eq!(actual, expect);
+        }
+    }
+
+    mod closure {
+
+        #[test]
+        fn success() {
+            let a = "alfa1";
+            let result = assert_contains_as_result!(a, |c: char| c.is_ascii_digit());
+            assert_eq!(result.unwrap(), ());
+        }
+
+        #[test]
+        fn failure() {
+            let a = "alfa";
+            let result = assert_contains_as_result!(a, |c: char| c.is_ascii_digit());
+            let actual = result.unwrap_err();
+            let expect = concat!(
+                "assertion failed: `assert_contains!(container, containee)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_contains.html\n",
+                " container label: `a`,\n",
+                " container debug: `\"alfa\"`,\n",
+                " containee label: `|c: char| c.is_ascii_digit()`,\n",
+                " containee debug: `(no Debug)`"
+            );
+            assert_eq!(actual, expect);
+        }
+    }
+
     mod range {
 
         #[test]
@@ -169,6 +243,62 @@ mod tests {
             assert_eq!(actual, expect);
         }
     }
+
+    mod btree_set {
+        use std::collections::BTreeSet;
+
+        #[test]
+        fn success() {
+            let a: BTreeSet<i32> = [1, 3, 5].into();
+            let b = 3;
+            let result = assert_contains_as_result!(a, &b);
+            assert_eq!(result.unwrap(), ());
+        }
+
+        #[test]
+        fn failure_shows_nearest_below_and_above() {
+            let a: BTreeSet<i32> = [1, 3, 5].into();
+            let b = 4;
+            let result = assert_contains_as_result!(a, &b);
+            let actual = result.unwrap_err();
+            let expect = concat!(
+                "assertion failed: `assert_contains!(container, containee)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_contains.html\n",
+                " container label: `a`,\n",
+                " container debug: `{1, 3, 5}`,\n",
+                " containee label: `&b`,\n",
+                " containee debug: `4`,\n",
+                "   nearest below: `Some(3)`,\n",
+                "   nearest above: `Some(5)`"
+            );
+            assert_eq!(actual, expect);
+        }
+
+        #[test]
+        fn failure_shows_none_beyond_the_ends() {
+            let a: BTreeSet<i32> = [1, 3, 5].into();
+            let b = 9;
+            let result = assert_contains_as_result!(a, &b);
+            let actual = result.unwrap_err();
+            assert!(actual.ends_with(concat!(
+                "   nearest below: `Some(5)`,\n",
+                "   nearest above: `None`"
+            )));
+        }
+    }
+
+    mod hash_set {
+        use std::collections::HashSet;
+
+        #[test]
+        fn failure_has_no_nearest() {
+            let a: HashSet<i32> = [1].into();
+            let b = 2;
+            let result = assert_contains_as_result!(a, &b);
+            let actual = result.unwrap_err();
+            assert!(actual.ends_with(" containee debug: `2`"));
+        }
+    }
 }
 
 /// Assert a container is a match for an expression.
@@ -0,0 +1,264 @@
+//! Assert a string contains a substring a number of times less than or equal to an expression.
+//!
+//! Pseudocode:<br>
+//! haystack.matches(needle).count() ≤ n
+//!
+//! This counts non-overlapping occurrences, in the same way as
+//! [`str::matches`](https://doc.rust-lang.org/std/primitive.str.html#method.matches).
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = "alfa";
+//! let b = "a";
+//! let n = 2;
+//! assert_contains_count_le!(a, b, n);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_contains_count_le`](macro@crate::assert_contains_count_le)
+//! * [`assert_contains_count_le_as_result`](macro@crate::assert_contains_count_le_as_result)
+//! * [`debug_assert_contains_count_le`](macro@crate::debug_assert_contains_count_le)
+
+/// Assert a string contains a substring a number of times less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// haystack.matches(needle).count() ≤ n
+///
+/// * If true, return Result `Ok(count)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message shows the actual count, and the byte positions
+/// of the first few matches.
+///
+/// This macro provides the same statements as [`assert_contains_count_le`](macro.assert_contains_count_le.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_contains_count_le`](macro@crate::assert_contains_count_le)
+/// * [`assert_contains_count_le_as_result`](macro@crate::assert_contains_count_le_as_result)
+/// * [`debug_assert_contains_count_le`](macro@crate::debug_assert_contains_count_le)
+///
+#[macro_export]
+macro_rules! assert_contains_count_le_as_result {
+    ($haystack:expr, $needle:expr, $n:expr $(,)?) => {{
+        match (&$haystack, &$needle, &$n) {
+            (haystack, needle, n) => {
+                let positions = $crate::assert_contains::Needle::match_indices(needle, haystack);
+                let count = positions.len();
+                if count <= *n {
+                    Ok(count)
+                } else {
+                    let positions: Vec<usize> = positions.into_iter().take(10).collect();
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_contains_count_le!(haystack, needle, n)`\n",
+                                "{}macro.assert_contains_count_le.html\n",
+                                " haystack label: `{}`,\n",
+                                " haystack debug: `{:?}`,\n",
+                                "   needle label: `{}`,\n",
+                                "   needle debug: `{:?}`,\n",
+                                "        n label: `{}`,\n",
+                                "        n debug: `{:?}`,\n",
+                                "          count: `{:?}`,\n",
+                                "      positions: `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($haystack),
+                            haystack,
+                            stringify!($needle),
+                            needle,
+                            stringify!($n),
+                            n,
+                            count,
+                            positions
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = "alfa";
+        let b = "a";
+        let n = 2;
+        let result = assert_contains_count_le_as_result!(a, b, n);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn success_with_string_needle() {
+        let a = String::from("alfa");
+        let b = String::from("a");
+        let result = assert_contains_count_le_as_result!(a, b, 2);
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(b, "a");
+    }
+
+    #[test]
+    fn success_with_char_needle() {
+        let a = "alfa";
+        let b = 'a';
+        let n = 2;
+        let result = assert_contains_count_le_as_result!(a, b, n);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure() {
+        let a = "alfa";
+        let b = "a";
+        let n = 1;
+        let result = assert_contains_count_le_as_result!(a, b, n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_contains_count_le!(haystack, needle, n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_contains_count_le.html\n",
+                " haystack label: `a`,\n",
+                " haystack debug: `\"alfa\"`,\n",
+                "   needle label: `b`,\n",
+                "   needle debug: `\"a\"`,\n",
+                "        n label: `n`,\n",
+                "        n debug: `1`,\n",
+                "          count: `2`,\n",
+                "      positions: `[0, 3]`",
+            )
+        );
+    }
+}
+
+/// Assert a string contains a substring a number of times less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// haystack.matches(needle).count() ≤ n
+///
+/// * If true, return `count`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa";
+/// let b = "a";
+/// let n = 2;
+/// assert_contains_count_le!(a, b, n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa";
+/// let b = "a";
+/// let n = 1;
+/// assert_contains_count_le!(a, b, n);
+/// # });
+/// // assertion failed: `assert_contains_count_le!(haystack, needle, n)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_contains_count_le.html
+/// //  haystack label: `a`,
+/// //  haystack debug: `\"alfa\"`,
+/// //    needle label: `b`,
+/// //    needle debug: `\"a\"`,
+/// //         n label: `n`,
+/// //         n debug: `1`,
+/// //           count: `2`,
+/// //       positions: `[0, 3]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_contains_count_le!(haystack, needle, n)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_contains_count_le.html\n",
+/// #     " haystack label: `a`,\n",
+/// #     " haystack debug: `\"alfa\"`,\n",
+/// #     "   needle label: `b`,\n",
+/// #     "   needle debug: `\"a\"`,\n",
+/// #     "        n label: `n`,\n",
+/// #     "        n debug: `1`,\n",
+/// #     "          count: `2`,\n",
+/// #     "      positions: `[0, 3]`",
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_contains_count_le`](macro@crate::assert_contains_count_le)
+/// * [`assert_contains_count_le_as_result`](macro@crate::assert_contains_count_le_as_result)
+/// * [`debug_assert_contains_count_le`](macro@crate::debug_assert_contains_count_le)
+///
+#[macro_export]
+macro_rules! assert_contains_count_le {
+    ($haystack:expr, $needle:expr, $n:expr $(,)?) => {{
+        match $crate::assert_contains_count_le_as_result!($haystack, $needle, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($haystack:expr, $needle:expr, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_contains_count_le_as_result!($haystack, $needle, $n) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a string contains a substring a number of times less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// haystack.matches(needle).count() ≤ n
+///
+/// This macro provides the same statements as [`assert_contains_count_le`](macro.assert_contains_count_le.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_contains_count_le`](macro@crate::assert_contains_count_le)
+/// * [`assert_contains_count_le_as_result`](macro@crate::assert_contains_count_le_as_result)
+/// * [`debug_assert_contains_count_le`](macro@crate::debug_assert_contains_count_le)
+///
+#[macro_export]
+macro_rules! debug_assert_contains_count_le {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_contains_count_le!($($arg)*);
+        }
+    };
+}
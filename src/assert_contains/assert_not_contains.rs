@@ -65,12 +65,13 @@ macro_rules! assert_not_contains_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_not_contains!(container, containee)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_not_contains.html\n",
+                                "{}macro.assert_not_contains.html\n",
                                 " container label: `{}`,\n",
                                 " container debug: `{:?}`,\n",
                                 " containee label: `{}`,\n",
                                 " containee debug: `{:?}`",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($container),
                             container,
                             stringify!($containee),
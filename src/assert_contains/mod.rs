@@ -3,10 +3,18 @@
 //! These macros help with comparison of a container (such as a string, array, range)
 //! and a containee (such as a string substring, an array element, a range value).
 //!
-//! * [`assert_contains(container, containee)`](macro@crate::assert_contains) ≈ container.contains(containee)
+//! * [`assert_contains(container, containee)`](macro@crate::assert_contains) ≈ container.contains(containee), with the nearest present elements for an ordered set
 //!
 //! * [`assert_not_contains!(container, containee)`](macro@crate::assert_not_contains) ≈ !container.contains(containee)
 //!
+//! Count how many times a string contains a substring:
+//!
+//! * [`assert_contains_count!(haystack, needle, n)`](macro@crate::assert_contains_count) ≈ haystack.matches(needle).count() = n
+//! * [`assert_contains_count_lt!(haystack, needle, n)`](macro@crate::assert_contains_count_lt) ≈ haystack.matches(needle).count() < n
+//! * [`assert_contains_count_le!(haystack, needle, n)`](macro@crate::assert_contains_count_le) ≈ haystack.matches(needle).count() ≤ n
+//! * [`assert_contains_count_gt!(haystack, needle, n)`](macro@crate::assert_contains_count_gt) ≈ haystack.matches(needle).count() > n
+//! * [`assert_contains_count_ge!(haystack, needle, n)`](macro@crate::assert_contains_count_ge) ≈ haystack.matches(needle).count() ≥ n
+//!
 //!
 //! # Example
 //!
@@ -33,3 +41,129 @@
 
 pub mod assert_contains;
 pub mod assert_not_contains;
+
+// Count substring occurrences
+pub mod assert_contains_count;
+pub mod assert_contains_count_ge;
+pub mod assert_contains_count_gt;
+pub mod assert_contains_count_le;
+pub mod assert_contains_count_lt;
+
+/// Probe a container for the nearest elements to a missing containee.
+///
+/// The contains macros use this probe in their failure messages. For an
+/// ordered set, such as a `::std::collections::BTreeSet`, the probe finds
+/// the nearest present elements below and above the containee, which helps
+/// to catch an off-by-one fixture. For any other container, the probe finds
+/// nothing, and the message has no nearest lines.
+///
+/// This uses method resolution on a reference to pick the ordered set
+/// implementation when it applies, and the fallback implementation otherwise.
+/// A macro calls `(&NearestProbe(container, containee)).nearest()` with both
+/// traits in scope.
+#[doc(hidden)]
+pub struct NearestProbe<'a, C: ?Sized, X: ?Sized>(pub &'a C, pub &'a X);
+
+/// Find the nearest present elements in an ordered set.
+#[doc(hidden)]
+pub trait NearestOrdered {
+    fn nearest(&self) -> Option<String>;
+}
+
+impl<T: Ord + ::std::fmt::Debug> NearestOrdered for NearestProbe<'_, ::std::collections::BTreeSet<T>, &T> {
+    fn nearest(&self) -> Option<String> {
+        use ::std::ops::Bound::{Excluded, Unbounded};
+        let set = self.0;
+        let x: &T = self.1;
+        let below = set.range((Unbounded, Excluded(x))).next_back();
+        let above = set.range((Excluded(x), Unbounded)).next();
+        Some(format!(
+            concat!(
+                ",\n",
+                "   nearest below: `{:?}`,\n",
+                "   nearest above: `{:?}`"
+            ),
+            below,
+            above
+        ))
+    }
+}
+
+/// Find nothing, for a container that is not an ordered set.
+#[doc(hidden)]
+pub trait NearestFallback {
+    fn nearest(&self) -> Option<String>;
+}
+
+impl<C: ?Sized, X: ?Sized> NearestFallback for &NearestProbe<'_, C, X> {
+    fn nearest(&self) -> Option<String> {
+        None
+    }
+}
+
+/// A probe for the debug representation of a containee.
+///
+/// A string containee can be any pattern that `str::contains` accepts, and a
+/// closure pattern, such as `|c: char| c.is_ascii_digit()`, does not implement
+/// `Debug`. A macro calls `(&DebugProbe(containee)).debug_string()` with both
+/// traits in scope, the same way as [`NearestProbe`].
+#[doc(hidden)]
+pub struct DebugProbe<'a, X: ?Sized>(pub &'a X);
+
+/// Format a containee that implements `Debug`.
+#[doc(hidden)]
+pub trait DebugPreferred {
+    fn debug_string(&self) -> String;
+}
+
+impl<X: ?Sized + ::std::fmt::Debug> DebugPreferred for DebugProbe<'_, X> {
+    fn debug_string(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// Describe a containee that does not implement `Debug`, such as a closure.
+#[doc(hidden)]
+pub trait DebugFallback {
+    fn debug_string(&self) -> String;
+}
+
+impl<X: ?Sized> DebugFallback for &DebugProbe<'_, X> {
+    fn debug_string(&self) -> String {
+        String::from("(no Debug)")
+    }
+}
+
+/// A needle for the contains count macros, which finds its match positions.
+///
+/// A needle can be a `char`, a `str`, or a `String`, or a reference to one
+/// of these. A macro calls `Needle::match_indices(needle, haystack)`, which
+/// borrows the needle, so a `String` needle does not need to move.
+#[doc(hidden)]
+pub trait Needle {
+    fn match_indices(&self, haystack: &str) -> Vec<usize>;
+}
+
+impl Needle for char {
+    fn match_indices(&self, haystack: &str) -> Vec<usize> {
+        haystack.match_indices(*self).map(|(i, _)| i).collect()
+    }
+}
+
+impl Needle for str {
+    fn match_indices(&self, haystack: &str) -> Vec<usize> {
+        haystack.match_indices(self).map(|(i, _)| i).collect()
+    }
+}
+
+impl Needle for String {
+    fn match_indices(&self, haystack: &str) -> Vec<usize> {
+        Needle::match_indices(self.as_str(), haystack)
+    }
+}
+
+impl<T: Needle + ?Sized> Needle for &T {
+    fn match_indices(&self, haystack: &str) -> Vec<usize> {
+        (**self).match_indices(haystack)
+    }
+}
@@ -55,13 +55,14 @@ macro_rules! assert_count_eq_x_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_count_eq_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_count_eq_x.html\n",
+                                "{}macro.assert_count_eq_x.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.count(): `{:?}`,\n",
                                 " b label: `{}`,\n",
                                 " b debug: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             a,
                             a_count,
@@ -56,7 +56,7 @@ macro_rules! assert_count_ge_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_count_ge!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_count_ge.html\n",
+                                "{}macro.assert_count_ge.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.count(): `{:?}`,\n",
@@ -64,6 +64,7 @@ macro_rules! assert_count_ge_as_result {
                                 " b debug: `{:?}`\n",
                                 " b.count(): `{:?}`",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             a,
                             a_count,
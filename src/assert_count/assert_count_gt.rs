@@ -56,7 +56,7 @@ macro_rules! assert_count_gt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_count_gt!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_count_gt.html\n",
+                                "{}macro.assert_count_gt.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.count(): `{:?}`,\n",
@@ -64,6 +64,7 @@ macro_rules! assert_count_gt_as_result {
                                 " b debug: `{:?}`\n",
                                 " b.count(): `{:?}`",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             a,
                             a_count,
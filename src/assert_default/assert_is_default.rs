@@ -0,0 +1,219 @@
+//! Assert an expression is equal to the default value of its type.
+//!
+//! Pseudocode:<br>
+//! a = T::default()
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Vec<i8> = Vec::new();
+//! assert_is_default!(a);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_is_default`](macro@crate::assert_is_default)
+//! * [`assert_is_default_as_result`](macro@crate::assert_is_default_as_result)
+//! * [`debug_assert_is_default`](macro@crate::debug_assert_is_default)
+
+/// Assert an expression is equal to the default value of its type.
+///
+/// Pseudocode:<br>
+/// a = T::default()
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The type `T` is inferred from `a`, and must implement `Default`,
+/// `PartialEq`, and `Debug`.
+///
+/// This macro provides the same statements as [`assert_is_default`](macro.assert_is_default.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_is_default`](macro@crate::assert_is_default)
+/// * [`assert_is_default_as_result`](macro@crate::assert_is_default_as_result)
+/// * [`debug_assert_is_default`](macro@crate::debug_assert_is_default)
+///
+#[macro_export]
+macro_rules! assert_is_default_as_result {
+    ($a:expr $(,)?) => {{
+        match (&$a) {
+            a => {
+                let default = $crate::assert_default::default_of(a);
+                if *a == default {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_is_default!(a)`\n",
+                                "{}macro.assert_is_default.html\n",
+                                "      a label: `{}`,\n",
+                                "      a debug: `{:?}`,\n",
+                                " T::default(): `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            a,
+                            default
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Counter {
+        count: u8,
+        label: String,
+    }
+
+    #[test]
+    fn success() {
+        let a: Vec<i8> = Vec::new();
+        let result = assert_is_default_as_result!(a);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_struct() {
+        let a = Counter::default();
+        let result = assert_is_default_as_result!(a);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let a = Counter { count: 1, label: String::from("alfa") };
+        let result = assert_is_default_as_result!(a);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_is_default!(a)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_default.html\n",
+                "      a label: `a`,\n",
+                "      a debug: `Counter { count: 1, label: \"alfa\" }`,\n",
+                " T::default(): `Counter { count: 0, label: \"\" }`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is equal to the default value of its type.
+///
+/// Pseudocode:<br>
+/// a = T::default()
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Vec<i8> = Vec::new();
+/// assert_is_default!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Vec<i8> = vec![1];
+/// assert_is_default!(a);
+/// # });
+/// // assertion failed: `assert_is_default!(a)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_default.html
+/// //       a label: `a`,
+/// //       a debug: `[1]`,
+/// //  T::default(): `[]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_is_default!(a)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_default.html\n",
+/// #     "      a label: `a`,\n",
+/// #     "      a debug: `[1]`,\n",
+/// #     " T::default(): `[]`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_is_default`](macro@crate::assert_is_default)
+/// * [`assert_is_default_as_result`](macro@crate::assert_is_default_as_result)
+/// * [`debug_assert_is_default`](macro@crate::debug_assert_is_default)
+///
+#[macro_export]
+macro_rules! assert_is_default {
+    ($a:expr $(,)?) => {{
+        match $crate::assert_is_default_as_result!($a) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $($message:tt)+) => {{
+        match $crate::assert_is_default_as_result!($a) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is equal to the default value of its type.
+///
+/// Pseudocode:<br>
+/// a = T::default()
+///
+/// This macro provides the same statements as [`assert_is_default`](macro.assert_is_default.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_is_default`](macro@crate::assert_is_default)
+/// * [`assert_is_default_as_result`](macro@crate::assert_is_default_as_result)
+/// * [`debug_assert_is_default`](macro@crate::debug_assert_is_default)
+///
+#[macro_export]
+macro_rules! debug_assert_is_default {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_is_default!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,32 @@
+//! Assert for a value and the default value of its type.
+//!
+//! These macros help with any item whose type implements `Default`, such as
+//! checking that a struct was properly reset. The type is inferred from the
+//! value, so there is no need to name it.
+//!
+//! * [`assert_is_default!(a)`](macro@crate::assert_is_default) ≈ a = T::default()
+//!
+//! * [`assert_is_not_default!(a)`](macro@crate::assert_is_not_default) ≈ a ≠ T::default()
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Vec<i8> = Vec::new();
+//! assert_is_default!(a);
+//! # }
+//! ```
+
+pub mod assert_is_default;
+pub mod assert_is_not_default;
+
+/// Return the default value of the type of a reference.
+///
+/// The macros call this to infer `T` from the value, so the caller does not
+/// need to name the type.
+#[doc(hidden)]
+pub fn default_of<T: Default>(_: &T) -> T {
+    T::default()
+}
@@ -0,0 +1,305 @@
+//! Assert a duration is within delta of another duration.
+//!
+//! Pseudocode:<br>
+//! | a - b | ≤ Δ
+//!
+//! Each argument is any type that implements
+//! [`DurationLike`](crate::assert_duration::DurationLike), such as
+//! `::std::time::Duration`, or a duration type from another crate. The macro
+//! compares the durations as nanoseconds, and computes the difference in the
+//! larger-minus-smaller order, because a duration can not be negative.
+//!
+//! The message shows the durations with their `Debug` representations,
+//! which are human-readable, such as `1.5s` or `250ms`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::time::Duration;
+//!
+//! # fn main() {
+//! let a = Duration::from_millis(100);
+//! let b = Duration::from_millis(105);
+//! let delta = Duration::from_millis(10);
+//! assert_duration_in_delta!(a, b, delta);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_duration_in_delta`](macro@crate::assert_duration_in_delta)
+//! * [`assert_duration_in_delta_as_result`](macro@crate::assert_duration_in_delta_as_result)
+//! * [`debug_assert_duration_in_delta`](macro@crate::debug_assert_duration_in_delta)
+
+/// Assert a duration is within delta of another duration.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ Δ
+///
+/// * If true, return Result `Ok((abs_diff, delta))`, where both are
+///   `::std::time::Duration` values.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_duration_in_delta`](macro.assert_duration_in_delta.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_duration_in_delta`](macro@crate::assert_duration_in_delta)
+/// * [`assert_duration_in_delta_as_result`](macro@crate::assert_duration_in_delta_as_result)
+/// * [`debug_assert_duration_in_delta`](macro@crate::debug_assert_duration_in_delta)
+///
+#[macro_export]
+macro_rules! assert_duration_in_delta_as_result {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match (&$a, &$b, &$delta) {
+            (a, b, delta) => {
+                let a_nanos: u128 = $crate::assert_duration::DurationLike::as_nanos_u128(a);
+                let b_nanos: u128 = $crate::assert_duration::DurationLike::as_nanos_u128(b);
+                let delta_nanos: u128 = $crate::assert_duration::DurationLike::as_nanos_u128(delta);
+                let abs_diff_nanos = if a_nanos >= b_nanos { a_nanos - b_nanos } else { b_nanos - a_nanos };
+                let abs_diff = $crate::assert_duration::duration_from_nanos_u128(abs_diff_nanos);
+                if abs_diff_nanos <= delta_nanos {
+                    Ok((abs_diff, $crate::assert_duration::duration_from_nanos_u128(delta_nanos)))
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+                                "{}macro.assert_duration_in_delta.html\n",
+                                "       a label: `{}`,\n",
+                                "       a debug: `{:?}`,\n",
+                                "       b label: `{}`,\n",
+                                "       b debug: `{:?}`,\n",
+                                "       Δ label: `{}`,\n",
+                                "       Δ debug: `{:?}`,\n",
+                                "     | a - b |: `{:?}`,\n",
+                                " | a - b | ≤ Δ: {}"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($delta),
+                            delta,
+                            abs_diff,
+                            false
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let a = Duration::from_millis(100);
+        let b = Duration::from_millis(105);
+        let delta = Duration::from_millis(10);
+        let result = assert_duration_in_delta_as_result!(a, b, delta);
+        assert_eq!(result.unwrap(), (Duration::from_millis(5), Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn success_with_a_greater_than_b() {
+        let a = Duration::from_millis(105);
+        let b = Duration::from_millis(100);
+        let delta = Duration::from_millis(5);
+        let result = assert_duration_in_delta_as_result!(a, b, delta);
+        assert_eq!(result.unwrap(), (Duration::from_millis(5), Duration::from_millis(5)));
+    }
+
+    #[derive(Debug)]
+    struct Millis(u64);
+
+    impl crate::assert_duration::DurationLike for Millis {
+        fn as_nanos_u128(&self) -> u128 {
+            u128::from(self.0) * 1_000_000
+        }
+    }
+
+    #[test]
+    fn success_with_duration_like() {
+        let a = Millis(100);
+        let b = Millis(105);
+        let delta = Duration::from_millis(10);
+        let result = assert_duration_in_delta_as_result!(a, b, delta);
+        assert_eq!(result.unwrap(), (Duration::from_millis(5), Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn failure_with_duration_like() {
+        let a = Millis(100);
+        let b = Millis(1500);
+        let delta = Millis(10);
+        let result = assert_duration_in_delta_as_result!(a, b, delta);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_duration_in_delta.html\n",
+                "       a label: `a`,\n",
+                "       a debug: `Millis(100)`,\n",
+                "       b label: `b`,\n",
+                "       b debug: `Millis(1500)`,\n",
+                "       Δ label: `delta`,\n",
+                "       Δ debug: `Millis(10)`,\n",
+                "     | a - b |: `1.4s`,\n",
+                " | a - b | ≤ Δ: false"
+            )
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = Duration::from_millis(100);
+        let b = Duration::from_millis(1500);
+        let delta = Duration::from_millis(10);
+        let result = assert_duration_in_delta_as_result!(a, b, delta);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_duration_in_delta.html\n",
+                "       a label: `a`,\n",
+                "       a debug: `100ms`,\n",
+                "       b label: `b`,\n",
+                "       b debug: `1.5s`,\n",
+                "       Δ label: `delta`,\n",
+                "       Δ debug: `10ms`,\n",
+                "     | a - b |: `1.4s`,\n",
+                " | a - b | ≤ Δ: false"
+            )
+        );
+    }
+}
+
+/// Assert a duration is within delta of another duration.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ Δ
+///
+/// * If true, return `(abs_diff, delta)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::time::Duration;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = Duration::from_millis(100);
+/// let b = Duration::from_millis(105);
+/// let delta = Duration::from_millis(10);
+/// assert_duration_in_delta!(a, b, delta);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = Duration::from_millis(100);
+/// let b = Duration::from_millis(1500);
+/// let delta = Duration::from_millis(10);
+/// assert_duration_in_delta!(a, b, delta);
+/// # });
+/// // assertion failed: `assert_duration_in_delta!(a, b, Δ)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_duration_in_delta.html
+/// //        a label: `a`,
+/// //        a debug: `100ms`,
+/// //        b label: `b`,
+/// //        b debug: `1.5s`,
+/// //        Δ label: `delta`,
+/// //        Δ debug: `10ms`,
+/// //      | a - b |: `1.4s`,
+/// //  | a - b | ≤ Δ: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_duration_in_delta.html\n",
+/// #     "       a label: `a`,\n",
+/// #     "       a debug: `100ms`,\n",
+/// #     "       b label: `b`,\n",
+/// #     "       b debug: `1.5s`,\n",
+/// #     "       Δ label: `delta`,\n",
+/// #     "       Δ debug: `10ms`,\n",
+/// #     "     | a - b |: `1.4s`,\n",
+/// #     " | a - b | ≤ Δ: false"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_duration_in_delta`](macro@crate::assert_duration_in_delta)
+/// * [`assert_duration_in_delta_as_result`](macro@crate::assert_duration_in_delta_as_result)
+/// * [`debug_assert_duration_in_delta`](macro@crate::debug_assert_duration_in_delta)
+///
+#[macro_export]
+macro_rules! assert_duration_in_delta {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match $crate::assert_duration_in_delta_as_result!($a, $b, $delta) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $delta:expr, $($message:tt)+) => {{
+        match $crate::assert_duration_in_delta_as_result!($a, $b, $delta) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a duration is within delta of another duration.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ Δ
+///
+/// This macro provides the same statements as [`assert_duration_in_delta`](macro.assert_duration_in_delta.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_duration_in_delta`](macro@crate::assert_duration_in_delta)
+/// * [`assert_duration_in_delta_as_result`](macro@crate::assert_duration_in_delta_as_result)
+/// * [`debug_assert_duration_in_delta`](macro@crate::debug_assert_duration_in_delta)
+///
+#[macro_export]
+macro_rules! debug_assert_duration_in_delta {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_duration_in_delta!($($arg)*);
+        }
+    };
+}
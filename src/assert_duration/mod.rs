@@ -0,0 +1,80 @@
+//! Assert for comparing durations.
+//!
+//! These macros compare durations, such as elapsed times, where one duration
+//! may be close to another but not quite equal. A duration is any type that
+//! implements [`DurationLike`], such as `::std::time::Duration`.
+//!
+//! * [`assert_duration_in_delta!(a, b, delta)`](macro@crate::assert_duration_in_delta) ≈ | a - b | ≤ Δ
+//!
+//! A duration can not be negative, so these macros compare nanoseconds, and
+//! compute the difference in the larger-minus-smaller order, rather than with
+//! the numeric subtraction used by [`assert_in_delta`](macro@crate::assert_in_delta).
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::time::Duration;
+//!
+//! # fn main() {
+//! let a = Duration::from_millis(100);
+//! let b = Duration::from_millis(105);
+//! let delta = Duration::from_millis(10);
+//! assert_duration_in_delta!(a, b, delta);
+//! # }
+//! ```
+
+pub mod assert_duration_in_delta;
+
+/// A duration that can be compared as a count of nanoseconds.
+///
+/// The duration macros call this trait, so they accept any duration type that
+/// implements it, and the crate does not need a dependency on a time crate.
+/// This crate implements it for `::std::time::Duration`.
+///
+/// To use a duration type from another crate, such as `time` or `chrono`,
+/// implement this trait for a local wrapper type. A negative duration has
+/// no nanosecond count, so an implementation can use its absolute value.
+///
+/// # Example
+///
+/// ```rust
+/// use assertables::*;
+/// use assertables::assert_duration::DurationLike;
+/// use std::time::Duration;
+///
+/// #[derive(Debug)]
+/// struct Millis(u64);
+///
+/// impl DurationLike for Millis {
+///     fn as_nanos_u128(&self) -> u128 {
+///         u128::from(self.0) * 1_000_000
+///     }
+/// }
+///
+/// # fn main() {
+/// let a = Millis(100);
+/// let b = Millis(105);
+/// let delta = Duration::from_millis(10);
+/// assert_duration_in_delta!(a, b, delta);
+/// # }
+/// ```
+pub trait DurationLike {
+    /// Return the duration as a count of nanoseconds.
+    fn as_nanos_u128(&self) -> u128;
+}
+
+impl DurationLike for ::std::time::Duration {
+    fn as_nanos_u128(&self) -> u128 {
+        self.as_nanos()
+    }
+}
+
+/// Convert a count of nanoseconds to a `::std::time::Duration`.
+///
+/// The macros use this to show a difference with the human-readable
+/// `Debug` of a `Duration`, such as `1.5s`.
+#[doc(hidden)]
+pub fn duration_from_nanos_u128(nanos: u128) -> ::std::time::Duration {
+    ::std::time::Duration::new((nanos / 1_000_000_000) as u64, (nanos % 1_000_000_000) as u32)
+}
@@ -12,12 +12,12 @@
 //! // String ends with substring?
 //! let whole: &str = "alfa";
 //! let part: &str = "al";
-//! assert_not_ends_with!(sequence, x);
+//! assert_not_ends_with!(whole, part);
 //!
 //! // Vector ends with element?
 //! let whole = vec![1, 2, 3];
 //! let part = [1];
-//! assert_not_ends_with!(sequence, x);
+//! assert_not_ends_with!(whole, part);
 //! # }
 //! ```
 //!
@@ -52,20 +52,21 @@
 macro_rules! assert_not_ends_with_as_result {
     ($whole:expr, $part:expr $(,)?) => {{
         match (&$whole, &$part) {
-            (sequence, x) => {
-                if !(sequence.ends_with(x)) {
+            (whole, part) => {
+                if !(whole.ends_with(part)) {
                     Ok(())
                 } else {
                     Err(
                         format!(
                             concat!(
-                                "assertion failed: `assert_not_ends_with!(sequence, x)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_not_ends_with.html\n",
+                                "assertion failed: `assert_not_ends_with!(whole, part)`\n",
+                                "{}macro.assert_not_ends_with.html\n",
                                 " whole label: `{}`,\n",
                                 " whole debug: `{:?}`,\n",
                                 "  part label: `{}`,\n",
                                 "  part debug: `{:?}`",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($whole),
                             whole,
                             stringify!($part),
@@ -85,7 +86,7 @@ mod tests {
     fn test_assert_not_ends_with_as_result_success() {
         let whole = "alfa";
         let part = "al";
-        let result = assert_not_ends_with_as_result!(sequence, x);
+        let result = assert_not_ends_with_as_result!(whole, part);
         assert_eq!(result.unwrap(), ());
     }
 
@@ -93,10 +94,10 @@ mod tests {
     fn test_assert_not_ends_with_as_result_x_failure() {
         let whole = "alfa";
         let part = "fa";
-        let result = assert_not_ends_with_as_result!(sequence, x);
+        let result = assert_not_ends_with_as_result!(whole, part);
         let actual = result.unwrap_err();
         let expect = concat!(
-            "assertion failed: `assert_not_ends_with!(sequence, x)`\n",
+            "assertion failed: `assert_not_ends_with!(whole, part)`\n",
             "https://docs.rs/assertables/9.2.0/assertables/macro.assert_not_ends_with.html\n",
             " whole label: `whole`,\n",
             " whole debug: `\"alfa\"`,\n",
@@ -127,20 +128,20 @@ mod tests {
 /// // String ends with substring?
 /// let whole: &str = "alfa";
 /// let part: &str = "al";
-/// assert_not_ends_with!(sequence, x);
+/// assert_not_ends_with!(whole, part);
 ///
 /// // Vector ends with element?
 /// let whole = vec![1, 2, 3];
 /// let part = [1];
-/// assert_not_ends_with!(sequence, x);
+/// assert_not_ends_with!(whole, part);
 ///
 /// # let result = panic::catch_unwind(|| {
 /// // This will panic
 /// let whole = "alfa";
 /// let part = "fa";
-/// assert_not_ends_with!(sequence, x);
+/// assert_not_ends_with!(whole, part);
 /// # });
-/// // assertion failed: `assert_not_ends_with!(sequence, x)`
+/// // assertion failed: `assert_not_ends_with!(whole, part)`
 /// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_not_ends_with.html
 /// //  whole label: `whole`,
 /// //  whole debug: `\"alfa\"`,
@@ -148,7 +149,7 @@ mod tests {
 /// //   part debug: `\"fa\"`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let expect = concat!(
-/// #     "assertion failed: `assert_not_ends_with!(sequence, x)`\n",
+/// #     "assertion failed: `assert_not_ends_with!(whole, part)`\n",
 /// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_not_ends_with.html\n",
 /// #     " whole label: `whole`,\n",
 /// #     " whole debug: `\"alfa\"`,\n",
@@ -3,9 +3,12 @@
 //! These macros help with comparison of a whole (such as a string, array, range)
 //! and a part (such as a string substring, an array element, a range value).
 //!
-//! * [`assert_ends_with(sequence, x)`](macro@crate::assert_ends_with) ≈ container.contains(containee)
+//! * [`assert_ends_with!(whole, part)`](macro@crate::assert_ends_with) ≈ whole.ends_with(part)
 //!
-//! * [`assert_not_ends_with!(sequence, x)`](macro@crate::assert_not_ends_with) ≈ !container.contains(containee)
+//! * [`assert_not_ends_with!(whole, part)`](macro@crate::assert_not_ends_with) ≈ !whole.ends_with(part)
+//!
+//! To check a string starts with an open delimiter and ends with a close
+//! delimiter, see [`assert_wrapped_with`](macro@crate::assert_wrapped_with).
 //!
 //!
 //! # Example
@@ -17,12 +20,12 @@
 //! // String ends with substring?
 //! let whole: &str = "alfa";
 //! let part: &str = "fa";
-//! assert_ends_with!(sequence, x);
+//! assert_ends_with!(whole, part);
 //!
 //! // Vector ends with element?
 //! let whole = vec![1, 2, 3];
 //! let part = [3];
-//! assert_ends_with!(sequence, x);
+//! assert_ends_with!(whole, part);
 //! # }
 //! ```
 
@@ -47,12 +47,13 @@ macro_rules! assert_eq_as_result {
                     Err(format!(
                         concat!(
                             "assertion failed: `assert_eq!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_eq.html\n",
+                            "{}macro.assert_eq.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
                         a,
                         stringify!($b),
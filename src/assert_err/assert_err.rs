@@ -49,10 +49,11 @@ macro_rules! assert_err_as_result {
             _ => Err(format!(
                 concat!(
                     "assertion failed: `assert_err!(a)`\n",
-                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err.html\n",
+                    "{}macro.assert_err.html\n",
                     " a label: `{}`,\n",
                     " a debug: `{:?}`",
                 ),
+                $crate::DOC_URL_BASE,
                 stringify!($a),
                 $a
             )),
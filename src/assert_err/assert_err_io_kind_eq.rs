@@ -0,0 +1,268 @@
+//! Assert an expression is Err with an I/O error of a given kind.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Err(e) ⇒ e.kind()) = kind
+//!
+//! This is for a result with the error type `::std::io::Error`, such as from
+//! file system code or network code. This is cleaner than a `match` on the
+//! result and on the error kind.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::io::ErrorKind;
+//!
+//! # fn main() {
+//! let a = std::fs::read_to_string("no-such-file.txt");
+//! assert_err_io_kind_eq!(a, ErrorKind::NotFound);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_err_io_kind_eq`](macro@crate::assert_err_io_kind_eq)
+//! * [`assert_err_io_kind_eq_as_result`](macro@crate::assert_err_io_kind_eq_as_result)
+//! * [`debug_assert_err_io_kind_eq`](macro@crate::debug_assert_err_io_kind_eq)
+
+/// Assert an expression is Err with an I/O error of a given kind.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Err(e) ⇒ e.kind()) = kind
+///
+/// * If true, return Result `Ok(kind)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The Err type must be `::std::io::Error`; any other type is a compile
+/// error. When the kind is different, the message shows the actual kind and
+/// the error string. When the expression is Ok, the message shows the Ok value.
+///
+/// This macro provides the same statements as [`assert_err_io_kind_eq`](macro.assert_err_io_kind_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_err_io_kind_eq`](macro@crate::assert_err_io_kind_eq)
+/// * [`assert_err_io_kind_eq_as_result`](macro@crate::assert_err_io_kind_eq_as_result)
+/// * [`debug_assert_err_io_kind_eq`](macro@crate::debug_assert_err_io_kind_eq)
+///
+#[macro_export]
+macro_rules! assert_err_io_kind_eq_as_result {
+    ($a:expr, $kind:expr $(,)?) => {{
+        match (&$a, &$kind) {
+            (a, kind) => {
+                let kind: &::std::io::ErrorKind = kind;
+                match a {
+                    Err(a1) => {
+                        let a_kind = ::std::io::Error::kind(a1);
+                        if a_kind == *kind {
+                            Ok(a_kind)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_err_io_kind_eq!(a, kind)`\n",
+                                        "{}macro.assert_err_io_kind_eq.html\n",
+                                        "      a label: `{}`,\n",
+                                        "   a err kind: `{:?}`,\n",
+                                        " a err string: `{:?}`,\n",
+                                        "   kind label: `{}`,\n",
+                                        "   kind debug: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a),
+                                    a_kind,
+                                    a1.to_string(),
+                                    stringify!($kind),
+                                    kind
+                                )
+                            )
+                        }
+                    },
+                    Ok(a1) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_err_io_kind_eq!(a, kind)`\n",
+                                    "{}macro.assert_err_io_kind_eq.html\n",
+                                    "      a label: `{}`,\n",
+                                    "   a ok debug: `{:?}`,\n",
+                                    "   kind label: `{}`,\n",
+                                    "   kind debug: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a1,
+                                stringify!($kind),
+                                kind
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::{Error, ErrorKind};
+
+    #[test]
+    fn success() {
+        let a = std::fs::File::open("no-such-file.txt");
+        let result = assert_err_io_kind_eq_as_result!(a, ErrorKind::NotFound);
+        assert_eq!(result.unwrap(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn failure_because_kind() {
+        let a: Result<i8, Error> = Err(Error::new(ErrorKind::InvalidData, "bad header"));
+        let kind = ErrorKind::NotFound;
+        let result = assert_err_io_kind_eq_as_result!(a, kind);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_err_io_kind_eq!(a, kind)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_io_kind_eq.html\n",
+                "      a label: `a`,\n",
+                "   a err kind: `InvalidData`,\n",
+                " a err string: `\"bad header\"`,\n",
+                "   kind label: `kind`,\n",
+                "   kind debug: `NotFound`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_ok() {
+        let a: Result<i8, Error> = Ok(1);
+        let kind = ErrorKind::NotFound;
+        let result = assert_err_io_kind_eq_as_result!(a, kind);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_err_io_kind_eq!(a, kind)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_io_kind_eq.html\n",
+                "      a label: `a`,\n",
+                "   a ok debug: `1`,\n",
+                "   kind label: `kind`,\n",
+                "   kind debug: `NotFound`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Err with an I/O error of a given kind.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Err(e) ⇒ e.kind()) = kind
+///
+/// * If true, return `kind`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::io::{Error, ErrorKind};
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = std::fs::read_to_string("no-such-file.txt");
+/// assert_err_io_kind_eq!(a, ErrorKind::NotFound);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<i8, Error> = Err(Error::new(ErrorKind::InvalidData, "bad header"));
+/// assert_err_io_kind_eq!(a, ErrorKind::NotFound);
+/// # });
+/// // assertion failed: `assert_err_io_kind_eq!(a, kind)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_io_kind_eq.html
+/// //       a label: `a`,
+/// //    a err kind: `InvalidData`,
+/// //  a err string: `\"bad header\"`,
+/// //    kind label: `ErrorKind::NotFound`,
+/// //    kind debug: `NotFound`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_err_io_kind_eq!(a, kind)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_io_kind_eq.html\n",
+/// #     "      a label: `a`,\n",
+/// #     "   a err kind: `InvalidData`,\n",
+/// #     " a err string: `\"bad header\"`,\n",
+/// #     "   kind label: `ErrorKind::NotFound`,\n",
+/// #     "   kind debug: `NotFound`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_err_io_kind_eq`](macro@crate::assert_err_io_kind_eq)
+/// * [`assert_err_io_kind_eq_as_result`](macro@crate::assert_err_io_kind_eq_as_result)
+/// * [`debug_assert_err_io_kind_eq`](macro@crate::debug_assert_err_io_kind_eq)
+///
+#[macro_export]
+macro_rules! assert_err_io_kind_eq {
+    ($a:expr, $kind:expr $(,)?) => {{
+        match $crate::assert_err_io_kind_eq_as_result!($a, $kind) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $kind:expr, $($message:tt)+) => {{
+        match $crate::assert_err_io_kind_eq_as_result!($a, $kind) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Err with an I/O error of a given kind.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Err(e) ⇒ e.kind()) = kind
+///
+/// This macro provides the same statements as [`assert_err_io_kind_eq`](macro.assert_err_io_kind_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_err_io_kind_eq`](macro@crate::assert_err_io_kind_eq)
+/// * [`assert_err_io_kind_eq_as_result`](macro@crate::assert_err_io_kind_eq_as_result)
+/// * [`debug_assert_err_io_kind_eq`](macro@crate::debug_assert_err_io_kind_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_err_io_kind_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_err_io_kind_eq!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,274 @@
+//! Assert an expression is Err and its error string is a match for a matcher.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Err(e) ⇒ e.to_string()) is match matcher
+//!
+//! The error is formatted with `to_string()`, which uses its `Display`, then
+//! the matcher is checked against that string. This is useful for checking
+//! that an error message has a shape, such as a path and a line number,
+//! without hardcoding the entire message.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use regex::Regex;
+//!
+//! # fn main() {
+//! let a: Result<i8, String> = Err(String::from("alfa.txt:12: bad input"));
+//! let matcher = Regex::new(r"\.txt:\d+:").unwrap();
+//! assert_err_is_match!(a, matcher);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_err_is_match`](macro@crate::assert_err_is_match)
+//! * [`assert_err_is_match_as_result`](macro@crate::assert_err_is_match_as_result)
+//! * [`debug_assert_err_is_match`](macro@crate::debug_assert_err_is_match)
+
+/// Assert an expression is Err and its error string is a match for a matcher.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Err(e) ⇒ e.to_string()) is match matcher
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message distinguishes an expression that is Ok, which shows the Ok
+/// value, from an error string that is not a match, which shows the string.
+///
+/// This macro provides the same statements as [`assert_err_is_match`](macro.assert_err_is_match.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_err_is_match`](macro@crate::assert_err_is_match)
+/// * [`assert_err_is_match_as_result`](macro@crate::assert_err_is_match_as_result)
+/// * [`debug_assert_err_is_match`](macro@crate::debug_assert_err_is_match)
+///
+#[macro_export]
+macro_rules! assert_err_is_match_as_result {
+    ($a:expr, $matcher:expr $(,)?) => {{
+        match (&$a, &$matcher) {
+            (a, matcher) => {
+                match a {
+                    Err(a1) => {
+                        let a_string = a1.to_string();
+                        if matcher.is_match(&a_string) {
+                            Ok(())
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_err_is_match!(a, matcher)`\n",
+                                        "{}macro.assert_err_is_match.html\n",
+                                        "       a label: `{}`,\n",
+                                        "  a err string: `{:?}`,\n",
+                                        " matcher label: `{}`,\n",
+                                        " matcher debug: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a),
+                                    a_string,
+                                    stringify!($matcher),
+                                    matcher
+                                )
+                            )
+                        }
+                    },
+                    Ok(a1) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_err_is_match!(a, matcher)`\n",
+                                    "{}macro.assert_err_is_match.html\n",
+                                    "       a label: `{}`,\n",
+                                    "    a ok debug: `{:?}`,\n",
+                                    " matcher label: `{}`,\n",
+                                    " matcher debug: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a1,
+                                stringify!($matcher),
+                                matcher
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use regex::Regex;
+
+    #[test]
+    fn success() {
+        let a: Result<i8, String> = Err(String::from("alfa.txt:12: bad input"));
+        let matcher = Regex::new(r"\.txt:\d+:").unwrap();
+        let result = assert_err_is_match_as_result!(a, matcher);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_error_type() {
+        let a: Result<i8, std::num::ParseIntError> = "x".parse::<i8>();
+        let matcher = Regex::new(r"invalid digit").unwrap();
+        let result = assert_err_is_match_as_result!(a, matcher);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_because_not_match() {
+        let a: Result<i8, String> = Err(String::from("file not found"));
+        let matcher = Regex::new(r"line \d+").unwrap();
+        let result = assert_err_is_match_as_result!(a, matcher);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_err_is_match!(a, matcher)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_is_match.html\n",
+                "       a label: `a`,\n",
+                "  a err string: `\"file not found\"`,\n",
+                " matcher label: `matcher`,\n",
+                " matcher debug: `Regex(\"line \\\\d+\")`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_ok() {
+        let a: Result<i8, String> = Ok(1);
+        let matcher = Regex::new(r"line \d+").unwrap();
+        let result = assert_err_is_match_as_result!(a, matcher);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_err_is_match!(a, matcher)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_is_match.html\n",
+                "       a label: `a`,\n",
+                "    a ok debug: `1`,\n",
+                " matcher label: `matcher`,\n",
+                " matcher debug: `Regex(\"line \\\\d+\")`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Err and its error string is a match for a matcher.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Err(e) ⇒ e.to_string()) is match matcher
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use regex::Regex;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<i8, String> = Err(String::from("alfa.txt:12: bad input"));
+/// let matcher = Regex::new(r"\.txt:\d+:").unwrap();
+/// assert_err_is_match!(a, matcher);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<i8, String> = Err(String::from("file not found"));
+/// let matcher = Regex::new(r"line \d+").unwrap();
+/// assert_err_is_match!(a, matcher);
+/// # });
+/// // assertion failed: `assert_err_is_match!(a, matcher)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_is_match.html
+/// //        a label: `a`,
+/// //   a err string: `\"file not found\"`,
+/// //  matcher label: `matcher`,
+/// //  matcher debug: `Regex(\"line \\\\d+\")`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_err_is_match!(a, matcher)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_is_match.html\n",
+/// #     "       a label: `a`,\n",
+/// #     "  a err string: `\"file not found\"`,\n",
+/// #     " matcher label: `matcher`,\n",
+/// #     " matcher debug: `Regex(\"line \\\\d+\")`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_err_is_match`](macro@crate::assert_err_is_match)
+/// * [`assert_err_is_match_as_result`](macro@crate::assert_err_is_match_as_result)
+/// * [`debug_assert_err_is_match`](macro@crate::debug_assert_err_is_match)
+///
+#[macro_export]
+macro_rules! assert_err_is_match {
+    ($a:expr, $matcher:expr $(,)?) => {{
+        match $crate::assert_err_is_match_as_result!($a, $matcher) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $matcher:expr, $($message:tt)+) => {{
+        match $crate::assert_err_is_match_as_result!($a, $matcher) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Err and its error string is a match for a matcher.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Err(e) ⇒ e.to_string()) is match matcher
+///
+/// This macro provides the same statements as [`assert_err_is_match`](macro.assert_err_is_match.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_err_is_match`](macro@crate::assert_err_is_match)
+/// * [`assert_err_is_match_as_result`](macro@crate::assert_err_is_match_as_result)
+/// * [`debug_assert_err_is_match`](macro@crate::debug_assert_err_is_match)
+///
+#[macro_export]
+macro_rules! debug_assert_err_is_match {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_err_is_match!($($arg)*);
+        }
+    };
+}
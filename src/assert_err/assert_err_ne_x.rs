@@ -54,13 +54,14 @@ macro_rules! assert_err_ne_x_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_err_ne_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_ne_x.html\n",
+                                "{}macro.assert_err_ne_x.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a inner: `{:?}`,\n",
                                 " b label: `{}`,\n",
                                 " b debug: `{:?}`",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             $a,
                             a1,
@@ -75,12 +76,13 @@ macro_rules! assert_err_ne_x_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_err_ne_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_ne_x.html\n",
+                            "{}macro.assert_err_ne_x.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
                         $a,
                         stringify!($b),
@@ -0,0 +1,326 @@
+//! Assert an expression is Err and its error source chain contains a substring.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Err(e) ⇒ [e, e.source(), e.source().source(), …]) any to_string() contains containee
+//!
+//! This is useful for testing wrapped errors, where the detail you care about
+//! may be in an inner error rather than in the outermost error message.
+//!
+//! The chain walk stops after 64 links, to guard against cyclic sources.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<(), std::io::Error> = Err(std::io::Error::other("disk full"));
+//! assert_err_source_contains!(a, "disk");
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_err_source_contains`](macro@crate::assert_err_source_contains)
+//! * [`assert_err_source_contains_as_result`](macro@crate::assert_err_source_contains_as_result)
+//! * [`debug_assert_err_source_contains`](macro@crate::debug_assert_err_source_contains)
+
+/// Assert an expression is Err and its error source chain contains a substring.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Err(e) ⇒ [e, e.source(), e.source().source(), …]) any to_string() contains containee
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The error type must implement `::std::error::Error`.
+/// The containee can be any type that implements `AsRef<str>`, such as
+/// `&str` or `String`.
+/// The chain walk stops after 64 links, to guard against cyclic sources.
+///
+/// This macro provides the same statements as [`assert_err_source_contains`](macro.assert_err_source_contains.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_err_source_contains`](macro@crate::assert_err_source_contains)
+/// * [`assert_err_source_contains_as_result`](macro@crate::assert_err_source_contains_as_result)
+/// * [`debug_assert_err_source_contains`](macro@crate::debug_assert_err_source_contains)
+///
+#[macro_export]
+macro_rules! assert_err_source_contains_as_result {
+    ($a:expr, $containee:expr $(,)?) => {{
+        match (&$a, &$containee) {
+            (a, containee) => {
+                match a {
+                    Err(a_err) => {
+                        let mut chain: Vec<String> = Vec::new();
+                        let mut link: Option<&dyn ::std::error::Error> = Some(a_err);
+                        while let Some(e) = link {
+                            if chain.len() >= 64 {
+                                break;
+                            }
+                            chain.push(e.to_string());
+                            link = e.source();
+                        }
+                        if chain.iter().any(|s| s.contains(AsRef::<str>::as_ref(containee))) {
+                            Ok(())
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_err_source_contains!(a, containee)`\n",
+                                        "{}macro.assert_err_source_contains.html\n",
+                                        "         a label: `{}`,\n",
+                                        "         a debug: `{:?}`,\n",
+                                        " containee label: `{}`,\n",
+                                        " containee debug: `{:?}`,\n",
+                                        "           chain: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a),
+                                    a,
+                                    stringify!($containee),
+                                    containee,
+                                    chain
+                                )
+                            )
+                        }
+                    },
+                    _ => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_err_source_contains!(a, containee)`\n",
+                                    "{}macro.assert_err_source_contains.html\n",
+                                    "         a label: `{}`,\n",
+                                    "         a debug: `{:?}`,\n",
+                                    " containee label: `{}`,\n",
+                                    " containee debug: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                stringify!($containee),
+                                containee
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Inner;
+
+    impl fmt::Display for Inner {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "disk full")
+        }
+    }
+
+    impl std::error::Error for Inner {}
+
+    #[derive(Debug)]
+    struct Outer(Inner);
+
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "save failed")
+        }
+    }
+
+    impl std::error::Error for Outer {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn success_outer() {
+        let a: Result<(), Outer> = Err(Outer(Inner));
+        let result = assert_err_source_contains_as_result!(a, "save");
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn success_inner() {
+        let a: Result<(), Outer> = Err(Outer(Inner));
+        let result = assert_err_source_contains_as_result!(a, "disk");
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_string_containee() {
+        let a: Result<(), Outer> = Err(Outer(Inner));
+        let containee = String::from("disk");
+        let result = assert_err_source_contains_as_result!(a, containee);
+        assert_eq!(result.unwrap(), ());
+        assert_eq!(containee, "disk");
+    }
+
+    #[test]
+    fn failure_evaluates_a_once() {
+        let count = std::cell::Cell::new(0);
+        let a = || {
+            count.set(count.get() + 1);
+            Err::<(), Outer>(Outer(Inner))
+        };
+        let result = assert_err_source_contains_as_result!(a(), "zz");
+        assert!(result.is_err());
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn failure_because_no_link_contains() {
+        let a: Result<(), Outer> = Err(Outer(Inner));
+        let result = assert_err_source_contains_as_result!(a, "zz");
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_err_source_contains!(a, containee)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_source_contains.html\n",
+                "         a label: `a`,\n",
+                "         a debug: `Err(Outer(Inner))`,\n",
+                " containee label: `\"zz\"`,\n",
+                " containee debug: `\"zz\"`,\n",
+                "           chain: `[\"save failed\", \"disk full\"]`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_not_err() {
+        let a: Result<(), Outer> = Ok(());
+        let result = assert_err_source_contains_as_result!(a, "disk");
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_err_source_contains!(a, containee)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_source_contains.html\n",
+                "         a label: `a`,\n",
+                "         a debug: `Ok(())`,\n",
+                " containee label: `\"disk\"`,\n",
+                " containee debug: `\"disk\"`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Err and its error source chain contains a substring.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Err(e) ⇒ [e, e.source(), e.source().source(), …]) any to_string() contains containee
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<(), std::io::Error> = Err(std::io::Error::other("disk full"));
+/// assert_err_source_contains!(a, "disk");
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<(), std::io::Error> = Err(std::io::Error::other("disk full"));
+/// assert_err_source_contains!(a, "zz");
+/// # });
+/// // assertion failed: `assert_err_source_contains!(a, containee)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_source_contains.html
+/// //          a label: `a`,
+/// //          a debug: `Err(Custom { kind: Other, error: \"disk full\" })`,
+/// //  containee label: `\"zz\"`,
+/// //  containee debug: `\"zz\"`,
+/// //            chain: `[\"disk full\"]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_err_source_contains!(a, containee)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_err_source_contains.html\n",
+/// #     "         a label: `a`,\n",
+/// #     "         a debug: `Err(Custom { kind: Other, error: \"disk full\" })`,\n",
+/// #     " containee label: `\"zz\"`,\n",
+/// #     " containee debug: `\"zz\"`,\n",
+/// #     "           chain: `[\"disk full\"]`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_err_source_contains`](macro@crate::assert_err_source_contains)
+/// * [`assert_err_source_contains_as_result`](macro@crate::assert_err_source_contains_as_result)
+/// * [`debug_assert_err_source_contains`](macro@crate::debug_assert_err_source_contains)
+///
+#[macro_export]
+macro_rules! assert_err_source_contains {
+    ($a:expr, $containee:expr $(,)?) => {{
+        match $crate::assert_err_source_contains_as_result!($a, $containee) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $containee:expr, $($message:tt)+) => {{
+        match $crate::assert_err_source_contains_as_result!($a, $containee) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Err and its error source chain contains a substring.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Err(e) ⇒ [e, e.source(), e.source().source(), …]) any to_string() contains containee
+///
+/// This macro provides the same statements as [`assert_err_source_contains`](macro.assert_err_source_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_err_source_contains`](macro@crate::assert_err_source_contains)
+/// * [`assert_err_source_contains_as_result`](macro@crate::assert_err_source_contains_as_result)
+/// * [`debug_assert_err_source_contains`](macro@crate::debug_assert_err_source_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_err_source_contains {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_err_source_contains!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,201 @@
+//! Assert expression is Err, without formatting its payload.
+//!
+//! Pseudocode:<br>
+//! a.is_err()
+//!
+//! This macro is like [`assert_err`](macro@crate::assert_err), except its message
+//! shows only the variant name, so the `Ok` and `Err` payloads do not need
+//! to implement `Debug`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! struct NoDebug;
+//! let a: Result<NoDebug, NoDebug> = Err(NoDebug);
+//! assert_is_err!(a);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_is_err`](macro@crate::assert_is_err)
+//! * [`assert_is_err_as_result`](macro@crate::assert_is_err_as_result)
+//! * [`debug_assert_is_err`](macro@crate::debug_assert_is_err)
+
+/// Assert expression is Err, without formatting its payload.
+///
+/// Pseudocode:<br>
+/// a.is_err()
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message shows the variant name, and never formats the payload,
+/// so the payload types do not need to implement `Debug`.
+///
+/// This macro provides the same statements as [`assert_is_err`](macro.assert_is_err.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_is_err`](macro@crate::assert_is_err)
+/// * [`assert_is_err_as_result`](macro@crate::assert_is_err_as_result)
+/// * [`debug_assert_is_err`](macro@crate::debug_assert_is_err)
+///
+#[macro_export]
+macro_rules! assert_is_err_as_result {
+    ($a:expr $(,)?) => {
+        if ($a).is_err() {
+            Ok(())
+        } else {
+            Err(format!(
+                concat!(
+                    "assertion failed: `assert_is_err!(a)`\n",
+                    "{}macro.assert_is_err.html\n",
+                    "   a label: `{}`,\n",
+                    " a variant: `Ok`",
+                ),
+                $crate::DOC_URL_BASE,
+                stringify!($a)
+            ))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+
+    struct NoDebug;
+
+    #[test]
+    fn success() {
+        let a: Result<NoDebug, NoDebug> = Err(NoDebug);
+        let result = assert_is_err_as_result!(a);
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a: Result<NoDebug, NoDebug> = Ok(NoDebug);
+        let result = assert_is_err_as_result!(a);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_is_err!(a)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_err.html\n",
+                "   a label: `a`,\n",
+                " a variant: `Ok`",
+            )
+        );
+    }
+}
+
+/// Assert expression is Err, without formatting its payload.
+///
+/// Pseudocode:<br>
+/// a.is_err()
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message that shows the variant name,
+///   without formatting the payload.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// struct NoDebug;
+/// let a: Result<NoDebug, NoDebug> = Err(NoDebug);
+/// assert_is_err!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<NoDebug, NoDebug> = Ok(NoDebug);
+/// assert_is_err!(a);
+/// # });
+/// // assertion failed: `assert_is_err!(a)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_err.html
+/// //    a label: `a`,
+/// //  a variant: `Ok`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_is_err!(a)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_err.html\n",
+/// #     "   a label: `a`,\n",
+/// #     " a variant: `Ok`",
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_is_err`](macro@crate::assert_is_err)
+/// * [`assert_is_err_as_result`](macro@crate::assert_is_err_as_result)
+/// * [`debug_assert_is_err`](macro@crate::debug_assert_is_err)
+///
+#[macro_export]
+macro_rules! assert_is_err {
+    ($a:expr $(,)?) => {{
+        match $crate::assert_is_err_as_result!($a) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $($message:tt)+) => {{
+        match $crate::assert_is_err_as_result!($a) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert expression is Err, without formatting its payload.
+///
+/// Pseudocode:<br>
+/// a.is_err()
+///
+/// This macro provides the same statements as [`assert_is_err`](macro.assert_is_err.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_is_err`](macro@crate::assert_is_err)
+/// * [`assert_is_err_as_result`](macro@crate::assert_is_err_as_result)
+/// * [`debug_assert_is_err`](macro@crate::debug_assert_is_err)
+///
+#[macro_export]
+macro_rules! debug_assert_is_err {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_is_err!($($arg)*);
+        }
+    };
+}
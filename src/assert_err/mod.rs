@@ -5,6 +5,7 @@
 //! Assert expression is Err:
 //!
 //! * [`assert_err!(a)`](macro@crate::assert_err) ≈ a is Err(_)
+//! * [`assert_is_err!(a)`](macro@crate::assert_is_err) ≈ a.is_err(), without formatting the payload
 //!
 //! Compare Err(…) to another Err(…):
 //!
@@ -16,6 +17,18 @@
 //! * [`assert_err_eq_x!(a, expr)`](macro@crate::assert_err_eq_x) ≈ (a ⇒ Err(a1) ⇒ a1) = expr
 //! * [`assert_err_ne_x!(a, expr)`](macro@crate::assert_err_ne_x) ≈ (a ⇒ Err(a1) ⇒ a1) ≠ expr
 //!
+//! Match Err(…) error string:
+//!
+//! * [`assert_err_is_match!(a, matcher)`](macro@crate::assert_err_is_match) ≈ (a ⇒ Err(e) ⇒ e.to_string()) is match matcher
+//!
+//! Compare Err(…) I/O error kind, such as `Result<T, ::std::io::Error>`:
+//!
+//! * [`assert_err_io_kind_eq!(a, kind)`](macro@crate::assert_err_io_kind_eq) ≈ (a ⇒ Err(e) ⇒ e.kind()) = kind
+//!
+//! Inspect the Err(…) error source chain:
+//!
+//! * [`assert_err_source_contains!(a, containee)`](macro@crate::assert_err_source_contains) ≈ (a ⇒ Err(e) ⇒ e and its sources) any to_string() contains containee
+//!
 //! # Example
 //!
 //! ```rust
@@ -28,6 +41,7 @@
 //! ```
 
 // Verify Err(_)
+pub mod assert_is_err;
 pub mod assert_err;
 
 // Compare another
@@ -37,3 +51,12 @@ pub mod assert_err_ne;
 // Compare expression
 pub mod assert_err_eq_x;
 pub mod assert_err_ne_x;
+
+// Match error string
+pub mod assert_err_is_match;
+
+// Compare I/O error kind
+pub mod assert_err_io_kind_eq;
+
+// Inspect source chain
+pub mod assert_err_source_contains;
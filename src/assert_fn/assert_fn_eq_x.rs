@@ -50,15 +50,15 @@ macro_rules! assert_fn_eq_x_as_result {
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
         match (&$a_function, &$a_param, &$b_expr) {
             (_a_function, a_param, b_expr) => {
-                let a = $a_function($a_param);
-                if a == $b_expr {
+                let a = $a_function(a_param.clone());
+                if a == *b_expr {
                     Ok(a)
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_eq_x!(a_function, a_param, b_expr)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_eq_x.html\n",
+                                "{}macro.assert_fn_eq_x.html\n",
                                 " a_function label: `{}`,\n",
                                 "    a_param label: `{}`,\n",
                                 "    a_param debug: `{:?}`,\n",
@@ -67,6 +67,7 @@ macro_rules! assert_fn_eq_x_as_result {
                                 "                a: `{:?}`,\n",
                                 "                b: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_function),
                             stringify!($a_param),
                             a_param,
@@ -87,20 +88,21 @@ macro_rules! assert_fn_eq_x_as_result {
         match (&$a_function, &$b_expr) {
             (_a_function, b_expr) => {
                 let a = $a_function();
-                if a == $b_expr {
+                if a == *b_expr {
                     Ok(a)
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_eq_x!(a_function, b_expr)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_eq_x.html\n",
+                                "{}macro.assert_fn_eq_x.html\n",
                                 " a_function label: `{}`,\n",
                                 "     b_expr label: `{}`,\n",
                                 "     b_expr debug: `{:?}`,\n",
                                 "                a: `{:?}`,\n",
                                 "                b: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_function),
                             stringify!($b_expr),
                             b_expr,
@@ -154,6 +156,18 @@ mod tests {
                     )
                 );
             }
+
+            #[test]
+            fn evaluates_each_param_and_expr_once() {
+                let count = std::cell::Cell::new(0);
+                let next = || {
+                    count.set(count.get() + 1);
+                    count.get()
+                };
+                let result = assert_fn_eq_x_as_result!(f, next(), next());
+                assert!(result.is_err());
+                assert_eq!(count.get(), 2);
+            }
         }
 
         mod arity_0 {
@@ -50,8 +50,8 @@ macro_rules! assert_fn_gt_as_result {
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
         match (&$a_function, &$a_param, &$b_function, &$b_param) {
             (_a_function, a_param, _b_function, b_param) => {
-                let a = $a_function($a_param);
-                let b = $b_function($b_param);
+                let a = $a_function(a_param.clone());
+                let b = $b_function(b_param.clone());
                 if a > b {
                     Ok((a, b))
                 } else {
@@ -59,7 +59,7 @@ macro_rules! assert_fn_gt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_gt!(a_function, a_param, b_function, b_param)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_gt.html\n",
+                                "{}macro.assert_fn_gt.html\n",
                                 " a_function label: `{}`,\n",
                                 "    a_param label: `{}`,\n",
                                 "    a_param debug: `{:?}`,\n",
@@ -69,6 +69,7 @@ macro_rules! assert_fn_gt_as_result {
                                 "                a: `{:?}`,\n",
                                 "                b: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_function),
                             stringify!($a_param),
                             a_param,
@@ -96,12 +97,13 @@ macro_rules! assert_fn_gt_as_result {
                 format!(
                     concat!(
                         "assertion failed: `assert_fn_gt!(a_function, b_function)`\n",
-                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_gt.html\n",
+                        "{}macro.assert_fn_gt.html\n",
                         " a_function label: `{}`,\n",
                         " b_function label: `{}`,\n",
                         "                a: `{:?}`,\n",
                         "                b: `{:?}`"
                     ),
+                    $crate::DOC_URL_BASE,
                     stringify!($a_function),
                     stringify!($b_function),
                     a,
@@ -52,8 +52,8 @@ macro_rules! assert_fn_le_as_result {
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
         match (&$a_function, &$a_param, &$b_function, &$b_param) {
             (_a_function, a_param, _b_function, b_param) => {
-                let a = $a_function($a_param);
-                let b = $b_function($b_param);
+                let a = $a_function(a_param.clone());
+                let b = $b_function(b_param.clone());
                 if a <= b {
                     Ok((a, b))
                 } else {
@@ -61,7 +61,7 @@ macro_rules! assert_fn_le_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_le!(a_function, a_param, b_function, b_param)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_le.html\n",
+                                "{}macro.assert_fn_le.html\n",
                                 " a_function label: `{}`,\n",
                                 "    a_param label: `{}`,\n",
                                 "    a_param debug: `{:?}`,\n",
@@ -71,6 +71,7 @@ macro_rules! assert_fn_le_as_result {
                                 "                a: `{:?}`,\n",
                                 "                b: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_function),
                             stringify!($a_param),
                             a_param,
@@ -98,12 +99,13 @@ macro_rules! assert_fn_le_as_result {
                 format!(
                     concat!(
                         "assertion failed: `assert_fn_le!(a_function, b_function)`\n",
-                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_le.html\n",
+                        "{}macro.assert_fn_le.html\n",
                         " a_function label: `{}`,\n",
                         " b_function label: `{}`,\n",
                         "                a: `{:?}`,\n",
                         "                b: `{:?}`"
                     ),
+                    $crate::DOC_URL_BASE,
                     stringify!($a_function),
                     stringify!($b_function),
                     a,
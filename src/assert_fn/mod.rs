@@ -3,6 +3,14 @@
 //! These macros help compare functions that return anything.
 //! The macros call the functions, then compare the return values.
 //!
+//! Each macro evaluates each param and each expression once, even when the
+//! message shows its debug representation, so a param can have side effects,
+//! such as a counter. The function gets a clone of the param, so the message
+//! can still show the param after the call, and the param type must be `Clone`,
+//! such as a number, a reference, or a `String`. The same holds for the
+//! macros in the modules [`assert_fn_ok`](module@crate::assert_fn_ok) and
+//! [`assert_fn_err`](module@crate::assert_fn_err).
+//!
 //! Compare a function with another function:
 //!
 //! * [`assert_fn_eq!(a_function, b_function)`](macro@crate::assert_fn_eq) ≈ a_function() = b_function()
@@ -21,6 +29,10 @@
 //! * [`assert_fn_le_x!(function, expr)`](macro@crate::assert_fn_le_x) ≈ function() ≤ expr
 //! * [`assert_fn_lt_x!(function, expr)`](macro@crate::assert_fn_lt_x) ≈ function() < expr
 //!
+//! Check a closure for panics, in the module [`assert_panics`](module@crate::assert_panics):
+//!
+//! * [`assert_no_panic!(closure)`](macro@crate::assert_no_panic) ≈ catch_unwind(closure) is Ok(value)
+//! * [`assert_panics_with_type!(closure, T)`](macro@crate::assert_panics_with_type) ≈ catch_unwind(closure) is Err(payload) ⇒ payload.downcast::<T>() is Ok
 //!
 //! # Example
 //!
@@ -50,16 +50,16 @@ macro_rules! assert_fn_err_eq_x_as_result {
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
         match (&$a_function, &$a_param, &$b_expr) {
             (_a_function, a_param, b_expr) => {
-                match ($a_function($a_param)) {
+                match ($a_function(a_param.clone())) {
                     Err(a) => {
-                        if a == $b_expr {
+                        if a == *b_expr {
                             Ok(a)
                         } else {
                             Err(
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_eq_x!(a_function, a_param, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                                        "{}macro.assert_fn_err_eq_x.html\n",
                                         " a_function label: `{}`,\n",
                                         "    a_param label: `{}`,\n",
                                         "    a_param debug: `{:?}`,\n",
@@ -68,13 +68,14 @@ macro_rules! assert_fn_err_eq_x_as_result {
                                         "                a: `{:?}`,\n",
                                         "                b: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_function),
                                     stringify!($a_param),
                                     a_param,
                                     stringify!($b_expr),
                                     b_expr,
                                     a,
-                                    $b_expr
+                                    b_expr
                                 )
                             )
                         }
@@ -84,7 +85,7 @@ macro_rules! assert_fn_err_eq_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_eq_x!(a_function, a_param, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                                    "{}macro.assert_fn_err_eq_x.html\n",
                                     " a_function label: `{}`,\n",
                                     "    a_param label: `{}`,\n",
                                     "    a_param debug: `{:?}`,\n",
@@ -92,6 +93,7 @@ macro_rules! assert_fn_err_eq_x_as_result {
                                     "     b_expr debug: `{:?}`,\n",
                                     "                a: `{:?}`",
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_function),
                                 stringify!($a_param),
                                 a_param,
@@ -113,25 +115,26 @@ macro_rules! assert_fn_err_eq_x_as_result {
             (_a_function, b_expr) => {
                 match ($a_function()) {
                     Err(a) => {
-                        if a == $b_expr {
+                        if a == *b_expr {
                             Ok(a)
                         } else {
                             Err(
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_eq_x!(a_function, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                                        "{}macro.assert_fn_err_eq_x.html\n",
                                         " a_function label: `{}`,\n",
                                         "     b_expr label: `{}`,\n",
                                         "     b_expr debug: `{:?}`,\n",
                                         "                a: `{:?}`,\n",
                                         "                b: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_function),
                                     stringify!($b_expr),
                                     b_expr,
                                     a,
-                                    $b_expr
+                                    b_expr
                                 )
                             )
                         }
@@ -141,12 +144,13 @@ macro_rules! assert_fn_err_eq_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_eq_x!(a_function, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                                    "{}macro.assert_fn_err_eq_x.html\n",
                                     " a_function label: `{}`,\n",
                                     "     b_expr label: `{}`,\n",
                                     "     b_expr debug: `{:?}`,\n",
                                     "                a: `{:?}`",
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_function),
                                 stringify!($b_expr),
                                 b_expr,
@@ -199,6 +203,18 @@ mod tests {
                     )
                 );
             }
+
+            #[test]
+            fn evaluates_each_param_and_expr_once() {
+                let count = std::cell::Cell::new(0);
+                let next = || {
+                    count.set(count.get() + 1);
+                    count.get()
+                };
+                let result = assert_fn_err_eq_x_as_result!(f, next(), next());
+                assert!(result.is_err());
+                assert_eq!(count.get(), 2);
+            }
         }
 
         mod arity_0 {
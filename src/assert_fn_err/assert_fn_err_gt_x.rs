@@ -56,16 +56,16 @@ macro_rules! assert_fn_err_gt_x_as_result {
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
         match (&$a_function, &$a_param, &$b_expr) {
             (_a_function, a_param, b_expr) => {
-                match ($a_function($a_param)) {
+                match ($a_function(a_param.clone())) {
                     Err(a) => {
-                        if a > $b_expr {
+                        if a > *b_expr {
                             Ok(a)
                         } else {
                             Err(
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_gt_x!(a_function, a_param, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_gt_x.html\n",
+                                        "{}macro.assert_fn_err_gt_x.html\n",
                                         " a_function label: `{}`,\n",
                                         "    a_param label: `{}`,\n",
                                         "    a_param debug: `{:?}`,\n",
@@ -74,13 +74,14 @@ macro_rules! assert_fn_err_gt_x_as_result {
                                         "                a: `{:?}`,\n",
                                         "                b: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_function),
                                     stringify!($a_param),
                                     a_param,
                                     stringify!($b_expr),
                                     b_expr,
                                     a,
-                                    $b_expr
+                                    b_expr
                                 )
                             )
                         }
@@ -90,7 +91,7 @@ macro_rules! assert_fn_err_gt_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_gt_x!(a_function, a_param, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_gt_x.html\n",
+                                    "{}macro.assert_fn_err_gt_x.html\n",
                                     " a_function label: `{}`,\n",
                                     "    a_param label: `{}`,\n",
                                     "    a_param debug: `{:?}`,\n",
@@ -98,6 +99,7 @@ macro_rules! assert_fn_err_gt_x_as_result {
                                     "     b_expr debug: `{:?}`,\n",
                                     "                a: `{:?}`",
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_function),
                                 stringify!($a_param),
                                 a_param,
@@ -119,25 +121,26 @@ macro_rules! assert_fn_err_gt_x_as_result {
             (_a_function, b_expr) => {
                 match ($a_function()) {
                     Err(a) => {
-                        if a > $b_expr {
+                        if a > *b_expr {
                             Ok(a)
                         } else {
                             Err(
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_gt_x!(a_function, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_gt_x.html\n",
+                                        "{}macro.assert_fn_err_gt_x.html\n",
                                         " a_function label: `{}`,\n",
                                         "     b_expr label: `{}`,\n",
                                         "     b_expr debug: `{:?}`,\n",
                                         "                a: `{:?}`,\n",
                                         "                b: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_function),
                                     stringify!($b_expr),
                                     b_expr,
                                     a,
-                                    $b_expr
+                                    b_expr
                                 )
                             )
                         }
@@ -147,12 +150,13 @@ macro_rules! assert_fn_err_gt_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_gt_x!(a_function, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_gt_x.html\n",
+                                    "{}macro.assert_fn_err_gt_x.html\n",
                                     " a_function label: `{}`,\n",
                                     "     b_expr label: `{}`,\n",
                                     "     b_expr debug: `{:?}`,\n",
                                     "                a: `{:?}`",
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_function),
                                 stringify!($b_expr),
                                 b_expr,
@@ -57,8 +57,8 @@ macro_rules! assert_fn_err_le_as_result {
         match (&$a_function, &$a_param, &$b_function, &$b_param) {
             (_a_function, a_param, _b_function, b_param) => {
                 match (
-                    $a_function($a_param),
-                    $b_function($b_param)
+                    $a_function(a_param.clone()),
+                    $b_function(b_param.clone())
                 ) {
                     (Err(a), Err(b)) => {
                         if a <= b {
@@ -68,7 +68,7 @@ macro_rules! assert_fn_err_le_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_le!(a_function, a_param, b_function, b_param)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_le.html\n",
+                                        "{}macro.assert_fn_err_le.html\n",
                                         " a_function label: `{}`,\n",
                                         "    a_param label: `{}`,\n",
                                         "    a_param debug: `{:?}`,\n",
@@ -78,6 +78,7 @@ macro_rules! assert_fn_err_le_as_result {
                                         "                a: `{:?}`,\n",
                                         "                b: `{:?}`"
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_function),
                                     stringify!($a_param),
                                     a_param,
@@ -95,7 +96,7 @@ macro_rules! assert_fn_err_le_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_le!(a_function, a_param, b_function, b_param)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_le.html\n",
+                                    "{}macro.assert_fn_err_le.html\n",
                                     " a_function label: `{}`,\n",
                                     "    a_param label: `{}`,\n",
                                     "    a_param debug: `{:?}`,\n",
@@ -105,6 +106,7 @@ macro_rules! assert_fn_err_le_as_result {
                                     "                a: `{:?}`,\n",
                                     "                b: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_function),
                                 stringify!($a_param),
                                 a_param,
@@ -136,12 +138,13 @@ macro_rules! assert_fn_err_le_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_err_le!(a_function, b_function)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_le.html\n",
+                                "{}macro.assert_fn_err_le.html\n",
                                 " a_function label: `{}`,\n",
                                 " b_function label: `{}`,\n",
                                 "                a: `{:?}`,\n",
                                 "                b: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_function),
                             stringify!($b_function),
                             a,
@@ -155,12 +158,13 @@ macro_rules! assert_fn_err_le_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_fn_err_le!(a_function, b_function)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_le.html\n",
+                            "{}macro.assert_fn_err_le.html\n",
                             " a_function label: `{}`,\n",
                             " b_function label: `{}`,\n",
                             "                a: `{:?}`,\n",
                             "                b: `{:?}`"
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a_function),
                         stringify!($b_function),
                         a,
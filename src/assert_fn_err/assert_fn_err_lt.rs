@@ -54,8 +54,8 @@ macro_rules! assert_fn_err_lt_as_result {
         match (&$a_function, &$a_param, &$b_function, &$b_param) {
             (_a_function, a_param, _b_function, b_param) => {
                 match (
-                    $a_function($a_param),
-                    $b_function($b_param)
+                    $a_function(a_param.clone()),
+                    $b_function(b_param.clone())
                 ) {
                     (Err(a), Err(b)) => {
                         if a < b {
@@ -65,7 +65,7 @@ macro_rules! assert_fn_err_lt_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_lt!(a_function, a_param, b_function, b_param)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_lt.html\n",
+                                        "{}macro.assert_fn_err_lt.html\n",
                                         " a_function label: `{}`,\n",
                                         "    a_param label: `{}`,\n",
                                         "    a_param debug: `{:?}`,\n",
@@ -75,6 +75,7 @@ macro_rules! assert_fn_err_lt_as_result {
                                         "                a: `{:?}`,\n",
                                         "                b: `{:?}`"
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_function),
                                     stringify!($a_param),
                                     a_param,
@@ -92,7 +93,7 @@ macro_rules! assert_fn_err_lt_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_lt!(a_function, a_param, b_function, b_param)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_lt.html\n",
+                                    "{}macro.assert_fn_err_lt.html\n",
                                     " a_function label: `{}`,\n",
                                     "    a_param label: `{}`,\n",
                                     "    a_param debug: `{:?}`,\n",
@@ -102,6 +103,7 @@ macro_rules! assert_fn_err_lt_as_result {
                                     "                a: `{:?}`,\n",
                                     "                b: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_function),
                                 stringify!($a_param),
                                 a_param,
@@ -133,12 +135,13 @@ macro_rules! assert_fn_err_lt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_err_lt!(a_function, b_function)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_lt.html\n",
+                                "{}macro.assert_fn_err_lt.html\n",
                                 " a_function label: `{}`,\n",
                                 " b_function label: `{}`,\n",
                                 "                a: `{:?}`,\n",
                                 "                b: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_function),
                             stringify!($b_function),
                             a,
@@ -152,12 +155,13 @@ macro_rules! assert_fn_err_lt_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_fn_err_lt!(a_function, b_function)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_err_lt.html\n",
+                            "{}macro.assert_fn_err_lt.html\n",
                             " a_function label: `{}`,\n",
                             " b_function label: `{}`,\n",
                             "                a: `{:?}`,\n",
                             "                b: `{:?}`"
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a_function),
                         stringify!($b_function),
                         a,
@@ -0,0 +1,388 @@
+//! Assert exactly one of two functions returns Ok(…).
+//!
+//! Pseudocode:<br>
+//! (a_function(a_param) is Ok) ⊕ (b_function(b_param) is Ok)
+//!
+//! This is useful for testing that two implementations disagree on whether
+//! an input is valid, such as a parser and a reference parser on a fuzzed input.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! fn f(i: i8) -> Result<String, String> {
+//!     match i {
+//!         0..=9 => Ok(format!("{}", i)),
+//!         _ => Err(format!("{:?} is out of range", i)),
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let a: i8 = 1;
+//! let b: i8 = 10;
+//! assert_fn_ok_xor!(f, a, f, b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_fn_ok_xor`](macro@crate::assert_fn_ok_xor)
+//! * [`assert_fn_ok_xor_as_result`](macro@crate::assert_fn_ok_xor_as_result)
+//! * [`debug_assert_fn_ok_xor`](macro@crate::debug_assert_fn_ok_xor)
+
+/// Assert exactly one of two functions returns Ok(…).
+///
+/// Pseudocode:<br>
+/// (a_function(a_param) is Ok) ⊕ (b_function(b_param) is Ok)
+///
+/// * If true, return Result `Ok((a, b))`, where `a` and `b` are the two results.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_fn_ok_xor`](macro.assert_fn_ok_xor.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_fn_ok_xor`](macro@crate::assert_fn_ok_xor)
+/// * [`assert_fn_ok_xor_as_result`](macro@crate::assert_fn_ok_xor_as_result)
+/// * [`debug_assert_fn_ok_xor`](macro@crate::debug_assert_fn_ok_xor)
+///
+#[macro_export]
+macro_rules! assert_fn_ok_xor_as_result {
+
+    //// Arity 1
+
+    ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
+        match (&$a_function, &$a_param, &$b_function, &$b_param) {
+            (_a_function, a_param, _b_function, b_param) => {
+                match (
+                    $a_function(a_param.clone()),
+                    $b_function(b_param.clone())
+                ) {
+                    (a, b) => {
+                        if a.is_ok() != b.is_ok() {
+                            Ok((a, b))
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_fn_ok_xor!(a_function, a_param, b_function, b_param)`\n",
+                                        "{}macro.assert_fn_ok_xor.html\n",
+                                        " a_function label: `{}`,\n",
+                                        "    a_param label: `{}`,\n",
+                                        "    a_param debug: `{:?}`,\n",
+                                        " b_function label: `{}`,\n",
+                                        "    b_param label: `{}`,\n",
+                                        "    b_param debug: `{:?}`,\n",
+                                        "                a: `{:?}`,\n",
+                                        "                b: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a_function),
+                                    stringify!($a_param),
+                                    a_param,
+                                    stringify!($b_function),
+                                    stringify!($b_param),
+                                    b_param,
+                                    a,
+                                    b
+                                )
+                            )
+                        }
+                    }
+                }
+            }
+        }
+    }};
+
+    //// Arity 0
+
+    ($a_function:path, $b_function:path) => {{
+        match (
+            $a_function(),
+            $b_function()
+        ) {
+            (a, b) => {
+                if a.is_ok() != b.is_ok() {
+                    Ok((a, b))
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_fn_ok_xor!(a_function, b_function)`\n",
+                                "{}macro.assert_fn_ok_xor.html\n",
+                                " a_function label: `{}`,\n",
+                                " b_function label: `{}`,\n",
+                                "                a: `{:?}`,\n",
+                                "                b: `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a_function),
+                            stringify!($b_function),
+                            a,
+                            b
+                        )
+                    )
+                }
+            }
+        }
+    }};
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    mod assert_fn_ok_xor_as_result {
+
+        mod arity_1 {
+
+            fn f(i: i8) -> Result<i8, i8> {
+                if i < 10 { Ok(i) } else { Err(i) }
+            }
+
+            fn g(i: i8) -> Result<i8, i8> {
+                if i < 20 { Ok(i) } else { Err(i) }
+            }
+
+            #[test]
+            fn success_with_a_ok_and_b_err() {
+                let a: i8 = 1;
+                let b: i8 = 20;
+                let result = assert_fn_ok_xor_as_result!(f, a, g, b);
+                assert_eq!(result.unwrap(), (Ok(1), Err(20)));
+            }
+
+            #[test]
+            fn success_with_a_err_and_b_ok() {
+                let a: i8 = 15;
+                let b: i8 = 15;
+                let result = assert_fn_ok_xor_as_result!(f, a, g, b);
+                assert_eq!(result.unwrap(), (Err(15), Ok(15)));
+            }
+
+            #[test]
+            fn failure_because_both_ok() {
+                let a: i8 = 1;
+                let b: i8 = 2;
+                let result = assert_fn_ok_xor_as_result!(f, a, g, b);
+                assert_eq!(
+                    result.unwrap_err(),
+                    concat!(
+                        "assertion failed: `assert_fn_ok_xor!(a_function, a_param, b_function, b_param)`\n",
+                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_ok_xor.html\n",
+                        " a_function label: `f`,\n",
+                        "    a_param label: `a`,\n",
+                        "    a_param debug: `1`,\n",
+                        " b_function label: `g`,\n",
+                        "    b_param label: `b`,\n",
+                        "    b_param debug: `2`,\n",
+                        "                a: `Ok(1)`,\n",
+                        "                b: `Ok(2)`"
+                    )
+                );
+            }
+
+            #[test]
+            fn failure_because_both_err() {
+                let a: i8 = 30;
+                let b: i8 = 40;
+                let result = assert_fn_ok_xor_as_result!(f, a, g, b);
+                assert_eq!(
+                    result.unwrap_err(),
+                    concat!(
+                        "assertion failed: `assert_fn_ok_xor!(a_function, a_param, b_function, b_param)`\n",
+                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_ok_xor.html\n",
+                        " a_function label: `f`,\n",
+                        "    a_param label: `a`,\n",
+                        "    a_param debug: `30`,\n",
+                        " b_function label: `g`,\n",
+                        "    b_param label: `b`,\n",
+                        "    b_param debug: `40`,\n",
+                        "                a: `Err(30)`,\n",
+                        "                b: `Err(40)`"
+                    )
+                );
+            }
+        }
+
+        mod arity_0 {
+
+            fn f() -> Result<i8, i8> {
+                return Ok(1);
+            }
+
+            fn g() -> Result<i8, i8> {
+                return Err(2);
+            }
+
+            #[test]
+            fn success() {
+                let result = assert_fn_ok_xor_as_result!(f, g);
+                assert_eq!(result.unwrap(), (Ok(1), Err(2)));
+            }
+
+            #[test]
+            fn failure() {
+                let result = assert_fn_ok_xor_as_result!(f, f);
+                assert_eq!(
+                    result.unwrap_err(),
+                    concat!(
+                        "assertion failed: `assert_fn_ok_xor!(a_function, b_function)`\n",
+                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_ok_xor.html\n",
+                        " a_function label: `f`,\n",
+                        " b_function label: `f`,\n",
+                        "                a: `Ok(1)`,\n",
+                        "                b: `Ok(1)`"
+                    )
+                );
+            }
+        }
+    }
+}
+
+/// Assert exactly one of two functions returns Ok(…).
+///
+/// Pseudocode:<br>
+/// (a_function(a_param) is Ok) ⊕ (b_function(b_param) is Ok)
+///
+/// * If true, return `(a, b)`, where `a` and `b` are the two results.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// fn f(i: i8) -> Result<String, String> {
+///     match i {
+///         0..=9 => Ok(format!("{}", i)),
+///         _ => Err(format!("{:?} is out of range", i)),
+///     }
+/// }
+///
+/// # fn main() {
+/// let a: i8 = 1;
+/// let b: i8 = 10;
+/// assert_fn_ok_xor!(f, a, f, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: i8 = 1;
+/// let b: i8 = 2;
+/// assert_fn_ok_xor!(f, a, f, b);
+/// # });
+/// // assertion failed: `assert_fn_ok_xor!(a_function, a_param, b_function, b_param)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_ok_xor.html
+/// //  a_function label: `f`,
+/// //     a_param label: `a`,
+/// //     a_param debug: `1`,
+/// //  b_function label: `f`,
+/// //     b_param label: `b`,
+/// //     b_param debug: `2`,
+/// //                 a: `Ok(\"1\")`,
+/// //                 b: `Ok(\"2\")`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_fn_ok_xor!(a_function, a_param, b_function, b_param)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fn_ok_xor.html\n",
+/// #     " a_function label: `f`,\n",
+/// #     "    a_param label: `a`,\n",
+/// #     "    a_param debug: `1`,\n",
+/// #     " b_function label: `f`,\n",
+/// #     "    b_param label: `b`,\n",
+/// #     "    b_param debug: `2`,\n",
+/// #     "                a: `Ok(\"1\")`,\n",
+/// #     "                b: `Ok(\"2\")`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_fn_ok_xor`](macro@crate::assert_fn_ok_xor)
+/// * [`assert_fn_ok_xor_as_result`](macro@crate::assert_fn_ok_xor_as_result)
+/// * [`debug_assert_fn_ok_xor`](macro@crate::debug_assert_fn_ok_xor)
+///
+#[macro_export]
+macro_rules! assert_fn_ok_xor {
+
+    //// Arity 1
+
+    ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
+        match $crate::assert_fn_ok_xor_as_result!($a_function, $a_param, $b_function, $b_param) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+
+    ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr, $($message:tt)+) => {{
+        match $crate::assert_fn_ok_xor_as_result!($a_function, $a_param, $b_function, $b_param) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+
+    //// Arity 0
+
+    ($a_function:path, $b_function:path) => {{
+        match $crate::assert_fn_ok_xor_as_result!($a_function, $b_function) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+
+    ($a_function:path, $b_function:path, $($message:tt)+) => {{
+        match $crate::assert_fn_ok_xor_as_result!($a_function, $b_function) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert exactly one of two functions returns Ok(…).
+///
+/// Pseudocode:<br>
+/// (a_function(a_param) is Ok) ⊕ (b_function(b_param) is Ok)
+///
+/// This macro provides the same statements as [`assert_fn_ok_xor`](macro.assert_fn_ok_xor.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_fn_ok_xor`](macro@crate::assert_fn_ok_xor)
+/// * [`assert_fn_ok_xor_as_result`](macro@crate::assert_fn_ok_xor_as_result)
+/// * [`debug_assert_fn_ok_xor`](macro@crate::debug_assert_fn_ok_xor)
+///
+#[macro_export]
+macro_rules! debug_assert_fn_ok_xor {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_fn_ok_xor!($($arg)*);
+        }
+    };
+}
@@ -18,6 +18,10 @@
 //! * [`assert_fn_ok_le!(a_function, b_function)`](macro@crate::assert_fn_ok_le) ≈ a_function().unwrap_err() ≤ b_function().unwrap_err()
 //! * [`assert_fn_ok_lt!(a_function, b_function)`](macro@crate::assert_fn_ok_lt) ≈ a_function().unwrap_err() < b_function().unwrap_err()
 //!
+//! Assert exactly one of two functions returns Ok():
+//!
+//! * [`assert_fn_ok_xor!(a_function, b_function)`](macro@crate::assert_fn_ok_xor) ≈ a_function().is_ok() ⊕ b_function().is_ok()
+//!
 //! Compare a function Ok() with an expression:
 //!
 //! * [`assert_fn_ok_eq_x!(function, expr)`](macro@crate::assert_fn_ok_eq_x) ≈ function().unwrap_err() = expr
@@ -53,6 +57,9 @@ pub mod assert_fn_ok_le;
 pub mod assert_fn_ok_lt;
 pub mod assert_fn_ok_ne;
 
+// Compare variants
+pub mod assert_fn_ok_xor;
+
 // Compare expression
 pub mod assert_fn_ok_eq_x;
 pub mod assert_fn_ok_ge_x;
@@ -0,0 +1,238 @@
+//! Assert a path ends with a suffix path, by path components.
+//!
+//! Pseudocode:<br>
+//! path.ends_with(suffix)
+//!
+//! This uses [`Path::ends_with`](https://doc.rust-lang.org/std/path/struct.Path.html#method.ends_with),
+//! which compares whole path components, not string characters.
+//! A string suffix check would pass for `/alfa/bravo/xcharlie` and `charlie`,
+//! but this check fails, because the component `xcharlie` is not the
+//! component `charlie`. This is useful to check a file name, or the last
+//! directories of a path, without caring where the path is rooted.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let path = "/alfa/bravo/charlie";
+//! let suffix = "bravo/charlie";
+//! assert_path_ends_with!(path, suffix);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_path_ends_with`](macro@crate::assert_path_ends_with)
+//! * [`assert_path_ends_with_as_result`](macro@crate::assert_path_ends_with_as_result)
+//! * [`debug_assert_path_ends_with`](macro@crate::debug_assert_path_ends_with)
+
+/// Assert a path ends with a suffix path, by path components.
+///
+/// Pseudocode:<br>
+/// path.ends_with(suffix)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The path and the suffix can be any type that implements `AsRef<Path>`, such
+/// as `&str`, `String`, `&Path`, or `PathBuf`.
+///
+/// This macro provides the same statements as [`assert_path_ends_with`](macro.assert_path_ends_with.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_path_ends_with`](macro@crate::assert_path_ends_with)
+/// * [`assert_path_ends_with_as_result`](macro@crate::assert_path_ends_with_as_result)
+/// * [`debug_assert_path_ends_with`](macro@crate::debug_assert_path_ends_with)
+///
+#[macro_export]
+macro_rules! assert_path_ends_with_as_result {
+    ($path:expr, $suffix:expr $(,)?) => {{
+        match (&$path, &$suffix) {
+            (path, suffix) => {
+                let path = AsRef::<::std::path::Path>::as_ref(path);
+                let suffix = AsRef::<::std::path::Path>::as_ref(suffix);
+                if path.ends_with(suffix) {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_path_ends_with!(path, suffix)`\n",
+                                "{}macro.assert_path_ends_with.html\n",
+                                "   path label: `{}`,\n",
+                                "   path debug: `{:?}`,\n",
+                                " suffix label: `{}`,\n",
+                                " suffix debug: `{:?}`,\n",
+                                "      compare: `by path components`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($path),
+                            path,
+                            stringify!($suffix),
+                            suffix
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn success() {
+        let path = "/alfa/bravo/charlie";
+        let suffix = "bravo/charlie";
+        let result = assert_path_ends_with_as_result!(path, suffix);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_path_types() {
+        let path = PathBuf::from("/alfa/bravo/charlie");
+        let suffix = Path::new("charlie");
+        let result = assert_path_ends_with_as_result!(path, suffix);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let path = "/alfa/bravo/xcharlie";
+        let suffix = "charlie";
+        let result = assert_path_ends_with_as_result!(path, suffix);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_path_ends_with!(path, suffix)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_path_ends_with.html\n",
+                "   path label: `path`,\n",
+                "   path debug: `\"/alfa/bravo/xcharlie\"`,\n",
+                " suffix label: `suffix`,\n",
+                " suffix debug: `\"charlie\"`,\n",
+                "      compare: `by path components`"
+            )
+        );
+    }
+}
+
+/// Assert a path ends with a suffix path, by path components.
+///
+/// Pseudocode:<br>
+/// path.ends_with(suffix)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let path = "/alfa/bravo/charlie";
+/// let suffix = "bravo/charlie";
+/// assert_path_ends_with!(path, suffix);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let path = "/alfa/bravo/xcharlie";
+/// let suffix = "charlie";
+/// assert_path_ends_with!(path, suffix);
+/// # });
+/// // assertion failed: `assert_path_ends_with!(path, suffix)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_path_ends_with.html
+/// //    path label: `path`,
+/// //    path debug: `\"/alfa/bravo/xcharlie\"`,
+/// //  suffix label: `suffix`,
+/// //  suffix debug: `\"charlie\"`,
+/// //       compare: `by path components`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_path_ends_with!(path, suffix)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_path_ends_with.html\n",
+/// #     "   path label: `path`,\n",
+/// #     "   path debug: `\"/alfa/bravo/xcharlie\"`,\n",
+/// #     " suffix label: `suffix`,\n",
+/// #     " suffix debug: `\"charlie\"`,\n",
+/// #     "      compare: `by path components`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_path_ends_with`](macro@crate::assert_path_ends_with)
+/// * [`assert_path_ends_with_as_result`](macro@crate::assert_path_ends_with_as_result)
+/// * [`debug_assert_path_ends_with`](macro@crate::debug_assert_path_ends_with)
+///
+#[macro_export]
+macro_rules! assert_path_ends_with {
+    ($path:expr, $suffix:expr $(,)?) => {{
+        match $crate::assert_path_ends_with_as_result!($path, $suffix) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($path:expr, $suffix:expr, $($message:tt)+) => {{
+        match $crate::assert_path_ends_with_as_result!($path, $suffix) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a path ends with a suffix path, by path components.
+///
+/// Pseudocode:<br>
+/// path.ends_with(suffix)
+///
+/// This macro provides the same statements as [`assert_path_ends_with`](macro.assert_path_ends_with.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_path_ends_with`](macro@crate::assert_path_ends_with)
+/// * [`assert_path_ends_with_as_result`](macro@crate::assert_path_ends_with_as_result)
+/// * [`debug_assert_path_ends_with`](macro@crate::debug_assert_path_ends_with)
+///
+#[macro_export]
+macro_rules! debug_assert_path_ends_with {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_path_ends_with!($($arg)*);
+        }
+    };
+}
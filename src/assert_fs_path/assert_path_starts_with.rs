@@ -0,0 +1,238 @@
+//! Assert a path starts with a base path, by path components.
+//!
+//! Pseudocode:<br>
+//! path.starts_with(base)
+//!
+//! This uses [`Path::starts_with`](https://doc.rust-lang.org/std/path/struct.Path.html#method.starts_with),
+//! which compares whole path components, not string characters.
+//! A string prefix check would pass for `/alfa/bravocharlie` and `/alfa/bravo`,
+//! but this check fails, because the component `bravocharlie` is not the
+//! component `bravo`. This is useful to check that a generated path is in an
+//! expected directory.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let path = "/alfa/bravo/charlie";
+//! let base = "/alfa/bravo";
+//! assert_path_starts_with!(path, base);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_path_starts_with`](macro@crate::assert_path_starts_with)
+//! * [`assert_path_starts_with_as_result`](macro@crate::assert_path_starts_with_as_result)
+//! * [`debug_assert_path_starts_with`](macro@crate::debug_assert_path_starts_with)
+
+/// Assert a path starts with a base path, by path components.
+///
+/// Pseudocode:<br>
+/// path.starts_with(base)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The path and the base can be any type that implements `AsRef<Path>`, such
+/// as `&str`, `String`, `&Path`, or `PathBuf`.
+///
+/// This macro provides the same statements as [`assert_path_starts_with`](macro.assert_path_starts_with.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_path_starts_with`](macro@crate::assert_path_starts_with)
+/// * [`assert_path_starts_with_as_result`](macro@crate::assert_path_starts_with_as_result)
+/// * [`debug_assert_path_starts_with`](macro@crate::debug_assert_path_starts_with)
+///
+#[macro_export]
+macro_rules! assert_path_starts_with_as_result {
+    ($path:expr, $base:expr $(,)?) => {{
+        match (&$path, &$base) {
+            (path, base) => {
+                let path = AsRef::<::std::path::Path>::as_ref(path);
+                let base = AsRef::<::std::path::Path>::as_ref(base);
+                if path.starts_with(base) {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_path_starts_with!(path, base)`\n",
+                                "{}macro.assert_path_starts_with.html\n",
+                                " path label: `{}`,\n",
+                                " path debug: `{:?}`,\n",
+                                " base label: `{}`,\n",
+                                " base debug: `{:?}`,\n",
+                                "    compare: `by path components`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($path),
+                            path,
+                            stringify!($base),
+                            base
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn success() {
+        let path = "/alfa/bravo/charlie";
+        let base = "/alfa/bravo";
+        let result = assert_path_starts_with_as_result!(path, base);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_path_types() {
+        let path = PathBuf::from("/alfa/bravo/charlie");
+        let base = Path::new("/alfa");
+        let result = assert_path_starts_with_as_result!(path, base);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let path = "/alfa/bravocharlie";
+        let base = "/alfa/bravo";
+        let result = assert_path_starts_with_as_result!(path, base);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_path_starts_with!(path, base)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_path_starts_with.html\n",
+                " path label: `path`,\n",
+                " path debug: `\"/alfa/bravocharlie\"`,\n",
+                " base label: `base`,\n",
+                " base debug: `\"/alfa/bravo\"`,\n",
+                "    compare: `by path components`"
+            )
+        );
+    }
+}
+
+/// Assert a path starts with a base path, by path components.
+///
+/// Pseudocode:<br>
+/// path.starts_with(base)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let path = "/alfa/bravo/charlie";
+/// let base = "/alfa/bravo";
+/// assert_path_starts_with!(path, base);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let path = "/alfa/bravocharlie";
+/// let base = "/alfa/bravo";
+/// assert_path_starts_with!(path, base);
+/// # });
+/// // assertion failed: `assert_path_starts_with!(path, base)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_path_starts_with.html
+/// //  path label: `path`,
+/// //  path debug: `\"/alfa/bravocharlie\"`,
+/// //  base label: `base`,
+/// //  base debug: `\"/alfa/bravo\"`,
+/// //     compare: `by path components`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_path_starts_with!(path, base)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_path_starts_with.html\n",
+/// #     " path label: `path`,\n",
+/// #     " path debug: `\"/alfa/bravocharlie\"`,\n",
+/// #     " base label: `base`,\n",
+/// #     " base debug: `\"/alfa/bravo\"`,\n",
+/// #     "    compare: `by path components`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_path_starts_with`](macro@crate::assert_path_starts_with)
+/// * [`assert_path_starts_with_as_result`](macro@crate::assert_path_starts_with_as_result)
+/// * [`debug_assert_path_starts_with`](macro@crate::debug_assert_path_starts_with)
+///
+#[macro_export]
+macro_rules! assert_path_starts_with {
+    ($path:expr, $base:expr $(,)?) => {{
+        match $crate::assert_path_starts_with_as_result!($path, $base) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($path:expr, $base:expr, $($message:tt)+) => {{
+        match $crate::assert_path_starts_with_as_result!($path, $base) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a path starts with a base path, by path components.
+///
+/// Pseudocode:<br>
+/// path.starts_with(base)
+///
+/// This macro provides the same statements as [`assert_path_starts_with`](macro.assert_path_starts_with.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_path_starts_with`](macro@crate::assert_path_starts_with)
+/// * [`assert_path_starts_with_as_result`](macro@crate::assert_path_starts_with_as_result)
+/// * [`debug_assert_path_starts_with`](macro@crate::debug_assert_path_starts_with)
+///
+#[macro_export]
+macro_rules! debug_assert_path_starts_with {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_path_starts_with!($($arg)*);
+        }
+    };
+}
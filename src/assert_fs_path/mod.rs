@@ -0,0 +1,29 @@
+//! Assert for comparing file system paths, by path components.
+//!
+//! These macros help with file system paths, such as `Path`, `PathBuf`, and
+//! the trait `AsRef<Path>`. They compare paths without touching the disk.
+//!
+//! These macros compare whole path components, so they respect separators,
+//! unlike a string prefix or suffix check. For example, the path
+//! `/alfa/bravocharlie` starts with the string `/alfa/bravo`, but does not
+//! start with the path `/alfa/bravo`.
+//!
+//! * [`assert_path_starts_with!(path, base)`](macro@crate::assert_path_starts_with) ≈ path.starts_with(base)
+//! * [`assert_path_ends_with!(path, suffix)`](macro@crate::assert_path_ends_with) ≈ path.ends_with(suffix)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::path::PathBuf;
+//!
+//! # fn main() {
+//! let path = PathBuf::from("/alfa/bravo/charlie.txt");
+//! assert_path_starts_with!(path, "/alfa");
+//! assert_path_ends_with!(path, "bravo/charlie.txt");
+//! # }
+//! ```
+
+// Compare components
+pub mod assert_path_ends_with;
+pub mod assert_path_starts_with;
@@ -56,13 +56,14 @@ macro_rules! assert_fs_read_to_string_contains_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_contains!(path, containee)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fs_read_to_string_contains.html\n",
+                                        "{}macro.assert_fs_read_to_string_contains.html\n",
                                         "      path label: `{}`,\n",
                                         "      path debug: `{:?}`,\n",
                                         " containee label: `{}`,\n",
                                         " containee debug: `{:?}`,\n",
                                         "          string: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($path),
                                     path,
                                     stringify!($containee),
@@ -77,13 +78,14 @@ macro_rules! assert_fs_read_to_string_contains_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fs_read_to_string_contains!(path, containee)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fs_read_to_string_contains.html\n",
+                                    "{}macro.assert_fs_read_to_string_contains.html\n",
                                     "      path label: `{}`,\n",
                                     "      path debug: `{:?}`,\n",
                                     " containee label: `{}`,\n",
                                     " containee debug: `{:?}`,\n",
                                     "        read err: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($path),
                                 path,
                                 stringify!($containee),
@@ -56,7 +56,7 @@ macro_rules! assert_fs_read_to_string_eq_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_eq!(a_path, b_path)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fs_read_to_string_eq.html\n",
+                                        "{}macro.assert_fs_read_to_string_eq.html\n",
                                         " a_path label: `{}`,\n",
                                         " a_path debug: `{:?}`,\n",
                                         " b_path label: `{}`,\n",
@@ -64,6 +64,7 @@ macro_rules! assert_fs_read_to_string_eq_as_result {
                                         "     a string: `{:?}`,\n",
                                         "     b string: `{:?}`"
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_path),
                                     a_path,
                                     stringify!($b_path),
@@ -79,7 +80,7 @@ macro_rules! assert_fs_read_to_string_eq_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fs_read_to_string_eq!(a_path, b_path)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fs_read_to_string_eq.html\n",
+                                    "{}macro.assert_fs_read_to_string_eq.html\n",
                                     " a_path label: `{}`,\n",
                                     " a_path debug: `{:?}`,\n",
                                     " b_path label: `{}`,\n",
@@ -87,6 +88,7 @@ macro_rules! assert_fs_read_to_string_eq_as_result {
                                     "     a result: `{:?}`,\n",
                                     "     b result: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_path),
                                 a_path,
                                 stringify!($b_path),
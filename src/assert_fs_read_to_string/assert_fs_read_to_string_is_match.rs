@@ -57,13 +57,14 @@ macro_rules! assert_fs_read_to_string_is_match_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_is_match!(path, matcher)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fs_read_to_string_is_match.html\n",
+                                        "{}macro.assert_fs_read_to_string_is_match.html\n",
                                         "    path label: `{}`,\n",
                                         "    path debug: `{:?}`,\n",
                                         " matcher label: `{}`,\n",
                                         " matcher debug: `{:?}`,\n",
                                         "        string: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($path),
                                     path,
                                     stringify!($matcher),
@@ -78,13 +79,14 @@ macro_rules! assert_fs_read_to_string_is_match_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fs_read_to_string_is_match!(path, matcher)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fs_read_to_string_is_match.html\n",
+                                    "{}macro.assert_fs_read_to_string_is_match.html\n",
                                     "    path label: `{}`,\n",
                                     "    path debug: `{:?}`,\n",
                                     " matcher label: `{}`,\n",
                                     " matcher debug: `{:?}`,\n",
                                     "           err: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($path),
                                 path,
                                 stringify!($matcher),
@@ -57,7 +57,7 @@ macro_rules! assert_fs_read_to_string_le_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_le_x!(a_path, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fs_read_to_string_le_x.html\n",
+                                        "{}macro.assert_fs_read_to_string_le_x.html\n",
                                         " a_path label: `{}`,\n",
                                         " a_path debug: `{:?}`,\n",
                                         " b_expr label: `{}`,\n",
@@ -65,6 +65,7 @@ macro_rules! assert_fs_read_to_string_le_x_as_result {
                                         "     a string: `{:?}`,\n",
                                         "     b string: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_path),
                                     a_path,
                                     stringify!($b_expr),
@@ -80,13 +81,14 @@ macro_rules! assert_fs_read_to_string_le_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fs_read_to_string_le_x!(a_path, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_fs_read_to_string_le_x.html\n",
+                                    "{}macro.assert_fs_read_to_string_le_x.html\n",
                                     " a_path label: `{}`,\n",
                                     " a_path debug: `{:?}`,\n",
                                     " b_expr label: `{}`,\n",
                                     " b_expr debug: `{:?}`,\n",
                                     "          err: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_path),
                                 a_path,
                                 stringify!($b_expr),
@@ -27,6 +27,10 @@
 //! * [`assert_fs_read_to_string_contains!(path, containee)`](macro@crate::assert_fs_read_to_string_contains) ≈ std::fs::read_to_string(path).contains(containee)
 //! * [`assert_fs_read_to_string_is_match!(path, matcher)`](macro@crate::assert_fs_read_to_string_is_match) ≈ matcher.is_match(std::fs::read_to_string(path))
 //!
+//! Compare a command standard output with a path, in the module [`assert_command`](module@crate::assert_command):
+//!
+//! * [`assert_command_stdout_eq_file!(command, path)`](macro@crate::assert_command_stdout_eq_file) ≈ command stdout = std::fs::read(path)
+//!
 //! # Example
 //!
 //! ```rust
@@ -53,12 +53,13 @@ macro_rules! assert_ge_as_result {
                     Err(format!(
                         concat!(
                             "assertion failed: `assert_ge!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ge.html\n",
+                            "{}macro.assert_ge.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
                         a,
                         stringify!($b),
@@ -0,0 +1,381 @@
+//! Assert a value is greater than or equal to another value, within a tolerance.
+//!
+//! Pseudocode:<br>
+//! a ≥ (b - tol)
+//!
+//! This is useful for numeric measurements where strict ordering is too
+//! brittle, but [`assert_in_delta`](macro@crate::assert_in_delta) is too symmetric.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: i32 = 9;
+//! let b: i32 = 10;
+//! let tol: i32 = 2;
+//! assert_ge_within!(a, b, tol);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ge_within`](macro@crate::assert_ge_within)
+//! * [`assert_ge_within_as_result`](macro@crate::assert_ge_within_as_result)
+//! * [`debug_assert_ge_within`](macro@crate::debug_assert_ge_within)
+
+/// Assert a value is greater than or equal to another value, within a tolerance.
+///
+/// Pseudocode:<br>
+/// a ≥ (b - tol)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This means a is at least b, allowing a to dip below b by up to tol.
+///
+/// The macro only does arithmetic when needed: if `a >= b` then it succeeds
+/// without arithmetic, otherwise it compares `b - a` to `tol`, and it computes
+/// the threshold `b - tol` only for the failure message. This arithmetic
+/// never overflows, even for signed integers at opposite ends of their range,
+/// such as `i8::MIN` and `i8::MAX`.
+///
+/// This macro provides the same statements as [`assert_ge_within`](macro.assert_ge_within.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ge_within`](macro@crate::assert_ge_within)
+/// * [`assert_ge_within_as_result`](macro@crate::assert_ge_within_as_result)
+/// * [`debug_assert_ge_within`](macro@crate::debug_assert_ge_within)
+///
+#[macro_export]
+macro_rules! assert_ge_within_as_result {
+    ($a:expr, $b:expr, $tol:expr $(,)?) => {{
+        match (&$a, &$b, &$tol) {
+            (a, b, tol) => {
+                if a >= b || $crate::assert_ge_within::Tolerance::exceeds_within(b, a, tol) {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_ge_within!(a, b, tol)`\n",
+                                "{}macro.assert_ge_within.html\n",
+                                "   a label: `{}`,\n",
+                                "   a debug: `{:?}`,\n",
+                                "   b label: `{}`,\n",
+                                "   b debug: `{:?}`,\n",
+                                " tol label: `{}`,\n",
+                                " tol debug: `{:?}`,\n",
+                                "   b - tol: `{:?}`",
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($tol),
+                            tol,
+                            $crate::assert_ge_within::Tolerance::minus_tolerance(b, tol)
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success_without_tolerance() {
+        let a: i32 = 10;
+        let b: i32 = 10;
+        let tol: i32 = 2;
+        let result = assert_ge_within_as_result!(a, b, tol);
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn success_within_tolerance() {
+        let a: i32 = 9;
+        let b: i32 = 10;
+        let tol: i32 = 2;
+        let result = assert_ge_within_as_result!(a, b, tol);
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_unsigned_near_bound() {
+        let a: u8 = 0;
+        let b: u8 = 1;
+        let tol: u8 = 5;
+        let result = assert_ge_within_as_result!(a, b, tol);
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_float() {
+        let a: f32 = 9.0;
+        let b: f32 = 10.0;
+        let tol: f32 = 2.0;
+        let result = assert_ge_within_as_result!(a, b, tol);
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_signed_at_opposite_bounds() {
+        let a: i8 = -1;
+        let b: i8 = i8::MAX - 1;
+        let tol: i8 = i8::MAX;
+        let result = assert_ge_within_as_result!(a, b, tol);
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_signed_at_opposite_bounds() {
+        let a: i8 = i8::MIN;
+        let b: i8 = i8::MAX;
+        let tol: i8 = 1;
+        let result = assert_ge_within_as_result!(a, b, tol);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ge_within!(a, b, tol)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ge_within.html\n",
+                "   a label: `a`,\n",
+                "   a debug: `-128`,\n",
+                "   b label: `b`,\n",
+                "   b debug: `127`,\n",
+                " tol label: `tol`,\n",
+                " tol debug: `1`,\n",
+                "   b - tol: `126`",
+            )
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a: i32 = 7;
+        let b: i32 = 10;
+        let tol: i32 = 2;
+        let result = assert_ge_within_as_result!(a, b, tol);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ge_within!(a, b, tol)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ge_within.html\n",
+                "   a label: `a`,\n",
+                "   a debug: `7`,\n",
+                "   b label: `b`,\n",
+                "   b debug: `10`,\n",
+                " tol label: `tol`,\n",
+                " tol debug: `2`,\n",
+                "   b - tol: `8`",
+            )
+        );
+    }
+}
+
+/// Assert a value is greater than or equal to another value, within a tolerance.
+///
+/// Pseudocode:<br>
+/// a ≥ (b - tol)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: i32 = 9;
+/// let b: i32 = 10;
+/// let tol: i32 = 2;
+/// assert_ge_within!(a, b, tol);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: i32 = 7;
+/// let b: i32 = 10;
+/// let tol: i32 = 2;
+/// assert_ge_within!(a, b, tol);
+/// # });
+/// // assertion failed: `assert_ge_within!(a, b, tol)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ge_within.html
+/// //    a label: `a`,
+/// //    a debug: `7`,
+/// //    b label: `b`,
+/// //    b debug: `10`,
+/// //  tol label: `tol`,
+/// //  tol debug: `2`,
+/// //    b - tol: `8`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ge_within!(a, b, tol)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ge_within.html\n",
+/// #     "   a label: `a`,\n",
+/// #     "   a debug: `7`,\n",
+/// #     "   b label: `b`,\n",
+/// #     "   b debug: `10`,\n",
+/// #     " tol label: `tol`,\n",
+/// #     " tol debug: `2`,\n",
+/// #     "   b - tol: `8`",
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ge_within`](macro@crate::assert_ge_within)
+/// * [`assert_ge_within_as_result`](macro@crate::assert_ge_within_as_result)
+/// * [`debug_assert_ge_within`](macro@crate::debug_assert_ge_within)
+///
+#[macro_export]
+macro_rules! assert_ge_within {
+    ($a:expr, $b:expr, $tol:expr $(,)?) => {{
+        match $crate::assert_ge_within_as_result!($a, $b, $tol) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $tol:expr, $($message:tt)+) => {{
+        match $crate::assert_ge_within_as_result!($a, $b, $tol) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a value is greater than or equal to another value, within a tolerance.
+///
+/// Pseudocode:<br>
+/// a ≥ (b - tol)
+///
+/// This macro provides the same statements as [`assert_ge_within`](macro.assert_ge_within.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ge_within`](macro@crate::assert_ge_within)
+/// * [`assert_ge_within_as_result`](macro@crate::assert_ge_within_as_result)
+/// * [`debug_assert_ge_within`](macro@crate::debug_assert_ge_within)
+///
+#[macro_export]
+macro_rules! debug_assert_ge_within {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ge_within!($($arg)*);
+        }
+    };
+}
+
+/// Tolerance arithmetic for [`assert_ge_within`](macro@crate::assert_ge_within)
+/// and [`assert_le_within`](macro@crate::assert_le_within).
+///
+/// The integer implementations never overflow, even when the values are
+/// at opposite ends of their type's range, such as `i8::MIN` and `i8::MAX`.
+#[doc(hidden)]
+pub trait Tolerance {
+    /// Return true if `self - lower <= tol`, given that `lower < self`.
+    fn exceeds_within(&self, lower: &Self, tol: &Self) -> bool;
+
+    /// Return `self - tol`, saturating at the bounds of the type.
+    fn minus_tolerance(&self, tol: &Self) -> Self;
+
+    /// Return `self + tol`, saturating at the bounds of the type.
+    fn plus_tolerance(&self, tol: &Self) -> Self;
+}
+
+macro_rules! impl_tolerance_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl Tolerance for $t {
+                fn exceeds_within(&self, lower: &Self, tol: &Self) -> bool {
+                    // When the subtraction overflows, the difference is
+                    // greater than any value of the type, including `tol`.
+                    match self.checked_sub(*lower) {
+                        Some(diff) => diff <= *tol,
+                        None => false,
+                    }
+                }
+
+                fn minus_tolerance(&self, tol: &Self) -> Self {
+                    self.saturating_sub(*tol)
+                }
+
+                fn plus_tolerance(&self, tol: &Self) -> Self {
+                    self.saturating_add(*tol)
+                }
+            }
+        )*
+    };
+}
+
+impl_tolerance_for_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_tolerance_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl Tolerance for $t {
+                fn exceeds_within(&self, lower: &Self, tol: &Self) -> bool {
+                    self - lower <= *tol
+                }
+
+                fn minus_tolerance(&self, tol: &Self) -> Self {
+                    self - tol
+                }
+
+                fn plus_tolerance(&self, tol: &Self) -> Self {
+                    self + tol
+                }
+            }
+        )*
+    };
+}
+
+impl_tolerance_for_float!(f32, f64);
+
+impl Tolerance for ::std::time::Duration {
+    fn exceeds_within(&self, lower: &Self, tol: &Self) -> bool {
+        *self - *lower <= *tol
+    }
+
+    fn minus_tolerance(&self, tol: &Self) -> Self {
+        self.saturating_sub(*tol)
+    }
+
+    fn plus_tolerance(&self, tol: &Self) -> Self {
+        self.saturating_add(*tol)
+    }
+}
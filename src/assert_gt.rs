@@ -53,12 +53,13 @@ macro_rules! assert_gt_as_result {
                     Err(format!(
                         concat!(
                             "assertion failed: `assert_gt!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_gt.html\n",
+                            "{}macro.assert_gt.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
                         a,
                         stringify!($b),
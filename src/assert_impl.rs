@@ -0,0 +1,127 @@
+//! Assert a type implements trait bounds, at compile time.
+//!
+//! Pseudocode:<br>
+//! T: bounds
+//!
+//! This macro is a compile-time check, not a run-time check. If the type
+//! does not satisfy the bounds, then the program fails to compile, with an
+//! error at the macro site. The macro never panics and returns nothing.
+//!
+//! This is useful for library authors who want to guarantee that a public
+//! type stays `Send`, `Sync`, `Clone`, etc., so a change that removes an
+//! implementation breaks the build, rather than breaking a downstream user.
+//!
+//! The macro expands to an anonymous constant, so it works at module scope,
+//! such as next to the type definition, and also within a function body.
+//! The type can be unsized, such as `str` or `dyn Trait`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! #[derive(Clone)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! assert_impl!(Point: Send + Sync);
+//! assert_impl!(Point: Clone);
+//! assert_impl!(Vec<String>: Clone + Default);
+//! # }
+//! ```
+//!
+//! A type that fails the bounds is a compile error:
+//!
+//! ```compile_fail
+//! use assertables::*;
+//! use std::rc::Rc;
+//!
+//! # fn main() {
+//! assert_impl!(Rc<i32>: Send);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_impl`](macro@crate::assert_impl)
+
+/// Assert a type implements trait bounds, at compile time.
+///
+/// Pseudocode:<br>
+/// T: bounds
+///
+/// * If true, compile.
+///
+/// * Otherwise, fail to compile, with an error at the macro site, such as
+///   "the trait bound `T: Clone` is not satisfied".
+///
+/// The bounds use the same syntax as a generic bound, such as
+/// `Send + Sync`, `Clone`, `Iterator<Item = u8>`, or `'static`.
+///
+/// This macro has no `_as_result` form and no `debug_` form, because
+/// it has no run-time behavior.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+///
+/// # fn main() {
+/// assert_impl!(String: Send + Sync + Clone);
+/// assert_impl!(str: Send + Sync);
+/// assert_impl!(std::vec::IntoIter<u8>: Iterator<Item = u8>);
+/// # }
+/// ```
+///
+/// This will fail to compile:
+///
+/// ```compile_fail
+/// use assertables::*;
+///
+/// # fn main() {
+/// struct Point { x: i32, y: i32 }
+/// assert_impl!(Point: Clone);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_impl`](macro@crate::assert_impl)
+///
+#[macro_export]
+macro_rules! assert_impl {
+    ($type:ty: $($bound:tt)+) => {
+        const _: fn() = || {
+            fn assert_impl<T: ?Sized + $($bound)+>() {}
+            assert_impl::<$type>();
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    #[derive(Clone, Debug, Default)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    assert_impl!(Point: Send + Sync);
+    assert_impl!(Point: Clone + std::fmt::Debug + Default);
+    assert_impl!(Cell<i32>: Send);
+    assert_impl!(&'static str: Copy + 'static);
+
+    #[test]
+    fn success_in_function() {
+        assert_impl!(Vec<Point>: Clone);
+        assert_impl!(str: Send + Sync);
+        assert_impl!(dyn std::any::Any + Send: Send);
+        let point = Point::default();
+        assert_eq!((point.x, point.y), (0, 0));
+    }
+}
@@ -0,0 +1,325 @@
+//! Assert two slices of floating point numbers are equal item by item, within a relative tolerance.
+//!
+//! Pseudocode:<br>
+//! a.len() = b.len() ∧ each i: | a[i] - b[i] | ≤ rel_tol * max(| a[i] |, | b[i] |)
+//!
+//! The tolerance scales with the magnitude of each pair of items, so this
+//! works for arrays of numbers that span many orders of magnitude, where an
+//! absolute Δ is too strict for large items and too loose for small items.
+//!
+//! The failure message shows the first index that exceeds the tolerance, the
+//! items at that index, and the relative error, which is
+//! `| a[i] - b[i] | / max(| a[i] |, | b[i] |)`. When the lengths differ, the
+//! index is the length of the shorter slice. The message also shows the
+//! index of the first NaN in each slice, because a NaN never compares as near.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: [f64; 3] = [1.0e-10, 1.0, 1.0e10];
+//! let b: [f64; 3] = [1.0000001e-10, 1.0000001, 1.0000001e10];
+//! let rel_tol: f64 = 1.0e-6;
+//! assert_approx_eq_slice_rel!(&a, &b, rel_tol);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_approx_eq_slice_rel`](macro@crate::assert_approx_eq_slice_rel)
+//! * [`assert_approx_eq_slice_rel_as_result`](macro@crate::assert_approx_eq_slice_rel_as_result)
+//! * [`debug_assert_approx_eq_slice_rel`](macro@crate::debug_assert_approx_eq_slice_rel)
+
+/// Assert two slices of floating point numbers are equal item by item, within a relative tolerance.
+///
+/// Pseudocode:<br>
+/// a.len() = b.len() ∧ each i: | a[i] - b[i] | ≤ rel_tol * max(| a[i] |, | b[i] |)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The items can be `f32` or `f64`. Equal items always pass, such as two zeros
+/// or two infinities of the same sign.
+///
+/// This macro provides the same statements as [`assert_approx_eq_slice_rel`](macro.assert_approx_eq_slice_rel.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_approx_eq_slice_rel`](macro@crate::assert_approx_eq_slice_rel)
+/// * [`assert_approx_eq_slice_rel_as_result`](macro@crate::assert_approx_eq_slice_rel_as_result)
+/// * [`debug_assert_approx_eq_slice_rel`](macro@crate::debug_assert_approx_eq_slice_rel)
+///
+#[macro_export]
+macro_rules! assert_approx_eq_slice_rel_as_result {
+    ($a:expr, $b:expr, $rel_tol:expr $(,)?) => {{
+        match (&$a, &$b, &$rel_tol) {
+            (a, b, rel_tol) => {
+                let (a_slice, b_slice) = (&a[..], &b[..]);
+                let failure = a_slice.iter().zip(b_slice.iter()).enumerate().find_map(|(index, (a_item, b_item))| {
+                    if a_item == b_item {
+                        return None;
+                    }
+                    let abs_diff = (*a_item - *b_item).abs();
+                    let scale = a_item.abs().max(b_item.abs());
+                    if abs_diff <= *rel_tol * scale {
+                        None
+                    } else {
+                        Some((index, Some(*a_item), Some(*b_item), Some(abs_diff / scale)))
+                    }
+                });
+                let failure = match failure {
+                    Some(failure) => Some(failure),
+                    None if a_slice.len() != b_slice.len() => {
+                        let index = ::std::cmp::min(a_slice.len(), b_slice.len());
+                        Some((index, a_slice.get(index).copied(), b_slice.get(index).copied(), None))
+                    },
+                    None => None,
+                };
+                match failure {
+                    None => Ok(()),
+                    Some((index, a_item, b_item, relative_error)) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_approx_eq_slice_rel!(a, b, rel_tol)`\n",
+                                    "{}macro.assert_approx_eq_slice_rel.html\n",
+                                    "           a label: `{}`,\n",
+                                    "             a len: `{}`,\n",
+                                    "           b label: `{}`,\n",
+                                    "             b len: `{}`,\n",
+                                    "     rel_tol label: `{}`,\n",
+                                    "     rel_tol debug: `{:?}`,\n",
+                                    "             index: `{}`,\n",
+                                    "            a item: `{:?}`,\n",
+                                    "            b item: `{:?}`,\n",
+                                    "    relative error: `{:?}`,\n",
+                                    " a first NaN index: `{:?}`,\n",
+                                    " b first NaN index: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a_slice.len(),
+                                stringify!($b),
+                                b_slice.len(),
+                                stringify!($rel_tol),
+                                rel_tol,
+                                index,
+                                a_item,
+                                b_item,
+                                relative_error,
+                                a_slice.iter().position(|x| x.is_nan()),
+                                b_slice.iter().position(|x| x.is_nan())
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: [f64; 3] = [1.0e-10, 1.0, 1.0e10];
+        let b: [f64; 3] = [1.0000001e-10, 1.0000001, 1.0000001e10];
+        let rel_tol: f64 = 1.0e-6;
+        let result = assert_approx_eq_slice_rel_as_result!(&a, &b, rel_tol);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_zeros_and_infinities() {
+        let a: Vec<f32> = vec![0.0, f32::INFINITY, -2.0];
+        let b: Vec<f32> = vec![0.0, f32::INFINITY, -2.000001];
+        let rel_tol: f32 = 1.0e-5;
+        let result = assert_approx_eq_slice_rel_as_result!(a, b, rel_tol);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let a: [f64; 3] = [1.0e-10, 1.0, 1.0e10];
+        let b: [f64; 3] = [1.0e-10, 1.0, 1.01e10];
+        let rel_tol: f64 = 1.0e-6;
+        let result = assert_approx_eq_slice_rel_as_result!(&a, &b, rel_tol);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_approx_eq_slice_rel!(a, b, rel_tol)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_approx_eq_slice_rel.html\n",
+                "           a label: `&a`,\n",
+                "             a len: `3`,\n",
+                "           b label: `&b`,\n",
+                "             b len: `3`,\n",
+                "     rel_tol label: `rel_tol`,\n",
+                "     rel_tol debug: `1e-6`,\n",
+                "             index: `2`,\n",
+                "            a item: `Some(10000000000.0)`,\n",
+                "            b item: `Some(10100000000.0)`,\n",
+                "    relative error: `Some(0.009900990099009901)`,\n",
+                " a first NaN index: `None`,\n",
+                " b first NaN index: `None`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_len_differs() {
+        let a: [f64; 2] = [1.0, 2.0];
+        let b: [f64; 3] = [1.0, 2.0, 3.0];
+        let rel_tol: f64 = 1.0e-6;
+        let result = assert_approx_eq_slice_rel_as_result!(&a, &b, rel_tol);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("             index: `2`,\n"));
+        assert!(actual.contains("            a item: `None`,\n"));
+        assert!(actual.contains("    relative error: `None`,\n"));
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let a: [f64; 3] = [1.0, f64::NAN, 3.0];
+        let b: [f64; 3] = [1.0, f64::NAN, 3.0];
+        let rel_tol: f64 = 1.0e-6;
+        let result = assert_approx_eq_slice_rel_as_result!(&a, &b, rel_tol);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("             index: `1`,\n"));
+        assert!(actual.contains(" a first NaN index: `Some(1)`,\n"));
+        assert!(actual.contains(" b first NaN index: `Some(1)`"));
+    }
+}
+
+/// Assert two slices of floating point numbers are equal item by item, within a relative tolerance.
+///
+/// Pseudocode:<br>
+/// a.len() = b.len() ∧ each i: | a[i] - b[i] | ≤ rel_tol * max(| a[i] |, | b[i] |)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: [f64; 3] = [1.0e-10, 1.0, 1.0e10];
+/// let b: [f64; 3] = [1.0000001e-10, 1.0000001, 1.0000001e10];
+/// let rel_tol: f64 = 1.0e-6;
+/// assert_approx_eq_slice_rel!(&a, &b, rel_tol);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: [f64; 3] = [1.0e-10, 1.0, 1.0e10];
+/// let b: [f64; 3] = [1.0e-10, 1.0, 1.01e10];
+/// let rel_tol: f64 = 1.0e-6;
+/// assert_approx_eq_slice_rel!(&a, &b, rel_tol);
+/// # });
+/// // assertion failed: `assert_approx_eq_slice_rel!(a, b, rel_tol)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_approx_eq_slice_rel.html
+/// //            a label: `&a`,
+/// //              a len: `3`,
+/// //            b label: `&b`,
+/// //              b len: `3`,
+/// //      rel_tol label: `rel_tol`,
+/// //      rel_tol debug: `1e-6`,
+/// //              index: `2`,
+/// //             a item: `Some(10000000000.0)`,
+/// //             b item: `Some(10100000000.0)`,
+/// //     relative error: `Some(0.009900990099009901)`,
+/// //  a first NaN index: `None`,
+/// //  b first NaN index: `None`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_approx_eq_slice_rel!(a, b, rel_tol)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_approx_eq_slice_rel.html\n",
+/// #     "           a label: `&a`,\n",
+/// #     "             a len: `3`,\n",
+/// #     "           b label: `&b`,\n",
+/// #     "             b len: `3`,\n",
+/// #     "     rel_tol label: `rel_tol`,\n",
+/// #     "     rel_tol debug: `1e-6`,\n",
+/// #     "             index: `2`,\n",
+/// #     "            a item: `Some(10000000000.0)`,\n",
+/// #     "            b item: `Some(10100000000.0)`,\n",
+/// #     "    relative error: `Some(0.009900990099009901)`,\n",
+/// #     " a first NaN index: `None`,\n",
+/// #     " b first NaN index: `None`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_approx_eq_slice_rel`](macro@crate::assert_approx_eq_slice_rel)
+/// * [`assert_approx_eq_slice_rel_as_result`](macro@crate::assert_approx_eq_slice_rel_as_result)
+/// * [`debug_assert_approx_eq_slice_rel`](macro@crate::debug_assert_approx_eq_slice_rel)
+///
+#[macro_export]
+macro_rules! assert_approx_eq_slice_rel {
+    ($a:expr, $b:expr, $rel_tol:expr $(,)?) => {{
+        match $crate::assert_approx_eq_slice_rel_as_result!($a, $b, $rel_tol) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $rel_tol:expr, $($message:tt)+) => {{
+        match $crate::assert_approx_eq_slice_rel_as_result!($a, $b, $rel_tol) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert two slices of floating point numbers are equal item by item, within a relative tolerance.
+///
+/// Pseudocode:<br>
+/// a.len() = b.len() ∧ each i: | a[i] - b[i] | ≤ rel_tol * max(| a[i] |, | b[i] |)
+///
+/// This macro provides the same statements as [`assert_approx_eq_slice_rel`](macro.assert_approx_eq_slice_rel.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_approx_eq_slice_rel`](macro@crate::assert_approx_eq_slice_rel)
+/// * [`assert_approx_eq_slice_rel_as_result`](macro@crate::assert_approx_eq_slice_rel_as_result)
+/// * [`debug_assert_approx_eq_slice_rel`](macro@crate::debug_assert_approx_eq_slice_rel)
+///
+#[macro_export]
+macro_rules! debug_assert_approx_eq_slice_rel {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_approx_eq_slice_rel!($($arg)*);
+        }
+    };
+}
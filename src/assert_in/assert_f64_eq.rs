@@ -0,0 +1,368 @@
+//! Assert a f64 number is basically equal to another f64 number.
+//!
+//! Pseudocode:<br>
+//! | a - b | ≤ ε * max(| a |, | b |, 1.0)
+//!
+//! The default ε is `f64::EPSILON`, which is the difference between 1.0 and
+//! the next larger f64 number. The threshold scales with the larger magnitude
+//! of `a` and `b`, so the comparison works for large numbers, and never drops
+//! below ε, so the comparison works for numbers near zero.
+//!
+//! To choose a different ε, pass it as an optional third argument, such as
+//! `assert_f64_eq!(a, b, 0.01)`. To pass a custom message, pass ε too, such as
+//! `assert_f64_eq!(a, b, f64::EPSILON, "message")`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: f64 = 0.1 + 0.2;
+//! let b: f64 = 0.3;
+//! assert_f64_eq!(a, b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_f64_eq`](macro@crate::assert_f64_eq)
+//! * [`assert_f64_eq_as_result`](macro@crate::assert_f64_eq_as_result)
+//! * [`debug_assert_f64_eq`](macro@crate::debug_assert_f64_eq)
+
+/// Assert a f64 number is basically equal to another f64 number.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ ε * max(| a |, | b |, 1.0)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The default ε is `f64::EPSILON`. To choose a different ε, pass it as an
+/// optional third argument, such as `assert_f64_eq_as_result!(a, b, 0.01)`.
+///
+/// This macro provides the same statements as [`assert_f64_eq`](macro.assert_f64_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_f64_eq`](macro@crate::assert_f64_eq)
+/// * [`assert_f64_eq_as_result`](macro@crate::assert_f64_eq_as_result)
+/// * [`debug_assert_f64_eq`](macro@crate::debug_assert_f64_eq)
+///
+#[macro_export]
+macro_rules! assert_f64_eq_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let (a, b): (f64, f64) = (*a, *b);
+                let epsilon = f64::EPSILON;
+                let abs_diff = (a - b).abs();
+                let threshold = epsilon * a.abs().max(b.abs()).max(1.0);
+                if a == b || abs_diff <= threshold {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_f64_eq!(a, b)`\n",
+                                "{}macro.assert_f64_eq.html\n",
+                                "               a label: `{}`,\n",
+                                "               a debug: `{:?}`,\n",
+                                "               b label: `{}`,\n",
+                                "               b debug: `{:?}`,\n",
+                                "                     ε: `{:?}`,\n",
+                                "             | a - b |: `{:?}`,\n",
+                                "             threshold: `{:?}`,\n",
+                                " | a - b | ≤ threshold: false"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            epsilon,
+                            abs_diff,
+                            threshold
+                        )
+                    )
+                }
+            }
+        }
+    }};
+    ($a:expr, $b:expr, $epsilon:expr $(,)?) => {{
+        match (&$a, &$b, &$epsilon) {
+            (a, b, epsilon) => {
+                let (a, b, epsilon): (f64, f64, f64) = (*a, *b, *epsilon);
+                let abs_diff = (a - b).abs();
+                let threshold = epsilon * a.abs().max(b.abs()).max(1.0);
+                if a == b || abs_diff <= threshold {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_f64_eq!(a, b, ε)`\n",
+                                "{}macro.assert_f64_eq.html\n",
+                                "               a label: `{}`,\n",
+                                "               a debug: `{:?}`,\n",
+                                "               b label: `{}`,\n",
+                                "               b debug: `{:?}`,\n",
+                                "               ε label: `{}`,\n",
+                                "               ε debug: `{:?}`,\n",
+                                "             | a - b |: `{:?}`,\n",
+                                "             threshold: `{:?}`,\n",
+                                " | a - b | ≤ threshold: false"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($epsilon),
+                            epsilon,
+                            abs_diff,
+                            threshold
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: f64 = 0.1 + 0.2;
+        let b: f64 = 0.3;
+        let result = assert_f64_eq_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_large_numbers() {
+        let a: f64 = 1.0e20;
+        let b: f64 = 1.0e20 + 8192.0;
+        let result = assert_f64_eq_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_infinity() {
+        let a: f64 = f64::INFINITY;
+        let b: f64 = f64::INFINITY;
+        let result = assert_f64_eq_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let a: f64 = 1.0;
+        let b: f64 = 2.0;
+        let result = assert_f64_eq_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_f64_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_f64_eq.html\n",
+                "               a label: `a`,\n",
+                "               a debug: `1.0`,\n",
+                "               b label: `b`,\n",
+                "               b debug: `2.0`,\n",
+                "                     ε: `2.220446049250313e-16`,\n",
+                "             | a - b |: `1.0`,\n",
+                "             threshold: `4.440892098500626e-16`,\n",
+                " | a - b | ≤ threshold: false"
+            )
+        );
+    }
+
+    #[test]
+    fn success_with_epsilon() {
+        let a: f64 = 1.0;
+        let b: f64 = 1.001;
+        let epsilon: f64 = 0.01;
+        let result = assert_f64_eq_as_result!(a, b, epsilon);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_with_epsilon() {
+        let a: f64 = 1.0;
+        let b: f64 = 1.5;
+        let epsilon: f64 = 0.25;
+        let result = assert_f64_eq_as_result!(a, b, epsilon);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_f64_eq!(a, b, ε)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_f64_eq.html\n",
+                "               a label: `a`,\n",
+                "               a debug: `1.0`,\n",
+                "               b label: `b`,\n",
+                "               b debug: `1.5`,\n",
+                "               ε label: `epsilon`,\n",
+                "               ε debug: `0.25`,\n",
+                "             | a - b |: `0.5`,\n",
+                "             threshold: `0.375`,\n",
+                " | a - b | ≤ threshold: false"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_with_custom_message() {
+        let result = std::panic::catch_unwind(|| {
+            let a: f64 = 1.0;
+            let b: f64 = 2.0;
+            crate::assert_f64_eq!(a, b, f64::EPSILON, "custom message");
+        });
+        let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+        assert_eq!(actual, "custom message");
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let a: f64 = f64::NAN;
+        let b: f64 = f64::NAN;
+        let result = assert_f64_eq_as_result!(a, b);
+        assert!(result.is_err());
+    }
+}
+
+/// Assert a f64 number is basically equal to another f64 number.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ ε * max(| a |, | b |, 1.0)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// The default ε is `f64::EPSILON`. To choose a different ε, pass it as an
+/// optional third argument, such as `assert_f64_eq!(a, b, 0.01)`. To pass a
+/// custom message, pass ε too, such as `assert_f64_eq!(a, b, f64::EPSILON, "message")`.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: f64 = 0.1 + 0.2;
+/// let b: f64 = 0.3;
+/// assert_f64_eq!(a, b);
+///
+/// let a: f64 = 1.0;
+/// let b: f64 = 1.001;
+/// assert_f64_eq!(a, b, 0.01);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: f64 = 1.0;
+/// let b: f64 = 2.0;
+/// assert_f64_eq!(a, b);
+/// # });
+/// // assertion failed: `assert_f64_eq!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_f64_eq.html
+/// //                a label: `a`,
+/// //                a debug: `1.0`,
+/// //                b label: `b`,
+/// //                b debug: `2.0`,
+/// //                      ε: `2.220446049250313e-16`,
+/// //              | a - b |: `1.0`,
+/// //              threshold: `4.440892098500626e-16`,
+/// //  | a - b | ≤ threshold: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_f64_eq!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_f64_eq.html\n",
+/// #     "               a label: `a`,\n",
+/// #     "               a debug: `1.0`,\n",
+/// #     "               b label: `b`,\n",
+/// #     "               b debug: `2.0`,\n",
+/// #     "                     ε: `2.220446049250313e-16`,\n",
+/// #     "             | a - b |: `1.0`,\n",
+/// #     "             threshold: `4.440892098500626e-16`,\n",
+/// #     " | a - b | ≤ threshold: false"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_f64_eq`](macro@crate::assert_f64_eq)
+/// * [`assert_f64_eq_as_result`](macro@crate::assert_f64_eq_as_result)
+/// * [`debug_assert_f64_eq`](macro@crate::debug_assert_f64_eq)
+///
+#[macro_export]
+macro_rules! assert_f64_eq {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_f64_eq_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $epsilon:expr $(,)?) => {{
+        match $crate::assert_f64_eq_as_result!($a, $b, $epsilon) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $epsilon:expr, $($message:tt)+) => {{
+        match $crate::assert_f64_eq_as_result!($a, $b, $epsilon) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a f64 number is basically equal to another f64 number.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ ε * max(| a |, | b |, 1.0)
+///
+/// This macro provides the same statements as [`assert_f64_eq`](macro.assert_f64_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_f64_eq`](macro@crate::assert_f64_eq)
+/// * [`assert_f64_eq_as_result`](macro@crate::assert_f64_eq_as_result)
+/// * [`debug_assert_f64_eq`](macro@crate::debug_assert_f64_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_f64_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_f64_eq!($($arg)*);
+        }
+    };
+}
@@ -69,8 +69,7 @@
 ///
 /// * If true, return Result `Ok((lhs, rhs))`.
 ///
-/// * When false, return [`Err`] with a message and the values of the
-///   expressions with their debug representations.
+/// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro provides the same statements as [`assert_`](macro.assert_.html), except this macro
 /// returns a Result, rather than doing a panic.
@@ -97,7 +96,7 @@ macro_rules! assert_in_delta_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_in_delta!(a, b, Δ)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_in_delta.html\n",
+                                "{}macro.assert_in_delta.html\n",
                                 "       a label: `{}`,\n",
                                 "       a debug: `{:?}`,\n",
                                 "       b label: `{}`,\n",
@@ -107,6 +106,7 @@ macro_rules! assert_in_delta_as_result {
                                 "     | a - b |: `{:?}`,\n",
                                 " | a - b | ≤ Δ: {}"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             a,
                             stringify!($b),
@@ -0,0 +1,278 @@
+//! Assert a number is within delta of another number, with a verbose message.
+//!
+//! Pseudocode:<br>
+//! | a - b | ≤ Δ
+//!
+//! This is the same check as [`assert_in_delta`](macro@crate::assert_in_delta),
+//! and the failure message also shows the difference in scientific notation,
+//! and as a percentage of the larger magnitude of `a` and `b`.
+//!
+//! This helps with large numbers, such as `1.0000001e9` versus `1e9`, where
+//! the raw difference of `100.0` does not show whether it is significant.
+//! A tiny percentage uses scientific notation, so it does not round to zero.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: f64 = 1.0000001e9;
+//! let b: f64 = 1e9;
+//! let delta: f64 = 1000.0;
+//! assert_in_delta_verbose!(a, b, delta);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_in_delta_verbose`](macro@crate::assert_in_delta_verbose)
+//! * [`assert_in_delta_verbose_as_result`](macro@crate::assert_in_delta_verbose_as_result)
+//! * [`debug_assert_in_delta_verbose`](macro@crate::debug_assert_in_delta_verbose)
+
+/// Assert a number is within delta of another number, with a verbose message.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ Δ
+///
+/// * If true, return Result `Ok((lhs, rhs))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The numbers can be any primitive integer or float type.
+///
+/// This macro provides the same statements as [`assert_in_delta_verbose`](macro.assert_in_delta_verbose.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_in_delta_verbose`](macro@crate::assert_in_delta_verbose)
+/// * [`assert_in_delta_verbose_as_result`](macro@crate::assert_in_delta_verbose_as_result)
+/// * [`debug_assert_in_delta_verbose`](macro@crate::debug_assert_in_delta_verbose)
+///
+#[macro_export]
+macro_rules! assert_in_delta_verbose_as_result {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match (&$a, &$b, &$delta) {
+            (a, b, delta) => {
+                let abs_diff = if (a >= b) { a - b } else { b - a };
+                if abs_diff <= *delta {
+                    Ok((abs_diff, *delta))
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_in_delta_verbose!(a, b, Δ)`\n",
+                                "{}macro.assert_in_delta_verbose.html\n",
+                                "              a label: `{}`,\n",
+                                "              a debug: `{:?}`,\n",
+                                "              b label: `{}`,\n",
+                                "              b debug: `{:?}`,\n",
+                                "              Δ label: `{}`,\n",
+                                "              Δ debug: `{:?}`,\n",
+                                "            | a - b |: `{:?}`,\n",
+                                " | a - b | scientific: `{}`,\n",
+                                "    percent of larger: `{}`,\n",
+                                "        | a - b | ≤ Δ: {}"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($delta),
+                            delta,
+                            abs_diff,
+                            $crate::assert_in::scientific(abs_diff),
+                            $crate::assert_in::percent_of_larger(abs_diff, *a, *b),
+                            false
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: f64 = 1.0000001e9;
+        let b: f64 = 1e9;
+        let delta: f64 = 1000.0;
+        let result = assert_in_delta_verbose_as_result!(a, b, delta);
+        assert_eq!(result, Ok((100.0, 1000.0)));
+    }
+
+    #[test]
+    fn failure() {
+        let a: f64 = 1.0000001e9;
+        let b: f64 = 1e9;
+        let delta: f64 = 10.0;
+        let result = assert_in_delta_verbose_as_result!(a, b, delta);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_in_delta_verbose!(a, b, Δ)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_in_delta_verbose.html\n",
+                "              a label: `a`,\n",
+                "              a debug: `1000000100.0`,\n",
+                "              b label: `b`,\n",
+                "              b debug: `1000000000.0`,\n",
+                "              Δ label: `delta`,\n",
+                "              Δ debug: `10.0`,\n",
+                "            | a - b |: `100.0`,\n",
+                " | a - b | scientific: `1e2`,\n",
+                "    percent of larger: `1.000e-5%`,\n",
+                "        | a - b | ≤ Δ: false"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_with_integers() {
+        let a: i8 = 10;
+        let b: i8 = 12;
+        let delta: i8 = 1;
+        let result = assert_in_delta_verbose_as_result!(a, b, delta);
+        let actual = result.unwrap_err();
+        assert!(actual.contains(" | a - b | scientific: `2e0`,\n"));
+        assert!(actual.contains("    percent of larger: `16.667%`,\n"));
+    }
+
+    #[test]
+    fn failure_because_negative_delta_with_zeros() {
+        let a: f64 = 0.0;
+        let b: f64 = 0.0;
+        let delta: f64 = -1.0;
+        let result = assert_in_delta_verbose_as_result!(a, b, delta);
+        assert!(result.unwrap_err().contains("    percent of larger: `undefined, because a and b are 0`,\n"));
+    }
+}
+
+/// Assert a number is within delta of another number, with a verbose message.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ Δ
+///
+/// * If true, return `(lhs, rhs)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: f64 = 1.0000001e9;
+/// let b: f64 = 1e9;
+/// let delta: f64 = 1000.0;
+/// assert_in_delta_verbose!(a, b, delta);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: f64 = 1.0000001e9;
+/// let b: f64 = 1e9;
+/// let delta: f64 = 10.0;
+/// assert_in_delta_verbose!(a, b, delta);
+/// # });
+/// // assertion failed: `assert_in_delta_verbose!(a, b, Δ)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_in_delta_verbose.html
+/// //               a label: `a`,
+/// //               a debug: `1000000100.0`,
+/// //               b label: `b`,
+/// //               b debug: `1000000000.0`,
+/// //               Δ label: `delta`,
+/// //               Δ debug: `10.0`,
+/// //             | a - b |: `100.0`,
+/// //  | a - b | scientific: `1e2`,
+/// //     percent of larger: `1.000e-5%`,
+/// //         | a - b | ≤ Δ: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_in_delta_verbose!(a, b, Δ)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_in_delta_verbose.html\n",
+/// #     "              a label: `a`,\n",
+/// #     "              a debug: `1000000100.0`,\n",
+/// #     "              b label: `b`,\n",
+/// #     "              b debug: `1000000000.0`,\n",
+/// #     "              Δ label: `delta`,\n",
+/// #     "              Δ debug: `10.0`,\n",
+/// #     "            | a - b |: `100.0`,\n",
+/// #     " | a - b | scientific: `1e2`,\n",
+/// #     "    percent of larger: `1.000e-5%`,\n",
+/// #     "        | a - b | ≤ Δ: false"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_in_delta_verbose`](macro@crate::assert_in_delta_verbose)
+/// * [`assert_in_delta_verbose_as_result`](macro@crate::assert_in_delta_verbose_as_result)
+/// * [`debug_assert_in_delta_verbose`](macro@crate::debug_assert_in_delta_verbose)
+///
+#[macro_export]
+macro_rules! assert_in_delta_verbose {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match $crate::assert_in_delta_verbose_as_result!($a, $b, $delta) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $delta:expr, $($message:tt)+) => {{
+        match $crate::assert_in_delta_verbose_as_result!($a, $b, $delta) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a number is within delta of another number, with a verbose message.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ Δ
+///
+/// This macro provides the same statements as [`assert_in_delta_verbose`](macro.assert_in_delta_verbose.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_in_delta_verbose`](macro@crate::assert_in_delta_verbose)
+/// * [`assert_in_delta_verbose_as_result`](macro@crate::assert_in_delta_verbose_as_result)
+/// * [`debug_assert_in_delta_verbose`](macro@crate::debug_assert_in_delta_verbose)
+///
+#[macro_export]
+macro_rules! debug_assert_in_delta_verbose {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_in_delta_verbose!($($arg)*);
+        }
+    };
+}
@@ -1,7 +1,7 @@
 //! Assert a number is within epsilon of another number.
 //!
 //! Pseudocode:<br>
-//! | a - b | ≤ ε * min(a, b)
+//! | a - b | ≤ ε * | b |
 //!
 //! # Example
 //!
@@ -9,13 +9,24 @@
 //! use assertables::*;
 //!
 //! # fn main() {
-//! let a: i8 = 10;
-//! let b: i8 = 20;
-//! let epsilon: i8 = 1;
+//! let a: f64 = 10.0;
+//! let b: f64 = 20.0;
+//! let epsilon: f64 = 0.5;
 //! assert_in_epsilon!(a, b, epsilon);
 //! # }
 //! ```
 //!
+//! The expression `b` is the exact value, and its magnitude `| b |` scales
+//! the threshold, so the sign of `b` does not matter:
+//!
+//! * If `b` is negative, then the threshold is still positive, so a value of
+//!   `a` with the opposite sign fails, rather than passing by accident.
+//!
+//! * If `b` is zero, then the threshold is zero, so `a` must equal zero. For a
+//!   comparison near zero, use [`assert_in_delta`](macro@crate::assert_in_delta).
+//!
+//! * If `ε` is negative, then the threshold is negative, so the assertion fails.
+//!
 //!
 //! ## Comparisons
 //!
@@ -65,14 +76,17 @@
 /// Assert a number is within epsilon of another number.
 ///
 /// Pseudocode:<br>
-/// | a - b | ≤ ε * min(a, b)
+/// | a - b | ≤ ε * | b |
 ///
-/// * If true, return Result `Ok((lhs, rhs))`.
+/// * If true, return Result `Ok((| a - b |, ε * | b |))`.
 ///
-/// * When false, return [`Err`] with a message and the values of the
-///   expressions with their debug representations.
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The number type must implement `Copy`, `PartialOrd`, `Sub`, and `Mul`.
+/// The macro computes `| b |` with subtraction, so it works for signed and
+/// unsigned integers, and for floats. If `b` is zero, then `a` must be zero.
 ///
-/// This macro provides the same statements as [`assert_`](macro.assert_.html),
+/// This macro provides the same statements as [`assert_in_epsilon`](macro.assert_in_epsilon.html),
 /// except this macro returns a Result, rather than doing a panic.
 ///
 /// This macro is useful for runtime checks, such as checking parameters,
@@ -89,9 +103,10 @@ macro_rules! assert_in_epsilon_as_result {
     ($a:expr, $b:expr, $epsilon:expr $(,)?) => {{
         match (&$a, &$b, &$epsilon) {
             (a, b, epsilon) => {
-                let abs_diff = if (a >= b) { a - b } else { b - a };
-                let min = if (a < b) { a } else { b };
-                let rhs = *epsilon * min;
+                let zero = *b - *b;
+                let abs_b = if *b >= zero { *b } else { zero - *b };
+                let abs_diff = if a >= b { *a - *b } else { *b - *a };
+                let rhs = *epsilon * abs_b;
                 if abs_diff <= rhs {
                     Ok((abs_diff, rhs))
                 } else {
@@ -99,17 +114,18 @@ macro_rules! assert_in_epsilon_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_in_epsilon.html\n",
-                                "                   a label: `{}`,\n",
-                                "                   a debug: `{:?}`,\n",
-                                "                   b label: `{}`,\n",
-                                "                   b debug: `{:?}`,\n",
-                                "                   ε label: `{}`,\n",
-                                "                   ε debug: `{:?}`,\n",
-                                "                 | a - b |: `{:?}`,\n",
-                                "             ε * min(a, b): `{:?}`,\n",
-                                " | a - b | ≤ ε * min(a, b): {}",
+                                "{}macro.assert_in_epsilon.html\n",
+                                "               a label: `{}`,\n",
+                                "               a debug: `{:?}`,\n",
+                                "               b label: `{}`,\n",
+                                "               b debug: `{:?}`,\n",
+                                "               ε label: `{}`,\n",
+                                "               ε debug: `{:?}`,\n",
+                                "             | a - b |: `{:?}`,\n",
+                                "             ε * | b |: `{:?}`,\n",
+                                " | a - b | ≤ ε * | b |: {}",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             a,
                             stringify!($b),
@@ -132,44 +148,127 @@ mod tests {
 
     #[test]
     fn test_assert_in_epsilon_as_result_x_success() {
-        let a: i8 = 10;
-        let b: i8 = 20;
-        let epsilon: i8 = 1;
+        let a: f64 = 10.0;
+        let b: f64 = 20.0;
+        let epsilon: f64 = 0.5;
         let result = assert_in_epsilon_as_result!(a, b, epsilon);
-        assert_eq!(result.unwrap(), (10, 10));
+        assert_eq!(result.unwrap(), (10.0, 10.0));
     }
 
     #[test]
     fn test_assert_in_epsilon_as_result_x_failure() {
-        let a: i8 = 10;
-        let b: i8 = 30;
-        let epsilon: i8 = 1;
+        let a: f64 = 10.0;
+        let b: f64 = 30.0;
+        let epsilon: f64 = 0.5;
         let result = assert_in_epsilon_as_result!(a, b, epsilon);
         assert_eq!(
             result.unwrap_err(),
             concat!(
                 "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
                 "https://docs.rs/assertables/9.2.0/assertables/macro.assert_in_epsilon.html\n",
-                "                   a label: `a`,\n",
-                "                   a debug: `10`,\n",
-                "                   b label: `b`,\n",
-                "                   b debug: `30`,\n",
-                "                   ε label: `epsilon`,\n",
-                "                   ε debug: `1`,\n",
-                "                 | a - b |: `20`,\n",
-                "             ε * min(a, b): `10`,\n",
-                " | a - b | ≤ ε * min(a, b): false"
+                "               a label: `a`,\n",
+                "               a debug: `10.0`,\n",
+                "               b label: `b`,\n",
+                "               b debug: `30.0`,\n",
+                "               ε label: `epsilon`,\n",
+                "               ε debug: `0.5`,\n",
+                "             | a - b |: `20.0`,\n",
+                "             ε * | b |: `15.0`,\n",
+                " | a - b | ≤ ε * | b |: false"
             )
         );
     }
+
+    #[test]
+    fn success_with_integers() {
+        let a: i8 = 10;
+        let b: i8 = 20;
+        let epsilon: i8 = 1;
+        let result = assert_in_epsilon_as_result!(a, b, epsilon);
+        assert_eq!(result.unwrap(), (10, 20));
+    }
+
+    #[test]
+    fn success_with_unsigned_integers() {
+        let a: u8 = 30;
+        let b: u8 = 20;
+        let epsilon: u8 = 1;
+        let result = assert_in_epsilon_as_result!(a, b, epsilon);
+        assert_eq!(result.unwrap(), (10, 20));
+    }
+
+    #[test]
+    fn success_with_negative_b() {
+        let a: f64 = -10.0;
+        let b: f64 = -20.0;
+        let epsilon: f64 = 0.5;
+        let result = assert_in_epsilon_as_result!(a, b, epsilon);
+        assert_eq!(result.unwrap(), (10.0, 10.0));
+    }
+
+    #[test]
+    fn success_at_boundary() {
+        let a: f64 = 15.0;
+        let b: f64 = 10.0;
+        let epsilon: f64 = 0.5;
+        let result = assert_in_epsilon_as_result!(a, b, epsilon);
+        assert_eq!(result.unwrap(), (5.0, 5.0));
+    }
+
+    #[test]
+    fn failure_because_negative_b_and_opposite_sign() {
+        let a: f64 = 10.0;
+        let b: f64 = -10.0;
+        let epsilon: f64 = 0.5;
+        let result = assert_in_epsilon_as_result!(a, b, epsilon);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("             | a - b |: `20.0`,\n"));
+        assert!(actual.contains("             ε * | b |: `5.0`,\n"));
+    }
+
+    #[test]
+    fn failure_because_large_negative_b() {
+        let a: i32 = -1_000;
+        let b: i32 = -1_000_000;
+        let epsilon: i32 = 0;
+        let result = assert_in_epsilon_as_result!(a, b, epsilon);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn success_with_b_zero_and_a_zero() {
+        let a: f64 = 0.0;
+        let b: f64 = 0.0;
+        let epsilon: f64 = 0.5;
+        let result = assert_in_epsilon_as_result!(a, b, epsilon);
+        assert_eq!(result.unwrap(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn failure_because_b_zero_and_a_nonzero() {
+        let a: f64 = 0.001;
+        let b: f64 = 0.0;
+        let epsilon: f64 = 0.5;
+        let result = assert_in_epsilon_as_result!(a, b, epsilon);
+        assert!(result.unwrap_err().contains("             ε * | b |: `0.0`,\n"));
+    }
+
+    #[test]
+    fn failure_because_negative_epsilon() {
+        let a: f64 = 10.0;
+        let b: f64 = 10.0;
+        let epsilon: f64 = -0.5;
+        let result = assert_in_epsilon_as_result!(a, b, epsilon);
+        assert!(result.is_err());
+    }
 }
 
 /// Assert a number is within epsilon of another number.
 ///
 /// Pseudocode:<br>
-/// | a - b | ≤ ε * min(a, b)
+/// | a - b | ≤ ε * | b |
 ///
-/// * If true, return `(lhs, rhs)`.
+/// * If true, return `(| a - b |, ε * | b |)`.
 ///
 /// * Otherwise, call [`panic!`] with a message and the values of the
 ///   expressions with their debug representations.
@@ -181,42 +280,42 @@ mod tests {
 /// # use std::panic;
 ///
 /// # fn main() {
-/// let a: i8 = 10;
-/// let b: i8 = 20;
-/// let epsilon: i8 = 1;
+/// let a: f64 = 10.0;
+/// let b: f64 = 20.0;
+/// let epsilon: f64 = 0.5;
 /// assert_in_epsilon!(a, b, epsilon);
 ///
 /// # let result = panic::catch_unwind(|| {
 /// // This will panic
-/// let a: i8 = 10;
-/// let b: i8 = 30;
-/// let epsilon: i8 = 1;
+/// let a: f64 = 10.0;
+/// let b: f64 = 30.0;
+/// let epsilon: f64 = 0.5;
 /// assert_in_epsilon!(a, b, epsilon);
 /// # });
-/// // assertion failed: `assert_in_epsilon!(a, b, epsilon)`
+/// // assertion failed: `assert_in_epsilon!(a, b, ε)`
 /// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_in_epsilon.html
-/// //                    a label: `a`,
-/// //                    a debug: `10`,
-/// //                    b label: `b`,
-/// //                    b debug: `30`,
-/// //                    ε label: `epsilon`,
-/// //                    ε debug: `1`,
-/// //                  | a - b |: `20`,
-/// //              ε * min(a, b): `10`,\n",
-/// //  | a - b | ≤ ε * min(a, b): false"
+/// //                a label: `a`,
+/// //                a debug: `10.0`,
+/// //                b label: `b`,
+/// //                b debug: `30.0`,
+/// //                ε label: `epsilon`,
+/// //                ε debug: `0.5`,
+/// //              | a - b |: `20.0`,
+/// //              ε * | b |: `15.0`,
+/// //  | a - b | ≤ ε * | b |: false
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let expect = concat!(
 /// #     "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
 /// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_in_epsilon.html\n",
-/// #     "                   a label: `a`,\n",
-/// #     "                   a debug: `10`,\n",
-/// #     "                   b label: `b`,\n",
-/// #     "                   b debug: `30`,\n",
-/// #     "                   ε label: `epsilon`,\n",
-/// #     "                   ε debug: `1`,\n",
-/// #     "                 | a - b |: `20`,\n",
-/// #     "             ε * min(a, b): `10`,\n",
-/// #     " | a - b | ≤ ε * min(a, b): false"
+/// #     "               a label: `a`,\n",
+/// #     "               a debug: `10.0`,\n",
+/// #     "               b label: `b`,\n",
+/// #     "               b debug: `30.0`,\n",
+/// #     "               ε label: `epsilon`,\n",
+/// #     "               ε debug: `0.5`,\n",
+/// #     "             | a - b |: `20.0`,\n",
+/// #     "             ε * | b |: `15.0`,\n",
+/// #     " | a - b | ≤ ε * | b |: false"
 /// # );
 /// # assert_eq!(actual, expect);
 /// # }
@@ -265,7 +364,7 @@ macro_rules! assert_in_epsilon {
 /// Assert a number is within epsilon of another number.
 ///
 /// Pseudocode:<br>
-/// | a - b | ≤ ε * min(a, b)
+/// | a - b | ≤ ε * | b |
 ///
 /// This macro provides the same statements as [`assert_in_epsilon`](macro.assert_in_epsilon.html),
 /// except this macro's statements are only enabled in non-optimized
@@ -0,0 +1,234 @@
+//! Assert an iterable of floats has no NaN element.
+//!
+//! Pseudocode:<br>
+//! (a into iter).all(|x| !x.is_nan())
+//!
+//! This is useful as a guard on computation outputs, before other assertions.
+//! The check stops at the first NaN, and the failure message shows its index
+//! rather than the entire collection, so it stays fast and readable for large
+//! arrays. Infinity is not NaN, so it passes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: [f64; 3] = [1.0, 2.0, 3.0];
+//! assert_no_nan!(&a);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_no_nan`](macro@crate::assert_no_nan)
+//! * [`assert_no_nan_as_result`](macro@crate::assert_no_nan_as_result)
+//! * [`debug_assert_no_nan`](macro@crate::debug_assert_no_nan)
+
+/// Assert an iterable of floats has no NaN element.
+///
+/// Pseudocode:<br>
+/// (a into iter).all(|x| !x.is_nan())
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_no_nan`](macro.assert_no_nan.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_no_nan`](macro@crate::assert_no_nan)
+/// * [`assert_no_nan_as_result`](macro@crate::assert_no_nan_as_result)
+/// * [`debug_assert_no_nan`](macro@crate::debug_assert_no_nan)
+///
+#[macro_export]
+macro_rules! assert_no_nan_as_result {
+    ($a:expr $(,)?) => {{
+        match ($a) {
+            a => {
+                match a.into_iter().position(|x| x.is_nan()) {
+                    None => Ok(()),
+                    Some(index) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_no_nan!(a)`\n",
+                                    "{}macro.assert_no_nan.html\n",
+                                    "         a label: `{}`,\n",
+                                    " first NaN index: `{}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                index
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: [f64; 3] = [1.0, 2.0, 3.0];
+        let result = assert_no_nan_as_result!(&a);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_f32_vec() {
+        let a: Vec<f32> = vec![1.0, f32::INFINITY];
+        let result = assert_no_nan_as_result!(a);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_iterator_adapter() {
+        let v: Vec<f64> = vec![1.0, 2.0, 3.0];
+        let result = assert_no_nan_as_result!(v.iter().map(|x| x * 2.0));
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_with_iterator_adapter() {
+        let v: Vec<f64> = vec![1.0, f64::INFINITY, 3.0];
+        let result = assert_no_nan_as_result!(v.iter().map(|x| x * 0.0));
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_no_nan!(a)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_nan.html\n",
+                "         a label: `v.iter().map(|x| x * 0.0)`,\n",
+                " first NaN index: `1`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1.0, f64::NAN, 3.0, f64::NAN];
+        let result = assert_no_nan_as_result!(&a);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_no_nan!(a)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_nan.html\n",
+                "         a label: `&a`,\n",
+                " first NaN index: `1`"
+            )
+        );
+    }
+}
+
+/// Assert an iterable of floats has no NaN element.
+///
+/// Pseudocode:<br>
+/// (a into iter).all(|x| !x.is_nan())
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: [f64; 3] = [1.0, 2.0, 3.0];
+/// assert_no_nan!(&a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1.0, f64::NAN, 3.0, f64::NAN];
+/// assert_no_nan!(&a);
+/// # });
+/// // assertion failed: `assert_no_nan!(a)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_nan.html
+/// //          a label: `&a`,
+/// //  first NaN index: `1`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_no_nan!(a)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_nan.html\n",
+/// #     "         a label: `&a`,\n",
+/// #     " first NaN index: `1`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_no_nan`](macro@crate::assert_no_nan)
+/// * [`assert_no_nan_as_result`](macro@crate::assert_no_nan_as_result)
+/// * [`debug_assert_no_nan`](macro@crate::debug_assert_no_nan)
+///
+#[macro_export]
+macro_rules! assert_no_nan {
+    ($a:expr $(,)?) => {{
+        match $crate::assert_no_nan_as_result!($a) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $($message:tt)+) => {{
+        match $crate::assert_no_nan_as_result!($a) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an iterable of floats has no NaN element.
+///
+/// Pseudocode:<br>
+/// (a into iter).all(|x| !x.is_nan())
+///
+/// This macro provides the same statements as [`assert_no_nan`](macro.assert_no_nan.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_no_nan`](macro@crate::assert_no_nan)
+/// * [`assert_no_nan_as_result`](macro@crate::assert_no_nan_as_result)
+/// * [`debug_assert_no_nan`](macro@crate::debug_assert_no_nan)
+///
+#[macro_export]
+macro_rules! debug_assert_no_nan {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_no_nan!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,297 @@
+//! Assert each named field of a struct is within delta of the same field of another struct.
+//!
+//! Pseudocode:<br>
+//! [field1, field2, …].all(|field| | a.field - b.field | ≤ Δ)
+//!
+//! This is useful for a struct of numbers, such as a point or a vector in
+//! physics or geometry code, where each field may be very close to the
+//! expected field but not quite equal. The macro expands the field list into
+//! one check per field, so the struct needs no custom trait implementation.
+//!
+//! The failure message names the first field that diverges, and shows both
+//! values of that field.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! struct Point { x: f64, y: f64 }
+//! let a = Point { x: 1.0, y: 2.0 };
+//! let b = Point { x: 1.05, y: 1.95 };
+//! let delta: f64 = 0.1;
+//! assert_struct_in_delta!(a, b, delta, [x, y]);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_struct_in_delta`](macro@crate::assert_struct_in_delta)
+//! * [`assert_struct_in_delta_as_result`](macro@crate::assert_struct_in_delta_as_result)
+//! * [`debug_assert_struct_in_delta`](macro@crate::debug_assert_struct_in_delta)
+
+/// Assert each named field of a struct is within delta of the same field of another struct.
+///
+/// Pseudocode:<br>
+/// [field1, field2, …].all(|field| | a.field - b.field | ≤ Δ)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)` for the first field that
+///   diverges.
+///
+/// Each field can be any type that [`assert_in_delta`](macro.assert_in_delta.html)
+/// can compare, and the delta must be the same type as each field.
+///
+/// This macro provides the same statements as [`assert_struct_in_delta`](macro.assert_struct_in_delta.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_struct_in_delta`](macro@crate::assert_struct_in_delta)
+/// * [`assert_struct_in_delta_as_result`](macro@crate::assert_struct_in_delta_as_result)
+/// * [`debug_assert_struct_in_delta`](macro@crate::debug_assert_struct_in_delta)
+///
+#[macro_export]
+macro_rules! assert_struct_in_delta_as_result {
+    ($a:expr, $b:expr, $delta:expr, [$($field:ident),+ $(,)?] $(,)?) => {{
+        match (&$a, &$b, &$delta) {
+            (a, b, delta) => {
+                let mut result: Result<(), String> = Ok(());
+                $(
+                    if result.is_ok() {
+                        let (a_field, b_field) = (&a.$field, &b.$field);
+                        let abs_diff = if (a_field >= b_field) { a_field - b_field } else { b_field - a_field };
+                        let in_delta = abs_diff <= *delta;
+                        if !in_delta {
+                            result = Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_struct_in_delta!(a, b, Δ, [fields])`\n",
+                                        "{}macro.assert_struct_in_delta.html\n",
+                                        "                   a label: `{}`,\n",
+                                        "                   b label: `{}`,\n",
+                                        "                   Δ label: `{}`,\n",
+                                        "                   Δ debug: `{:?}`,\n",
+                                        "                     field: `{}`,\n",
+                                        "             a.field debug: `{:?}`,\n",
+                                        "             b.field debug: `{:?}`,\n",
+                                        "     | a.field - b.field |: `{:?}`,\n",
+                                        " | a.field - b.field | ≤ Δ: {}"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a),
+                                    stringify!($b),
+                                    stringify!($delta),
+                                    delta,
+                                    stringify!($field),
+                                    a_field,
+                                    b_field,
+                                    abs_diff,
+                                    false
+                                )
+                            );
+                        }
+                    }
+                )+
+                result
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[derive(Debug)]
+    struct Point {
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    #[test]
+    fn success() {
+        let a = Point { x: 1.0, y: 2.0, z: 3.0 };
+        let b = Point { x: 1.05, y: 1.95, z: 3.0 };
+        let result = assert_struct_in_delta_as_result!(a, b, 0.1, [x, y, z]);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_subset_of_fields() {
+        let a = Point { x: 1.0, y: 2.0, z: 3.0 };
+        let b = Point { x: 1.0, y: 2.0, z: 9.0 };
+        let result = assert_struct_in_delta_as_result!(a, b, 0.1, [x, y]);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_unsigned_fields() {
+        struct Size {
+            w: u8,
+            h: u8,
+        }
+        let a = Size { w: 10, h: 20 };
+        let b = Size { w: 11, h: 19 };
+        let result = assert_struct_in_delta_as_result!(a, b, 1, [w, h]);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_reports_first_divergent_field() {
+        let a = Point { x: 1.0, y: 2.0, z: 3.0 };
+        let b = Point { x: 1.0, y: 2.5, z: 4.0 };
+        let delta: f64 = 0.25;
+        let result = assert_struct_in_delta_as_result!(a, b, delta, [x, y, z]);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_struct_in_delta!(a, b, Δ, [fields])`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_struct_in_delta.html\n",
+                "                   a label: `a`,\n",
+                "                   b label: `b`,\n",
+                "                   Δ label: `delta`,\n",
+                "                   Δ debug: `0.25`,\n",
+                "                     field: `y`,\n",
+                "             a.field debug: `2.0`,\n",
+                "             b.field debug: `2.5`,\n",
+                "     | a.field - b.field |: `0.5`,\n",
+                " | a.field - b.field | ≤ Δ: false"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let a = Point { x: f64::NAN, y: 2.0, z: 3.0 };
+        let b = Point { x: 1.0, y: 2.0, z: 3.0 };
+        let result = assert_struct_in_delta_as_result!(a, b, 0.1, [x, y, z]);
+        assert!(result.unwrap_err().contains("                     field: `x`,\n"));
+    }
+}
+
+/// Assert each named field of a struct is within delta of the same field of another struct.
+///
+/// Pseudocode:<br>
+/// [field1, field2, …].all(|field| | a.field - b.field | ≤ Δ)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   first field that diverges.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// struct Point { x: f64, y: f64 }
+/// let a = Point { x: 1.0, y: 2.0 };
+/// let b = Point { x: 1.05, y: 1.95 };
+/// let delta: f64 = 0.1;
+/// assert_struct_in_delta!(a, b, delta, [x, y]);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = Point { x: 1.0, y: 2.0 };
+/// let b = Point { x: 1.0, y: 2.5 };
+/// let delta: f64 = 0.25;
+/// assert_struct_in_delta!(a, b, delta, [x, y]);
+/// # });
+/// // assertion failed: `assert_struct_in_delta!(a, b, Δ, [fields])`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_struct_in_delta.html
+/// //                    a label: `a`,
+/// //                    b label: `b`,
+/// //                    Δ label: `delta`,
+/// //                    Δ debug: `0.25`,
+/// //                      field: `y`,
+/// //              a.field debug: `2.0`,
+/// //              b.field debug: `2.5`,
+/// //      | a.field - b.field |: `0.5`,
+/// //  | a.field - b.field | ≤ Δ: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_struct_in_delta!(a, b, Δ, [fields])`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_struct_in_delta.html\n",
+/// #     "                   a label: `a`,\n",
+/// #     "                   b label: `b`,\n",
+/// #     "                   Δ label: `delta`,\n",
+/// #     "                   Δ debug: `0.25`,\n",
+/// #     "                     field: `y`,\n",
+/// #     "             a.field debug: `2.0`,\n",
+/// #     "             b.field debug: `2.5`,\n",
+/// #     "     | a.field - b.field |: `0.5`,\n",
+/// #     " | a.field - b.field | ≤ Δ: false"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_struct_in_delta`](macro@crate::assert_struct_in_delta)
+/// * [`assert_struct_in_delta_as_result`](macro@crate::assert_struct_in_delta_as_result)
+/// * [`debug_assert_struct_in_delta`](macro@crate::debug_assert_struct_in_delta)
+///
+#[macro_export]
+macro_rules! assert_struct_in_delta {
+    ($a:expr, $b:expr, $delta:expr, [$($field:ident),+ $(,)?] $(,)?) => {{
+        match $crate::assert_struct_in_delta_as_result!($a, $b, $delta, [$($field),+]) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $delta:expr, [$($field:ident),+ $(,)?], $($message:tt)+) => {{
+        match $crate::assert_struct_in_delta_as_result!($a, $b, $delta, [$($field),+]) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert each named field of a struct is within delta of the same field of another struct.
+///
+/// Pseudocode:<br>
+/// [field1, field2, …].all(|field| | a.field - b.field | ≤ Δ)
+///
+/// This macro provides the same statements as [`assert_struct_in_delta`](macro.assert_struct_in_delta.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_struct_in_delta`](macro@crate::assert_struct_in_delta)
+/// * [`assert_struct_in_delta_as_result`](macro@crate::assert_struct_in_delta_as_result)
+/// * [`debug_assert_struct_in_delta`](macro@crate::debug_assert_struct_in_delta)
+///
+#[macro_export]
+macro_rules! debug_assert_struct_in_delta {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_struct_in_delta!($($arg)*);
+        }
+    };
+}
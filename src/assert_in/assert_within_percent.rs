@@ -0,0 +1,331 @@
+//! Assert a number is within a percentage of another number.
+//!
+//! Pseudocode:<br>
+//! | a - b | ≤ | b | * pct / 100
+//!
+//! The percentage is relative to `b`, so `b` is the reference value, such as
+//! a baseline measurement. For example, 104 is within 5 percent of 100.
+//!
+//! When `b` is zero, any percentage of it is zero, so `a` must be zero too.
+//!
+//! The numbers are floating point, such as `f32` or `f64`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: f64 = 104.0;
+//! let b: f64 = 100.0;
+//! let pct: f64 = 5.0;
+//! assert_within_percent!(a, b, pct);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_within_percent`](macro@crate::assert_within_percent)
+//! * [`assert_within_percent_as_result`](macro@crate::assert_within_percent_as_result)
+//! * [`debug_assert_within_percent`](macro@crate::debug_assert_within_percent)
+
+/// Assert a number is within a percentage of another number.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ | b | * pct / 100
+///
+/// * If true, return Result `Ok(percent)`, where `percent` is the percentage
+///   difference `| a - b | / | b | * 100`. When `b` is zero, `a` must be
+///   zero too, so the difference is zero, and `percent` is zero.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_within_percent`](macro.assert_within_percent.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_within_percent`](macro@crate::assert_within_percent)
+/// * [`assert_within_percent_as_result`](macro@crate::assert_within_percent_as_result)
+/// * [`debug_assert_within_percent`](macro@crate::debug_assert_within_percent)
+///
+#[macro_export]
+macro_rules! assert_within_percent_as_result {
+    ($a:expr, $b:expr, $pct:expr $(,)?) => {{
+        match (&$a, &$b, &$pct) {
+            (a, b, pct) => {
+                let abs_diff = (*a - *b).abs();
+                if *b == 0.0 {
+                    if *a == 0.0 {
+                        Ok(0.0)
+                    } else {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_within_percent!(a, b, pct)`\n",
+                                    "{}macro.assert_within_percent.html\n",
+                                    "                       a label: `{}`,\n",
+                                    "                       a debug: `{:?}`,\n",
+                                    "                       b label: `{}`,\n",
+                                    "                       b debug: `{:?}`,\n",
+                                    "                     pct label: `{}`,\n",
+                                    "                     pct debug: `{:?}`,\n",
+                                    "                       outcome: `b is zero, so a must be zero`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b,
+                                stringify!($pct),
+                                pct
+                            )
+                        )
+                    }
+                } else {
+                    let percent = abs_diff / b.abs() * 100.0;
+                    if percent <= *pct {
+                        Ok(percent)
+                    } else {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_within_percent!(a, b, pct)`\n",
+                                    "{}macro.assert_within_percent.html\n",
+                                    "                       a label: `{}`,\n",
+                                    "                       a debug: `{:?}`,\n",
+                                    "                       b label: `{}`,\n",
+                                    "                       b debug: `{:?}`,\n",
+                                    "                     pct label: `{}`,\n",
+                                    "                     pct debug: `{:?}`,\n",
+                                    "                     | a - b |: `{:?}`,\n",
+                                    "       | a - b | / | b | * 100: `{:?}`,\n",
+                                    " | a - b | / | b | * 100 ≤ pct: false"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b,
+                                stringify!($pct),
+                                pct,
+                                abs_diff,
+                                percent
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: f64 = 104.0;
+        let b: f64 = 100.0;
+        let pct: f64 = 5.0;
+        let result = assert_within_percent_as_result!(a, b, pct);
+        assert_eq!(result.unwrap(), 4.0);
+    }
+
+    #[test]
+    fn success_with_negative_b() {
+        let a: f32 = -96.0;
+        let b: f32 = -100.0;
+        let pct: f32 = 5.0;
+        let result = assert_within_percent_as_result!(a, b, pct);
+        assert_eq!(result.unwrap(), 4.0);
+    }
+
+    #[test]
+    fn success_because_both_zero() {
+        let a: f64 = 0.0;
+        let b: f64 = 0.0;
+        let pct: f64 = 5.0;
+        let result = assert_within_percent_as_result!(a, b, pct);
+        assert_eq!(result.unwrap(), 0.0);
+    }
+
+    #[test]
+    fn success_because_both_zero_with_f32() {
+        let a: f32 = -0.0;
+        let b: f32 = 0.0;
+        let pct: f32 = 5.0;
+        let result = assert_within_percent_as_result!(a, b, pct);
+        assert_eq!(result.unwrap(), 0.0_f32);
+    }
+
+    #[test]
+    fn failure() {
+        let a: f64 = 112.0;
+        let b: f64 = 100.0;
+        let pct: f64 = 5.0;
+        let result = assert_within_percent_as_result!(a, b, pct);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_within_percent!(a, b, pct)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_within_percent.html\n",
+                "                       a label: `a`,\n",
+                "                       a debug: `112.0`,\n",
+                "                       b label: `b`,\n",
+                "                       b debug: `100.0`,\n",
+                "                     pct label: `pct`,\n",
+                "                     pct debug: `5.0`,\n",
+                "                     | a - b |: `12.0`,\n",
+                "       | a - b | / | b | * 100: `12.0`,\n",
+                " | a - b | / | b | * 100 ≤ pct: false"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_b_is_zero() {
+        let a: f64 = 1.0;
+        let b: f64 = 0.0;
+        let pct: f64 = 5.0;
+        let result = assert_within_percent_as_result!(a, b, pct);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_within_percent!(a, b, pct)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_within_percent.html\n",
+                "                       a label: `a`,\n",
+                "                       a debug: `1.0`,\n",
+                "                       b label: `b`,\n",
+                "                       b debug: `0.0`,\n",
+                "                     pct label: `pct`,\n",
+                "                     pct debug: `5.0`,\n",
+                "                       outcome: `b is zero, so a must be zero`"
+            )
+        );
+    }
+}
+
+/// Assert a number is within a percentage of another number.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ | b | * pct / 100
+///
+/// * If true, return `percent`, which is the percentage difference
+///   `| a - b | / | b | * 100`, or zero when `a` and `b` are both zero.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: f64 = 104.0;
+/// let b: f64 = 100.0;
+/// let pct: f64 = 5.0;
+/// assert_within_percent!(a, b, pct);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: f64 = 112.0;
+/// let b: f64 = 100.0;
+/// let pct: f64 = 5.0;
+/// assert_within_percent!(a, b, pct);
+/// # });
+/// // assertion failed: `assert_within_percent!(a, b, pct)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_within_percent.html
+/// //                        a label: `a`,
+/// //                        a debug: `112.0`,
+/// //                        b label: `b`,
+/// //                        b debug: `100.0`,
+/// //                      pct label: `pct`,
+/// //                      pct debug: `5.0`,
+/// //                      | a - b |: `12.0`,
+/// //        | a - b | / | b | * 100: `12.0`,
+/// //  | a - b | / | b | * 100 ≤ pct: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_within_percent!(a, b, pct)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_within_percent.html\n",
+/// #     "                       a label: `a`,\n",
+/// #     "                       a debug: `112.0`,\n",
+/// #     "                       b label: `b`,\n",
+/// #     "                       b debug: `100.0`,\n",
+/// #     "                     pct label: `pct`,\n",
+/// #     "                     pct debug: `5.0`,\n",
+/// #     "                     | a - b |: `12.0`,\n",
+/// #     "       | a - b | / | b | * 100: `12.0`,\n",
+/// #     " | a - b | / | b | * 100 ≤ pct: false"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_within_percent`](macro@crate::assert_within_percent)
+/// * [`assert_within_percent_as_result`](macro@crate::assert_within_percent_as_result)
+/// * [`debug_assert_within_percent`](macro@crate::debug_assert_within_percent)
+///
+#[macro_export]
+macro_rules! assert_within_percent {
+    ($a:expr, $b:expr, $pct:expr $(,)?) => {{
+        match $crate::assert_within_percent_as_result!($a, $b, $pct) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $pct:expr, $($message:tt)+) => {{
+        match $crate::assert_within_percent_as_result!($a, $b, $pct) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a number is within a percentage of another number.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ | b | * pct / 100
+///
+/// This macro provides the same statements as [`assert_within_percent`](macro.assert_within_percent.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_within_percent`](macro@crate::assert_within_percent)
+/// * [`assert_within_percent_as_result`](macro@crate::assert_within_percent_as_result)
+/// * [`debug_assert_within_percent`](macro@crate::debug_assert_within_percent)
+///
+#[macro_export]
+macro_rules! debug_assert_within_percent {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_within_percent!($($arg)*);
+        }
+    };
+}
@@ -5,7 +5,25 @@
 //!
 //! * [`assert_in_delta!(a, b, delta)`](macro@crate::assert_in_delta) ≈ | a - b | ≤ Δ
 //!
-//! * [`assert_in_epsilon!(a, b, epsilon)`](macro@crate::assert_in_epsilon) ≈ | a - b | ≤ ε * min(a, b)
+//! * [`assert_in_delta_verbose!(a, b, delta)`](macro@crate::assert_in_delta_verbose) ≈ | a - b | ≤ Δ, with the difference in scientific notation and as a percentage
+//!
+//! * [`assert_struct_in_delta!(a, b, delta, [fields])`](macro@crate::assert_struct_in_delta) ≈ each field: | a.field - b.field | ≤ Δ
+//!
+//! * [`assert_in_epsilon!(a, b, epsilon)`](macro@crate::assert_in_epsilon) ≈ | a - b | ≤ ε * | b |
+//!
+//! * [`assert_approx_eq_slice_rel!(a, b, rel_tol)`](macro@crate::assert_approx_eq_slice_rel) ≈ each i: | a[i] - b[i] | ≤ rel_tol * max(| a[i] |, | b[i] |)
+//!
+//! * [`assert_within_percent!(a, b, pct)`](macro@crate::assert_within_percent) ≈ | a - b | ≤ | b | * pct / 100
+//!
+//! * [`assert_f64_eq!(a, b)`](macro@crate::assert_f64_eq) ≈ | a - b | ≤ f64::EPSILON * max(| a |, | b |, 1.0)
+//!
+//! * [`assert_f64_eq!(a, b, ε)`](macro@crate::assert_f64_eq) ≈ | a - b | ≤ ε * max(| a |, | b |, 1.0)
+//!
+//! * [`assert_f32_eq!(a, b)`](macro@crate::assert_f32_eq) ≈ | a - b | ≤ f32::EPSILON * max(| a |, | b |, 1.0)
+//!
+//! * [`assert_f32_eq!(a, b, ε)`](macro@crate::assert_f32_eq) ≈ | a - b | ≤ ε * max(| a |, | b |, 1.0)
+//!
+//! * [`assert_no_nan!(a)`](macro@crate::assert_no_nan) ≈ (a into iter).all(|x| !x.is_nan())
 //!
 //! # Example
 //!
@@ -20,5 +38,56 @@
 //! # }
 //! ```
 
+pub mod assert_approx_eq_slice_rel;
+pub mod assert_f32_eq;
+pub mod assert_f64_eq;
 pub mod assert_in_delta;
+pub mod assert_in_delta_verbose;
 pub mod assert_in_epsilon;
+pub mod assert_no_nan;
+pub mod assert_struct_in_delta;
+pub mod assert_within_percent;
+
+/// Convert a primitive number to `f64`, for a message that shows scientific
+/// notation and a percentage, whatever the number type is.
+#[doc(hidden)]
+pub trait ToF64: Copy {
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty),*) => {
+        $(
+            impl ToF64 for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Format a number in scientific notation, such as `1e2` for 100.
+#[doc(hidden)]
+pub fn scientific<T: ToF64>(x: T) -> String {
+    format!("{:e}", x.to_f64())
+}
+
+/// Format a difference as a percentage of the larger magnitude of `a` and `b`.
+///
+/// A tiny percentage uses scientific notation, so it does not round to zero.
+#[doc(hidden)]
+pub fn percent_of_larger<T: ToF64>(diff: T, a: T, b: T) -> String {
+    let larger = a.to_f64().abs().max(b.to_f64().abs());
+    if larger == 0.0 {
+        return String::from("undefined, because a and b are 0");
+    }
+    let percent = diff.to_f64() / larger * 100.0;
+    if percent.abs() >= 0.01 {
+        format!("{:.3}%", percent)
+    } else {
+        format!("{:.3e}%", percent)
+    }
+}
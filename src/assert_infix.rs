@@ -99,13 +99,14 @@ macro_rules! assert_infix_as_result {
                 format!(
                     concat!(
                         "assertion failed: `assert_infix!(a {} b)`\n",
-                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_infix.html\n",
+                        "{}macro.assert_infix.html\n",
                         " a label: `{}`,\n",
                         " a debug: `{:?}`,\n",
                         " b label: `{}`,\n",
                         " b debug: `{:?}`",
                     ),
                     stringify!($infix),
+                    $crate::DOC_URL_BASE,
                     stringify!($a),
                     $a,
                     stringify!($b),
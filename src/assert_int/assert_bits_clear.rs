@@ -0,0 +1,240 @@
+//! Assert all the bits of a mask are clear in a value.
+//!
+//! Pseudocode:<br>
+//! value & mask = 0
+//!
+//! None of the mask bits may be set. To check that all of the mask bits
+//! are set, use [`assert_bits_set`](macro@crate::assert_bits_set).
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let value: u8 = 0b10110000;
+//! let mask: u8 = 0b00000110;
+//! assert_bits_clear!(value, mask);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_bits_clear`](macro@crate::assert_bits_clear)
+//! * [`assert_bits_clear_as_result`](macro@crate::assert_bits_clear_as_result)
+//! * [`debug_assert_bits_clear`](macro@crate::debug_assert_bits_clear)
+
+/// Assert all the bits of a mask are clear in a value.
+///
+/// Pseudocode:<br>
+/// value & mask = 0
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The value and the mask can be any signed or unsigned integer type.
+///
+/// This macro provides the same statements as [`assert_bits_clear`](macro.assert_bits_clear.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_bits_clear`](macro@crate::assert_bits_clear)
+/// * [`assert_bits_clear_as_result`](macro@crate::assert_bits_clear_as_result)
+/// * [`debug_assert_bits_clear`](macro@crate::debug_assert_bits_clear)
+///
+#[macro_export]
+macro_rules! assert_bits_clear_as_result {
+    ($value:expr, $mask:expr $(,)?) => {{
+        match (&$value, &$mask) {
+            (value, mask) => {
+                if *value & *mask == 0 {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_bits_clear!(value, mask)`\n",
+                                "{}macro.assert_bits_clear.html\n",
+                                "  value label: `{}`,\n",
+                                "  value debug: `{:?}`,\n",
+                                "   mask label: `{}`,\n",
+                                "   mask debug: `{:?}`,\n",
+                                " value binary: `{:#b}`,\n",
+                                "  mask binary: `{:#b}`,\n",
+                                "     set bits: `{:#b}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($value),
+                            value,
+                            stringify!($mask),
+                            mask,
+                            value,
+                            mask,
+                            *value & *mask
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let value: u8 = 0b10110000;
+        let mask: u8 = 0b00000110;
+        let result = assert_bits_clear_as_result!(value, mask);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_u128() {
+        let value: u128 = 1 << 100;
+        let mask: u128 = 1 << 99;
+        let result = assert_bits_clear_as_result!(value, mask);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let value: u8 = 0b10110010;
+        let mask: u8 = 0b00000110;
+        let result = assert_bits_clear_as_result!(value, mask);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_bits_clear!(value, mask)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_bits_clear.html\n",
+                "  value label: `value`,\n",
+                "  value debug: `178`,\n",
+                "   mask label: `mask`,\n",
+                "   mask debug: `6`,\n",
+                " value binary: `0b10110010`,\n",
+                "  mask binary: `0b110`,\n",
+                "     set bits: `0b10`"
+            )
+        );
+    }
+}
+
+/// Assert all the bits of a mask are clear in a value.
+///
+/// Pseudocode:<br>
+/// value & mask = 0
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let value: u8 = 0b10110000;
+/// let mask: u8 = 0b00000110;
+/// assert_bits_clear!(value, mask);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let value: u8 = 0b10110010;
+/// let mask: u8 = 0b00000110;
+/// assert_bits_clear!(value, mask);
+/// # });
+/// // assertion failed: `assert_bits_clear!(value, mask)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_bits_clear.html
+/// //   value label: `value`,
+/// //   value debug: `178`,
+/// //    mask label: `mask`,
+/// //    mask debug: `6`,
+/// //  value binary: `0b10110010`,
+/// //   mask binary: `0b110`,
+/// //      set bits: `0b10`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_bits_clear!(value, mask)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_bits_clear.html\n",
+/// #     "  value label: `value`,\n",
+/// #     "  value debug: `178`,\n",
+/// #     "   mask label: `mask`,\n",
+/// #     "   mask debug: `6`,\n",
+/// #     " value binary: `0b10110010`,\n",
+/// #     "  mask binary: `0b110`,\n",
+/// #     "     set bits: `0b10`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_bits_clear`](macro@crate::assert_bits_clear)
+/// * [`assert_bits_clear_as_result`](macro@crate::assert_bits_clear_as_result)
+/// * [`debug_assert_bits_clear`](macro@crate::debug_assert_bits_clear)
+///
+#[macro_export]
+macro_rules! assert_bits_clear {
+    ($value:expr, $mask:expr $(,)?) => {{
+        match $crate::assert_bits_clear_as_result!($value, $mask) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($value:expr, $mask:expr, $($message:tt)+) => {{
+        match $crate::assert_bits_clear_as_result!($value, $mask) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert all the bits of a mask are clear in a value.
+///
+/// Pseudocode:<br>
+/// value & mask = 0
+///
+/// This macro provides the same statements as [`assert_bits_clear`](macro.assert_bits_clear.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_bits_clear`](macro@crate::assert_bits_clear)
+/// * [`assert_bits_clear_as_result`](macro@crate::assert_bits_clear_as_result)
+/// * [`debug_assert_bits_clear`](macro@crate::debug_assert_bits_clear)
+///
+#[macro_export]
+macro_rules! debug_assert_bits_clear {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_bits_clear!($($arg)*);
+        }
+    };
+}
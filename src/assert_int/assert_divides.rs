@@ -0,0 +1,278 @@
+//! Assert a number divides another number.
+//!
+//! Pseudocode:<br>
+//! b % a = 0
+//!
+//! When `a` is zero, the macro does not panic with a division by zero.
+//! Instead, the assertion fails, and the message says `division by zero`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = 64;
+//! let b = 4096;
+//! assert_divides!(a, b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_divides`](macro@crate::assert_divides)
+//! * [`assert_divides_as_result`](macro@crate::assert_divides_as_result)
+//! * [`debug_assert_divides`](macro@crate::debug_assert_divides)
+
+/// Assert a number divides another number.
+///
+/// Pseudocode:<br>
+/// b % a = 0
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The numbers can be any signed or unsigned integer type.
+///
+/// This macro provides the same statements as [`assert_divides`](macro.assert_divides.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_divides`](macro@crate::assert_divides)
+/// * [`assert_divides_as_result`](macro@crate::assert_divides_as_result)
+/// * [`debug_assert_divides`](macro@crate::debug_assert_divides)
+///
+#[macro_export]
+macro_rules! assert_divides_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                match $crate::assert_int::AssertInt::int_rem_of(*a, *b) {
+                    Some(rem) if $crate::assert_int::AssertInt::int_is_zero(rem) => Ok(()),
+                    Some(rem) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_divides!(a, b)`\n",
+                                    "{}macro.assert_divides.html\n",
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " b label: `{}`,\n",
+                                    " b debug: `{:?}`,\n",
+                                    "   b % a: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b,
+                                rem
+                            )
+                        )
+                    },
+                    None => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_divides!(a, b)`\n",
+                                    "{}macro.assert_divides.html\n",
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " b label: `{}`,\n",
+                                    " b debug: `{:?}`,\n",
+                                    "   b % a: `division by zero`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = 64;
+        let b = 4096;
+        let result = assert_divides_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_negative() {
+        let a: i32 = -3;
+        let b: i32 = 9;
+        let result = assert_divides_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_min_and_minus_one() {
+        let a: i8 = -1;
+        let b: i8 = i8::MIN;
+        let result = assert_divides_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let a = 3;
+        let b = 10;
+        let result = assert_divides_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_divides!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_divides.html\n",
+                " a label: `a`,\n",
+                " a debug: `3`,\n",
+                " b label: `b`,\n",
+                " b debug: `10`,\n",
+                "   b % a: `1`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_division_by_zero() {
+        let a: u32 = 0;
+        let b: u32 = 10;
+        let result = assert_divides_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_divides!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_divides.html\n",
+                " a label: `a`,\n",
+                " a debug: `0`,\n",
+                " b label: `b`,\n",
+                " b debug: `10`,\n",
+                "   b % a: `division by zero`"
+            )
+        );
+    }
+}
+
+/// Assert a number divides another number.
+///
+/// Pseudocode:<br>
+/// b % a = 0
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = 64;
+/// let b = 4096;
+/// assert_divides!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = 3;
+/// let b = 10;
+/// assert_divides!(a, b);
+/// # });
+/// // assertion failed: `assert_divides!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_divides.html
+/// //  a label: `a`,
+/// //  a debug: `3`,
+/// //  b label: `b`,
+/// //  b debug: `10`,
+/// //    b % a: `1`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_divides!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_divides.html\n",
+/// #     " a label: `a`,\n",
+/// #     " a debug: `3`,\n",
+/// #     " b label: `b`,\n",
+/// #     " b debug: `10`,\n",
+/// #     "   b % a: `1`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_divides`](macro@crate::assert_divides)
+/// * [`assert_divides_as_result`](macro@crate::assert_divides_as_result)
+/// * [`debug_assert_divides`](macro@crate::debug_assert_divides)
+///
+#[macro_export]
+macro_rules! assert_divides {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_divides_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_divides_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a number divides another number.
+///
+/// Pseudocode:<br>
+/// b % a = 0
+///
+/// This macro provides the same statements as [`assert_divides`](macro.assert_divides.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_divides`](macro@crate::assert_divides)
+/// * [`assert_divides_as_result`](macro@crate::assert_divides_as_result)
+/// * [`debug_assert_divides`](macro@crate::debug_assert_divides)
+///
+#[macro_export]
+macro_rules! debug_assert_divides {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_divides!($($arg)*);
+        }
+    };
+}
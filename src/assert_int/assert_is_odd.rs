@@ -0,0 +1,210 @@
+//! Assert a number is odd.
+//!
+//! Pseudocode:<br>
+//! n % 2 ≠ 0
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let n = 3;
+//! assert_is_odd!(n);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_is_odd`](macro@crate::assert_is_odd)
+//! * [`assert_is_odd_as_result`](macro@crate::assert_is_odd_as_result)
+//! * [`debug_assert_is_odd`](macro@crate::debug_assert_is_odd)
+
+/// Assert a number is odd.
+///
+/// Pseudocode:<br>
+/// n % 2 ≠ 0
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The number can be any signed or unsigned integer type.
+///
+/// This macro provides the same statements as [`assert_is_odd`](macro.assert_is_odd.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_is_odd`](macro@crate::assert_is_odd)
+/// * [`assert_is_odd_as_result`](macro@crate::assert_is_odd_as_result)
+/// * [`debug_assert_is_odd`](macro@crate::debug_assert_is_odd)
+///
+#[macro_export]
+macro_rules! assert_is_odd_as_result {
+    ($n:expr $(,)?) => {{
+        match (&$n) {
+            n => {
+                if !$crate::assert_int::AssertInt::int_is_even(*n) {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_is_odd!(n)`\n",
+                                "{}macro.assert_is_odd.html\n",
+                                "n label: `{}`,\n",
+                                "n debug: `{:?}`,\n",
+                                " is odd: false"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($n),
+                            n
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let n = 3;
+        let result = assert_is_odd_as_result!(n);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_negative() {
+        let n: i8 = -3;
+        let result = assert_is_odd_as_result!(n);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let n = 4;
+        let result = assert_is_odd_as_result!(n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_is_odd!(n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_odd.html\n",
+                "n label: `n`,\n",
+                "n debug: `4`,\n",
+                " is odd: false"
+            )
+        );
+    }
+}
+
+/// Assert a number is odd.
+///
+/// Pseudocode:<br>
+/// n % 2 ≠ 0
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let n = 3;
+/// assert_is_odd!(n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let n = 4;
+/// assert_is_odd!(n);
+/// # });
+/// // assertion failed: `assert_is_odd!(n)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_odd.html
+/// // n label: `n`,
+/// // n debug: `4`,
+/// //  is odd: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_is_odd!(n)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_odd.html\n",
+/// #     "n label: `n`,\n",
+/// #     "n debug: `4`,\n",
+/// #     " is odd: false"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_is_odd`](macro@crate::assert_is_odd)
+/// * [`assert_is_odd_as_result`](macro@crate::assert_is_odd_as_result)
+/// * [`debug_assert_is_odd`](macro@crate::debug_assert_is_odd)
+///
+#[macro_export]
+macro_rules! assert_is_odd {
+    ($n:expr $(,)?) => {{
+        match $crate::assert_is_odd_as_result!($n) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($n:expr, $($message:tt)+) => {{
+        match $crate::assert_is_odd_as_result!($n) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a number is odd.
+///
+/// Pseudocode:<br>
+/// n % 2 ≠ 0
+///
+/// This macro provides the same statements as [`assert_is_odd`](macro.assert_is_odd.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_is_odd`](macro@crate::assert_is_odd)
+/// * [`assert_is_odd_as_result`](macro@crate::assert_is_odd_as_result)
+/// * [`debug_assert_is_odd`](macro@crate::debug_assert_is_odd)
+///
+#[macro_export]
+macro_rules! debug_assert_is_odd {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_is_odd!($($arg)*);
+        }
+    };
+}
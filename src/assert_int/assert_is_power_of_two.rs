@@ -0,0 +1,234 @@
+//! Assert a number is a power of two.
+//!
+//! Pseudocode:<br>
+//! n is a power of two
+//!
+//! A power of two is positive, so zero and negative numbers fail.
+//! This is useful for checking buffer sizes and alignments.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let n = 64;
+//! assert_is_power_of_two!(n);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_is_power_of_two`](macro@crate::assert_is_power_of_two)
+//! * [`assert_is_power_of_two_as_result`](macro@crate::assert_is_power_of_two_as_result)
+//! * [`debug_assert_is_power_of_two`](macro@crate::debug_assert_is_power_of_two)
+
+/// Assert a number is a power of two.
+///
+/// Pseudocode:<br>
+/// n is a power of two
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The number can be any signed or unsigned integer type.
+///
+/// This macro provides the same statements as [`assert_is_power_of_two`](macro.assert_is_power_of_two.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_is_power_of_two`](macro@crate::assert_is_power_of_two)
+/// * [`assert_is_power_of_two_as_result`](macro@crate::assert_is_power_of_two_as_result)
+/// * [`debug_assert_is_power_of_two`](macro@crate::debug_assert_is_power_of_two)
+///
+#[macro_export]
+macro_rules! assert_is_power_of_two_as_result {
+    ($n:expr $(,)?) => {{
+        match (&$n) {
+            n => {
+                if $crate::assert_int::AssertInt::int_is_power_of_two(*n) {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_is_power_of_two!(n)`\n",
+                                "{}macro.assert_is_power_of_two.html\n",
+                                "         n label: `{}`,\n",
+                                "         n debug: `{:?}`,\n",
+                                " is power of two: false"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($n),
+                            n
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let n = 64;
+        let result = assert_is_power_of_two_as_result!(n);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_one() {
+        let n: u8 = 1;
+        let result = assert_is_power_of_two_as_result!(n);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_signed() {
+        let n: i64 = 1 << 40;
+        let result = assert_is_power_of_two_as_result!(n);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_because_zero() {
+        let n: usize = 0;
+        let result = assert_is_power_of_two_as_result!(n);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn failure_because_negative() {
+        let n: i32 = -4;
+        let result = assert_is_power_of_two_as_result!(n);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn failure() {
+        let n = 48;
+        let result = assert_is_power_of_two_as_result!(n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_is_power_of_two!(n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_power_of_two.html\n",
+                "         n label: `n`,\n",
+                "         n debug: `48`,\n",
+                " is power of two: false"
+            )
+        );
+    }
+}
+
+/// Assert a number is a power of two.
+///
+/// Pseudocode:<br>
+/// n is a power of two
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let n = 64;
+/// assert_is_power_of_two!(n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let n = 48;
+/// assert_is_power_of_two!(n);
+/// # });
+/// // assertion failed: `assert_is_power_of_two!(n)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_power_of_two.html
+/// //          n label: `n`,
+/// //          n debug: `48`,
+/// //  is power of two: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_is_power_of_two!(n)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_power_of_two.html\n",
+/// #     "         n label: `n`,\n",
+/// #     "         n debug: `48`,\n",
+/// #     " is power of two: false"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_is_power_of_two`](macro@crate::assert_is_power_of_two)
+/// * [`assert_is_power_of_two_as_result`](macro@crate::assert_is_power_of_two_as_result)
+/// * [`debug_assert_is_power_of_two`](macro@crate::debug_assert_is_power_of_two)
+///
+#[macro_export]
+macro_rules! assert_is_power_of_two {
+    ($n:expr $(,)?) => {{
+        match $crate::assert_is_power_of_two_as_result!($n) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($n:expr, $($message:tt)+) => {{
+        match $crate::assert_is_power_of_two_as_result!($n) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a number is a power of two.
+///
+/// Pseudocode:<br>
+/// n is a power of two
+///
+/// This macro provides the same statements as [`assert_is_power_of_two`](macro.assert_is_power_of_two.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_is_power_of_two`](macro@crate::assert_is_power_of_two)
+/// * [`assert_is_power_of_two_as_result`](macro@crate::assert_is_power_of_two_as_result)
+/// * [`debug_assert_is_power_of_two`](macro@crate::debug_assert_is_power_of_two)
+///
+#[macro_export]
+macro_rules! debug_assert_is_power_of_two {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_is_power_of_two!($($arg)*);
+        }
+    };
+}
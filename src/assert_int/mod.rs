@@ -0,0 +1,89 @@
+//! Assert for integer properties.
+//!
+//! These macros help with recurrent checks in algorithm tests, such as
+//! buffer sizes, alignments, and flags. They support the signed and unsigned
+//! integer types, from `i8` and `u8` to `i128`, `u128`, `isize`, and `usize`.
+//!
+//! * [`assert_is_power_of_two!(n)`](macro@crate::assert_is_power_of_two) ≈ n is a power of two
+//! * [`assert_is_even!(n)`](macro@crate::assert_is_even) ≈ n % 2 = 0
+//! * [`assert_is_odd!(n)`](macro@crate::assert_is_odd) ≈ n % 2 ≠ 0
+//! * [`assert_divides!(a, b)`](macro@crate::assert_divides) ≈ b % a = 0
+//!
+//! Bits:
+//!
+//! * [`assert_bits_set!(value, mask)`](macro@crate::assert_bits_set) ≈ value & mask = mask
+//! * [`assert_bits_clear!(value, mask)`](macro@crate::assert_bits_clear) ≈ value & mask = 0
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let n: usize = 4096;
+//! assert_is_power_of_two!(n);
+//! assert_divides!(64, n);
+//!
+//! let flags: u8 = 0b0000_0101;
+//! assert_bits_set!(flags, 0b0000_0001);
+//! assert_bits_clear!(flags, 0b0000_0010);
+//! # }
+//! ```
+
+pub mod assert_bits_clear;
+pub mod assert_bits_set;
+pub mod assert_divides;
+pub mod assert_is_even;
+pub mod assert_is_odd;
+pub mod assert_is_power_of_two;
+
+/// Integer properties for the macros of this module.
+///
+/// This trait has an implementation for each integer type, so the macros
+/// work for signed types, such as `i32`, which do not have the method
+/// `is_power_of_two`.
+#[doc(hidden)]
+pub trait AssertInt: Copy {
+    /// Is this a power of two, which is positive?
+    fn int_is_power_of_two(self) -> bool;
+
+    /// Is this even?
+    fn int_is_even(self) -> bool;
+
+    /// Return the remainder of `b` divided by this, or `None` for zero.
+    fn int_rem_of(self, b: Self) -> Option<Self>;
+
+    /// Is this zero?
+    fn int_is_zero(self) -> bool;
+}
+
+macro_rules! impl_assert_int {
+    ($($t:ty),*) => {
+        $(
+            impl AssertInt for $t {
+                fn int_is_power_of_two(self) -> bool {
+                    self > 0 && self & (self - 1) == 0
+                }
+
+                fn int_is_even(self) -> bool {
+                    self % 2 == 0
+                }
+
+                fn int_rem_of(self, b: Self) -> Option<Self> {
+                    if self == 0 {
+                        None
+                    } else {
+                        // The only overflow is MIN % -1, which is 0.
+                        Some(b.checked_rem(self).unwrap_or(0))
+                    }
+                }
+
+                fn int_is_zero(self) -> bool {
+                    self == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_assert_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
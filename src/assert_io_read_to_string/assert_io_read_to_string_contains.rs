@@ -58,13 +58,14 @@ macro_rules! assert_io_read_to_string_contains_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_io_read_to_string_contains!(reader, &containee)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_contains.html\n",
+                                        "{}macro.assert_io_read_to_string_contains.html\n",
                                         "    reader label: `{}`,\n",
                                         "    reader debug: `{:?}`,\n",
                                         " containee label: `{}`,\n",
                                         " containee debug: `{:?}`,\n",
                                         "          string: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($reader),
                                     $reader,
                                     stringify!($containee),
@@ -79,13 +80,14 @@ macro_rules! assert_io_read_to_string_contains_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_io_read_to_string_contains!(reader, &containee)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_contains.html\n",
+                                    "{}macro.assert_io_read_to_string_contains.html\n",
                                     "    reader label: `{}`,\n",
                                     "    reader debug: `{:?}`,\n",
                                     " containee label: `{}`,\n",
                                     " containee debug: `{:?}`,\n",
                                     "             err: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($reader),
                                 $reader,
                                 stringify!($containee),
@@ -60,7 +60,7 @@ macro_rules! assert_io_read_to_string_gt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_io_read_to_string_gt!(a_reader, b_reader)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_gt.html\n",
+                                "{}macro.assert_io_read_to_string_gt.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -68,6 +68,7 @@ macro_rules! assert_io_read_to_string_gt_as_result {
                                 "       a: `{:?}`,\n",
                                 "       b: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_reader),
                             $a_reader,
                             stringify!($b_reader),
@@ -84,7 +85,7 @@ macro_rules! assert_io_read_to_string_gt_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_io_read_to_string_gt!(a_reader, b_reader)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_gt.html\n",
+                            "{}macro.assert_io_read_to_string_gt.html\n",
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             "  b label: `{}`,\n",
@@ -92,6 +93,7 @@ macro_rules! assert_io_read_to_string_gt_as_result {
                             " a result: `{:?}`,\n",
                             " b result: `{:?}`"
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a_reader),
                         $a_reader,
                         stringify!($b_reader),
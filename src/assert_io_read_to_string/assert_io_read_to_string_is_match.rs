@@ -59,7 +59,7 @@ macro_rules! assert_io_read_to_string_is_match_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_io_read_to_string_is_match!(a_reader, &matcher)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_is_match.html\n",
+                                        "{}macro.assert_io_read_to_string_is_match.html\n",
                                         "  reader label: `{}`,\n",
                                         "  reader debug: `{:?}`,\n",
                                         " matcher label: `{}`,\n",
@@ -67,6 +67,7 @@ macro_rules! assert_io_read_to_string_is_match_as_result {
                                         "   reader size: `{:?}`,\n",
                                         " reader string: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($reader),
                                     $reader,
                                     stringify!($matcher),
@@ -82,13 +83,14 @@ macro_rules! assert_io_read_to_string_is_match_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_io_read_to_string_is_match!(a_reader, &matcher)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_is_match.html\n",
+                                    "{}macro.assert_io_read_to_string_is_match.html\n",
                                     "  reader label: `{}`,\n",
                                     "  reader debug: `{:?}`,\n",
                                     " matcher label: `{}`,\n",
                                     " matcher debug: `{:?}`,\n",
                                     "           err: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($reader),
                                 $reader,
                                 stringify!($matcher),
@@ -0,0 +1,292 @@
+//! Assert a ::std::io::Read read_to_string() is not a match to a regex.
+//!
+//! Pseudocode:<br>
+//! (reader.read_to_string(a_string) ⇒ a_string) does not match matcher
+//!
+//! This is useful to assert a stream never contains a forbidden pattern,
+//! such as a password in a log. The failure message shows the first match,
+//! with its byte range, and the whole string.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::io::Read;
+//! use regex::Regex;
+//!
+//! # fn main() {
+//! let mut reader = "hello".as_bytes();
+//! let matcher = Regex::new(r"zz").unwrap();
+//! assert_io_read_to_string_is_not_match!(reader, &matcher);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_io_read_to_string_is_not_match`](macro@crate::assert_io_read_to_string_is_not_match)
+//! * [`assert_io_read_to_string_is_not_match_as_result`](macro@crate::assert_io_read_to_string_is_not_match_as_result)
+//! * [`debug_assert_io_read_to_string_is_not_match`](macro@crate::debug_assert_io_read_to_string_is_not_match)
+
+/// Assert a ::std::io::Read read_to_string() is not a match to a regex.
+///
+/// Pseudocode:<br>
+/// (reader.read_to_string(a_string) ⇒ a_string) does not match matcher
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)` with the first match.
+///
+/// The matcher must provide `find`, such as `regex::Regex`.
+///
+/// This macro provides the same statements as [`assert_io_read_to_string_is_not_match`](macro.assert_io_read_to_string_is_not_match.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_io_read_to_string_is_not_match`](macro@crate::assert_io_read_to_string_is_not_match)
+/// * [`assert_io_read_to_string_is_not_match_as_result`](macro@crate::assert_io_read_to_string_is_not_match_as_result)
+/// * [`debug_assert_io_read_to_string_is_not_match`](macro@crate::debug_assert_io_read_to_string_is_not_match)
+///
+#[macro_export]
+macro_rules! assert_io_read_to_string_is_not_match_as_result {
+    ($reader:expr, $matcher:expr $(,)?) => {{
+        match (&mut $reader, &$matcher) {
+            (reader, matcher) => {
+                let mut string = String::new();
+                match (reader.read_to_string(&mut string)) {
+                    Ok(size) => {
+                        match matcher.find(&string) {
+                            None => Ok(()),
+                            Some(found) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_io_read_to_string_is_not_match!(a_reader, &matcher)`\n",
+                                            "{}macro.assert_io_read_to_string_is_not_match.html\n",
+                                            "  reader label: `{}`,\n",
+                                            "  reader debug: `{:?}`,\n",
+                                            " matcher label: `{}`,\n",
+                                            " matcher debug: `{:?}`,\n",
+                                            "   reader size: `{:?}`,\n",
+                                            " reader string: `{:?}`,\n",
+                                            "   match range: `{:?}`,\n",
+                                            "  match string: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($reader),
+                                        reader,
+                                        stringify!($matcher),
+                                        matcher,
+                                        size,
+                                        string,
+                                        found.range(),
+                                        found.as_str()
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_io_read_to_string_is_not_match!(a_reader, &matcher)`\n",
+                                    "{}macro.assert_io_read_to_string_is_not_match.html\n",
+                                    "  reader label: `{}`,\n",
+                                    "  reader debug: `{:?}`,\n",
+                                    " matcher label: `{}`,\n",
+                                    " matcher debug: `{:?}`,\n",
+                                    "           err: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($reader),
+                                reader,
+                                stringify!($matcher),
+                                matcher,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+    use std::io::Read;
+
+    #[test]
+    fn success() {
+        let mut reader = "alfa".as_bytes();
+        let matcher = Regex::new(r"zz").unwrap();
+        let result = assert_io_read_to_string_is_not_match_as_result!(reader, &matcher);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_empty_reader() {
+        let mut reader = "".as_bytes();
+        let matcher = Regex::new(r"alfa").unwrap();
+        let result = assert_io_read_to_string_is_not_match_as_result!(reader, &matcher);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_evaluates_reader_once() {
+        let count = std::cell::Cell::new(0);
+        let reader = || {
+            count.set(count.get() + 1);
+            "alfa".as_bytes()
+        };
+        let matcher = Regex::new(r"lf").unwrap();
+        let result = assert_io_read_to_string_is_not_match_as_result!(reader(), &matcher);
+        assert!(result.unwrap_err().contains("  reader debug: `[]`,\n"));
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn failure() {
+        let mut reader = "user=alfa password=bravo".as_bytes();
+        let matcher = Regex::new(r"password=\w+").unwrap();
+        let result = assert_io_read_to_string_is_not_match_as_result!(reader, &matcher);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_io_read_to_string_is_not_match!(a_reader, &matcher)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_is_not_match.html\n",
+                "  reader label: `reader`,\n",
+                "  reader debug: `[]`,\n",
+                " matcher label: `&matcher`,\n",
+                " matcher debug: `Regex(\"password=\\\\w+\")`,\n",
+                "   reader size: `24`,\n",
+                " reader string: `\"user=alfa password=bravo\"`,\n",
+                "   match range: `10..24`,\n",
+                "  match string: `\"password=bravo\"`"
+            )
+        );
+    }
+}
+
+/// Assert a ::std::io::Read read_to_string() is not a match to a regex.
+///
+/// Pseudocode:<br>
+/// (reader.read_to_string(a_string) ⇒ a_string) does not match matcher
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::io::Read;
+/// use regex::Regex;
+///
+/// # fn main() {
+/// let mut reader = "hello".as_bytes();
+/// let matcher = Regex::new(r"zz").unwrap();
+/// assert_io_read_to_string_is_not_match!(reader, &matcher);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut reader = "hello".as_bytes();
+/// let matcher = Regex::new(r"ell").unwrap();
+/// assert_io_read_to_string_is_not_match!(reader, &matcher);
+/// # });
+/// // assertion failed: `assert_io_read_to_string_is_not_match!(a_reader, &matcher)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_is_not_match.html
+/// //   reader label: `reader`,
+/// //   reader debug: `[]`,
+/// //  matcher label: `&matcher`,
+/// //  matcher debug: `Regex(\"ell\")`,
+/// //    reader size: `5`,
+/// //  reader string: `\"hello\"`,
+/// //    match range: `1..4`,
+/// //   match string: `\"ell\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_io_read_to_string_is_not_match!(a_reader, &matcher)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_is_not_match.html\n",
+/// #     "  reader label: `reader`,\n",
+/// #     "  reader debug: `[]`,\n",
+/// #     " matcher label: `&matcher`,\n",
+/// #     " matcher debug: `Regex(\"ell\")`,\n",
+/// #     "   reader size: `5`,\n",
+/// #     " reader string: `\"hello\"`,\n",
+/// #     "   match range: `1..4`,\n",
+/// #     "  match string: `\"ell\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_io_read_to_string_is_not_match`](macro@crate::assert_io_read_to_string_is_not_match)
+/// * [`assert_io_read_to_string_is_not_match_as_result`](macro@crate::assert_io_read_to_string_is_not_match_as_result)
+/// * [`debug_assert_io_read_to_string_is_not_match`](macro@crate::debug_assert_io_read_to_string_is_not_match)
+///
+#[macro_export]
+macro_rules! assert_io_read_to_string_is_not_match {
+    ($a_reader:expr, $b_matcher:expr $(,)?) => {{
+        match $crate::assert_io_read_to_string_is_not_match_as_result!($a_reader, $b_matcher) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a_reader:expr, $b_matcher:expr, $($message:tt)+) => {{
+        match $crate::assert_io_read_to_string_is_not_match_as_result!($a_reader, $b_matcher) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a ::std::io::Read read_to_string() is not a match to a regex.
+///
+/// Pseudocode:<br>
+/// (reader.read_to_string(a_string) ⇒ a_string) does not match matcher
+///
+/// This macro provides the same statements as [`assert_io_read_to_string_is_not_match`](macro.assert_io_read_to_string_is_not_match.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_io_read_to_string_is_not_match`](macro@crate::assert_io_read_to_string_is_not_match)
+/// * [`assert_io_read_to_string_is_not_match_as_result`](macro@crate::assert_io_read_to_string_is_not_match_as_result)
+/// * [`debug_assert_io_read_to_string_is_not_match`](macro@crate::debug_assert_io_read_to_string_is_not_match)
+///
+#[macro_export]
+macro_rules! debug_assert_io_read_to_string_is_not_match {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_io_read_to_string_is_not_match!($($arg)*);
+        }
+    };
+}
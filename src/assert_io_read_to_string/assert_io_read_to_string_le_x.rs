@@ -51,7 +51,7 @@ macro_rules! assert_io_read_to_string_le_x_as_result {
                 let mut a_string = String::new();
                 match ($a_reader.read_to_string(&mut a_string)) {
                     Ok(_a_size) => {
-                        let b_string = String::from($b_expr);
+                        let b_string = String::from(AsRef::<str>::as_ref(b_expr));
                         if (a_string <= b_string) {
                             Ok(a_string)
                         } else {
@@ -59,7 +59,7 @@ macro_rules! assert_io_read_to_string_le_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_io_read_to_string_le_x!(a_reader, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_le_x.html\n",
+                                        "{}macro.assert_io_read_to_string_le_x.html\n",
                                         " a_reader label: `{}`,\n",
                                         " a_reader debug: `{:?}`,\n",
                                         "   b_expr label: `{}`,\n",
@@ -67,6 +67,7 @@ macro_rules! assert_io_read_to_string_le_x_as_result {
                                         "              a: `{:?}`,\n",
                                         "              b: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_reader),
                                     $a_reader,
                                     stringify!($b_expr),
@@ -82,13 +83,14 @@ macro_rules! assert_io_read_to_string_le_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_io_read_to_string_le_x!(a_reader, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_le_x.html\n",
+                                    "{}macro.assert_io_read_to_string_le_x.html\n",
                                     " a_reader label: `{}`,\n",
                                     " a_reader debug: `{:?}`,\n",
                                     "   b_expr label: `{}`,\n",
                                     "   b_expr debug: `{:?}`,\n",
                                     "            err: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_reader),
                                 $a_reader,
                                 stringify!($b_expr),
@@ -135,6 +137,14 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn success_with_owned_string() {
+        let mut reader = "alfa".as_bytes();
+        let value = String::from("alfa");
+        let result = assert_io_read_to_string_le_x_as_result!(reader, value);
+        assert_eq!(result.unwrap(), String::from("alfa"));
+    }
 }
 
 /// Assert a ::std::io::Read read_to_string() value is less than or equal to an expression.
@@ -60,7 +60,7 @@ macro_rules! assert_io_read_to_string_lt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_io_read_to_string_lt!(a_reader, b_reader)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_lt.html\n",
+                                "{}macro.assert_io_read_to_string_lt.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -68,6 +68,7 @@ macro_rules! assert_io_read_to_string_lt_as_result {
                                 "       a: `{:?}`,\n",
                                 "       b: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_reader),
                             $a_reader,
                             stringify!($b_reader),
@@ -83,7 +84,7 @@ macro_rules! assert_io_read_to_string_lt_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_io_read_to_string_lt!(a_reader, b_reader)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_io_read_to_string_lt.html\n",
+                            "{}macro.assert_io_read_to_string_lt.html\n",
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             "  b label: `{}`,\n",
@@ -91,6 +92,7 @@ macro_rules! assert_io_read_to_string_lt_as_result {
                             "        a: `{:?}`,\n",
                             "        b: `{:?}`"
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a_reader),
                         $a_reader,
                         stringify!($b_reader),
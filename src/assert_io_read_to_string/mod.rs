@@ -22,10 +22,15 @@
 //! * [`assert_io_read_to_string_gt_x!(reader, expr)`](macro@crate::assert_io_read_to_string_gt_x) ≈ reader.read_to_string() > expr
 //! * [`assert_io_read_to_string_ge_x!(reader, expr)`](macro@crate::assert_io_read_to_string_ge_x) ≈ reader.read_to_string() ≥ expr
 //!
+//! Each of these reads the reader once, and returns the read string on success.
+//! The expression can be any type that implements `AsRef<str>`, such as `&str`
+//! or `String`, and it is evaluated once.
+//!
 //! Compare a reader with its contents:
 //!
 //! * [`assert_io_read_to_string_contains!(reader, &containee)`](macro@crate::assert_io_read_to_string_contains) ≈ reader.read_to_string().contains(containee)
 //! * [`assert_io_read_to_string_is_match!(reader, &matcher)`](macro@crate::assert_io_read_to_string_is_match) ≈ matcher.is_match(reader.read_to_string())
+//! * [`assert_io_read_to_string_is_not_match!(reader, &matcher)`](macro@crate::assert_io_read_to_string_is_not_match) ≈ !matcher.is_match(reader.read_to_string())
 //!
 //!
 //! # Example
@@ -60,4 +65,5 @@ pub mod assert_io_read_to_string_ne_x;
 // Specializations
 pub mod assert_io_read_to_string_contains;
 pub mod assert_io_read_to_string_is_match;
+pub mod assert_io_read_to_string_is_not_match;
 pub mod assert_io_read_to_string_matches; // Deprecated.
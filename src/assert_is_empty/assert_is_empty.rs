@@ -53,10 +53,11 @@ macro_rules! assert_is_empty_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_is_empty!(a)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_is_empty.html\n",
+                                "{}macro.assert_is_empty.html\n",
                                 " label: `{}`,\n",
                                 " debug: `{:?}`",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             a,
                         )
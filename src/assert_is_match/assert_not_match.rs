@@ -55,12 +55,13 @@ macro_rules! assert_not_match_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_not_match!(matcher, matchee)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_not_match.html\n",
+                                "{}macro.assert_not_match.html\n",
                                 " matcher label: `{}`,\n",
                                 " matcher debug: `{:?}`,\n",
                                 " matchee label: `{}`,\n",
                                 " matchee debug: `{:?}`",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($matcher),
                             matcher,
                             stringify!($matchee),
@@ -0,0 +1,276 @@
+//! Assert an iter is equal to another, in any order.
+//!
+//! Pseudocode:<br>
+//! (collection1 into iter ⇒ counts) = (collection2 into iter ⇒ counts)
+//!
+//! This macro counts the items of each iterator into a map of item to count,
+//! then compares the maps. This preserves multiplicity, so `[1, 1, 2]` is not
+//! equal to `[1, 2, 2]`, unlike a comparison of sets.
+//!
+//! The inputs can be any type that implements `IntoIterator`, including lazy
+//! iterators, so there is no need to collect into a vector first. The inputs
+//! are consumed. The item type must implement `Ord` and `Debug`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = [1, 2, 2];
+//! let b = [2, 1, 2];
+//! assert_iter_eq_unordered!(a.iter(), b.iter());
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_iter_eq_unordered`](macro@crate::assert_iter_eq_unordered)
+//! * [`assert_iter_eq_unordered_as_result`](macro@crate::assert_iter_eq_unordered_as_result)
+//! * [`debug_assert_iter_eq_unordered`](macro@crate::debug_assert_iter_eq_unordered)
+
+/// Assert an iter is equal to another, in any order.
+///
+/// Pseudocode:<br>
+/// (collection1 into iter ⇒ counts) = (collection2 into iter ⇒ counts)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message reports the first item, in `Ord` order, whose counts differ.
+///
+/// This macro provides the same statements as [`assert_iter_eq_unordered`](macro.assert_iter_eq_unordered.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_iter_eq_unordered`](macro@crate::assert_iter_eq_unordered)
+/// * [`assert_iter_eq_unordered_as_result`](macro@crate::assert_iter_eq_unordered_as_result)
+/// * [`debug_assert_iter_eq_unordered`](macro@crate::debug_assert_iter_eq_unordered)
+///
+#[macro_export]
+macro_rules! assert_iter_eq_unordered_as_result {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        let a_counts = $crate::assert_iter::iter_counts($a_collection);
+        let b_counts = $crate::assert_iter::iter_counts($b_collection);
+        match a_counts
+            .keys()
+            .chain(b_counts.keys())
+            .filter(|&key| a_counts.get(key) != b_counts.get(key))
+            .min()
+        {
+            None => Ok(()),
+            Some(key) => Err(
+                format!(
+                    concat!(
+                        "assertion failed: `assert_iter_eq_unordered!(a_collection, b_collection)`\n",
+                        "{}macro.assert_iter_eq_unordered.html\n",
+                        "   a label: `{}`,\n",
+                        "  a counts: `{:?}`,\n",
+                        "   b label: `{}`,\n",
+                        "  b counts: `{:?}`,\n",
+                        " first key: `{:?}`,\n",
+                        "   a count: `{}`,\n",
+                        "   b count: `{}`"
+                    ),
+                    $crate::DOC_URL_BASE,
+                    stringify!($a_collection),
+                    a_counts,
+                    stringify!($b_collection),
+                    b_counts,
+                    key,
+                    a_counts.get(key).copied().unwrap_or(0),
+                    b_counts.get(key).copied().unwrap_or(0)
+                )
+            ),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 2];
+        let b = [2, 1, 2];
+        let result = assert_iter_eq_unordered_as_result!(&a, &b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_lazy_iterators() {
+        let a = (1..=3).map(|x| x * 2);
+        let b = vec![6, 2, 4].into_iter();
+        let result = assert_iter_eq_unordered_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_empty() {
+        let a: Vec<i8> = vec![];
+        let b: Vec<i8> = vec![];
+        let result = assert_iter_eq_unordered_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_because_multiplicity() {
+        let a = [1, 1, 2];
+        let b = [1, 2, 2];
+        let result = assert_iter_eq_unordered_as_result!(&a, &b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_iter_eq_unordered!(a_collection, b_collection)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_iter_eq_unordered.html\n",
+                "   a label: `&a`,\n",
+                "  a counts: `{1: 2, 2: 1}`,\n",
+                "   b label: `&b`,\n",
+                "  b counts: `{1: 1, 2: 2}`,\n",
+                " first key: `1`,\n",
+                "   a count: `2`,\n",
+                "   b count: `1`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_missing_key() {
+        let a = ["alfa", "charlie"];
+        let b = ["charlie", "bravo", "alfa"];
+        let result = assert_iter_eq_unordered_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_iter_eq_unordered!(a_collection, b_collection)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_iter_eq_unordered.html\n",
+                "   a label: `a`,\n",
+                "  a counts: `{\"alfa\": 1, \"charlie\": 1}`,\n",
+                "   b label: `b`,\n",
+                "  b counts: `{\"alfa\": 1, \"bravo\": 1, \"charlie\": 1}`,\n",
+                " first key: `\"bravo\"`,\n",
+                "   a count: `0`,\n",
+                "   b count: `1`"
+            )
+        );
+    }
+}
+
+/// Assert an iter is equal to another, in any order.
+///
+/// Pseudocode:<br>
+/// (collection1 into iter ⇒ counts) = (collection2 into iter ⇒ counts)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 2];
+/// let b = [2, 1, 2];
+/// assert_iter_eq_unordered!(a.iter(), b.iter());
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 1, 2];
+/// let b = [1, 2, 2];
+/// assert_iter_eq_unordered!(a.iter(), b.iter());
+/// # });
+/// // assertion failed: `assert_iter_eq_unordered!(a_collection, b_collection)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_iter_eq_unordered.html
+/// //    a label: `a.iter()`,
+/// //   a counts: `{1: 2, 2: 1}`,
+/// //    b label: `b.iter()`,
+/// //   b counts: `{1: 1, 2: 2}`,
+/// //  first key: `1`,
+/// //    a count: `2`,
+/// //    b count: `1`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_iter_eq_unordered!(a_collection, b_collection)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_iter_eq_unordered.html\n",
+/// #     "   a label: `a.iter()`,\n",
+/// #     "  a counts: `{1: 2, 2: 1}`,\n",
+/// #     "   b label: `b.iter()`,\n",
+/// #     "  b counts: `{1: 1, 2: 2}`,\n",
+/// #     " first key: `1`,\n",
+/// #     "   a count: `2`,\n",
+/// #     "   b count: `1`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_iter_eq_unordered`](macro@crate::assert_iter_eq_unordered)
+/// * [`assert_iter_eq_unordered_as_result`](macro@crate::assert_iter_eq_unordered_as_result)
+/// * [`debug_assert_iter_eq_unordered`](macro@crate::debug_assert_iter_eq_unordered)
+///
+#[macro_export]
+macro_rules! assert_iter_eq_unordered {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        match $crate::assert_iter_eq_unordered_as_result!($a_collection, $b_collection) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
+        match $crate::assert_iter_eq_unordered_as_result!($a_collection, $b_collection) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an iter is equal to another, in any order.
+///
+/// Pseudocode:<br>
+/// (collection1 into iter ⇒ counts) = (collection2 into iter ⇒ counts)
+///
+/// This macro provides the same statements as [`assert_iter_eq_unordered`](macro.assert_iter_eq_unordered.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_iter_eq_unordered`](macro@crate::assert_iter_eq_unordered)
+/// * [`assert_iter_eq_unordered_as_result`](macro@crate::assert_iter_eq_unordered_as_result)
+/// * [`debug_assert_iter_eq_unordered`](macro@crate::debug_assert_iter_eq_unordered)
+///
+#[macro_export]
+macro_rules! debug_assert_iter_eq_unordered {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_iter_eq_unordered!($($arg)*);
+        }
+    };
+}
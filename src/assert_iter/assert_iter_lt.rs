@@ -60,12 +60,13 @@ macro_rules! assert_iter_lt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_iter_lt!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_iter_lt.html\n",
+                                "{}macro.assert_iter_lt.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
                                 " b debug: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_collection),
                             a_collection,
                             stringify!($b_collection),
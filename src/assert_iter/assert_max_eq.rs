@@ -0,0 +1,325 @@
+//! Assert the maximum element of an iterable is equal to a value.
+//!
+//! Pseudocode:<br>
+//! (collection into iter).max() = value
+//!
+//! This is useful for checking the bounds of a dataset. The items must
+//! implement `Ord`, and an empty collection is a failure.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = [3, 1, 2];
+//! let b = 3;
+//! assert_max_eq!(&a, b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_max_eq`](macro@crate::assert_max_eq)
+//! * [`assert_max_eq_as_result`](macro@crate::assert_max_eq_as_result)
+//! * [`debug_assert_max_eq`](macro@crate::debug_assert_max_eq)
+
+/// Assert the maximum element of an iterable is equal to a value.
+///
+/// Pseudocode:<br>
+/// (collection into iter).max() = value
+///
+/// * If true, return Result `Ok(max)`, where `max` is a reference to the maximum element.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// When the collection is empty, the message says so, rather than
+/// showing a maximum element.
+///
+/// This macro provides the same statements as [`assert_max_eq`](macro.assert_max_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// The collection is taken by value, so it can be a reference such as `&a`,
+/// or an iterator such as `0..3` or `a.iter().map(|x| x * 2)`. The macro
+/// iterates a clone of the collection, and keeps the collection to show in
+/// the message, so the collection must implement `Clone` and `Debug`, and
+/// the macro formats the collection only when the assertion fails.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_max_eq`](macro@crate::assert_max_eq)
+/// * [`assert_max_eq_as_result`](macro@crate::assert_max_eq_as_result)
+/// * [`debug_assert_max_eq`](macro@crate::debug_assert_max_eq)
+///
+#[macro_export]
+macro_rules! assert_max_eq_as_result {
+    ($collection:expr, $value:expr $(,)?) => {{
+        match ($collection, &$value) {
+            (collection, value) => {
+                match ::std::clone::Clone::clone(&collection).into_iter().max() {
+                    Some(max) => {
+                        if $crate::assert_iter::item_eq(&max, value) {
+                            Ok(max)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_max_eq!(collection, value)`\n",
+                                        "{}macro.assert_max_eq.html\n",
+                                        " collection label: `{}`,\n",
+                                        " collection debug: `{:?}`,\n",
+                                        "      value label: `{}`,\n",
+                                        "      value debug: `{:?}`,\n",
+                                        "              max: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($collection),
+                                    collection,
+                                    stringify!($value),
+                                    value,
+                                    max
+                                )
+                            )
+                        }
+                    },
+                    None => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_max_eq!(collection, value)`\n",
+                                    "{}macro.assert_max_eq.html\n",
+                                    " collection label: `{}`,\n",
+                                    " collection debug: `{:?}`,\n",
+                                    "      value label: `{}`,\n",
+                                    "      value debug: `{:?}`,\n",
+                                    "          outcome: `collection was empty`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($collection),
+                                collection,
+                                stringify!($value),
+                                value
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = [3, 1, 2];
+        let b = 3;
+        let result = assert_max_eq_as_result!(&a, b);
+        assert_eq!(result.unwrap(), &3);
+    }
+
+    #[test]
+    fn success_with_range() {
+        let result = assert_max_eq_as_result!(3..6, 5);
+        assert_eq!(result.unwrap(), 5);
+    }
+
+    #[test]
+    fn success_with_iterator_adapter() {
+        let a = [3, 1, 2];
+        let result = assert_max_eq_as_result!(a.iter().map(|x| x * 2), 6);
+        assert_eq!(result.unwrap(), 6);
+    }
+
+    #[test]
+    fn success_without_formatting_collection() {
+        #[derive(Clone)]
+        struct Collection(Vec<i32>);
+        impl std::fmt::Debug for Collection {
+            fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+                panic!("the collection is formatted")
+            }
+        }
+        impl IntoIterator for Collection {
+            type Item = i32;
+            type IntoIter = std::vec::IntoIter<i32>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+        let collection = Collection(vec![1, 2]);
+        let result = assert_max_eq_as_result!(collection, 2);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure_with_range() {
+        let result = assert_max_eq_as_result!(0..3, 1);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_max_eq!(collection, value)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_max_eq.html\n",
+                " collection label: `0..3`,\n",
+                " collection debug: `0..3`,\n",
+                "      value label: `1`,\n",
+                "      value debug: `1`,\n",
+                "              max: `2`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = [3, 1, 2];
+        let b = 2;
+        let result = assert_max_eq_as_result!(&a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_max_eq!(collection, value)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_max_eq.html\n",
+                " collection label: `&a`,\n",
+                " collection debug: `[3, 1, 2]`,\n",
+                "      value label: `b`,\n",
+                "      value debug: `2`,\n",
+                "              max: `3`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_empty() {
+        let a: Vec<i8> = vec![];
+        let b: i8 = 1;
+        let result = assert_max_eq_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_max_eq!(collection, value)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_max_eq.html\n",
+                " collection label: `a`,\n",
+                " collection debug: `[]`,\n",
+                "      value label: `b`,\n",
+                "      value debug: `1`,\n",
+                "          outcome: `collection was empty`"
+            )
+        );
+    }
+}
+
+/// Assert the maximum element of an iterable is equal to a value.
+///
+/// Pseudocode:<br>
+/// (collection into iter).max() = value
+///
+/// * If true, return `max`, where `max` is a reference to the maximum element.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [3, 1, 2];
+/// let b = 3;
+/// assert_max_eq!(&a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [3, 1, 2];
+/// let b = 2;
+/// assert_max_eq!(&a, b);
+/// # });
+/// // assertion failed: `assert_max_eq!(collection, value)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_max_eq.html
+/// //  collection label: `&a`,
+/// //  collection debug: `[3, 1, 2]`,
+/// //       value label: `b`,
+/// //       value debug: `2`,
+/// //               max: `3`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_max_eq!(collection, value)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_max_eq.html\n",
+/// #     " collection label: `&a`,\n",
+/// #     " collection debug: `[3, 1, 2]`,\n",
+/// #     "      value label: `b`,\n",
+/// #     "      value debug: `2`,\n",
+/// #     "              max: `3`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_max_eq`](macro@crate::assert_max_eq)
+/// * [`assert_max_eq_as_result`](macro@crate::assert_max_eq_as_result)
+/// * [`debug_assert_max_eq`](macro@crate::debug_assert_max_eq)
+///
+#[macro_export]
+macro_rules! assert_max_eq {
+    ($collection:expr, $value:expr $(,)?) => {{
+        match $crate::assert_max_eq_as_result!($collection, $value) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($collection:expr, $value:expr, $($message:tt)+) => {{
+        match $crate::assert_max_eq_as_result!($collection, $value) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert the maximum element of an iterable is equal to a value.
+///
+/// Pseudocode:<br>
+/// (collection into iter).max() = value
+///
+/// This macro provides the same statements as [`assert_max_eq`](macro.assert_max_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_max_eq`](macro@crate::assert_max_eq)
+/// * [`assert_max_eq_as_result`](macro@crate::assert_max_eq_as_result)
+/// * [`debug_assert_max_eq`](macro@crate::debug_assert_max_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_max_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_max_eq!($($arg)*);
+        }
+    };
+}
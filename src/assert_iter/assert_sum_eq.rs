@@ -0,0 +1,296 @@
+//! Assert the sum of an iterable's elements is equal to a value.
+//!
+//! Pseudocode:<br>
+//! (collection into iter).sum() = value
+//!
+//! The sum has the same type as the value, and is computed with
+//! `Iterator::sum`, so an empty collection sums to zero. Integer overflow
+//! behaves like `Iterator::sum`: it panics in debug builds and wraps in
+//! release builds. To sum narrow integers without overflow, widen them
+//! first, such as collecting `a.iter().map(|&x| i64::from(x))` into a
+//! `Vec<i64>`, and compare to an `i64` value.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = [1, 2, 3];
+//! let b = 6;
+//! assert_sum_eq!(&a, b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_sum_eq`](macro@crate::assert_sum_eq)
+//! * [`assert_sum_eq_as_result`](macro@crate::assert_sum_eq_as_result)
+//! * [`debug_assert_sum_eq`](macro@crate::debug_assert_sum_eq)
+
+/// Assert the sum of an iterable's elements is equal to a value.
+///
+/// Pseudocode:<br>
+/// (collection into iter).sum() = value
+///
+/// * If true, return Result `Ok(sum)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_sum_eq`](macro.assert_sum_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// The collection is taken by value, so it can be a reference such as `&a`,
+/// or an iterator such as `0..3` or `a.iter().map(|x| x * 2)`. The macro
+/// iterates a clone of the collection, and keeps the collection to show in
+/// the message, so the collection must implement `Clone` and `Debug`, and
+/// the macro formats the collection only when the assertion fails.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_sum_eq`](macro@crate::assert_sum_eq)
+/// * [`assert_sum_eq_as_result`](macro@crate::assert_sum_eq_as_result)
+/// * [`debug_assert_sum_eq`](macro@crate::debug_assert_sum_eq)
+///
+#[macro_export]
+macro_rules! assert_sum_eq_as_result {
+    ($collection:expr, $value:expr $(,)?) => {{
+        match ($collection, &$value) {
+            (collection, value) => {
+                fn sum_as<I: Iterator, S: ::std::iter::Sum<I::Item>>(iter: I, _: &S) -> S {
+                    iter.sum()
+                }
+                let sum = sum_as(::std::clone::Clone::clone(&collection).into_iter(), value);
+                if sum == *value {
+                    Ok(sum)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_sum_eq!(collection, value)`\n",
+                                "{}macro.assert_sum_eq.html\n",
+                                " collection label: `{}`,\n",
+                                " collection debug: `{:?}`,\n",
+                                "      value label: `{}`,\n",
+                                "      value debug: `{:?}`,\n",
+                                "              sum: `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($collection),
+                            collection,
+                            stringify!($value),
+                            value,
+                            sum
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let b = 6;
+        let result = assert_sum_eq_as_result!(&a, b);
+        assert_eq!(result.unwrap(), 6);
+    }
+
+    #[test]
+    fn success_with_floats() {
+        let a = vec![0.5, 0.25, 0.25];
+        let b = 1.0;
+        let result = assert_sum_eq_as_result!(a, b);
+        assert_eq!(result.unwrap(), 1.0);
+    }
+
+    #[test]
+    fn success_with_widened_items() {
+        let a: [i8; 2] = [100, 100];
+        let wide: Vec<i64> = a.iter().map(|&x| i64::from(x)).collect();
+        let b: i64 = 200;
+        let result = assert_sum_eq_as_result!(wide, b);
+        assert_eq!(result.unwrap(), 200);
+    }
+
+    #[test]
+    fn success_with_range() {
+        let result = assert_sum_eq_as_result!(1..4, 6);
+        assert_eq!(result.unwrap(), 6);
+    }
+
+    #[test]
+    fn success_with_iterator_adapter() {
+        let a: [i8; 2] = [100, 100];
+        let b: i64 = 200;
+        let result = assert_sum_eq_as_result!(a.iter().map(|&x| i64::from(x)), b);
+        assert_eq!(result.unwrap(), 200);
+    }
+
+    #[test]
+    fn success_with_empty_collection() {
+        let a: Vec<i8> = vec![];
+        let b: i8 = 0;
+        let result = assert_sum_eq_as_result!(a, b);
+        assert_eq!(result.unwrap(), 0);
+    }
+
+    #[test]
+    fn success_without_formatting_collection() {
+        #[derive(Clone)]
+        struct Collection(Vec<i32>);
+        impl std::fmt::Debug for Collection {
+            fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+                panic!("the collection is formatted")
+            }
+        }
+        impl IntoIterator for Collection {
+            type Item = i32;
+            type IntoIter = std::vec::IntoIter<i32>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+        let collection = Collection(vec![1, 2]);
+        let result = assert_sum_eq_as_result!(collection, 3);
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 2, 3];
+        let b = 7;
+        let result = assert_sum_eq_as_result!(&a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_sum_eq!(collection, value)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_sum_eq.html\n",
+                " collection label: `&a`,\n",
+                " collection debug: `[1, 2, 3]`,\n",
+                "      value label: `b`,\n",
+                "      value debug: `7`,\n",
+                "              sum: `6`"
+            )
+        );
+    }
+}
+
+/// Assert the sum of an iterable's elements is equal to a value.
+///
+/// Pseudocode:<br>
+/// (collection into iter).sum() = value
+///
+/// * If true, return `sum`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// let b = 6;
+/// assert_sum_eq!(&a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3];
+/// let b = 7;
+/// assert_sum_eq!(&a, b);
+/// # });
+/// // assertion failed: `assert_sum_eq!(collection, value)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_sum_eq.html
+/// //  collection label: `&a`,
+/// //  collection debug: `[1, 2, 3]`,
+/// //       value label: `b`,
+/// //       value debug: `7`,
+/// //               sum: `6`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_sum_eq!(collection, value)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_sum_eq.html\n",
+/// #     " collection label: `&a`,\n",
+/// #     " collection debug: `[1, 2, 3]`,\n",
+/// #     "      value label: `b`,\n",
+/// #     "      value debug: `7`,\n",
+/// #     "              sum: `6`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_sum_eq`](macro@crate::assert_sum_eq)
+/// * [`assert_sum_eq_as_result`](macro@crate::assert_sum_eq_as_result)
+/// * [`debug_assert_sum_eq`](macro@crate::debug_assert_sum_eq)
+///
+#[macro_export]
+macro_rules! assert_sum_eq {
+    ($collection:expr, $value:expr $(,)?) => {{
+        match $crate::assert_sum_eq_as_result!($collection, $value) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($collection:expr, $value:expr, $($message:tt)+) => {{
+        match $crate::assert_sum_eq_as_result!($collection, $value) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert the sum of an iterable's elements is equal to a value.
+///
+/// Pseudocode:<br>
+/// (collection into iter).sum() = value
+///
+/// This macro provides the same statements as [`assert_sum_eq`](macro.assert_sum_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_sum_eq`](macro@crate::assert_sum_eq)
+/// * [`assert_sum_eq_as_result`](macro@crate::assert_sum_eq_as_result)
+/// * [`debug_assert_sum_eq`](macro@crate::debug_assert_sum_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_sum_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_sum_eq!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,296 @@
+//! Assert the sum of an iterable's elements is within delta of a value.
+//!
+//! Pseudocode:<br>
+//! | (collection into iter).sum() - value | ≤ Δ
+//!
+//! This is the tolerant form of [`assert_sum_eq`](macro@crate::assert_sum_eq),
+//! for floating point sums, where rounding makes exact equality brittle.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = [0.1, 0.2];
+//! let b = 0.3;
+//! let delta = 0.001;
+//! assert_sum_in_delta!(&a, b, delta);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_sum_in_delta`](macro@crate::assert_sum_in_delta)
+//! * [`assert_sum_in_delta_as_result`](macro@crate::assert_sum_in_delta_as_result)
+//! * [`debug_assert_sum_in_delta`](macro@crate::debug_assert_sum_in_delta)
+
+/// Assert the sum of an iterable's elements is within delta of a value.
+///
+/// Pseudocode:<br>
+/// | (collection into iter).sum() - value | ≤ Δ
+///
+/// * If true, return Result `Ok(sum)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_sum_in_delta`](macro.assert_sum_in_delta.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// The collection is taken by value, so it can be a reference such as `&a`,
+/// or an iterator such as `0..3` or `a.iter().map(|x| x * 2)`. The macro
+/// iterates a clone of the collection, and keeps the collection to show in
+/// the message, so the collection must implement `Clone` and `Debug`, and
+/// the macro formats the collection only when the assertion fails.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_sum_in_delta`](macro@crate::assert_sum_in_delta)
+/// * [`assert_sum_in_delta_as_result`](macro@crate::assert_sum_in_delta_as_result)
+/// * [`debug_assert_sum_in_delta`](macro@crate::debug_assert_sum_in_delta)
+///
+#[macro_export]
+macro_rules! assert_sum_in_delta_as_result {
+    ($collection:expr, $value:expr, $delta:expr $(,)?) => {{
+        match ($collection, &$value, &$delta) {
+            (collection, value, delta) => {
+                fn sum_as<I: Iterator, S: ::std::iter::Sum<I::Item>>(iter: I, _: &S) -> S {
+                    iter.sum()
+                }
+                let sum = sum_as(::std::clone::Clone::clone(&collection).into_iter(), value);
+                let abs_diff = if sum >= *value { &sum - value } else { value - &sum };
+                if abs_diff <= *delta {
+                    Ok(sum)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_sum_in_delta!(collection, value, Δ)`\n",
+                                "{}macro.assert_sum_in_delta.html\n",
+                                "    collection label: `{}`,\n",
+                                "    collection debug: `{:?}`,\n",
+                                "         value label: `{}`,\n",
+                                "         value debug: `{:?}`,\n",
+                                "             Δ label: `{}`,\n",
+                                "             Δ debug: `{:?}`,\n",
+                                "                 sum: `{:?}`,\n",
+                                "     | sum - value |: `{:?}`,\n",
+                                " | sum - value | ≤ Δ: false"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($collection),
+                            collection,
+                            stringify!($value),
+                            value,
+                            stringify!($delta),
+                            delta,
+                            sum,
+                            abs_diff
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = [0.1, 0.2];
+        let b = 0.3;
+        let delta = 0.001;
+        let result = assert_sum_in_delta_as_result!(&a, b, delta);
+        assert!((result.unwrap() - 0.3_f64).abs() < 0.001);
+    }
+
+    #[test]
+    fn success_with_integers() {
+        let a = [1, 2, 3];
+        let b = 7;
+        let delta = 1;
+        let result = assert_sum_in_delta_as_result!(&a, b, delta);
+        assert_eq!(result.unwrap(), 6);
+    }
+
+    #[test]
+    fn success_with_iterator_adapter() {
+        let a = [1, 2, 3];
+        let b = 13;
+        let delta = 1;
+        let result = assert_sum_in_delta_as_result!(a.iter().map(|x| x * 2), b, delta);
+        assert_eq!(result.unwrap(), 12);
+    }
+
+    #[test]
+    fn success_without_formatting_collection() {
+        #[derive(Clone)]
+        struct Collection(Vec<i32>);
+        impl std::fmt::Debug for Collection {
+            fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+                panic!("the collection is formatted")
+            }
+        }
+        impl IntoIterator for Collection {
+            type Item = i32;
+            type IntoIter = std::vec::IntoIter<i32>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+        let collection = Collection(vec![1, 2]);
+        let result = assert_sum_in_delta_as_result!(collection, 3, 0);
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn failure() {
+        let a = [0.5, 0.25, 0.25];
+        let b = 1.5;
+        let delta = 0.1;
+        let result = assert_sum_in_delta_as_result!(&a, b, delta);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_sum_in_delta!(collection, value, Δ)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_sum_in_delta.html\n",
+                "    collection label: `&a`,\n",
+                "    collection debug: `[0.5, 0.25, 0.25]`,\n",
+                "         value label: `b`,\n",
+                "         value debug: `1.5`,\n",
+                "             Δ label: `delta`,\n",
+                "             Δ debug: `0.1`,\n",
+                "                 sum: `1.0`,\n",
+                "     | sum - value |: `0.5`,\n",
+                " | sum - value | ≤ Δ: false"
+            )
+        );
+    }
+}
+
+/// Assert the sum of an iterable's elements is within delta of a value.
+///
+/// Pseudocode:<br>
+/// | (collection into iter).sum() - value | ≤ Δ
+///
+/// * If true, return `sum`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [0.1, 0.2];
+/// let b = 0.3;
+/// let delta = 0.001;
+/// assert_sum_in_delta!(&a, b, delta);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [0.5, 0.25, 0.25];
+/// let b = 1.5;
+/// let delta = 0.1;
+/// assert_sum_in_delta!(&a, b, delta);
+/// # });
+/// // assertion failed: `assert_sum_in_delta!(collection, value, Δ)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_sum_in_delta.html
+/// //     collection label: `&a`,
+/// //     collection debug: `[0.5, 0.25, 0.25]`,
+/// //          value label: `b`,
+/// //          value debug: `1.5`,
+/// //              Δ label: `delta`,
+/// //              Δ debug: `0.1`,
+/// //                  sum: `1.0`,
+/// //      | sum - value |: `0.5`,
+/// //  | sum - value | ≤ Δ: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_sum_in_delta!(collection, value, Δ)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_sum_in_delta.html\n",
+/// #     "    collection label: `&a`,\n",
+/// #     "    collection debug: `[0.5, 0.25, 0.25]`,\n",
+/// #     "         value label: `b`,\n",
+/// #     "         value debug: `1.5`,\n",
+/// #     "             Δ label: `delta`,\n",
+/// #     "             Δ debug: `0.1`,\n",
+/// #     "                 sum: `1.0`,\n",
+/// #     "     | sum - value |: `0.5`,\n",
+/// #     " | sum - value | ≤ Δ: false"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_sum_in_delta`](macro@crate::assert_sum_in_delta)
+/// * [`assert_sum_in_delta_as_result`](macro@crate::assert_sum_in_delta_as_result)
+/// * [`debug_assert_sum_in_delta`](macro@crate::debug_assert_sum_in_delta)
+///
+#[macro_export]
+macro_rules! assert_sum_in_delta {
+    ($collection:expr, $value:expr, $delta:expr $(,)?) => {{
+        match $crate::assert_sum_in_delta_as_result!($collection, $value, $delta) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($collection:expr, $value:expr, $delta:expr, $($message:tt)+) => {{
+        match $crate::assert_sum_in_delta_as_result!($collection, $value, $delta) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert the sum of an iterable's elements is within delta of a value.
+///
+/// Pseudocode:<br>
+/// | (collection into iter).sum() - value | ≤ Δ
+///
+/// This macro provides the same statements as [`assert_sum_in_delta`](macro.assert_sum_in_delta.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_sum_in_delta`](macro@crate::assert_sum_in_delta)
+/// * [`assert_sum_in_delta_as_result`](macro@crate::assert_sum_in_delta_as_result)
+/// * [`debug_assert_sum_in_delta`](macro@crate::debug_assert_sum_in_delta)
+///
+#[macro_export]
+macro_rules! debug_assert_sum_in_delta {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_sum_in_delta!($($arg)*);
+        }
+    };
+}
@@ -10,6 +10,14 @@
 //! * [`assert_iter_gt!(collection1, collection2)`](macro@crate::assert_iter_gt) ≈ iter a > iter b
 //! * [`assert_iter_ge!(collection1, collection2)`](macro@crate::assert_iter_ge) ≈ iter a ≥ iter b
 //!
+//! * [`assert_iter_eq_unordered!(collection1, collection2)`](macro@crate::assert_iter_eq_unordered) ≈ iter a counts = iter b counts
+//!
+//! * [`assert_all_eq!(collection, value)`](macro@crate::assert_all_eq) ≈ iter a all = value
+//! * [`assert_min_eq!(collection, value)`](macro@crate::assert_min_eq) ≈ iter a min = value
+//! * [`assert_max_eq!(collection, value)`](macro@crate::assert_max_eq) ≈ iter a max = value
+//! * [`assert_sum_eq!(collection, value)`](macro@crate::assert_sum_eq) ≈ iter a sum = value
+//! * [`assert_sum_in_delta!(collection, value, delta)`](macro@crate::assert_sum_in_delta) ≈ | iter a sum - value | ≤ Δ
+//!
 //! # Example
 //!
 //! ```rust
@@ -29,3 +37,48 @@ pub mod assert_iter_gt;
 pub mod assert_iter_le;
 pub mod assert_iter_lt;
 pub mod assert_iter_ne;
+
+// Comparisons in any order
+pub mod assert_iter_eq_unordered;
+
+// Every element
+pub mod assert_all_eq;
+
+// Extremes
+pub mod assert_max_eq;
+pub mod assert_min_eq;
+
+// Sums
+pub mod assert_sum_eq;
+pub mod assert_sum_in_delta;
+
+/// Count the items of an iterator into a map of item to count.
+///
+/// This is a helper for [`assert_iter_eq_unordered`](macro@crate::assert_iter_eq_unordered).
+#[doc(hidden)]
+pub fn iter_counts<I>(iter: I) -> std::collections::BTreeMap<I::Item, usize>
+where
+    I: IntoIterator,
+    I::Item: Ord,
+{
+    let mut counts = std::collections::BTreeMap::new();
+    for item in iter {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Compare an item to a value, whether the item is owned or borrowed.
+///
+/// This is a helper for [`assert_all_eq`](macro@crate::assert_all_eq),
+/// [`assert_min_eq`](macro@crate::assert_min_eq), and
+/// [`assert_max_eq`](macro@crate::assert_max_eq), which take the collection
+/// by value, so its items can be `T` or `&T`.
+#[doc(hidden)]
+pub fn item_eq<T, V>(item: &T, value: &V) -> bool
+where
+    T: std::borrow::Borrow<V>,
+    V: PartialEq + ?Sized,
+{
+    item.borrow() == value
+}
@@ -0,0 +1,303 @@
+//! Assert a JSON string contains every part of a subset JSON string.
+//!
+//! Pseudocode:<br>
+//! (actual ⇒ JSON) contains (subset ⇒ JSON)
+//!
+//! This parses both strings, then compares recursively:
+//!
+//! * An object contains a subset object when each subset key is in the object,
+//!   and the object value contains the subset value. Extra keys are ignored.
+//!
+//! * An array contains a subset array when each subset element is contained
+//!   by at least one array element, in any order. Extra elements are ignored.
+//!
+//! * Any other value contains a subset value when they are equal.
+//!
+//! This is useful for API tests, where a response has extra fields that the
+//! test does not care about. The failure message shows the JSON pointer of
+//! the first part of the subset that is not met.
+//!
+//! This macro needs the crate feature `json`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let actual = r#"{"id": 1, "name": "alfa", "tags": ["x", "y"]}"#;
+//! let subset = r#"{"name": "alfa", "tags": ["y"]}"#;
+//! assert_json_contains!(actual, subset);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_json_contains`](macro@crate::assert_json_contains)
+//! * [`assert_json_contains_as_result`](macro@crate::assert_json_contains_as_result)
+//! * [`debug_assert_json_contains`](macro@crate::debug_assert_json_contains)
+
+/// Assert a JSON string contains every part of a subset JSON string.
+///
+/// Pseudocode:<br>
+/// (actual ⇒ JSON) contains (subset ⇒ JSON)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)` with the JSON pointer of the
+///   first part of the subset that is not met, or with the parse error.
+///
+/// The strings can be any type that implements `AsRef<str>`, such as `&str`
+/// or `String`. For an array, the pointer ends with the index of the element
+/// in the subset, because there is no single matching element in the actual.
+///
+/// This macro provides the same statements as [`assert_json_contains`](macro.assert_json_contains.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_json_contains`](macro@crate::assert_json_contains)
+/// * [`assert_json_contains_as_result`](macro@crate::assert_json_contains_as_result)
+/// * [`debug_assert_json_contains`](macro@crate::debug_assert_json_contains)
+///
+#[macro_export]
+macro_rules! assert_json_contains_as_result {
+    ($actual:expr, $subset:expr $(,)?) => {{
+        match (&$actual, &$subset) {
+            (actual, subset) => {
+                match (
+                    $crate::assert_json::parse(AsRef::<str>::as_ref(actual)),
+                    $crate::assert_json::parse(AsRef::<str>::as_ref(subset)),
+                ) {
+                    (Ok(actual_value), Ok(subset_value)) => {
+                        match $crate::assert_json::json_contains_pointer(&actual_value, &subset_value) {
+                            None => Ok(()),
+                            Some((pointer, value)) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_json_contains!(actual, subset)`\n",
+                                            "{}macro.assert_json_contains.html\n",
+                                            " actual label: `{}`,\n",
+                                            " actual debug: `{:?}`,\n",
+                                            " subset label: `{}`,\n",
+                                            " subset debug: `{:?}`,\n",
+                                            "      pointer: `{}`,\n",
+                                            " subset value: `{}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($actual),
+                                        actual,
+                                        stringify!($subset),
+                                        subset,
+                                        pointer,
+                                        value
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    (actual_parse, subset_parse) => {
+                        let err = match (actual_parse, subset_parse) {
+                            (Err(err), _) => format!("actual is not JSON: {}", err),
+                            (_, Err(err)) => format!("subset is not JSON: {}", err),
+                            _ => unreachable!(),
+                        };
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_json_contains!(actual, subset)`\n",
+                                    "{}macro.assert_json_contains.html\n",
+                                    " actual label: `{}`,\n",
+                                    " actual debug: `{:?}`,\n",
+                                    " subset label: `{}`,\n",
+                                    " subset debug: `{:?}`,\n",
+                                    "    parse err: `{}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($actual),
+                                actual,
+                                stringify!($subset),
+                                subset,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let actual = r#"{"id": 1, "name": "alfa", "tags": ["x", "y"]}"#;
+        let subset = r#"{"name": "alfa", "tags": ["y"]}"#;
+        let result = assert_json_contains_as_result!(actual, subset);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_nested_array_of_objects() {
+        let actual = String::from(r#"{"users": [{"id": 1, "admin": true}, {"id": 2, "admin": false}]}"#);
+        let subset = String::from(r#"{"users": [{"id": 2}]}"#);
+        let result = assert_json_contains_as_result!(actual, subset);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let actual = r#"{"id": 1, "user": {"name": "alfa"}}"#;
+        let subset = r#"{"user": {"name": "bravo"}}"#;
+        let result = assert_json_contains_as_result!(actual, subset);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_json_contains!(actual, subset)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_json_contains.html\n",
+                " actual label: `actual`,\n",
+                " actual debug: `\"{\\\"id\\\": 1, \\\"user\\\": {\\\"name\\\": \\\"alfa\\\"}}\"`,\n",
+                " subset label: `subset`,\n",
+                " subset debug: `\"{\\\"user\\\": {\\\"name\\\": \\\"bravo\\\"}}\"`,\n",
+                "      pointer: `/user/name`,\n",
+                " subset value: `\"bravo\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_array_element() {
+        let actual = r#"{"tags": ["x", "y"]}"#;
+        let subset = r#"{"tags": ["y", "z"]}"#;
+        let result = assert_json_contains_as_result!(actual, subset);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("      pointer: `/tags/1`,\n"));
+        assert!(actual.ends_with(" subset value: `\"z\"`"));
+    }
+
+    #[test]
+    fn failure_because_parse() {
+        let actual = r#"{"id": 1"#;
+        let subset = r#"{"id": 1}"#;
+        let result = assert_json_contains_as_result!(actual, subset);
+        assert!(result.unwrap_err().contains("    parse err: `actual is not JSON: "));
+    }
+}
+
+/// Assert a JSON string contains every part of a subset JSON string.
+///
+/// Pseudocode:<br>
+/// (actual ⇒ JSON) contains (subset ⇒ JSON)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let actual = r#"{"id": 1, "name": "alfa"}"#;
+/// let subset = r#"{"name": "alfa"}"#;
+/// assert_json_contains!(actual, subset);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let actual = r#"{"id": 1, "name": "alfa"}"#;
+/// let subset = r#"{"name": "bravo"}"#;
+/// assert_json_contains!(actual, subset);
+/// # });
+/// // assertion failed: `assert_json_contains!(actual, subset)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_json_contains.html
+/// //  actual label: `actual`,
+/// //  actual debug: `\"{\\\"id\\\": 1, \\\"name\\\": \\\"alfa\\\"}\"`,
+/// //  subset label: `subset`,
+/// //  subset debug: `\"{\\\"name\\\": \\\"bravo\\\"}\"`,
+/// //       pointer: `/name`,
+/// //  subset value: `\"bravo\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_json_contains!(actual, subset)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_json_contains.html\n",
+/// #     " actual label: `actual`,\n",
+/// #     " actual debug: `\"{\\\"id\\\": 1, \\\"name\\\": \\\"alfa\\\"}\"`,\n",
+/// #     " subset label: `subset`,\n",
+/// #     " subset debug: `\"{\\\"name\\\": \\\"bravo\\\"}\"`,\n",
+/// #     "      pointer: `/name`,\n",
+/// #     " subset value: `\"bravo\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_json_contains`](macro@crate::assert_json_contains)
+/// * [`assert_json_contains_as_result`](macro@crate::assert_json_contains_as_result)
+/// * [`debug_assert_json_contains`](macro@crate::debug_assert_json_contains)
+///
+#[macro_export]
+macro_rules! assert_json_contains {
+    ($actual:expr, $subset:expr $(,)?) => {{
+        match $crate::assert_json_contains_as_result!($actual, $subset) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($actual:expr, $subset:expr, $($message:tt)+) => {{
+        match $crate::assert_json_contains_as_result!($actual, $subset) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a JSON string contains every part of a subset JSON string.
+///
+/// Pseudocode:<br>
+/// (actual ⇒ JSON) contains (subset ⇒ JSON)
+///
+/// This macro provides the same statements as [`assert_json_contains`](macro.assert_json_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_json_contains`](macro@crate::assert_json_contains)
+/// * [`assert_json_contains_as_result`](macro@crate::assert_json_contains_as_result)
+/// * [`debug_assert_json_contains`](macro@crate::debug_assert_json_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_json_contains {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_json_contains!($($arg)*);
+        }
+    };
+}
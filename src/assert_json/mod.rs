@@ -0,0 +1,148 @@
+//! Assert for JSON strings.
+//!
+//! These macros are behind the crate feature `json`, which uses the crate
+//! `serde_json` to parse each string. To use them, add the feature to your
+//! file `Cargo.toml`:
+//!
+//! ```toml
+//! assertables = { version = "9.2.0", features = ["json"] }
+//! ```
+//!
+//! * [`assert_json_contains!(actual, subset)`](macro@crate::assert_json_contains) ≈ actual JSON contains every part of subset JSON
+//!
+//! The feature also enables a command macro, which runs a command once, then
+//! checks its stdout JSON:
+//!
+//! * [`assert_command_stdout_json_contains!(command, subset)`](macro@crate::assert_command_stdout_json_contains) ≈ command stdout JSON contains every part of subset JSON
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let actual = r#"{"id": 1, "name": "alfa", "tags": ["x", "y"]}"#;
+//! let subset = r#"{"name": "alfa", "tags": ["y"]}"#;
+//! assert_json_contains!(actual, subset);
+//! # }
+//! ```
+
+pub mod assert_json_contains;
+
+use serde_json::Value;
+
+/// Parse a JSON string into a JSON value.
+///
+/// The macros call this, so a user of the macros does not need to depend on
+/// the crate `serde_json`.
+#[doc(hidden)]
+pub fn parse(s: &str) -> Result<Value, serde_json::Error> {
+    serde_json::from_str(s)
+}
+
+/// Find the first part of a subset JSON value that an actual JSON value does
+/// not contain.
+///
+/// * An object contains a subset object when each subset key is in the object,
+///   and the object value contains the subset value. Extra keys are ignored.
+///
+/// * An array contains a subset array when each subset element is contained
+///   by at least one array element, in any order.
+///
+/// * Any other value contains a subset value when they are equal.
+///
+/// If the actual value contains the subset value, then return `None`.
+/// Otherwise, return the JSON pointer of the first part that is not met,
+/// and the subset value at that pointer. For an array, the pointer ends
+/// with the index of the element in the subset, because there is no
+/// single matching element in the actual.
+#[doc(hidden)]
+pub fn json_contains_pointer(actual: &Value, subset: &Value) -> Option<(String, Value)> {
+    fn find(actual: &Value, subset: &Value, pointer: &mut String) -> Option<(String, Value)> {
+        match (actual, subset) {
+            (Value::Object(actual_map), Value::Object(subset_map)) => {
+                for (key, subset_value) in subset_map {
+                    let len = pointer.len();
+                    pointer.push('/');
+                    pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                    let found = match actual_map.get(key) {
+                        Some(actual_value) => find(actual_value, subset_value, pointer),
+                        None => Some((pointer.clone(), subset_value.clone())),
+                    };
+                    if found.is_some() {
+                        return found;
+                    }
+                    pointer.truncate(len);
+                }
+                None
+            }
+            (Value::Array(actual_vec), Value::Array(subset_vec)) => {
+                for (index, subset_value) in subset_vec.iter().enumerate() {
+                    let contained = actual_vec
+                        .iter()
+                        .any(|actual_value| find(actual_value, subset_value, &mut String::new()).is_none());
+                    if !contained {
+                        return Some((format!("{}/{}", pointer, index), subset_value.clone()));
+                    }
+                }
+                None
+            }
+            _ => {
+                if actual == subset {
+                    None
+                } else {
+                    Some((pointer.clone(), subset.clone()))
+                }
+            }
+        }
+    }
+    find(actual, subset, &mut String::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::json_contains_pointer;
+    use serde_json::json;
+
+    #[test]
+    fn contains_with_extra_keys() {
+        let actual = json!({"a": 1, "b": {"c": 2, "d": 3}});
+        let subset = json!({"b": {"c": 2}});
+        assert_eq!(json_contains_pointer(&actual, &subset), None);
+    }
+
+    #[test]
+    fn contains_with_array_in_any_order() {
+        let actual = json!([{"id": 1, "x": true}, {"id": 2}]);
+        let subset = json!([{"id": 2}, {"id": 1}]);
+        assert_eq!(json_contains_pointer(&actual, &subset), None);
+    }
+
+    #[test]
+    fn not_contains_because_missing_key() {
+        let actual = json!({"a": {"b": 1}});
+        let subset = json!({"a": {"c": 1}});
+        assert_eq!(json_contains_pointer(&actual, &subset), Some((String::from("/a/c"), json!(1))));
+    }
+
+    #[test]
+    fn not_contains_because_array_element() {
+        let actual = json!({"tags": ["x", "y"]});
+        let subset = json!({"tags": ["y", "z"]});
+        assert_eq!(json_contains_pointer(&actual, &subset), Some((String::from("/tags/1"), json!("z"))));
+    }
+
+    #[test]
+    fn not_contains_because_type() {
+        let actual = json!({"a": [1]});
+        let subset = json!({"a": {"b": 1}});
+        assert_eq!(json_contains_pointer(&actual, &subset), Some((String::from("/a"), json!({"b": 1}))));
+    }
+
+    #[test]
+    fn not_contains_with_escaped_key() {
+        let actual = json!({});
+        let subset = json!({"a/b~c": 1});
+        assert_eq!(json_contains_pointer(&actual, &subset), Some((String::from("/a~1b~0c"), json!(1))));
+    }
+}
@@ -56,7 +56,7 @@ macro_rules! assert_len_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_len_eq!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_len_eq.html\n",
+                                "{}macro.assert_len_eq.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.len(): `{:?}`,\n",
@@ -64,6 +64,7 @@ macro_rules! assert_len_eq_as_result {
                                 " b debug: `{:?}`\n",
                                 " b.len(): `{:?}`",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             a,
                             a_len,
@@ -48,22 +48,23 @@ macro_rules! assert_len_eq_x_as_result {
         match (&$a, &$b) {
             (a, b) => {
                 let a_len = a.len();
-                if a_len == $b {
-                    Ok((a_len, $b))
+                if a_len == *b {
+                    Ok((a_len, *b))
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_len_eq_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_len_eq_x.html\n",
+                                "{}macro.assert_len_eq_x.html\n",
                                 " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
+                                " a debug: `{}`,\n",
                                 " a.len(): `{:?}`,\n",
                                 " b label: `{}`,\n",
                                 " b debug: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
-                            a,
+                            $crate::assert_len::debug_truncated(a),
                             a_len,
                             stringify!($b),
                             b
@@ -123,6 +124,48 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn eq_with_collections() {
+        use std::collections::{BTreeSet, HashMap, VecDeque};
+        let a: VecDeque<i8> = VecDeque::from([1, 2]);
+        assert_eq!(assert_len_eq_x_as_result!(a, 2), Ok((2, 2)));
+        let a: HashMap<&str, i8> = HashMap::from([("alfa", 1)]);
+        assert_eq!(assert_len_eq_x_as_result!(a, 1), Ok((1, 1)));
+        let a: BTreeSet<i8> = BTreeSet::from([1, 2, 3]);
+        assert_eq!(assert_len_eq_x_as_result!(a, 3), Ok((3, 3)));
+    }
+
+    #[test]
+    fn eq_evaluates_expr_once() {
+        let a = [1, 2];
+        let mut count = 0;
+        let result = assert_len_eq_x_as_result!(a, {
+            count += 1;
+            2
+        });
+        assert_eq!(result, Ok((2, 2)));
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn failure_with_long_collection_is_truncated() {
+        let a: Vec<u8> = vec![0; 100];
+        let b = 1;
+        let result = assert_len_eq_x_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_len_eq_x!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_len_eq_x.html\n",
+                " a label: `a`,\n",
+                " a debug: `[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, …`,\n",
+                " a.len(): `100`,\n",
+                " b label: `b`,\n",
+                " b debug: `1`"
+            )
+        );
+    }
 }
 
 /// Assert a length is equal to an expression.
@@ -48,22 +48,23 @@ macro_rules! assert_len_ge_x_as_result {
         match (&$a, &$b) {
             (a, b) => {
                 let a_len = a.len();
-                if a_len >= $b {
-                    Ok((a_len, $b))
+                if a_len >= *b {
+                    Ok((a_len, *b))
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_len_ge_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_len_ge_x.html\n",
+                                "{}macro.assert_len_ge_x.html\n",
                                 " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
+                                " a debug: `{}`,\n",
                                 " a.len(): `{:?}`,\n",
                                 " b label: `{}`,\n",
                                 " b debug: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
-                            a,
+                            $crate::assert_len::debug_truncated(a),
                             a_len,
                             stringify!($b),
                             b
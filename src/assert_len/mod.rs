@@ -12,7 +12,7 @@
 //! * [`assert_len_gt!(a, b)`](macro@crate::assert_len_gt) ≈ a.len() > b.len()
 //! * [`assert_len_ge!(a, b)`](macro@crate::assert_len_ge) ≈ a.len() ≥ b.len()
 //!
-//! Compare a length with an expression:
+//! Compare a length with an expression, such as a number:
 //!
 //! * [`assert_len_eq_x!(a, expr)`](macro@crate::assert_len_eq_x) ≈ a.len() = expr
 //! * [`assert_len_ne_x!(a, expr)`](macro@crate::assert_len_ne_x) ≈ a.len() ≠ expr
@@ -21,6 +21,13 @@
 //! * [`assert_len_gt_x!(a, expr)`](macro@crate::assert_len_gt_x) ≈ a.len() > expr
 //! * [`assert_len_ge_x!(a, expr)`](macro@crate::assert_len_ge_x) ≈ a.len() ≥ expr
 //!
+//! These work with any type that has a `.len()` method, such as `str`,
+//! `String`, a slice, an array, `Vec`, `VecDeque`, `HashMap`, `BTreeMap`,
+//! `HashSet`, and `BTreeSet`. Each evaluates its arguments once, returns
+//! `(a.len(), expr)` on success, and on failure shows the debug of `a`
+//! truncated to its first 64 chars, so a large collection does not flood
+//! the message.
+//!
 //! # Example
 //!
 //! ```rust
@@ -48,3 +55,16 @@ pub mod assert_len_gt_x;
 pub mod assert_len_le_x;
 pub mod assert_len_lt_x;
 pub mod assert_len_ne_x;
+
+/// Format a value with its debug representation, truncated to its first 64 chars.
+///
+/// If the debug representation is longer, then append `…`.
+#[doc(hidden)]
+pub fn debug_truncated<T: ::std::fmt::Debug + ?Sized>(x: &T) -> String {
+    let s = format!("{:?}", x);
+    if s.chars().count() > 64 {
+        format!("{}…", s.chars().take(64).collect::<String>())
+    } else {
+        s
+    }
+}
@@ -0,0 +1,216 @@
+//! Assert a string has a blank line.
+//!
+//! Pseudocode:<br>
+//! a.lines() any is empty or only whitespace
+//!
+//! A blank line is an empty line, or a line of only whitespace. Lines come from
+//! `str::lines()`, so a trailing newline does not make a blank last line.
+//! This is the inverse of [`assert_no_blank_lines`](macro@crate::assert_no_blank_lines).
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = "alfa\n\ncharlie";
+//! assert_has_blank_line!(a);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_has_blank_line`](macro@crate::assert_has_blank_line)
+//! * [`assert_has_blank_line_as_result`](macro@crate::assert_has_blank_line_as_result)
+//! * [`debug_assert_has_blank_line`](macro@crate::debug_assert_has_blank_line)
+
+/// Assert a string has a blank line.
+///
+/// Pseudocode:<br>
+/// a.lines() any is empty or only whitespace
+///
+/// * If true, return Result `Ok(number)`, which is the line number of the first blank line, starting at 1.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The string can be any type that has a `lines()` method, such as `&str` or `String`.
+///
+/// This macro provides the same statements as [`assert_has_blank_line`](macro.assert_has_blank_line.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_has_blank_line`](macro@crate::assert_has_blank_line)
+/// * [`assert_has_blank_line_as_result`](macro@crate::assert_has_blank_line_as_result)
+/// * [`debug_assert_has_blank_line`](macro@crate::debug_assert_has_blank_line)
+///
+#[macro_export]
+macro_rules! assert_has_blank_line_as_result {
+    ($a:expr $(,)?) => {{
+        match (&$a) {
+            a => {
+                match a.lines().position(|line| line.trim().is_empty()) {
+                    Some(index) => Ok(index + 1),
+                    None => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_has_blank_line!(a)`\n",
+                                    "{}macro.assert_has_blank_line.html\n",
+                                    "    a label: `{}`,\n",
+                                    "    a debug: `{:?}`,\n",
+                                    " line count: `{}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                a.lines().count()
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = "alfa\n\ncharlie";
+        let result = assert_has_blank_line_as_result!(a);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn success_with_whitespace_line() {
+        let a = "alfa\n \t\ncharlie";
+        let result = assert_has_blank_line_as_result!(a);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure() {
+        let a = "alfa\nbravo\n";
+        let result = assert_has_blank_line_as_result!(a);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_has_blank_line!(a)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_has_blank_line.html\n",
+                "    a label: `a`,\n",
+                "    a debug: `\"alfa\\nbravo\\n\"`,\n",
+                " line count: `2`"
+            )
+        );
+    }
+}
+
+/// Assert a string has a blank line.
+///
+/// Pseudocode:<br>
+/// a.lines() any is empty or only whitespace
+///
+/// * If true, return `number`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa\n\ncharlie";
+/// assert_has_blank_line!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa\nbravo\n";
+/// assert_has_blank_line!(a);
+/// # });
+/// // assertion failed: `assert_has_blank_line!(a)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_has_blank_line.html
+/// //     a label: `a`,
+/// //     a debug: `\"alfa\\nbravo\\n\"`,
+/// //  line count: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_has_blank_line!(a)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_has_blank_line.html\n",
+/// #     "    a label: `a`,\n",
+/// #     "    a debug: `\"alfa\\nbravo\\n\"`,\n",
+/// #     " line count: `2`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_has_blank_line`](macro@crate::assert_has_blank_line)
+/// * [`assert_has_blank_line_as_result`](macro@crate::assert_has_blank_line_as_result)
+/// * [`debug_assert_has_blank_line`](macro@crate::debug_assert_has_blank_line)
+///
+#[macro_export]
+macro_rules! assert_has_blank_line {
+    ($a:expr $(,)?) => {{
+        match $crate::assert_has_blank_line_as_result!($a) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $($message:tt)+) => {{
+        match $crate::assert_has_blank_line_as_result!($a) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a string has a blank line.
+///
+/// Pseudocode:<br>
+/// a.lines() any is empty or only whitespace
+///
+/// This macro provides the same statements as [`assert_has_blank_line`](macro.assert_has_blank_line.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_has_blank_line`](macro@crate::assert_has_blank_line)
+/// * [`assert_has_blank_line_as_result`](macro@crate::assert_has_blank_line_as_result)
+/// * [`debug_assert_has_blank_line`](macro@crate::debug_assert_has_blank_line)
+///
+#[macro_export]
+macro_rules! debug_assert_has_blank_line {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_has_blank_line!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,230 @@
+//! Assert a string's line count is less than or equal to an expression.
+//!
+//! Pseudocode:<br>
+//! a.lines().count() ≤ n
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = "alfa\nbravo\ncharlie";
+//! let n = 3;
+//! assert_lines_count_le!(a, n);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_lines_count_le`](macro@crate::assert_lines_count_le)
+//! * [`assert_lines_count_le_as_result`](macro@crate::assert_lines_count_le_as_result)
+//! * [`debug_assert_lines_count_le`](macro@crate::debug_assert_lines_count_le)
+
+/// Assert a string's line count is less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// a.lines().count() ≤ n
+///
+/// * If true, return Result `Ok(count)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message shows the actual count, and the first line and last line.
+///
+/// This macro provides the same statements as [`assert_lines_count_le`](macro.assert_lines_count_le.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_lines_count_le`](macro@crate::assert_lines_count_le)
+/// * [`assert_lines_count_le_as_result`](macro@crate::assert_lines_count_le_as_result)
+/// * [`debug_assert_lines_count_le`](macro@crate::debug_assert_lines_count_le)
+///
+#[macro_export]
+macro_rules! assert_lines_count_le_as_result {
+    ($a:expr, $n:expr $(,)?) => {{
+        match (&$a, &$n) {
+            (a, n) => {
+                let count = a.lines().count();
+                if count <= *n {
+                    Ok(count)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_lines_count_le!(a, n)`\n",
+                                "{}macro.assert_lines_count_le.html\n",
+                                "    a label: `{}`,\n",
+                                "    a debug: `{:?}`,\n",
+                                "    n label: `{}`,\n",
+                                "    n debug: `{:?}`,\n",
+                                "      count: `{:?}`,\n",
+                                " first line: `{:?}`,\n",
+                                "  last line: `{:?}`",
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            a,
+                            stringify!($n),
+                            n,
+                            count,
+                            a.lines().next(),
+                            a.lines().last()
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = "alfa\nbravo\ncharlie";
+        let n = 3;
+        let result = assert_lines_count_le_as_result!(a, n);
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn failure() {
+        let a = "alfa\nbravo\ncharlie";
+        let n = 2;
+        let result = assert_lines_count_le_as_result!(a, n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_lines_count_le!(a, n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_lines_count_le.html\n",
+                "    a label: `a`,\n",
+                "    a debug: `\"alfa\\nbravo\\ncharlie\"`,\n",
+                "    n label: `n`,\n",
+                "    n debug: `2`,\n",
+                "      count: `3`,\n",
+                " first line: `Some(\"alfa\")`,\n",
+                "  last line: `Some(\"charlie\")`",
+            )
+        );
+    }
+}
+
+/// Assert a string's line count is less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// a.lines().count() ≤ n
+///
+/// * If true, return `count`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa\nbravo\ncharlie";
+/// let n = 3;
+/// assert_lines_count_le!(a, n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa\nbravo\ncharlie";
+/// let n = 2;
+/// assert_lines_count_le!(a, n);
+/// # });
+/// // assertion failed: `assert_lines_count_le!(a, n)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_lines_count_le.html
+/// //     a label: `a`,
+/// //     a debug: `\"alfa\\nbravo\\ncharlie\"`,
+/// //     n label: `n`,
+/// //     n debug: `2`,
+/// //       count: `3`,
+/// //  first line: `Some(\"alfa\")`,
+/// //   last line: `Some(\"charlie\")`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_lines_count_le!(a, n)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_lines_count_le.html\n",
+/// #     "    a label: `a`,\n",
+/// #     "    a debug: `\"alfa\\nbravo\\ncharlie\"`,\n",
+/// #     "    n label: `n`,\n",
+/// #     "    n debug: `2`,\n",
+/// #     "      count: `3`,\n",
+/// #     " first line: `Some(\"alfa\")`,\n",
+/// #     "  last line: `Some(\"charlie\")`",
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_lines_count_le`](macro@crate::assert_lines_count_le)
+/// * [`assert_lines_count_le_as_result`](macro@crate::assert_lines_count_le_as_result)
+/// * [`debug_assert_lines_count_le`](macro@crate::debug_assert_lines_count_le)
+///
+#[macro_export]
+macro_rules! assert_lines_count_le {
+    ($a:expr, $n:expr $(,)?) => {{
+        match $crate::assert_lines_count_le_as_result!($a, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_lines_count_le_as_result!($a, $n) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a string's line count is less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// a.lines().count() ≤ n
+///
+/// This macro provides the same statements as [`assert_lines_count_le`](macro.assert_lines_count_le.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_lines_count_le`](macro@crate::assert_lines_count_le)
+/// * [`assert_lines_count_le_as_result`](macro@crate::assert_lines_count_le_as_result)
+/// * [`debug_assert_lines_count_le`](macro@crate::debug_assert_lines_count_le)
+///
+#[macro_export]
+macro_rules! debug_assert_lines_count_le {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_lines_count_le!($($arg)*);
+        }
+    };
+}
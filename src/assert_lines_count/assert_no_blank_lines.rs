@@ -0,0 +1,244 @@
+//! Assert a string has no blank lines.
+//!
+//! Pseudocode:<br>
+//! a.lines() all have a non-whitespace char
+//!
+//! A blank line is an empty line, or a line of only whitespace. Lines come from
+//! `str::lines()`, so a trailing newline does not make a blank last line.
+//! This is useful for formatted reports, where a blank line indicates a
+//! formatting bug.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = "alfa\nbravo\ncharlie";
+//! assert_no_blank_lines!(a);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_no_blank_lines`](macro@crate::assert_no_blank_lines)
+//! * [`assert_no_blank_lines_as_result`](macro@crate::assert_no_blank_lines_as_result)
+//! * [`debug_assert_no_blank_lines`](macro@crate::debug_assert_no_blank_lines)
+
+/// Assert a string has no blank lines.
+///
+/// Pseudocode:<br>
+/// a.lines() all have a non-whitespace char
+///
+/// * If true, return Result `Ok(count)`, which is the line count.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The string can be any type that has a `lines()` method, such as `&str` or `String`.
+///
+/// This macro provides the same statements as [`assert_no_blank_lines`](macro.assert_no_blank_lines.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_no_blank_lines`](macro@crate::assert_no_blank_lines)
+/// * [`assert_no_blank_lines_as_result`](macro@crate::assert_no_blank_lines_as_result)
+/// * [`debug_assert_no_blank_lines`](macro@crate::debug_assert_no_blank_lines)
+///
+#[macro_export]
+macro_rules! assert_no_blank_lines_as_result {
+    ($a:expr $(,)?) => {{
+        match (&$a) {
+            a => {
+                let mut count = 0;
+                let mut blank = None;
+                for (index, line) in a.lines().enumerate() {
+                    count += 1;
+                    if blank.is_none() && line.trim().is_empty() {
+                        blank = Some((index + 1, line));
+                    }
+                }
+                match blank {
+                    None => Ok(count),
+                    Some((number, line)) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_no_blank_lines!(a)`\n",
+                                    "{}macro.assert_no_blank_lines.html\n",
+                                    "           a label: `{}`,\n",
+                                    "           a debug: `{:?}`,\n",
+                                    " blank line number: `{}`,\n",
+                                    "        blank line: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                number,
+                                line
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = "alfa\nbravo\ncharlie";
+        let result = assert_no_blank_lines_as_result!(a);
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn success_with_trailing_newline() {
+        let a = "alfa\nbravo\n";
+        let result = assert_no_blank_lines_as_result!(a);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn success_with_string() {
+        let a = String::from("alfa");
+        let result = assert_no_blank_lines_as_result!(a);
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[test]
+    fn failure() {
+        let a = "alfa\n  \ncharlie";
+        let result = assert_no_blank_lines_as_result!(a);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_no_blank_lines!(a)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_blank_lines.html\n",
+                "           a label: `a`,\n",
+                "           a debug: `\"alfa\\n  \\ncharlie\"`,\n",
+                " blank line number: `2`,\n",
+                "        blank line: `\"  \"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_empty_line() {
+        let a = "alfa\nbravo\n\n";
+        let result = assert_no_blank_lines_as_result!(a);
+        assert!(result.unwrap_err().contains(" blank line number: `3`,\n"));
+    }
+}
+
+/// Assert a string has no blank lines.
+///
+/// Pseudocode:<br>
+/// a.lines() all have a non-whitespace char
+///
+/// * If true, return `count`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa\nbravo\ncharlie";
+/// assert_no_blank_lines!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa\n  \ncharlie";
+/// assert_no_blank_lines!(a);
+/// # });
+/// // assertion failed: `assert_no_blank_lines!(a)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_blank_lines.html
+/// //            a label: `a`,
+/// //            a debug: `\"alfa\\n  \\ncharlie\"`,
+/// //  blank line number: `2`,
+/// //         blank line: `\"  \"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_no_blank_lines!(a)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_blank_lines.html\n",
+/// #     "           a label: `a`,\n",
+/// #     "           a debug: `\"alfa\\n  \\ncharlie\"`,\n",
+/// #     " blank line number: `2`,\n",
+/// #     "        blank line: `\"  \"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_no_blank_lines`](macro@crate::assert_no_blank_lines)
+/// * [`assert_no_blank_lines_as_result`](macro@crate::assert_no_blank_lines_as_result)
+/// * [`debug_assert_no_blank_lines`](macro@crate::debug_assert_no_blank_lines)
+///
+#[macro_export]
+macro_rules! assert_no_blank_lines {
+    ($a:expr $(,)?) => {{
+        match $crate::assert_no_blank_lines_as_result!($a) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $($message:tt)+) => {{
+        match $crate::assert_no_blank_lines_as_result!($a) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a string has no blank lines.
+///
+/// Pseudocode:<br>
+/// a.lines() all have a non-whitespace char
+///
+/// This macro provides the same statements as [`assert_no_blank_lines`](macro.assert_no_blank_lines.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_no_blank_lines`](macro@crate::assert_no_blank_lines)
+/// * [`assert_no_blank_lines_as_result`](macro@crate::assert_no_blank_lines_as_result)
+/// * [`debug_assert_no_blank_lines`](macro@crate::debug_assert_no_blank_lines)
+///
+#[macro_export]
+macro_rules! debug_assert_no_blank_lines {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_no_blank_lines!($($arg)*);
+        }
+    };
+}
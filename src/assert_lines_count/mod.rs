@@ -0,0 +1,40 @@
+//! Assert for comparing line counts of strings, and for checking blank lines.
+//!
+//! These macros help with multi-line strings, such as generated reports,
+//! by counting lines via `str::lines()`. This is distinct from the command
+//! macros, which run a subprocess.
+//!
+//! Compare a line count with an expression:
+//!
+//! * [`assert_lines_count_eq!(a, n)`](macro@crate::assert_lines_count_eq) ≈ a.lines().count() = n
+//! * [`assert_lines_count_lt!(a, n)`](macro@crate::assert_lines_count_lt) ≈ a.lines().count() < n
+//! * [`assert_lines_count_le!(a, n)`](macro@crate::assert_lines_count_le) ≈ a.lines().count() ≤ n
+//! * [`assert_lines_count_gt!(a, n)`](macro@crate::assert_lines_count_gt) ≈ a.lines().count() > n
+//! * [`assert_lines_count_ge!(a, n)`](macro@crate::assert_lines_count_ge) ≈ a.lines().count() ≥ n
+//!
+//! Check for blank lines, which are empty or only whitespace:
+//!
+//! * [`assert_no_blank_lines!(a)`](macro@crate::assert_no_blank_lines) ≈ a.lines() all have a non-whitespace char
+//! * [`assert_has_blank_line!(a)`](macro@crate::assert_has_blank_line) ≈ a.lines() any is empty or only whitespace
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = "alfa\nbravo\ncharlie";
+//! assert_lines_count_eq!(a, 3);
+//! # }
+//! ```
+
+// Compare expression
+pub mod assert_lines_count_eq;
+pub mod assert_lines_count_ge;
+pub mod assert_lines_count_gt;
+pub mod assert_lines_count_le;
+pub mod assert_lines_count_lt;
+
+// Blank lines
+pub mod assert_has_blank_line;
+pub mod assert_no_blank_lines;
@@ -0,0 +1,294 @@
+//! Assert a container value at a key is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (container.get(key) ⇒ Some(value) ⇒ value) = expected
+//!
+//! The container is anything with a `get(key)` method that returns an
+//! `Option` of a reference, such as `HashMap`, `BTreeMap`, `Vec`, a slice,
+//! or a `serde_json::Value` with a string key or an index.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::collections::BTreeMap;
+//!
+//! # fn main() {
+//! let a: BTreeMap<String, i8> = BTreeMap::from([(String::from("alfa"), 1)]);
+//! assert_get_eq!(a, "alfa", 1);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_get_eq`](macro@crate::assert_get_eq)
+//! * [`assert_get_eq_as_result`](macro@crate::assert_get_eq_as_result)
+//! * [`debug_assert_get_eq`](macro@crate::debug_assert_get_eq)
+
+/// Assert a container value at a key is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (container.get(key) ⇒ Some(value) ⇒ value) = expected
+///
+/// * If true, return Result `Ok(value)`, where `value` is a reference to the found value.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message distinguishes a key that is absent from a value that is not equal.
+///
+/// This macro provides the same statements as [`assert_get_eq`](macro.assert_get_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_get_eq`](macro@crate::assert_get_eq)
+/// * [`assert_get_eq_as_result`](macro@crate::assert_get_eq_as_result)
+/// * [`debug_assert_get_eq`](macro@crate::debug_assert_get_eq)
+///
+#[macro_export]
+macro_rules! assert_get_eq_as_result {
+    ($container:expr, $key:expr, $expected:expr $(,)?) => {{
+        match (&$container, &$key, &$expected) {
+            (container, key, expected) => {
+                match container.get(*key) {
+                    Some(value) => {
+                        if value == expected {
+                            Ok(value)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_get_eq!(container, key, expected)`\n",
+                                        "{}macro.assert_get_eq.html\n",
+                                        " container label: `{}`,\n",
+                                        " container debug: `{:?}`,\n",
+                                        "       key label: `{}`,\n",
+                                        "       key debug: `{:?}`,\n",
+                                        "  expected label: `{}`,\n",
+                                        "  expected debug: `{:?}`,\n",
+                                        "     value debug: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($container),
+                                    container,
+                                    stringify!($key),
+                                    key,
+                                    stringify!($expected),
+                                    expected,
+                                    value
+                                )
+                            )
+                        }
+                    },
+                    None => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_get_eq!(container, key, expected)`\n",
+                                    "{}macro.assert_get_eq.html\n",
+                                    " container label: `{}`,\n",
+                                    " container debug: `{:?}`,\n",
+                                    "       key label: `{}`,\n",
+                                    "       key debug: `{:?}`,\n",
+                                    "  expected label: `{}`,\n",
+                                    "  expected debug: `{:?}`,\n",
+                                    "         outcome: `key is absent`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($container),
+                                container,
+                                stringify!($key),
+                                key,
+                                stringify!($expected),
+                                expected
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn success_with_map() {
+        let a: BTreeMap<String, i8> = BTreeMap::from([(String::from("alfa"), 1)]);
+        let result = assert_get_eq_as_result!(a, "alfa", 1);
+        assert_eq!(result.unwrap(), &1);
+    }
+
+    #[test]
+    fn success_with_vec() {
+        let a = vec![1, 2, 3];
+        let result = assert_get_eq_as_result!(a, 1, 2);
+        assert_eq!(result.unwrap(), &2);
+    }
+
+    #[test]
+    fn success_with_json() {
+        let a = serde_json::json!({"alfa": [1, 2]});
+        let result = assert_get_eq_as_result!(a, "alfa", serde_json::json!([1, 2]));
+        assert_eq!(result.unwrap(), &serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn failure_because_value_is_not_equal() {
+        let a: BTreeMap<String, i8> = BTreeMap::from([(String::from("alfa"), 1)]);
+        let result = assert_get_eq_as_result!(a, "alfa", 2);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_get_eq!(container, key, expected)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_get_eq.html\n",
+                " container label: `a`,\n",
+                " container debug: `{\"alfa\": 1}`,\n",
+                "       key label: `\"alfa\"`,\n",
+                "       key debug: `\"alfa\"`,\n",
+                "  expected label: `2`,\n",
+                "  expected debug: `2`,\n",
+                "     value debug: `1`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_key_is_absent() {
+        let a: BTreeMap<String, i8> = BTreeMap::from([(String::from("alfa"), 1)]);
+        let result = assert_get_eq_as_result!(a, "bravo", 1);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_get_eq!(container, key, expected)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_get_eq.html\n",
+                " container label: `a`,\n",
+                " container debug: `{\"alfa\": 1}`,\n",
+                "       key label: `\"bravo\"`,\n",
+                "       key debug: `\"bravo\"`,\n",
+                "  expected label: `1`,\n",
+                "  expected debug: `1`,\n",
+                "         outcome: `key is absent`"
+            )
+        );
+    }
+}
+
+/// Assert a container value at a key is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (container.get(key) ⇒ Some(value) ⇒ value) = expected
+///
+/// * If true, return `value`, where `value` is a reference to the found value.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let a: BTreeMap<String, i8> = BTreeMap::from([(String::from("alfa"), 1)]);
+/// assert_get_eq!(a, "alfa", 1);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: BTreeMap<String, i8> = BTreeMap::from([(String::from("alfa"), 1)]);
+/// assert_get_eq!(a, "alfa", 2);
+/// # });
+/// // assertion failed: `assert_get_eq!(container, key, expected)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_get_eq.html
+/// //  container label: `a`,
+/// //  container debug: `{"alfa": 1}`,
+/// //        key label: `"alfa"`,
+/// //        key debug: `"alfa"`,
+/// //   expected label: `2`,
+/// //   expected debug: `2`,
+/// //      value debug: `1`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_get_eq!(container, key, expected)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_get_eq.html\n",
+/// #     " container label: `a`,\n",
+/// #     " container debug: `{\"alfa\": 1}`,\n",
+/// #     "       key label: `\"alfa\"`,\n",
+/// #     "       key debug: `\"alfa\"`,\n",
+/// #     "  expected label: `2`,\n",
+/// #     "  expected debug: `2`,\n",
+/// #     "     value debug: `1`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_get_eq`](macro@crate::assert_get_eq)
+/// * [`assert_get_eq_as_result`](macro@crate::assert_get_eq_as_result)
+/// * [`debug_assert_get_eq`](macro@crate::debug_assert_get_eq)
+///
+#[macro_export]
+macro_rules! assert_get_eq {
+    ($container:expr, $key:expr, $expected:expr $(,)?) => {{
+        match $crate::assert_get_eq_as_result!($container, $key, $expected) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($container:expr, $key:expr, $expected:expr, $($message:tt)+) => {{
+        match $crate::assert_get_eq_as_result!($container, $key, $expected) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a container value at a key is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (container.get(key) ⇒ Some(value) ⇒ value) = expected
+///
+/// This macro provides the same statements as [`assert_get_eq`](macro.assert_get_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_get_eq`](macro@crate::assert_get_eq)
+/// * [`assert_get_eq_as_result`](macro@crate::assert_get_eq_as_result)
+/// * [`debug_assert_get_eq`](macro@crate::debug_assert_get_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_get_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_get_eq!($($arg)*);
+        }
+    };
+}
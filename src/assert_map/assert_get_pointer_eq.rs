@@ -0,0 +1,280 @@
+//! Assert a container value at a JSON pointer is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (container.pointer(pointer) ⇒ Some(value) ⇒ value) = expected
+//!
+//! The container is anything with a `pointer(path)` method that returns an
+//! `Option` of a reference, such as a `serde_json::Value`. The pointer is a
+//! JSON pointer path, such as `"/alfa/bravo/0"`, as defined by RFC 6901.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use serde_json::json;
+//!
+//! # fn main() {
+//! let a = json!({"alfa": {"bravo": [1, 2]}});
+//! assert_get_pointer_eq!(a, "/alfa/bravo/0", 1);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_get_pointer_eq`](macro@crate::assert_get_pointer_eq)
+//! * [`assert_get_pointer_eq_as_result`](macro@crate::assert_get_pointer_eq_as_result)
+//! * [`debug_assert_get_pointer_eq`](macro@crate::debug_assert_get_pointer_eq)
+
+/// Assert a container value at a JSON pointer is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (container.pointer(pointer) ⇒ Some(value) ⇒ value) = expected
+///
+/// * If true, return Result `Ok(value)`, where `value` is a reference to the found value.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message distinguishes a pointer that is absent from a value that is not equal.
+///
+/// This macro provides the same statements as [`assert_get_pointer_eq`](macro.assert_get_pointer_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_get_pointer_eq`](macro@crate::assert_get_pointer_eq)
+/// * [`assert_get_pointer_eq_as_result`](macro@crate::assert_get_pointer_eq_as_result)
+/// * [`debug_assert_get_pointer_eq`](macro@crate::debug_assert_get_pointer_eq)
+///
+#[macro_export]
+macro_rules! assert_get_pointer_eq_as_result {
+    ($container:expr, $pointer:expr, $expected:expr $(,)?) => {{
+        match (&$container, &$pointer, &$expected) {
+            (container, pointer, expected) => {
+                match container.pointer(pointer) {
+                    Some(value) => {
+                        if value == expected {
+                            Ok(value)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_get_pointer_eq!(container, pointer, expected)`\n",
+                                        "{}macro.assert_get_pointer_eq.html\n",
+                                        " container label: `{}`,\n",
+                                        " container debug: `{:?}`,\n",
+                                        "   pointer label: `{}`,\n",
+                                        "   pointer debug: `{:?}`,\n",
+                                        "  expected label: `{}`,\n",
+                                        "  expected debug: `{:?}`,\n",
+                                        "     value debug: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($container),
+                                    container,
+                                    stringify!($pointer),
+                                    pointer,
+                                    stringify!($expected),
+                                    expected,
+                                    value
+                                )
+                            )
+                        }
+                    },
+                    None => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_get_pointer_eq!(container, pointer, expected)`\n",
+                                    "{}macro.assert_get_pointer_eq.html\n",
+                                    " container label: `{}`,\n",
+                                    " container debug: `{:?}`,\n",
+                                    "   pointer label: `{}`,\n",
+                                    "   pointer debug: `{:?}`,\n",
+                                    "  expected label: `{}`,\n",
+                                    "  expected debug: `{:?}`,\n",
+                                    "         outcome: `pointer is absent`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($container),
+                                container,
+                                stringify!($pointer),
+                                pointer,
+                                stringify!($expected),
+                                expected
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use serde_json::json;
+
+    #[test]
+    fn success() {
+        let a = json!({"alfa": {"bravo": [1, 2]}});
+        let result = assert_get_pointer_eq_as_result!(a, "/alfa/bravo/0", 1);
+        assert_eq!(result.unwrap(), &json!(1));
+    }
+
+    #[test]
+    fn failure_because_value_is_not_equal() {
+        let a = json!({"alfa": {"bravo": [1, 2]}});
+        let result = assert_get_pointer_eq_as_result!(a, "/alfa/bravo/0", 2);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_get_pointer_eq!(container, pointer, expected)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_get_pointer_eq.html\n",
+                " container label: `a`,\n",
+                " container debug: `Object {\"alfa\": Object {\"bravo\": Array [Number(1), Number(2)]}}`,\n",
+                "   pointer label: `\"/alfa/bravo/0\"`,\n",
+                "   pointer debug: `\"/alfa/bravo/0\"`,\n",
+                "  expected label: `2`,\n",
+                "  expected debug: `2`,\n",
+                "     value debug: `Number(1)`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_pointer_is_absent() {
+        let a = json!({"alfa": {"bravo": [1, 2]}});
+        let result = assert_get_pointer_eq_as_result!(a, "/alfa/bravo/9", 1);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_get_pointer_eq!(container, pointer, expected)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_get_pointer_eq.html\n",
+                " container label: `a`,\n",
+                " container debug: `Object {\"alfa\": Object {\"bravo\": Array [Number(1), Number(2)]}}`,\n",
+                "   pointer label: `\"/alfa/bravo/9\"`,\n",
+                "   pointer debug: `\"/alfa/bravo/9\"`,\n",
+                "  expected label: `1`,\n",
+                "  expected debug: `1`,\n",
+                "         outcome: `pointer is absent`"
+            )
+        );
+    }
+}
+
+/// Assert a container value at a JSON pointer is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (container.pointer(pointer) ⇒ Some(value) ⇒ value) = expected
+///
+/// * If true, return `value`, where `value` is a reference to the found value.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use serde_json::json;
+///
+/// # fn main() {
+/// let a = json!({"alfa": {"bravo": [1, 2]}});
+/// assert_get_pointer_eq!(a, "/alfa/bravo/0", 1);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = json!({"alfa": {"bravo": [1, 2]}});
+/// assert_get_pointer_eq!(a, "/alfa/bravo/0", 2);
+/// # });
+/// // assertion failed: `assert_get_pointer_eq!(container, pointer, expected)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_get_pointer_eq.html
+/// //  container label: `a`,
+/// //  container debug: `Object {"alfa": Object {"bravo": Array [Number(1), Number(2)]}}`,
+/// //    pointer label: `"/alfa/bravo/0"`,
+/// //    pointer debug: `"/alfa/bravo/0"`,
+/// //   expected label: `2`,
+/// //   expected debug: `2`,
+/// //      value debug: `Number(1)`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_get_pointer_eq!(container, pointer, expected)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_get_pointer_eq.html\n",
+/// #     " container label: `a`,\n",
+/// #     " container debug: `Object {\"alfa\": Object {\"bravo\": Array [Number(1), Number(2)]}}`,\n",
+/// #     "   pointer label: `\"/alfa/bravo/0\"`,\n",
+/// #     "   pointer debug: `\"/alfa/bravo/0\"`,\n",
+/// #     "  expected label: `2`,\n",
+/// #     "  expected debug: `2`,\n",
+/// #     "     value debug: `Number(1)`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_get_pointer_eq`](macro@crate::assert_get_pointer_eq)
+/// * [`assert_get_pointer_eq_as_result`](macro@crate::assert_get_pointer_eq_as_result)
+/// * [`debug_assert_get_pointer_eq`](macro@crate::debug_assert_get_pointer_eq)
+///
+#[macro_export]
+macro_rules! assert_get_pointer_eq {
+    ($container:expr, $pointer:expr, $expected:expr $(,)?) => {{
+        match $crate::assert_get_pointer_eq_as_result!($container, $pointer, $expected) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($container:expr, $pointer:expr, $expected:expr, $($message:tt)+) => {{
+        match $crate::assert_get_pointer_eq_as_result!($container, $pointer, $expected) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a container value at a JSON pointer is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (container.pointer(pointer) ⇒ Some(value) ⇒ value) = expected
+///
+/// This macro provides the same statements as [`assert_get_pointer_eq`](macro.assert_get_pointer_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_get_pointer_eq`](macro@crate::assert_get_pointer_eq)
+/// * [`assert_get_pointer_eq_as_result`](macro@crate::assert_get_pointer_eq_as_result)
+/// * [`debug_assert_get_pointer_eq`](macro@crate::debug_assert_get_pointer_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_get_pointer_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_get_pointer_eq!($($arg)*);
+        }
+    };
+}
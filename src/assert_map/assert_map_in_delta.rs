@@ -0,0 +1,292 @@
+//! Assert two maps have the same keys and each pair of values is within delta.
+//!
+//! Pseudocode:<br>
+//! a keys = b keys ∧ | a[key] - b[key] | ≤ Δ for each key
+//!
+//! This is the map analog of [`assert_in_delta`](macro@crate::assert_in_delta).
+//! This is useful for comparing numbers that are keyed by name, such as
+//! feature vectors, or metrics, where floating point arithmetic can make each
+//! value a bit different.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::collections::BTreeMap;
+//!
+//! # fn main() {
+//! let a: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.0), ("bravo", 2.0)]);
+//! let b: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.1), ("bravo", 2.1)]);
+//! let delta: f64 = 0.2;
+//! assert_map_in_delta!(a, b, delta);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_map_in_delta`](macro@crate::assert_map_in_delta)
+//! * [`assert_map_in_delta_as_result`](macro@crate::assert_map_in_delta_as_result)
+//! * [`debug_assert_map_in_delta`](macro@crate::debug_assert_map_in_delta)
+
+/// Assert two maps have the same keys and each pair of values is within delta.
+///
+/// Pseudocode:<br>
+/// a keys = b keys ∧ | a[key] - b[key] | ≤ Δ for each key
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)` with the first failing key.
+///
+/// The maps can be any collections whose references iterate `(&K, &V)`
+/// pairs, such as `BTreeMap` or `HashMap`. The keys are compared in key
+/// order, so the first failing key is the same for each run, even for a
+/// `HashMap`. A key that is in one map but not the other is a failure, and
+/// its absent value is shown as `None`.
+///
+/// This macro provides the same statements as [`assert_map_in_delta`](macro.assert_map_in_delta.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_map_in_delta`](macro@crate::assert_map_in_delta)
+/// * [`assert_map_in_delta_as_result`](macro@crate::assert_map_in_delta_as_result)
+/// * [`debug_assert_map_in_delta`](macro@crate::debug_assert_map_in_delta)
+///
+#[macro_export]
+macro_rules! assert_map_in_delta_as_result {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match (&$a, &$b, &$delta) {
+            (a, b, delta) => {
+                match $crate::assert_map::map_in_delta_first_failure(a, b, delta) {
+                    None => Ok(()),
+                    Some((key, a_value, b_value, abs_diff)) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_map_in_delta!(a, b, Δ)`\n",
+                                    "{}macro.assert_map_in_delta.html\n",
+                                    "   a label: `{}`,\n",
+                                    "   a debug: `{:?}`,\n",
+                                    "   b label: `{}`,\n",
+                                    "   b debug: `{:?}`,\n",
+                                    "   Δ label: `{}`,\n",
+                                    "   Δ debug: `{:?}`,\n",
+                                    " first key: `{:?}`,\n",
+                                    "   a value: `{:?}`,\n",
+                                    "   b value: `{:?}`,\n",
+                                    " | a - b |: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b,
+                                stringify!($delta),
+                                delta,
+                                key,
+                                a_value,
+                                b_value,
+                                abs_diff
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn success() {
+        let a: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.0), ("bravo", 2.0)]);
+        let b: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.1), ("bravo", 2.0)]);
+        let delta: f64 = 0.2;
+        let result = assert_map_in_delta_as_result!(a, b, delta);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_hash_map() {
+        let a: HashMap<String, f32> = HashMap::from([(String::from("alfa"), 1.0)]);
+        let b: HashMap<String, f32> = HashMap::from([(String::from("alfa"), 1.5)]);
+        let result = assert_map_in_delta_as_result!(a, b, 0.5);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_because_values_diverge() {
+        let a: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.0), ("bravo", 2.0), ("charlie", 4.0)]);
+        let b: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.0), ("bravo", 3.0), ("charlie", 5.0)]);
+        let delta: f64 = 0.5;
+        let result = assert_map_in_delta_as_result!(a, b, delta);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_map_in_delta!(a, b, Δ)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_map_in_delta.html\n",
+                "   a label: `a`,\n",
+                "   a debug: `{\"alfa\": 1.0, \"bravo\": 2.0, \"charlie\": 4.0}`,\n",
+                "   b label: `b`,\n",
+                "   b debug: `{\"alfa\": 1.0, \"bravo\": 3.0, \"charlie\": 5.0}`,\n",
+                "   Δ label: `delta`,\n",
+                "   Δ debug: `0.5`,\n",
+                " first key: `\"bravo\"`,\n",
+                "   a value: `Some(2.0)`,\n",
+                "   b value: `Some(3.0)`,\n",
+                " | a - b |: `Some(1.0)`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_key_only_in_b() {
+        let a: BTreeMap<&str, f64> = BTreeMap::from([("bravo", 2.0)]);
+        let b: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.0), ("bravo", 2.0)]);
+        let delta: f64 = 0.5;
+        let result = assert_map_in_delta_as_result!(a, b, delta);
+        let actual = result.unwrap_err();
+        assert!(actual.contains(" first key: `\"alfa\"`,\n"));
+        assert!(actual.contains("   a value: `None`,\n"));
+        assert!(actual.contains("   b value: `Some(1.0)`,\n"));
+        assert!(actual.ends_with(" | a - b |: `None`"));
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let a: BTreeMap<&str, f64> = BTreeMap::from([("alfa", f64::NAN)]);
+        let b: BTreeMap<&str, f64> = BTreeMap::from([("alfa", f64::NAN)]);
+        let result = assert_map_in_delta_as_result!(a, b, 1.0);
+        assert!(result.is_err());
+    }
+}
+
+/// Assert two maps have the same keys and each pair of values is within delta.
+///
+/// Pseudocode:<br>
+/// a keys = b keys ∧ | a[key] - b[key] | ≤ Δ for each key
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::collections::BTreeMap;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.0), ("bravo", 2.0)]);
+/// let b: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.1), ("bravo", 2.1)]);
+/// let delta: f64 = 0.2;
+/// assert_map_in_delta!(a, b, delta);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.0), ("bravo", 2.0)]);
+/// let b: BTreeMap<&str, f64> = BTreeMap::from([("alfa", 1.0), ("bravo", 3.0)]);
+/// let delta: f64 = 0.5;
+/// assert_map_in_delta!(a, b, delta);
+/// # });
+/// // assertion failed: `assert_map_in_delta!(a, b, Δ)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_map_in_delta.html
+/// //    a label: `a`,
+/// //    a debug: `{\"alfa\": 1.0, \"bravo\": 2.0}`,
+/// //    b label: `b`,
+/// //    b debug: `{\"alfa\": 1.0, \"bravo\": 3.0}`,
+/// //    Δ label: `delta`,
+/// //    Δ debug: `0.5`,
+/// //  first key: `\"bravo\"`,
+/// //    a value: `Some(2.0)`,
+/// //    b value: `Some(3.0)`,
+/// //  | a - b |: `Some(1.0)`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_map_in_delta!(a, b, Δ)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_map_in_delta.html\n",
+/// #     "   a label: `a`,\n",
+/// #     "   a debug: `{\"alfa\": 1.0, \"bravo\": 2.0}`,\n",
+/// #     "   b label: `b`,\n",
+/// #     "   b debug: `{\"alfa\": 1.0, \"bravo\": 3.0}`,\n",
+/// #     "   Δ label: `delta`,\n",
+/// #     "   Δ debug: `0.5`,\n",
+/// #     " first key: `\"bravo\"`,\n",
+/// #     "   a value: `Some(2.0)`,\n",
+/// #     "   b value: `Some(3.0)`,\n",
+/// #     " | a - b |: `Some(1.0)`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_map_in_delta`](macro@crate::assert_map_in_delta)
+/// * [`assert_map_in_delta_as_result`](macro@crate::assert_map_in_delta_as_result)
+/// * [`debug_assert_map_in_delta`](macro@crate::debug_assert_map_in_delta)
+///
+#[macro_export]
+macro_rules! assert_map_in_delta {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match $crate::assert_map_in_delta_as_result!($a, $b, $delta) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $delta:expr, $($message:tt)+) => {{
+        match $crate::assert_map_in_delta_as_result!($a, $b, $delta) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert two maps have the same keys and each pair of values is within delta.
+///
+/// Pseudocode:<br>
+/// a keys = b keys ∧ | a[key] - b[key] | ≤ Δ for each key
+///
+/// This macro provides the same statements as [`assert_map_in_delta`](macro.assert_map_in_delta.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_map_in_delta`](macro@crate::assert_map_in_delta)
+/// * [`assert_map_in_delta_as_result`](macro@crate::assert_map_in_delta_as_result)
+/// * [`debug_assert_map_in_delta`](macro@crate::debug_assert_map_in_delta)
+///
+#[macro_export]
+macro_rules! debug_assert_map_in_delta {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_map_in_delta!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,73 @@
+//! Assert for values in containers that are looked up by key.
+//!
+//! These macros help with containers that provide a lookup method that
+//! returns an `Option`, such as `HashMap`, `BTreeMap`, `Vec`, or a
+//! `serde_json::Value`.
+//!
+//! * [`assert_get_eq!(container, key, expected)`](macro@crate::assert_get_eq) ≈ (container.get(key) ⇒ Some(value) ⇒ value) = expected
+//!
+//! * [`assert_get_pointer_eq!(container, pointer, expected)`](macro@crate::assert_get_pointer_eq) ≈ (container.pointer(pointer) ⇒ Some(value) ⇒ value) = expected
+//!
+//! * [`assert_map_in_delta!(a, b, delta)`](macro@crate::assert_map_in_delta) ≈ a keys = b keys ∧ | a[key] - b[key] | ≤ Δ for each key
+//!
+//! These macros call the container methods by name, so the crate does not
+//! need a dependency on a JSON crate.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::collections::BTreeMap;
+//!
+//! # fn main() {
+//! let a: BTreeMap<String, i8> = BTreeMap::from([(String::from("alfa"), 1)]);
+//! assert_get_eq!(a, "alfa", 1);
+//! # }
+//! ```
+
+pub mod assert_get_eq;
+pub mod assert_get_pointer_eq;
+
+// Compare maps of numbers
+pub mod assert_map_in_delta;
+
+/// The first key, in key order, where two maps are not within a delta.
+///
+/// Return `None` if both maps have the same keys, and each pair of values is
+/// within the delta. Otherwise, return `Some((key, a_value, b_value, abs_diff))`,
+/// where a value is `None` if its map does not have the key, and the absolute
+/// difference is `None` if either value is absent.
+///
+/// The maps can be any collections that iterate `(&K, &V)` pairs, such as a
+/// `&BTreeMap` or a `&HashMap`. This sorts the keys, so the result is the
+/// same regardless of the iteration order.
+///
+/// This is a helper for [`assert_map_in_delta`](macro@crate::assert_map_in_delta).
+#[doc(hidden)]
+#[allow(clippy::type_complexity)]
+pub fn map_in_delta_first_failure<'a, K, V, A, B>(
+    a: A,
+    b: B,
+    delta: &V,
+) -> Option<(&'a K, Option<V>, Option<V>, Option<V>)>
+where
+    A: IntoIterator<Item = (&'a K, &'a V)>,
+    B: IntoIterator<Item = (&'a K, &'a V)>,
+    K: Ord + 'a,
+    V: Copy + PartialOrd + ::std::ops::Sub<Output = V> + 'a,
+{
+    let a: ::std::collections::BTreeMap<&K, &V> = a.into_iter().collect();
+    let b: ::std::collections::BTreeMap<&K, &V> = b.into_iter().collect();
+    let keys: ::std::collections::BTreeSet<&K> = a.keys().chain(b.keys()).copied().collect();
+    keys.into_iter().find_map(|key| match (a.get(key), b.get(key)) {
+        (Some(&&a_value), Some(&&b_value)) => {
+            let abs_diff = if a_value >= b_value { a_value - b_value } else { b_value - a_value };
+            if abs_diff <= *delta {
+                None
+            } else {
+                Some((key, Some(a_value), Some(b_value), Some(abs_diff)))
+            }
+        }
+        (a_value, b_value) => Some((key, a_value.map(|x| **x), b_value.map(|x| **x), None)),
+    })
+}
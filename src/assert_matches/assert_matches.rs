@@ -8,6 +8,11 @@
 //! # fn main() {
 //! let a = 'a';
 //! assert_matches!(a, 'a'..='z');
+//!
+//! // Return a binding from the matched pattern
+//! let a = Some(1);
+//! let x = assert_matches!(a, Some(x) if x > 0 => x);
+//! assert_eq!(x, 1);
 //! # }
 //! ```
 //!
@@ -25,10 +30,19 @@
 
 /// Assert expression matches a case.
 ///
-/// * If true, return Result `Ok(())`.
+/// * If true, return Result `Ok(())`, or with a `=> returned` expression,
+///   return Result `Ok(returned)`.
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
+/// The optional `=> returned` expression can use the pattern bindings, so
+/// the matched inner value can be returned, such as
+/// `assert_matches_as_result!(a, Some(x) => x)`.
+///
+/// The message shows the expression debug and the pattern, so the
+/// expression type must implement `Debug`. The pattern can be any
+/// pattern, such as an enum variant, with an optional `if` guard.
+///
 /// This macro provides the same statements as [`assert_matches`](macro.assert_matches.html),
 /// except this macro returns a Result, rather than doing a panic.
 ///
@@ -43,20 +57,48 @@
 ///
 #[macro_export]
 macro_rules! assert_matches_as_result {
-    ($($arg:tt)*) => {{
-        if matches!($($arg)*) {
-            Ok(())
-        } else {
-            Err(
-                format!(
-                    concat!(
-                        "assertion failed: `assert_matches!(a)`\n",
-                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches.html\n",
-                        " args: `{}`",
-                    ),
-                    stringify!($($arg)*)
+    ($expression:expr, $pattern:pat $(if $guard:expr)? => $returned:expr $(,)?) => {{
+        match $expression {
+            $pattern $(if $guard)? => Ok($returned),
+            ref a => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_matches!(a, pattern)`\n",
+                            "{}macro.assert_matches.html\n",
+                            "       a label: `{}`,\n",
+                            "       a debug: `{:?}`,\n",
+                            " pattern label: `{}`"
+                        ),
+                        $crate::DOC_URL_BASE,
+                        stringify!($expression),
+                        a,
+                        stringify!($pattern $(if $guard)?)
+                    )
                 )
-            )
+            }
+        }
+    }};
+    ($expression:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {{
+        match $expression {
+            $pattern $(if $guard)? => Ok(()),
+            ref a => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_matches!(a, pattern)`\n",
+                            "{}macro.assert_matches.html\n",
+                            "       a label: `{}`,\n",
+                            "       a debug: `{:?}`,\n",
+                            " pattern label: `{}`"
+                        ),
+                        $crate::DOC_URL_BASE,
+                        stringify!($expression),
+                        a,
+                        stringify!($pattern $(if $guard)?)
+                    )
+                )
+            }
         }
     }};
 }
@@ -80,9 +122,11 @@ mod tests {
         assert_eq!(
             result.unwrap_err(),
             concat!(
-                "assertion failed: `assert_matches!(a)`\n",
+                "assertion failed: `assert_matches!(a, pattern)`\n",
                 "https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches.html\n",
-                " args: `a, 'b'..='z'`",
+                "       a label: `a`,\n",
+                "       a debug: `'a'`,\n",
+                " pattern label: `'b'..='z'`"
             )
         );
     }
@@ -103,17 +147,105 @@ mod tests {
         assert_eq!(
             result.unwrap_err(),
             concat!(
-                "assertion failed: `assert_matches!(a)`\n",
+                "assertion failed: `assert_matches!(a, pattern)`\n",
                 "https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches.html\n",
-                " args: `a, Some(x) if x < 2`",
+                "       a label: `a`,\n",
+                "       a debug: `Some(2)`,\n",
+                " pattern label: `Some(x) if x < 2`"
+            )
+        );
+    }
+
+    //// For any enum variant
+
+    #[derive(Debug)]
+    enum Shape {
+        Circle(f64),
+        Square(String),
+    }
+
+    #[test]
+    fn test_assert_matches_as_result_x_borrows_x_success() {
+        let a = Some(String::from("alfa"));
+        let result = assert_matches_as_result!(a, Some(_));
+        assert_eq!(result, Ok(()));
+        let result = assert_matches_as_result!(a, None);
+        assert!(result.is_err());
+        let result = assert_matches_as_result!(a, Some(ref x) => x.len());
+        assert_eq!(result, Ok(4));
+        assert_eq!(a, Some(String::from("alfa")));
+    }
+
+    #[test]
+    fn test_assert_matches_as_result_x_enum_x_success() {
+        let a = Shape::Square(String::from("alfa"));
+        let result = assert_matches_as_result!(a, Shape::Square(name) if name.len() == 4);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[derive(Debug)]
+    enum Event {
+        Created { id: u32, name: String },
+        Deleted { id: u32 },
+    }
+
+    #[test]
+    fn test_assert_matches_as_result_x_returned_x_success() {
+        let a = Event::Created { id: 7, name: String::from("alfa") };
+        let result = assert_matches_as_result!(a, Event::Created { id, .. } => id);
+        assert_eq!(result, Ok(7));
+    }
+
+    #[test]
+    fn test_assert_matches_as_result_x_returned_with_guard_x_success() {
+        let a = Event::Created { id: 7, name: String::from("alfa") };
+        let result = assert_matches_as_result!(a, Event::Created { name, .. } if name.starts_with('a') => name);
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn test_assert_matches_as_result_x_returned_other_variant_x_success() {
+        let a = Event::Deleted { id: 7 };
+        let result = assert_matches_as_result!(a, Event::Deleted { id } => id);
+        assert_eq!(result, Ok(7));
+    }
+
+    #[test]
+    fn test_assert_matches_as_result_x_returned_x_failure() {
+        let a = Event::Deleted { id: 7 };
+        let result = assert_matches_as_result!(a, Event::Created { id, .. } => id);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_matches!(a, pattern)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches.html\n",
+                "       a label: `a`,\n",
+                "       a debug: `Deleted { id: 7 }`,\n",
+                " pattern label: `Event::Created { id, .. }`"
+            )
+        );
+    }
+
+    #[test]
+    fn test_assert_matches_as_result_x_enum_x_failure() {
+        let a = Shape::Circle(1.5);
+        let result = assert_matches_as_result!(a, Shape::Square(_) | Shape::Circle(0.0));
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_matches!(a, pattern)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches.html\n",
+                "       a label: `a`,\n",
+                "       a debug: `Circle(1.5)`,\n",
+                " pattern label: `Shape::Square(_) | Shape::Circle(0.0)`"
             )
         );
     }
 }
 
-/// Assert expression is Some.
+/// Assert expression matches a case.
 ///
-/// * If true, return `()`.
+/// * If true, return `()`, or with a `=> returned` expression, return `returned`.
 ///
 /// * Otherwise, call [`panic!`] with a message and the values of the
 ///   expressions with their debug representations.
@@ -133,14 +265,18 @@ mod tests {
 /// let a = 'a';
 /// assert_matches!(a, 'b'..='z');
 /// # });
-/// // assertion failed: `assert_matches!(a)`
+/// // assertion failed: `assert_matches!(a, pattern)`
 /// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches.html
-/// //  args: `a, 'b'..='z'`
+/// //        a label: `a`,
+/// //        a debug: `'a'`,
+/// //  pattern label: `'b'..='z'`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let expect = concat!(
-/// #     "assertion failed: `assert_matches!(a)`\n",
+/// #     "assertion failed: `assert_matches!(a, pattern)`\n",
 /// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches.html\n",
-/// #     " args: `a, 'b'..='z'`",
+/// #     "       a label: `a`,\n",
+/// #     "       a debug: `'a'`,\n",
+/// #     " pattern label: `'b'..='z'`"
 /// # );
 /// # assert_eq!(actual, expect);
 /// # }
@@ -154,6 +290,18 @@ mod tests {
 ///
 #[macro_export]
 macro_rules! assert_matches {
+    ($expression:expr, $pattern:pat $(if $guard:expr)? => $returned:expr $(,)?) => {{
+        match $crate::assert_matches_as_result!($expression, $pattern $(if $guard)? => $returned) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($expression:expr, $pattern:pat $(if $guard:expr)? => $returned:expr, $($message:tt)+) => {{
+        match $crate::assert_matches_as_result!($expression, $pattern $(if $guard)? => $returned) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
     ($expression:expr, $pattern:pat if $guard:expr $(,)?) => {{
         match $crate::assert_matches_as_result!($expression, $pattern if $guard) {
             Ok(()) => (),
@@ -173,14 +321,14 @@ macro_rules! assert_matches {
         }
     }};
     ($expression:expr, $pattern:pat, $($message:tt)+) => {{
-        match $crate::assert_matches_as_result!($expression, $pattern if $guard) {
+        match $crate::assert_matches_as_result!($expression, $pattern) {
             Ok(()) => (),
             Err(_err) => panic!("{}", $($message)+),
         }
     }};
 }
 
-/// Assert expression is Some.
+/// Assert expression matches a case.
 ///
 /// This macro provides the same statements as [`assert_matches`](macro.assert_matches.html),
 /// except this macro's statements are only enabled in non-optimized
@@ -0,0 +1,241 @@
+//! Assert a string matches a glob pattern.
+//!
+//! Pseudocode:<br>
+//! string matches glob pattern
+//!
+//! The glob pattern `*` matches any sequence of chars, including none, and
+//! the glob pattern `?` matches exactly one char. Any other char matches
+//! itself. The pattern matches the whole string.
+//!
+//! This is useful for file-name-like strings, and for command output that
+//! has a variable middle, when a full regular expression is more than needed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let string = "alfa.txt";
+//! let pattern = "alf?.*";
+//! assert_matches_glob!(string, pattern);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_matches_glob`](macro@crate::assert_matches_glob)
+//! * [`assert_matches_glob_as_result`](macro@crate::assert_matches_glob_as_result)
+//! * [`debug_assert_matches_glob`](macro@crate::debug_assert_matches_glob)
+
+/// Assert a string matches a glob pattern.
+///
+/// Pseudocode:<br>
+/// string matches glob pattern
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The string and the pattern can be any type that implements `AsRef<str>`,
+/// such as `&str` or `String`.
+///
+/// This macro provides the same statements as [`assert_matches_glob`](macro.assert_matches_glob.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_matches_glob`](macro@crate::assert_matches_glob)
+/// * [`assert_matches_glob_as_result`](macro@crate::assert_matches_glob_as_result)
+/// * [`debug_assert_matches_glob`](macro@crate::debug_assert_matches_glob)
+///
+#[macro_export]
+macro_rules! assert_matches_glob_as_result {
+    ($string:expr, $pattern:expr $(,)?) => {{
+        match (&$string, &$pattern) {
+            (string, pattern) => {
+                if $crate::assert_matches::glob_match(
+                    AsRef::<str>::as_ref(string),
+                    AsRef::<str>::as_ref(pattern),
+                ) {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_matches_glob!(string, pattern)`\n",
+                                "{}macro.assert_matches_glob.html\n",
+                                "  string label: `{}`,\n",
+                                "  string debug: `{:?}`,\n",
+                                " pattern label: `{}`,\n",
+                                " pattern debug: `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($string),
+                            string,
+                            stringify!($pattern),
+                            pattern
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let string = "alfa.txt";
+        let pattern = "*.txt";
+        let result = assert_matches_glob_as_result!(string, pattern);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_strings() {
+        let string = String::from("alfa.txt");
+        let pattern = String::from("alf?.*");
+        let result = assert_matches_glob_as_result!(string, pattern);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let string = "alfa.txt";
+        let pattern = "*.rs";
+        let result = assert_matches_glob_as_result!(string, pattern);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_matches_glob!(string, pattern)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches_glob.html\n",
+                "  string label: `string`,\n",
+                "  string debug: `\"alfa.txt\"`,\n",
+                " pattern label: `pattern`,\n",
+                " pattern debug: `\"*.rs\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_empty_pattern() {
+        let string = "alfa";
+        let pattern = "";
+        let result = assert_matches_glob_as_result!(string, pattern);
+        assert!(result.is_err());
+    }
+}
+
+/// Assert a string matches a glob pattern.
+///
+/// Pseudocode:<br>
+/// string matches glob pattern
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let string = "alfa.txt";
+/// let pattern = "alf?.*";
+/// assert_matches_glob!(string, pattern);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let string = "alfa.txt";
+/// let pattern = "*.rs";
+/// assert_matches_glob!(string, pattern);
+/// # });
+/// // assertion failed: `assert_matches_glob!(string, pattern)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches_glob.html
+/// //   string label: `string`,
+/// //   string debug: `\"alfa.txt\"`,
+/// //  pattern label: `pattern`,
+/// //  pattern debug: `\"*.rs\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_matches_glob!(string, pattern)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_matches_glob.html\n",
+/// #     "  string label: `string`,\n",
+/// #     "  string debug: `\"alfa.txt\"`,\n",
+/// #     " pattern label: `pattern`,\n",
+/// #     " pattern debug: `\"*.rs\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_matches_glob`](macro@crate::assert_matches_glob)
+/// * [`assert_matches_glob_as_result`](macro@crate::assert_matches_glob_as_result)
+/// * [`debug_assert_matches_glob`](macro@crate::debug_assert_matches_glob)
+///
+#[macro_export]
+macro_rules! assert_matches_glob {
+    ($string:expr, $pattern:expr $(,)?) => {{
+        match $crate::assert_matches_glob_as_result!($string, $pattern) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($string:expr, $pattern:expr, $($message:tt)+) => {{
+        match $crate::assert_matches_glob_as_result!($string, $pattern) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a string matches a glob pattern.
+///
+/// Pseudocode:<br>
+/// string matches glob pattern
+///
+/// This macro provides the same statements as [`assert_matches_glob`](macro.assert_matches_glob.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_matches_glob`](macro@crate::assert_matches_glob)
+/// * [`assert_matches_glob_as_result`](macro@crate::assert_matches_glob_as_result)
+/// * [`debug_assert_matches_glob`](macro@crate::debug_assert_matches_glob)
+///
+#[macro_export]
+macro_rules! debug_assert_matches_glob {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_matches_glob!($($arg)*);
+        }
+    };
+}
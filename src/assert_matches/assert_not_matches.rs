@@ -51,9 +51,10 @@ macro_rules! assert_not_matches_as_result {
                 format!(
                     concat!(
                         "assertion failed: `assert_not_matches!(a)`\n",
-                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_not_matches.html\n",
+                        "{}macro.assert_not_matches.html\n",
                         " args: `{}`",
                     ),
+                    $crate::DOC_URL_BASE,
                     stringify!($($arg)*)
                 )
             )
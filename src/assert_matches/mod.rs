@@ -4,8 +4,14 @@
 //!
 //! * [`assert_matches!(a, b)`](macro@crate::assert_matches) ≈ match(a) { b }
 //!
+//! * [`assert_matches!(a, b => x)`](macro@crate::assert_matches) ≈ match(a) { b => x }
+//!
 //! * [`assert_not_matches!(a, b)`](macro@crate::assert_matches) ≈ match(a) { b }
 //!
+//! Compare a string with a glob pattern, where `*` matches any chars and `?` matches one char:
+//!
+//! * [`assert_matches_glob!(string, pattern)`](macro@crate::assert_matches_glob) ≈ string matches glob pattern
+//!
 //! # Example
 //!
 //! ```rust
@@ -19,3 +25,134 @@
 
 pub mod assert_matches;
 pub mod assert_not_matches;
+
+// Glob
+pub mod assert_matches_glob;
+
+/// Match a string with a glob pattern.
+///
+/// * `*` matches any sequence of chars, including none.
+///
+/// * `?` matches exactly one char.
+///
+/// * Any other char matches itself. There is no escape, and no char class.
+///
+/// The pattern matches the whole string, so an empty pattern matches only an
+/// empty string. This uses a greedy scan that backtracks to the last `*`,
+/// so the time is at most the string length times the pattern length.
+///
+/// # Example
+///
+/// ```rust
+/// use assertables::assert_matches::glob_match;
+///
+/// # fn main() {
+/// assert!(glob_match("alfa.txt", "*.txt"));
+/// assert!(glob_match("alfa.txt", "alf?.*"));
+/// assert!(!glob_match("alfa.txt", "*.rs"));
+/// # }
+/// ```
+#[doc(hidden)]
+pub fn glob_match(string: &str, pattern: &str) -> bool {
+    let s: Vec<char> = string.chars().collect();
+    let p: Vec<char> = pattern.chars().collect();
+    let (mut si, mut pi) = (0, 0);
+    // The pattern index after the last `*`, and the string index that it matched up to.
+    let mut backtrack: Option<(usize, usize)> = None;
+    while si < s.len() {
+        if pi < p.len() && p[pi] == '*' {
+            pi += 1;
+            backtrack = Some((pi, si));
+        } else if pi < p.len() && (p[pi] == '?' || p[pi] == s[si]) {
+            pi += 1;
+            si += 1;
+        } else if let Some((star_pi, star_si)) = backtrack {
+            pi = star_pi;
+            si = star_si + 1;
+            backtrack = Some((star_pi, si));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glob_match;
+
+    #[test]
+    fn literal() {
+        assert!(glob_match("alfa", "alfa"));
+        assert!(!glob_match("alfa", "alf"));
+        assert!(!glob_match("alf", "alfa"));
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert!(glob_match("", ""));
+        assert!(!glob_match("alfa", ""));
+    }
+
+    #[test]
+    fn empty_string() {
+        assert!(glob_match("", "*"));
+        assert!(glob_match("", "**"));
+        assert!(!glob_match("", "?"));
+        assert!(!glob_match("", "a"));
+    }
+
+    #[test]
+    fn question_mark_matches_exactly_one_char() {
+        assert!(glob_match("alfa", "al?a"));
+        assert!(glob_match("alfa", "????"));
+        assert!(!glob_match("alfa", "???"));
+        assert!(!glob_match("alfa", "?????"));
+        assert!(glob_match("αβγ", "α?γ"));
+    }
+
+    #[test]
+    fn trailing_star() {
+        assert!(glob_match("alfa", "al*"));
+        assert!(glob_match("al", "al*"));
+        assert!(!glob_match("a", "al*"));
+    }
+
+    #[test]
+    fn leading_star() {
+        assert!(glob_match("alfa.txt", "*.txt"));
+        assert!(glob_match(".txt", "*.txt"));
+        assert!(!glob_match("alfa.txt.bak", "*.txt"));
+    }
+
+    #[test]
+    fn consecutive_stars() {
+        assert!(glob_match("alfa", "**"));
+        assert!(glob_match("alfa", "a**a"));
+        assert!(glob_match("alfa", "**l**"));
+        assert!(!glob_match("alfa", "b**"));
+    }
+
+    #[test]
+    fn backtracking() {
+        assert!(glob_match("aab", "*ab"));
+        assert!(glob_match("abxbc", "a*bc"));
+        assert!(glob_match("alfa bravo charlie", "*a*a*"));
+        assert!(glob_match("mississippi", "m*iss*ppi"));
+        assert!(!glob_match("mississippi", "m*iss*ppx"));
+        assert!(!glob_match("abc", "*c*c"));
+    }
+
+    #[test]
+    fn star_and_question_mark() {
+        assert!(glob_match("alfa", "*?"));
+        assert!(glob_match("alfa", "?*?"));
+        assert!(!glob_match("a", "?*?"));
+    }
+
+    #[test]
+    fn literal_star_in_string() {
+        assert!(glob_match("a*b", "a*b"));
+        assert!(glob_match("a*b", "a?b"));
+    }
+}
@@ -19,8 +19,7 @@
 ///
 /// * If true, return Result `Ok(())`.
 ///
-/// * When false, return [`Err`] with a message and the values of the
-///   expressions with their debug representations.
+/// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro provides the same statements as [`assert_`](macro.assert_.html),
 /// except this macro returns a Result, rather than doing a panic.
@@ -48,12 +47,13 @@ macro_rules! assert_ne_as_result {
                     Err(format!(
                         concat!(
                             "assertion failed: `assert_ne!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ne.html\n",
+                            "{}macro.assert_ne.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`"
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
                         a,
                         stringify!($b),
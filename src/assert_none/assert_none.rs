@@ -55,10 +55,11 @@ macro_rules! assert_none_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_none!(a)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_none.html\n",
+                                    "{}macro.assert_none.html\n",
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`",
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a),
                                 a
                             )
@@ -49,10 +49,11 @@ macro_rules! assert_ok_as_result {
             _ => Err(format!(
                 concat!(
                     "assertion failed: `assert_ok!(a)`\n",
-                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok.html\n",
+                    "{}macro.assert_ok.html\n",
                     " a label: `{}`,\n",
                     " a debug: `{:?}`",
                 ),
+                $crate::DOC_URL_BASE,
                 stringify!($a),
                 $a
             )),
@@ -0,0 +1,272 @@
+//! Assert an expression is Ok and its value's Display is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Ok(a1) ⇒ a1.to_string()) = b
+//!
+//! This is useful for types where the string representation is the contract,
+//! such as formatted decimals, and where `PartialEq` is not suitable.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<f32, String> = Ok(1.5);
+//! assert_ok_display_eq_x!(a, "1.5");
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_display_eq_x`](macro@crate::assert_ok_display_eq_x)
+//! * [`assert_ok_display_eq_x_as_result`](macro@crate::assert_ok_display_eq_x_as_result)
+//! * [`debug_assert_ok_display_eq_x`](macro@crate::debug_assert_ok_display_eq_x)
+
+/// Assert an expression is Ok and its value's Display is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1.to_string()) = b
+///
+/// * If true, return Result `Ok(a1.to_string())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The Ok value needs `Display`, rather than `PartialEq` or `Debug`.
+/// The message shows the Ok value with its `Display`, and shows only
+/// the variant name when the expression is Err.
+///
+/// This macro provides the same statements as [`assert_ok_display_eq_x`](macro.assert_ok_display_eq_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_display_eq_x`](macro@crate::assert_ok_display_eq_x)
+/// * [`assert_ok_display_eq_x_as_result`](macro@crate::assert_ok_display_eq_x_as_result)
+/// * [`debug_assert_ok_display_eq_x`](macro@crate::debug_assert_ok_display_eq_x)
+///
+#[macro_export]
+macro_rules! assert_ok_display_eq_x_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                match (a) {
+                    Ok(a1) => {
+                        let a_string = a1.to_string();
+                        if a_string == *b {
+                            Ok(a_string)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_ok_display_eq_x!(a, b)`\n",
+                                        "{}macro.assert_ok_display_eq_x.html\n",
+                                        "         a label: `{}`,\n",
+                                        " a inner display: `{}`,\n",
+                                        "         b label: `{}`,\n",
+                                        "         b debug: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a),
+                                    a_string,
+                                    stringify!($b),
+                                    b
+                                )
+                            )
+                        }
+                    },
+                    Err(_) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_ok_display_eq_x!(a, b)`\n",
+                                    "{}macro.assert_ok_display_eq_x.html\n",
+                                    "         a label: `{}`,\n",
+                                    "       a variant: `Err`,\n",
+                                    "         b label: `{}`,\n",
+                                    "         b debug: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                stringify!($b),
+                                b
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    struct Money(i64);
+
+    impl fmt::Display for Money {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}.{:02}", self.0 / 100, self.0 % 100)
+        }
+    }
+
+    #[test]
+    fn success() {
+        let a: Result<Money, ()> = Ok(Money(1250));
+        let result = assert_ok_display_eq_x_as_result!(a, "12.50");
+        assert_eq!(result.unwrap(), "12.50");
+    }
+
+    #[test]
+    fn success_with_string_expr() {
+        let a: Result<Money, ()> = Ok(Money(1250));
+        let b = String::from("12.50");
+        let result = assert_ok_display_eq_x_as_result!(a, b);
+        assert_eq!(result.unwrap(), "12.50");
+    }
+
+    #[test]
+    fn failure_because_display_is_not_equal() {
+        let a: Result<Money, ()> = Ok(Money(1250));
+        let result = assert_ok_display_eq_x_as_result!(a, "12.5");
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_display_eq_x!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_display_eq_x.html\n",
+                "         a label: `a`,\n",
+                " a inner display: `12.50`,\n",
+                "         b label: `\"12.5\"`,\n",
+                "         b debug: `\"12.5\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_not_ok() {
+        let a: Result<Money, ()> = Err(());
+        let result = assert_ok_display_eq_x_as_result!(a, "12.50");
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_display_eq_x!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_display_eq_x.html\n",
+                "         a label: `a`,\n",
+                "       a variant: `Err`,\n",
+                "         b label: `\"12.50\"`,\n",
+                "         b debug: `\"12.50\"`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Ok and its value's Display is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1.to_string()) = b
+///
+/// * If true, return `a1.to_string()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their display or debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<f32, String> = Ok(1.5);
+/// let s = assert_ok_display_eq_x!(a, "1.5");
+/// assert_eq!(s, "1.5");
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<f32, String> = Ok(1.5);
+/// assert_ok_display_eq_x!(a, "1.50");
+/// # });
+/// // assertion failed: `assert_ok_display_eq_x!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_display_eq_x.html
+/// //          a label: `a`,
+/// //  a inner display: `1.5`,
+/// //          b label: `\"1.50\"`,
+/// //          b debug: `\"1.50\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ok_display_eq_x!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_display_eq_x.html\n",
+/// #     "         a label: `a`,\n",
+/// #     " a inner display: `1.5`,\n",
+/// #     "         b label: `\"1.50\"`,\n",
+/// #     "         b debug: `\"1.50\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ok_display_eq_x`](macro@crate::assert_ok_display_eq_x)
+/// * [`assert_ok_display_eq_x_as_result`](macro@crate::assert_ok_display_eq_x_as_result)
+/// * [`debug_assert_ok_display_eq_x`](macro@crate::debug_assert_ok_display_eq_x)
+///
+#[macro_export]
+macro_rules! assert_ok_display_eq_x {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_ok_display_eq_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_display_eq_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Ok and its value's Display is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1.to_string()) = b
+///
+/// This macro provides the same statements as [`assert_ok_display_eq_x`](macro.assert_ok_display_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_display_eq_x`](macro@crate::assert_ok_display_eq_x)
+/// * [`assert_ok_display_eq_x_as_result`](macro@crate::assert_ok_display_eq_x_as_result)
+/// * [`debug_assert_ok_display_eq_x`](macro@crate::debug_assert_ok_display_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_display_eq_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ok_display_eq_x!($($arg)*);
+        }
+    };
+}
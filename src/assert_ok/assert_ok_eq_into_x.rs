@@ -0,0 +1,317 @@
+//! Assert an expression is Ok and its value is equal to an expression converted into the Ok type.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Ok(a1) ⇒ a1) = (b ⇒ try_into)
+//!
+//! This is like [`assert_ok_eq_x`](macro@crate::assert_ok_eq_x), except the
+//! expression can be a different type than the Ok value, such as an `i32`
+//! literal compared with an `Ok(u64)`. The macro converts the expression into
+//! the Ok type via `TryInto`, then compares.
+//!
+//! Every `Into` conversion is also a `TryInto` conversion that can not fail,
+//! so this works for widening conversions, such as `u8` into `u64`, and for
+//! fallible conversions, such as `i32` into `u8`. If the conversion fails,
+//! the message says so, rather than reporting the values as unequal.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<u64, String> = Ok(1);
+//! assert_ok_eq_into_x!(a, 1);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_eq_into_x`](macro@crate::assert_ok_eq_into_x)
+//! * [`assert_ok_eq_into_x_as_result`](macro@crate::assert_ok_eq_into_x_as_result)
+//! * [`debug_assert_ok_eq_into_x`](macro@crate::debug_assert_ok_eq_into_x)
+
+/// Assert an expression is Ok and its value is equal to an expression converted into the Ok type.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1) = (b ⇒ try_into)
+///
+/// * If true, return Result `Ok(a1)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The Ok type must implement `PartialEq` and `Debug`. The expression type
+/// must implement `TryInto<Ok type>` and `Debug`, and its conversion error
+/// must implement `Debug`. The expression is evaluated only if `a` is Ok.
+///
+/// This macro provides the same statements as [`assert_ok_eq_into_x`](macro.assert_ok_eq_into_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_eq_into_x`](macro@crate::assert_ok_eq_into_x)
+/// * [`assert_ok_eq_into_x_as_result`](macro@crate::assert_ok_eq_into_x_as_result)
+/// * [`debug_assert_ok_eq_into_x`](macro@crate::debug_assert_ok_eq_into_x)
+///
+#[macro_export]
+macro_rules! assert_ok_eq_into_x_as_result {
+    ($a:expr, $b:expr $(,)?) => {
+        match ($a) {
+            Ok(a1) => {
+                let b = $b;
+                let b_debug = format!("{:?}", b);
+                match $crate::assert_ok::try_into_type_of(&a1, b) {
+                    Ok(b1) => {
+                        if a1 == b1 {
+                            Ok(a1)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_ok_eq_into_x!(a, b)`\n",
+                                        "{}macro.assert_ok_eq_into_x.html\n",
+                                        "     a label: `{}`,\n",
+                                        "     a inner: `{:?}`,\n",
+                                        "     b label: `{}`,\n",
+                                        "     b debug: `{}`,\n",
+                                        "      b into: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a),
+                                    a1,
+                                    stringify!($b),
+                                    b_debug,
+                                    b1
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_ok_eq_into_x!(a, b)`\n",
+                                    "{}macro.assert_ok_eq_into_x.html\n",
+                                    "     a label: `{}`,\n",
+                                    "     a inner: `{:?}`,\n",
+                                    "     b label: `{}`,\n",
+                                    "     b debug: `{}`,\n",
+                                    "  b into err: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a1,
+                                stringify!($b),
+                                b_debug,
+                                err
+                            )
+                        )
+                    }
+                }
+            },
+            a => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_ok_eq_into_x!(a, b)`\n",
+                            "{}macro.assert_ok_eq_into_x.html\n",
+                            "     a label: `{}`,\n",
+                            "     a debug: `{:?}`,\n",
+                            "     b label: `{}`"
+                        ),
+                        $crate::DOC_URL_BASE,
+                        stringify!($a),
+                        a,
+                        stringify!($b)
+                    )
+                )
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: Result<u64, i8> = Ok(1);
+        let b: i32 = 1;
+        let result = assert_ok_eq_into_x_as_result!(a, b);
+        assert_eq!(result.unwrap(), 1_u64);
+    }
+
+    #[test]
+    fn success_with_into() {
+        let a: Result<String, i8> = Ok(String::from("alfa"));
+        let result = assert_ok_eq_into_x_as_result!(a, "alfa");
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn failure_because_ne() {
+        let a: Result<u64, i8> = Ok(1);
+        let b: i32 = 2;
+        let result = assert_ok_eq_into_x_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_eq_into_x!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_eq_into_x.html\n",
+                "     a label: `a`,\n",
+                "     a inner: `1`,\n",
+                "     b label: `b`,\n",
+                "     b debug: `2`,\n",
+                "      b into: `2`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_conversion() {
+        let a: Result<u8, i8> = Ok(1);
+        let b: i32 = -1;
+        let result = assert_ok_eq_into_x_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_eq_into_x!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_eq_into_x.html\n",
+                "     a label: `a`,\n",
+                "     a inner: `1`,\n",
+                "     b label: `b`,\n",
+                "     b debug: `-1`,\n",
+                "  b into err: `TryFromIntError(())`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_not_ok() {
+        let a: Result<u64, i8> = Err(1);
+        let b: i32 = 1;
+        let result = assert_ok_eq_into_x_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_eq_into_x!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_eq_into_x.html\n",
+                "     a label: `a`,\n",
+                "     a debug: `Err(1)`,\n",
+                "     b label: `b`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Ok and its value is equal to an expression converted into the Ok type.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1) = (b ⇒ try_into)
+///
+/// * If true, return `a1`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<u64, String> = Ok(1);
+/// let b: i32 = 1;
+/// assert_ok_eq_into_x!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<u8, String> = Ok(1);
+/// let b: i32 = 256;
+/// assert_ok_eq_into_x!(a, b);
+/// # });
+/// // assertion failed: `assert_ok_eq_into_x!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_eq_into_x.html
+/// //      a label: `a`,
+/// //      a inner: `1`,
+/// //      b label: `b`,
+/// //      b debug: `256`,
+/// //   b into err: `TryFromIntError(())`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ok_eq_into_x!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_eq_into_x.html\n",
+/// #     "     a label: `a`,\n",
+/// #     "     a inner: `1`,\n",
+/// #     "     b label: `b`,\n",
+/// #     "     b debug: `256`,\n",
+/// #     "  b into err: `TryFromIntError(())`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ok_eq_into_x`](macro@crate::assert_ok_eq_into_x)
+/// * [`assert_ok_eq_into_x_as_result`](macro@crate::assert_ok_eq_into_x_as_result)
+/// * [`debug_assert_ok_eq_into_x`](macro@crate::debug_assert_ok_eq_into_x)
+///
+#[macro_export]
+macro_rules! assert_ok_eq_into_x {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_ok_eq_into_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_eq_into_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Ok and its value is equal to an expression converted into the Ok type.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1) = (b ⇒ try_into)
+///
+/// This macro provides the same statements as [`assert_ok_eq_into_x`](macro.assert_ok_eq_into_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_eq_into_x`](macro@crate::assert_ok_eq_into_x)
+/// * [`assert_ok_eq_into_x_as_result`](macro@crate::assert_ok_eq_into_x_as_result)
+/// * [`debug_assert_ok_eq_into_x`](macro@crate::debug_assert_ok_eq_into_x)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_eq_into_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ok_eq_into_x!($($arg)*);
+        }
+    };
+}
@@ -30,6 +30,12 @@
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
+/// The expression `a` is evaluated once. The macro moves the Ok value out,
+/// so the Ok type does not need to be `Copy`, and the caller can keep using
+/// the returned value, such as `let x = assert_ok_eq_x!(parse(s), b);`. To borrow
+/// rather than move, pass a reference, such as `&a`, and the macro returns a
+/// reference to the Ok value.
+///
 /// This macro provides the same statements as [`assert_ok_eq_x`](macro.assert_ok_eq_x.html),
 /// except this macro returns a Result, rather than doing a panic.
 ///
@@ -44,57 +50,93 @@
 ///
 #[macro_export]
 macro_rules! assert_ok_eq_x_as_result {
-    ($a:expr, $b:expr $(,)?) => {
-        match ($a) {
-            Ok(a1) => {
-                if a1 == $b {
-                    Ok(a1)
-                } else {
-                    Err(
-                        format!(
-                            concat!(
-                                "assertion failed: `assert_ok_eq_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_eq_x.html\n",
-                                " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
-                                " a inner: `{:?}`,\n",
-                                " b label: `{}`,\n",
-                                " b debug: `{:?}`",
-                            ),
-                            stringify!($a),
-                            $a,
-                            a1,
-                            stringify!($b),
-                            $b
-                        )
-                    )
+    ($a:expr, $b:expr $(,)?) => {{
+        match ($a, &$b) {
+            (a, b) => {
+                match a {
+                    Ok(a1) if a1 == *b => Ok(a1),
+                    a => {
+                        match &a {
+                            Ok(a1) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_ok_eq_x!(a, b)`\n",
+                                            "{}macro.assert_ok_eq_x.html\n",
+                                            " a label: `{}`,\n",
+                                            " a debug: `{:?}`,\n",
+                                            " a inner: `{:?}`,\n",
+                                            " b label: `{}`,\n",
+                                            " b debug: `{:?}`",
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($a),
+                                        a,
+                                        a1,
+                                        stringify!($b),
+                                        b
+                                    )
+                                )
+                            },
+                            _ => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_ok_eq_x!(a, b)`\n",
+                                            "{}macro.assert_ok_eq_x.html\n",
+                                            " a label: `{}`,\n",
+                                            " a debug: `{:?}`,\n",
+                                            " b label: `{}`,\n",
+                                            " b debug: `{:?}`",
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($a),
+                                        a,
+                                        stringify!($b),
+                                        b
+                                    )
+                                )
+                            }
+                        }
+                    }
                 }
-            },
-            _ => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_ok_eq_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_eq_x.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`",
-                        ),
-                        stringify!($a),
-                        $a,
-                        stringify!($b),
-                        $b
-                    )
-                )
             }
         }
-    };
+    }};
 }
 
 #[cfg(test)]
 mod tests {
 
+    #[test]
+    fn success_returns_owned_value() {
+        let a: Result<String, i8> = Ok(String::from("alfa"));
+        let result = assert_ok_eq_x_as_result!(a, "alfa");
+        let actual: String = result.unwrap();
+        assert_eq!(actual, "alfa");
+    }
+
+    #[test]
+    fn success_with_reference() {
+        let a: Result<String, i8> = Ok(String::from("alfa"));
+        let result = assert_ok_eq_x_as_result!(&a, "alfa");
+        let actual: &String = result.unwrap();
+        assert_eq!(actual, "alfa");
+        assert!(a.is_ok());
+    }
+
+    #[test]
+    fn success_evaluates_a_once() {
+        let mut count = 0;
+        let mut parse = |s: &str| {
+            count += 1;
+            s.parse::<i8>()
+        };
+        let result = assert_ok_eq_x_as_result!(parse("1"), 2);
+        assert!(result.is_err());
+        assert_eq!(count, 1);
+    }
+
     #[test]
     fn test_assert_ok_eq_x_expr_as_result_x_success() {
         let a: Result<i8, i8> = Ok(1);
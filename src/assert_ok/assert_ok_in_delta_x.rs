@@ -0,0 +1,315 @@
+//! Assert an expression is Ok, and its inner number is within delta of an expression.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Ok(a1) ⇒ a1) ∧ | a1 - b | ≤ Δ
+//!
+//! This is useful for a fallible numeric function, such as a parser that
+//! returns `Result<f64, _>`, where the inner value needs a tolerance rather
+//! than exact equality. A NaN is never within delta, so an inner NaN fails.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<f64, String> = "1.05".parse::<f64>().map_err(|e| e.to_string());
+//! let b: f64 = 1.0;
+//! let delta: f64 = 0.1;
+//! assert_ok_in_delta_x!(a, b, delta);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_in_delta_x`](macro@crate::assert_ok_in_delta_x)
+//! * [`assert_ok_in_delta_x_as_result`](macro@crate::assert_ok_in_delta_x_as_result)
+//! * [`debug_assert_ok_in_delta_x`](macro@crate::debug_assert_ok_in_delta_x)
+
+/// Assert an expression is Ok, and its inner number is within delta of an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1) ∧ | a1 - b | ≤ Δ
+///
+/// * If true, return Result `Ok(a1)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The inner number can be any type that is `Copy`, `PartialOrd`, and `Sub`,
+/// such as `f64` or `i32`.
+///
+/// This macro provides the same statements as [`assert_ok_in_delta_x`](macro.assert_ok_in_delta_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_in_delta_x`](macro@crate::assert_ok_in_delta_x)
+/// * [`assert_ok_in_delta_x_as_result`](macro@crate::assert_ok_in_delta_x_as_result)
+/// * [`debug_assert_ok_in_delta_x`](macro@crate::debug_assert_ok_in_delta_x)
+///
+#[macro_export]
+macro_rules! assert_ok_in_delta_x_as_result {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match ($a, &$b, &$delta) {
+            (a, b, delta) => {
+                match &a {
+                    Ok(a1) => {
+                        let a1 = *a1;
+                        let abs_diff = if a1 >= *b { a1 - *b } else { *b - a1 };
+                        if abs_diff <= *delta {
+                            Ok(a1)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_ok_in_delta_x!(a, b, Δ)`\n",
+                                        "{}macro.assert_ok_in_delta_x.html\n",
+                                        "             a label: `{}`,\n",
+                                        "             a debug: `{:?}`,\n",
+                                        "             a inner: `{:?}`,\n",
+                                        "             b label: `{}`,\n",
+                                        "             b debug: `{:?}`,\n",
+                                        "             Δ label: `{}`,\n",
+                                        "             Δ debug: `{:?}`,\n",
+                                        "     | a inner - b |: `{:?}`,\n",
+                                        " | a inner - b | ≤ Δ: {}"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a),
+                                    a,
+                                    a1,
+                                    stringify!($b),
+                                    b,
+                                    stringify!($delta),
+                                    delta,
+                                    abs_diff,
+                                    false
+                                )
+                            )
+                        }
+                    },
+                    _ => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_ok_in_delta_x!(a, b, Δ)`\n",
+                                    "{}macro.assert_ok_in_delta_x.html\n",
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " b label: `{}`,\n",
+                                    " b debug: `{:?}`,\n",
+                                    " Δ label: `{}`,\n",
+                                    " Δ debug: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b,
+                                stringify!($delta),
+                                delta
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: Result<f64, String> = Ok(1.05);
+        let b: f64 = 1.0;
+        let delta: f64 = 0.1;
+        let result = assert_ok_in_delta_x_as_result!(a, b, delta);
+        assert_eq!(result, Ok(1.05));
+    }
+
+    #[test]
+    fn success_with_integer() {
+        let a: Result<i32, String> = Ok(9);
+        let result = assert_ok_in_delta_x_as_result!(a, 10, 1);
+        assert_eq!(result, Ok(9));
+    }
+
+    #[test]
+    fn failure_because_miss() {
+        let a: Result<f64, String> = Ok(1.5);
+        let b: f64 = 1.0;
+        let delta: f64 = 0.1;
+        let result = assert_ok_in_delta_x_as_result!(a, b, delta);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_in_delta_x!(a, b, Δ)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_in_delta_x.html\n",
+                "             a label: `a`,\n",
+                "             a debug: `Ok(1.5)`,\n",
+                "             a inner: `1.5`,\n",
+                "             b label: `b`,\n",
+                "             b debug: `1.0`,\n",
+                "             Δ label: `delta`,\n",
+                "             Δ debug: `0.1`,\n",
+                "     | a inner - b |: `0.5`,\n",
+                " | a inner - b | ≤ Δ: false"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_err() {
+        let a: Result<f64, String> = Err(String::from("alfa"));
+        let b: f64 = 1.0;
+        let delta: f64 = 0.1;
+        let result = assert_ok_in_delta_x_as_result!(a, b, delta);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_in_delta_x!(a, b, Δ)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_in_delta_x.html\n",
+                " a label: `a`,\n",
+                " a debug: `Err(\"alfa\")`,\n",
+                " b label: `b`,\n",
+                " b debug: `1.0`,\n",
+                " Δ label: `delta`,\n",
+                " Δ debug: `0.1`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let a: Result<f64, String> = Ok(f64::NAN);
+        let b: f64 = 1.0;
+        let delta: f64 = 0.1;
+        let result = assert_ok_in_delta_x_as_result!(a, b, delta);
+        assert!(result.unwrap_err().contains("     | a inner - b |: `NaN`,\n"));
+    }
+}
+
+/// Assert an expression is Ok, and its inner number is within delta of an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1) ∧ | a1 - b | ≤ Δ
+///
+/// * If true, return `a1`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<f64, String> = "1.05".parse::<f64>().map_err(|e| e.to_string());
+/// let b: f64 = 1.0;
+/// let delta: f64 = 0.1;
+/// assert_ok_in_delta_x!(a, b, delta);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<f64, String> = Ok(1.5);
+/// let b: f64 = 1.0;
+/// let delta: f64 = 0.1;
+/// assert_ok_in_delta_x!(a, b, delta);
+/// # });
+/// // assertion failed: `assert_ok_in_delta_x!(a, b, Δ)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_in_delta_x.html
+/// //              a label: `a`,
+/// //              a debug: `Ok(1.5)`,
+/// //              a inner: `1.5`,
+/// //              b label: `b`,
+/// //              b debug: `1.0`,
+/// //              Δ label: `delta`,
+/// //              Δ debug: `0.1`,
+/// //      | a inner - b |: `0.5`,
+/// //  | a inner - b | ≤ Δ: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ok_in_delta_x!(a, b, Δ)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_in_delta_x.html\n",
+/// #     "             a label: `a`,\n",
+/// #     "             a debug: `Ok(1.5)`,\n",
+/// #     "             a inner: `1.5`,\n",
+/// #     "             b label: `b`,\n",
+/// #     "             b debug: `1.0`,\n",
+/// #     "             Δ label: `delta`,\n",
+/// #     "             Δ debug: `0.1`,\n",
+/// #     "     | a inner - b |: `0.5`,\n",
+/// #     " | a inner - b | ≤ Δ: false"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ok_in_delta_x`](macro@crate::assert_ok_in_delta_x)
+/// * [`assert_ok_in_delta_x_as_result`](macro@crate::assert_ok_in_delta_x_as_result)
+/// * [`debug_assert_ok_in_delta_x`](macro@crate::debug_assert_ok_in_delta_x)
+///
+#[macro_export]
+macro_rules! assert_ok_in_delta_x {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match $crate::assert_ok_in_delta_x_as_result!($a, $b, $delta) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $delta:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_in_delta_x_as_result!($a, $b, $delta) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Ok, and its inner number is within delta of an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1) ∧ | a1 - b | ≤ Δ
+///
+/// This macro provides the same statements as [`assert_ok_in_delta_x`](macro.assert_ok_in_delta_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_in_delta_x`](macro@crate::assert_ok_in_delta_x)
+/// * [`assert_ok_in_delta_x_as_result`](macro@crate::assert_ok_in_delta_x_as_result)
+/// * [`debug_assert_ok_in_delta_x`](macro@crate::debug_assert_ok_in_delta_x)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_in_delta_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ok_in_delta_x!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,281 @@
+//! Assert an expression is Ok and a function of its value is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Ok(a1) ⇒ f(a1)) = b
+//!
+//! This is useful to check one derived property of an Ok value, such as a
+//! length or a field, without unwrapping first. The function is typically a
+//! closure, and takes the Ok value by value, like `Result::map`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<String, i8> = Ok(String::from("alfa"));
+//! assert_ok_map_eq!(a, |s| s.len(), 4);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_map_eq`](macro@crate::assert_ok_map_eq)
+//! * [`assert_ok_map_eq_as_result`](macro@crate::assert_ok_map_eq_as_result)
+//! * [`debug_assert_ok_map_eq`](macro@crate::debug_assert_ok_map_eq)
+
+/// Assert an expression is Ok and a function of its value is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ f(a1)) = b
+///
+/// * If true, return Result `Ok(f(a1))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The function is called only if `a` is Ok, and the expression `b` is
+/// evaluated only after the function is called.
+///
+/// This macro provides the same statements as [`assert_ok_map_eq`](macro.assert_ok_map_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_map_eq`](macro@crate::assert_ok_map_eq)
+/// * [`assert_ok_map_eq_as_result`](macro@crate::assert_ok_map_eq_as_result)
+/// * [`debug_assert_ok_map_eq`](macro@crate::debug_assert_ok_map_eq)
+///
+#[macro_export]
+macro_rules! assert_ok_map_eq_as_result {
+    ($a:expr, $f:expr, $b:expr $(,)?) => {
+        match ($a) {
+            Ok(a1) => {
+                let a1_debug = format!("{:?}", a1);
+                let a2 = $crate::assert_ok::call_with(a1, $f);
+                match (&$b) {
+                    b => {
+                        if a2 == *b {
+                            Ok(a2)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_ok_map_eq!(a, f, b)`\n",
+                                        "{}macro.assert_ok_map_eq.html\n",
+                                        "     a label: `{}`,\n",
+                                        "     a inner: `{}`,\n",
+                                        "     f label: `{}`,\n",
+                                        "  f(a inner): `{:?}`,\n",
+                                        "     b label: `{}`,\n",
+                                        "     b debug: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a),
+                                    a1_debug,
+                                    stringify!($f),
+                                    a2,
+                                    stringify!($b),
+                                    b
+                                )
+                            )
+                        }
+                    }
+                }
+            },
+            a => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_ok_map_eq!(a, f, b)`\n",
+                            "{}macro.assert_ok_map_eq.html\n",
+                            "     a label: `{}`,\n",
+                            "     a debug: `{:?}`,\n",
+                            "     f label: `{}`,\n",
+                            "     b label: `{}`"
+                        ),
+                        $crate::DOC_URL_BASE,
+                        stringify!($a),
+                        a,
+                        stringify!($f),
+                        stringify!($b)
+                    )
+                )
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[derive(Debug)]
+    struct User {
+        name: String,
+        age: u8,
+    }
+
+    #[test]
+    fn success() {
+        let a: Result<String, i8> = Ok(String::from("alfa"));
+        let result = assert_ok_map_eq_as_result!(a, |s| s.len(), 4);
+        assert_eq!(result.unwrap(), 4);
+    }
+
+    #[test]
+    fn success_with_field() {
+        let a: Result<User, i8> = Ok(User { name: String::from("alfa"), age: 1 });
+        let result = assert_ok_map_eq_as_result!(a, |user: User| user.name, "alfa");
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn failure_because_ne() {
+        let a: Result<User, i8> = Ok(User { name: String::from("alfa"), age: 1 });
+        let b: u8 = 2;
+        let result = assert_ok_map_eq_as_result!(a, |user: User| user.age, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_map_eq!(a, f, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_map_eq.html\n",
+                "     a label: `a`,\n",
+                "     a inner: `User { name: \"alfa\", age: 1 }`,\n",
+                "     f label: `|user: User| user.age`,\n",
+                "  f(a inner): `1`,\n",
+                "     b label: `b`,\n",
+                "     b debug: `2`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_not_ok() {
+        let a: Result<String, i8> = Err(1);
+        let result = assert_ok_map_eq_as_result!(a, |s: String| s.len(), 4);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_map_eq!(a, f, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_map_eq.html\n",
+                "     a label: `a`,\n",
+                "     a debug: `Err(1)`,\n",
+                "     f label: `|s: String| s.len()`,\n",
+                "     b label: `4`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Ok and a function of its value is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ f(a1)) = b
+///
+/// * If true, return `f(a1)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<String, i8> = Ok(String::from("alfa"));
+/// let len = assert_ok_map_eq!(a, |s| s.len(), 4);
+/// assert_eq!(len, 4);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<String, i8> = Ok(String::from("alfa"));
+/// assert_ok_map_eq!(a, |s| s.len(), 5);
+/// # });
+/// // assertion failed: `assert_ok_map_eq!(a, f, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_map_eq.html
+/// //      a label: `a`,
+/// //      a inner: `\"alfa\"`,
+/// //      f label: `|s| s.len()`,
+/// //   f(a inner): `4`,
+/// //      b label: `5`,
+/// //      b debug: `5`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ok_map_eq!(a, f, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_map_eq.html\n",
+/// #     "     a label: `a`,\n",
+/// #     "     a inner: `\"alfa\"`,\n",
+/// #     "     f label: `|s| s.len()`,\n",
+/// #     "  f(a inner): `4`,\n",
+/// #     "     b label: `5`,\n",
+/// #     "     b debug: `5`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ok_map_eq`](macro@crate::assert_ok_map_eq)
+/// * [`assert_ok_map_eq_as_result`](macro@crate::assert_ok_map_eq_as_result)
+/// * [`debug_assert_ok_map_eq`](macro@crate::debug_assert_ok_map_eq)
+///
+#[macro_export]
+macro_rules! assert_ok_map_eq {
+    ($a:expr, $f:expr, $b:expr $(,)?) => {{
+        match $crate::assert_ok_map_eq_as_result!($a, $f, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $f:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_map_eq_as_result!($a, $f, $b) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Ok and a function of its value is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ f(a1)) = b
+///
+/// This macro provides the same statements as [`assert_ok_map_eq`](macro.assert_ok_map_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_map_eq`](macro@crate::assert_ok_map_eq)
+/// * [`assert_ok_map_eq_as_result`](macro@crate::assert_ok_map_eq_as_result)
+/// * [`debug_assert_ok_map_eq`](macro@crate::debug_assert_ok_map_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_map_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ok_map_eq!($($arg)*);
+        }
+    };
+}
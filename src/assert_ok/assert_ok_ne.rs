@@ -26,10 +26,17 @@
 /// Pseudocode:<br>
 /// (a ⇒ Ok(a1) ⇒ a1) ≠ (b ⇒ Ok(b1) ⇒ b1)
 ///
-/// * If true, return Result `Ok((a1, b1))`.
+/// * If true, return Result `Ok((a1, b1))`, where `a1` and `b1` are
+///   references to the inner values.
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
+/// This macro borrows `a` and `b`, rather than consuming them, so an inner
+/// value that is not `Copy` is still usable after the assertion. The returned
+/// references borrow from `a` and `b`, so they live only as long as `a` and
+/// `b` do; to keep the returned references, pass named variables rather than
+/// temporary expressions.
+///
 /// This macro provides the same statements as [`assert_ok_ne`](macro.assert_ok_ne.html),
 /// except this macro returns a Result, rather than doing a panic.
 ///
@@ -45,7 +52,7 @@
 #[macro_export]
 macro_rules! assert_ok_ne_as_result {
     ($a:expr, $b:expr $(,)?) => {
-        match ($a, $b) {
+        match (&$a, &$b) {
             (Ok(a1), Ok(b1)) => {
                 if a1 != b1 {
                     Ok((a1, b1))
@@ -54,7 +61,7 @@ macro_rules! assert_ok_ne_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_ok_ne!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_ne.html\n",
+                                "{}macro.assert_ok_ne.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a inner: `{:?}`,\n",
@@ -62,6 +69,7 @@ macro_rules! assert_ok_ne_as_result {
                                 " b debug: `{:?}`,\n",
                                 " b inner: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             $a,
                             a1,
@@ -77,12 +85,13 @@ macro_rules! assert_ok_ne_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_ok_ne!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_ne.html\n",
+                            "{}macro.assert_ok_ne.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
                         $a,
                         stringify!($b),
@@ -102,7 +111,17 @@ mod tests {
         let a: Result<i8, i8> = Ok(1);
         let b: Result<i8, i8> = Ok(2);
         let result = assert_ok_ne_as_result!(a, b);
-        assert_eq!(result.unwrap(), (1, 2));
+        assert_eq!(result.unwrap(), (&1, &2));
+    }
+
+    #[test]
+    fn success_borrows_non_copy_values() {
+        let a: Result<String, i8> = Ok(String::from("alfa"));
+        let b: Result<String, i8> = Ok(String::from("bravo"));
+        let (a1, b1) = assert_ok_ne_as_result!(a, b).unwrap();
+        assert_eq!(a1, "alfa");
+        assert_eq!(b1, "bravo");
+        assert_eq!(a, Ok(String::from("alfa")));
     }
 
     #[test]
@@ -149,11 +168,18 @@ mod tests {
 /// Pseudocode:<br>
 /// (a ⇒ Ok(a1) ⇒ a1) ≠ (b ⇒ Ok(b1) ⇒ b1)
 ///
-/// * If true, return `(a1, b1)`.
+/// * If true, return `(a1, b1)`, where `a1` and `b1` are references to
+///   the inner values.
 ///
 /// * Otherwise, call [`panic!`] with a message and the values of the
 ///   expressions with their debug representations.
 ///
+/// This macro borrows `a` and `b`, rather than consuming them, so an inner
+/// value that is not `Copy` is still usable after the assertion. The returned
+/// references borrow from `a` and `b`, so they live only as long as `a` and
+/// `b` do; to keep the returned references, pass named variables rather than
+/// temporary expressions.
+///
 /// # Examples
 ///
 /// ```rust
@@ -21,15 +21,21 @@
 //! * [`assert_ok_ne_x_as_result`](macro@crate::assert_ok_ne_x_as_result)
 //! * [`debug_assert_ok_ne_x`](macro@crate::debug_assert_ok_ne_x)
 
-/// Assert an expression is Ok and its value is equal to an expression.
+/// Assert an expression is Ok and its value is not equal to an expression.
 ///
 /// Pseudocode:<br>
-/// (a ⇒ Ok(a1) ⇒ a1) = b
+/// (a ⇒ Ok(a1) ⇒ a1) ≠ b
 ///
-/// * If true, return Result `Ok((a1))`.
+/// * If true, return Result `Ok(a1)`.
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
+/// The expression `a` is evaluated once. The macro moves the Ok value out,
+/// so the Ok type does not need to be `Copy`, and the caller can keep using
+/// the returned value, such as `let x = assert_ok_ne_x!(parse(s), b);`. To borrow
+/// rather than move, pass a reference, such as `&a`, and the macro returns a
+/// reference to the Ok value.
+///
 /// This macro provides the same statements as [`assert_ok_ne_x`](macro.assert_ok_ne_x.html),
 /// except this macro returns a Result, rather than doing a panic.
 ///
@@ -44,57 +50,81 @@
 ///
 #[macro_export]
 macro_rules! assert_ok_ne_x_as_result {
-    ($a:expr, $b:expr $(,)?) => {
-        match ($a) {
-            Ok(a1) => {
-                if a1 != $b {
-                    Ok((a1))
-                } else {
-                    Err(
-                        format!(
-                            concat!(
-                                "assertion failed: `assert_ok_ne_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_ne_x.html\n",
-                                " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
-                                " a inner: `{:?}`,\n",
-                                " b label: `{}`,\n",
-                                " b debug: `{:?}`",
-                            ),
-                            stringify!($a),
-                            $a,
-                            a1,
-                            stringify!($b),
-                            $b
-                        )
-                    )
+    ($a:expr, $b:expr $(,)?) => {{
+        match ($a, &$b) {
+            (a, b) => {
+                match a {
+                    Ok(a1) if a1 != *b => Ok(a1),
+                    a => {
+                        match &a {
+                            Ok(a1) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_ok_ne_x!(a, b)`\n",
+                                            "{}macro.assert_ok_ne_x.html\n",
+                                            " a label: `{}`,\n",
+                                            " a debug: `{:?}`,\n",
+                                            " a inner: `{:?}`,\n",
+                                            " b label: `{}`,\n",
+                                            " b debug: `{:?}`",
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($a),
+                                        a,
+                                        a1,
+                                        stringify!($b),
+                                        b
+                                    )
+                                )
+                            },
+                            _ => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_ok_ne_x!(a, b)`\n",
+                                            "{}macro.assert_ok_ne_x.html\n",
+                                            " a label: `{}`,\n",
+                                            " a debug: `{:?}`,\n",
+                                            " b label: `{}`,\n",
+                                            " b debug: `{:?}`",
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($a),
+                                        a,
+                                        stringify!($b),
+                                        b
+                                    )
+                                )
+                            }
+                        }
+                    }
                 }
-            },
-            _ => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_ok_ne_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_ne_x.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`",
-                        ),
-                        stringify!($a),
-                        $a,
-                        stringify!($b),
-                        $b,
-                    )
-                )
             }
         }
-    };
+    }};
 }
 
 #[cfg(test)]
 mod tests {
 
+    #[test]
+    fn success_returns_owned_value() {
+        let a: Result<String, i8> = Ok(String::from("alfa"));
+        let result = assert_ok_ne_x_as_result!(a, "bravo");
+        let actual: String = result.unwrap();
+        assert_eq!(actual, "alfa");
+    }
+
+    #[test]
+    fn success_with_reference() {
+        let a: Result<String, i8> = Ok(String::from("alfa"));
+        let result = assert_ok_ne_x_as_result!(&a, "bravo");
+        let actual: &String = result.unwrap();
+        assert_eq!(actual, "alfa");
+        assert!(a.is_ok());
+    }
+
     #[test]
     fn test_assert_ok_ne_x_expr_as_result_x_success() {
         let a: Result<i8, i8> = Ok(1);
@@ -0,0 +1,227 @@
+//! Assert an expression is Ok(None).
+//!
+//! Pseudocode:<br>
+//! a is Ok(None)
+//!
+//! This is useful for an API that returns `Result<Option<T>, E>`, such as a
+//! database lookup. The failure message says whether `a` is `Err`, or is
+//! `Ok(Some(…))`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<Option<i8>, i8> = Ok(None);
+//! assert_ok_none!(a);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_none`](macro@crate::assert_ok_none)
+//! * [`assert_ok_none_as_result`](macro@crate::assert_ok_none_as_result)
+//! * [`debug_assert_ok_none`](macro@crate::debug_assert_ok_none)
+
+/// Assert an expression is Ok(None).
+///
+/// Pseudocode:<br>
+/// a is Ok(None)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_ok_none`](macro.assert_ok_none.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_none`](macro@crate::assert_ok_none)
+/// * [`assert_ok_none_as_result`](macro@crate::assert_ok_none_as_result)
+/// * [`debug_assert_ok_none`](macro@crate::debug_assert_ok_none)
+///
+#[macro_export]
+macro_rules! assert_ok_none_as_result {
+    ($a:expr $(,)?) => {{
+        match (&$a) {
+            a => {
+                match a {
+                    Ok(None) => Ok(()),
+                    _ => {
+                        let failure = match a {
+                            Err(_) => "a is Err",
+                            _ => "a is Ok(Some(a1))",
+                        };
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_ok_none!(a)`\n",
+                                    "{}macro.assert_ok_none.html\n",
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " failure: `{}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                failure
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: Result<Option<i8>, i8> = Ok(None);
+        let result = assert_ok_none_as_result!(a);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_because_err() {
+        let a: Result<Option<i8>, i8> = Err(1);
+        let result = assert_ok_none_as_result!(a);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_none!(a)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_none.html\n",
+                " a label: `a`,\n",
+                " a debug: `Err(1)`,\n",
+                " failure: `a is Err`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_ok_some() {
+        let a: Result<Option<i8>, i8> = Ok(Some(1));
+        let result = assert_ok_none_as_result!(a);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_none!(a)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_none.html\n",
+                " a label: `a`,\n",
+                " a debug: `Ok(Some(1))`,\n",
+                " failure: `a is Ok(Some(a1))`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Ok(None).
+///
+/// Pseudocode:<br>
+/// a is Ok(None)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<Option<i8>, i8> = Ok(None);
+/// assert_ok_none!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<Option<i8>, i8> = Ok(Some(1));
+/// assert_ok_none!(a);
+/// # });
+/// // assertion failed: `assert_ok_none!(a)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_none.html
+/// //  a label: `a`,
+/// //  a debug: `Ok(Some(1))`,
+/// //  failure: `a is Ok(Some(a1))`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ok_none!(a)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_none.html\n",
+/// #     " a label: `a`,\n",
+/// #     " a debug: `Ok(Some(1))`,\n",
+/// #     " failure: `a is Ok(Some(a1))`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ok_none`](macro@crate::assert_ok_none)
+/// * [`assert_ok_none_as_result`](macro@crate::assert_ok_none_as_result)
+/// * [`debug_assert_ok_none`](macro@crate::debug_assert_ok_none)
+///
+#[macro_export]
+macro_rules! assert_ok_none {
+    ($a:expr $(,)?) => {{
+        match $crate::assert_ok_none_as_result!($a) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_none_as_result!($a) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Ok(None).
+///
+/// Pseudocode:<br>
+/// a is Ok(None)
+///
+/// This macro provides the same statements as [`assert_ok_none`](macro.assert_ok_none.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_none`](macro@crate::assert_ok_none)
+/// * [`assert_ok_none_as_result`](macro@crate::assert_ok_none_as_result)
+/// * [`debug_assert_ok_none`](macro@crate::debug_assert_ok_none)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_none {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ok_none!($($arg)*);
+        }
+    };
+}
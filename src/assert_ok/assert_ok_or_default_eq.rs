@@ -0,0 +1,264 @@
+//! Assert an expression unwrapped or defaulted is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Ok(a1) ⇒ a1, or Err(_) ⇒ default) = b
+//!
+//! This is like `a.unwrap_or_default() == b`. It models code that falls back
+//! to a default value, so it checks the observable value whether `a` is Ok
+//! or Err. For a strict check that `a` is Ok, use
+//! [`assert_ok_eq_x`](macro@crate::assert_ok_eq_x).
+//!
+//! The Ok type must implement `Default` and `PartialEq`. The Err type does
+//! not need `Debug`, because the failure message shows the variant name.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<i8, i8> = Err(1);
+//! let b: i8 = 0;
+//! assert_ok_or_default_eq!(a, b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_or_default_eq`](macro@crate::assert_ok_or_default_eq)
+//! * [`assert_ok_or_default_eq_as_result`](macro@crate::assert_ok_or_default_eq_as_result)
+//! * [`debug_assert_ok_or_default_eq`](macro@crate::debug_assert_ok_or_default_eq)
+
+/// Assert an expression unwrapped or defaulted is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1, or Err(_) ⇒ default) = b
+///
+/// * If true, return Result `Ok(a_value)`, where `a_value` is the Ok value
+///   or the default value.
+///
+/// * Otherwise, return Result `Err(message)`, which says whether the
+///   compared value is the default because `a` is Err.
+///
+/// This macro provides the same statements as [`assert_ok_or_default_eq`](macro.assert_ok_or_default_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_or_default_eq`](macro@crate::assert_ok_or_default_eq)
+/// * [`assert_ok_or_default_eq_as_result`](macro@crate::assert_ok_or_default_eq_as_result)
+/// * [`debug_assert_ok_or_default_eq`](macro@crate::debug_assert_ok_or_default_eq)
+///
+#[macro_export]
+macro_rules! assert_ok_or_default_eq_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$b) {
+            b => {
+                let (a_value, a_used_default) = match ($a) {
+                    Ok(a1) => (a1, false),
+                    Err(_) => (Default::default(), true),
+                };
+                if a_value == *b {
+                    Ok(a_value)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_ok_or_default_eq!(a, b)`\n",
+                                "{}macro.assert_ok_or_default_eq.html\n",
+                                "        a label: `{}`,\n",
+                                "      a variant: `{}`,\n",
+                                " a used default: `{}`,\n",
+                                "        a value: `{:?}`,\n",
+                                "        b label: `{}`,\n",
+                                "        b debug: `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            if a_used_default { "Err" } else { "Ok" },
+                            a_used_default,
+                            a_value,
+                            stringify!($b),
+                            b
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: Result<i8, i8> = Ok(1);
+        let b: i8 = 1;
+        let result = assert_ok_or_default_eq_as_result!(a, b);
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[test]
+    fn success_because_err_uses_default() {
+        let a: Result<String, i8> = Err(1);
+        let b = String::new();
+        let result = assert_ok_or_default_eq_as_result!(a, b);
+        assert_eq!(result.unwrap(), "");
+    }
+
+    #[test]
+    fn failure() {
+        let a: Result<i8, i8> = Ok(1);
+        let b: i8 = 2;
+        let result = assert_ok_or_default_eq_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_or_default_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_or_default_eq.html\n",
+                "        a label: `a`,\n",
+                "      a variant: `Ok`,\n",
+                " a used default: `false`,\n",
+                "        a value: `1`,\n",
+                "        b label: `b`,\n",
+                "        b debug: `2`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_err_uses_default() {
+        let a: Result<i8, i8> = Err(1);
+        let b: i8 = 2;
+        let result = assert_ok_or_default_eq_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_or_default_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_or_default_eq.html\n",
+                "        a label: `a`,\n",
+                "      a variant: `Err`,\n",
+                " a used default: `true`,\n",
+                "        a value: `0`,\n",
+                "        b label: `b`,\n",
+                "        b debug: `2`"
+            )
+        );
+    }
+}
+
+/// Assert an expression unwrapped or defaulted is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1, or Err(_) ⇒ default) = b
+///
+/// * If true, return `a_value`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<i8, i8> = Err(1);
+/// let b: i8 = 0;
+/// assert_ok_or_default_eq!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<i8, i8> = Ok(1);
+/// let b: i8 = 2;
+/// assert_ok_or_default_eq!(a, b);
+/// # });
+/// // assertion failed: `assert_ok_or_default_eq!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_or_default_eq.html
+/// //         a label: `a`,
+/// //       a variant: `Ok`,
+/// //  a used default: `false`,
+/// //         a value: `1`,
+/// //         b label: `b`,
+/// //         b debug: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ok_or_default_eq!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_or_default_eq.html\n",
+/// #     "        a label: `a`,\n",
+/// #     "      a variant: `Ok`,\n",
+/// #     " a used default: `false`,\n",
+/// #     "        a value: `1`,\n",
+/// #     "        b label: `b`,\n",
+/// #     "        b debug: `2`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ok_or_default_eq`](macro@crate::assert_ok_or_default_eq)
+/// * [`assert_ok_or_default_eq_as_result`](macro@crate::assert_ok_or_default_eq_as_result)
+/// * [`debug_assert_ok_or_default_eq`](macro@crate::debug_assert_ok_or_default_eq)
+///
+#[macro_export]
+macro_rules! assert_ok_or_default_eq {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_ok_or_default_eq_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_or_default_eq_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression unwrapped or defaulted is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1, or Err(_) ⇒ default) = b
+///
+/// This macro provides the same statements as [`assert_ok_or_default_eq`](macro.assert_ok_or_default_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_or_default_eq`](macro@crate::assert_ok_or_default_eq)
+/// * [`assert_ok_or_default_eq_as_result`](macro@crate::assert_ok_or_default_eq_as_result)
+/// * [`debug_assert_ok_or_default_eq`](macro@crate::debug_assert_ok_or_default_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_or_default_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ok_or_default_eq!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,325 @@
+//! Assert an expression is Ok and its reader read_to_string() value is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Ok(a_reader) ⇒ a_reader.read_to_string(a_string) ⇒ a_string) = expr
+//!
+//! This is useful for factory functions that open a resource and return
+//! a `Result` of a reader, such as `File::open(path)`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<&[u8], String> = Ok("alfa".as_bytes());
+//! let value = String::from("alfa");
+//! assert_ok_read_to_string_eq_x!(a, &value);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_read_to_string_eq_x`](macro@crate::assert_ok_read_to_string_eq_x)
+//! * [`assert_ok_read_to_string_eq_x_as_result`](macro@crate::assert_ok_read_to_string_eq_x_as_result)
+//! * [`debug_assert_ok_read_to_string_eq_x`](macro@crate::debug_assert_ok_read_to_string_eq_x)
+
+/// Assert an expression is Ok and its reader read_to_string() value is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a_reader) ⇒ a_reader.read_to_string(a_string) ⇒ a_string) = expr
+///
+/// * If true, return Result `Ok(a_string)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_ok_read_to_string_eq_x`](macro.assert_ok_read_to_string_eq_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_read_to_string_eq_x`](macro@crate::assert_ok_read_to_string_eq_x)
+/// * [`assert_ok_read_to_string_eq_x_as_result`](macro@crate::assert_ok_read_to_string_eq_x_as_result)
+/// * [`debug_assert_ok_read_to_string_eq_x`](macro@crate::debug_assert_ok_read_to_string_eq_x)
+///
+#[macro_export]
+macro_rules! assert_ok_read_to_string_eq_x_as_result {
+    ($a:expr, $b_expr:expr $(,)?) => {{
+        match (&$b_expr) {
+            b_expr => {
+                match ($a) {
+                    Ok(mut a_reader) => {
+                        let mut a_string = String::new();
+                        match (::std::io::Read::read_to_string(&mut a_reader, &mut a_string)) {
+                            Ok(_a_size) => {
+                                let b_string = String::from(AsRef::<str>::as_ref(b_expr));
+                                if (a_string == b_string) {
+                                    Ok(a_string)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_ok_read_to_string_eq_x!(a, b_expr)`\n",
+                                                "{}macro.assert_ok_read_to_string_eq_x.html\n",
+                                                "      a label: `{}`,\n",
+                                                " b_expr label: `{}`,\n",
+                                                " b_expr debug: `{:?}`,\n",
+                                                "            a: `{:?}`,\n",
+                                                "            b: `{:?}`"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($a),
+                                            stringify!($b_expr),
+                                            b_expr,
+                                            a_string,
+                                            b_string
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_ok_read_to_string_eq_x!(a, b_expr)`\n",
+                                            "{}macro.assert_ok_read_to_string_eq_x.html\n",
+                                            "      a label: `{}`,\n",
+                                            " b_expr label: `{}`,\n",
+                                            " b_expr debug: `{:?}`,\n",
+                                            "          err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($a),
+                                        stringify!($b_expr),
+                                        b_expr,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    Err(a_err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_ok_read_to_string_eq_x!(a, b_expr)`\n",
+                                    "{}macro.assert_ok_read_to_string_eq_x.html\n",
+                                    "      a label: `{}`,\n",
+                                    "      a debug: `Err({:?})`,\n",
+                                    " b_expr label: `{}`,\n",
+                                    " b_expr debug: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a_err,
+                                stringify!($b_expr),
+                                b_expr
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    struct FailingReader;
+
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("boom"))
+        }
+    }
+
+    #[test]
+    fn eq() {
+        let a: Result<&[u8], String> = Ok("alfa".as_bytes());
+        let value = String::from("alfa");
+        let result = assert_ok_read_to_string_eq_x_as_result!(a, &value);
+        assert_eq!(result.unwrap(), String::from("alfa"));
+    }
+
+    #[test]
+    fn eq_evaluates_b_expr_once() {
+        let a: Result<&[u8], String> = Ok("alfa".as_bytes());
+        let count = std::cell::Cell::new(0);
+        let value = || {
+            count.set(count.get() + 1);
+            String::from("alfa")
+        };
+        let result = assert_ok_read_to_string_eq_x_as_result!(a, value());
+        assert_eq!(result.unwrap(), String::from("alfa"));
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn ne() {
+        let a: Result<&[u8], String> = Ok("alfa".as_bytes());
+        let value = String::from("bravo");
+        let result = assert_ok_read_to_string_eq_x_as_result!(a, &value);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_read_to_string_eq_x!(a, b_expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_read_to_string_eq_x.html\n",
+                "      a label: `a`,\n",
+                " b_expr label: `&value`,\n",
+                " b_expr debug: `\"bravo\"`,\n",
+                "            a: `\"alfa\"`,\n",
+                "            b: `\"bravo\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn not_ok() {
+        let a: Result<&[u8], String> = Err(String::from("nope"));
+        let value = String::from("alfa");
+        let result = assert_ok_read_to_string_eq_x_as_result!(a, &value);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_read_to_string_eq_x!(a, b_expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_read_to_string_eq_x.html\n",
+                "      a label: `a`,\n",
+                "      a debug: `Err(\"nope\")`,\n",
+                " b_expr label: `&value`,\n",
+                " b_expr debug: `\"alfa\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn read_err() {
+        let a: Result<FailingReader, String> = Ok(FailingReader);
+        let value = String::from("alfa");
+        let result = assert_ok_read_to_string_eq_x_as_result!(a, &value);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_read_to_string_eq_x!(a, b_expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_read_to_string_eq_x.html\n",
+                "      a label: `a`,\n",
+                " b_expr label: `&value`,\n",
+                " b_expr debug: `\"alfa\"`,\n",
+                "          err: `Custom { kind: Other, error: \"boom\" }`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Ok and its reader read_to_string() value is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a_reader) ⇒ a_reader.read_to_string(a_string) ⇒ a_string) = expr
+///
+/// * If true, return `a_string`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<&[u8], String> = Ok("alfa".as_bytes());
+/// let value = String::from("alfa");
+/// assert_ok_read_to_string_eq_x!(a, &value);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<&[u8], String> = Ok("alfa".as_bytes());
+/// let value = String::from("bravo");
+/// assert_ok_read_to_string_eq_x!(a, &value);
+/// # });
+/// // assertion failed: `assert_ok_read_to_string_eq_x!(a, b_expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_read_to_string_eq_x.html
+/// //       a label: `a`,
+/// //  b_expr label: `&value`,
+/// //  b_expr debug: `\"bravo\"`,
+/// //             a: `\"alfa\"`,
+/// //             b: `\"bravo\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ok_read_to_string_eq_x!(a, b_expr)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_read_to_string_eq_x.html\n",
+/// #     "      a label: `a`,\n",
+/// #     " b_expr label: `&value`,\n",
+/// #     " b_expr debug: `\"bravo\"`,\n",
+/// #     "            a: `\"alfa\"`,\n",
+/// #     "            b: `\"bravo\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ok_read_to_string_eq_x`](macro@crate::assert_ok_read_to_string_eq_x)
+/// * [`assert_ok_read_to_string_eq_x_as_result`](macro@crate::assert_ok_read_to_string_eq_x_as_result)
+/// * [`debug_assert_ok_read_to_string_eq_x`](macro@crate::debug_assert_ok_read_to_string_eq_x)
+///
+#[macro_export]
+macro_rules! assert_ok_read_to_string_eq_x {
+    ($a:expr, $b_expr:expr $(,)?) => {{
+        match $crate::assert_ok_read_to_string_eq_x_as_result!($a, $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b_expr:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_read_to_string_eq_x_as_result!($a, $b_expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Ok and its reader read_to_string() value is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a_reader) ⇒ a_reader.read_to_string(a_string) ⇒ a_string) = expr
+///
+/// This macro provides the same statements as [`assert_ok_read_to_string_eq_x`](macro.assert_ok_read_to_string_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_read_to_string_eq_x`](macro@crate::assert_ok_read_to_string_eq_x)
+/// * [`assert_ok_read_to_string_eq_x_as_result`](macro@crate::assert_ok_read_to_string_eq_x_as_result)
+/// * [`debug_assert_ok_read_to_string_eq_x`](macro@crate::debug_assert_ok_read_to_string_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_read_to_string_eq_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ok_read_to_string_eq_x!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,308 @@
+//! Assert an expression is Ok(vec) and its slice is equal to a slice expression.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Ok(a1) ⇒ a1[..]) = b[..]
+//!
+//! Rather than show both vectors, the failure message shows the lengths, the
+//! first index where the slices differ, and the items at that index, the same
+//! way as [`assert_slice_eq`](macro@crate::assert_slice_eq). This helps with
+//! a long result, such as the tokens of a parser that returns `Result<Vec<Token>, E>`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<Vec<i8>, i8> = Ok(vec![1, 2, 3]);
+//! assert_ok_slice_eq_x!(a, &[1, 2, 3]);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_slice_eq_x`](macro@crate::assert_ok_slice_eq_x)
+//! * [`assert_ok_slice_eq_x_as_result`](macro@crate::assert_ok_slice_eq_x_as_result)
+//! * [`debug_assert_ok_slice_eq_x`](macro@crate::debug_assert_ok_slice_eq_x)
+
+/// Assert an expression is Ok(vec) and its slice is equal to a slice expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1[..]) = b[..]
+///
+/// * If true, return Result `Ok(a1)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The inner value and the expression can be any types that can be sliced,
+/// such as a vector or an array, with items of the same type. On success,
+/// the macro returns the inner value, such as the vector.
+///
+/// If the expression is not Ok(…), then the message shows its debug
+/// representation, because there are no items to compare.
+///
+/// This macro provides the same statements as [`assert_ok_slice_eq_x`](macro.assert_ok_slice_eq_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_slice_eq_x`](macro@crate::assert_ok_slice_eq_x)
+/// * [`assert_ok_slice_eq_x_as_result`](macro@crate::assert_ok_slice_eq_x_as_result)
+/// * [`debug_assert_ok_slice_eq_x`](macro@crate::debug_assert_ok_slice_eq_x)
+///
+#[macro_export]
+macro_rules! assert_ok_slice_eq_x_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match ($a, &$b) {
+            (a, b) => {
+                match a {
+                    Ok(a1) => {
+                        let (a_slice, b_slice) = (&a1[..], &b[..]);
+                        match $crate::assert_slice::first_difference(a_slice, b_slice) {
+                            None => Ok(a1),
+                            Some(index) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_ok_slice_eq_x!(a, b)`\n",
+                                            "{}macro.assert_ok_slice_eq_x.html\n",
+                                            "     a label: `{}`,\n",
+                                            " a inner len: `{}`,\n",
+                                            "     b label: `{}`,\n",
+                                            "       b len: `{}`,\n",
+                                            "       index: `{}`,\n",
+                                            "      a item: `{:?}`,\n",
+                                            "      b item: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($a),
+                                        a_slice.len(),
+                                        stringify!($b),
+                                        b_slice.len(),
+                                        index,
+                                        a_slice.get(index),
+                                        b_slice.get(index)
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    a => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_ok_slice_eq_x!(a, b)`\n",
+                                    "{}macro.assert_ok_slice_eq_x.html\n",
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " b label: `{}`,\n",
+                                    " b debug: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: Result<Vec<i8>, i8> = Ok(vec![1, 2, 3]);
+        let result = assert_ok_slice_eq_x_as_result!(a, &[1, 2, 3]);
+        assert_eq!(result, Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn success_with_strings() {
+        let a: Result<Vec<String>, String> = Ok(vec![String::from("alfa")]);
+        let b = vec![String::from("alfa")];
+        let result = assert_ok_slice_eq_x_as_result!(a, b);
+        assert_eq!(result, Ok(vec![String::from("alfa")]));
+    }
+
+    #[test]
+    fn failure_because_item_differs() {
+        let a: Result<Vec<i8>, i8> = Ok(vec![1, 2, 3, 4]);
+        let b = [1, 2, 9, 4];
+        let result = assert_ok_slice_eq_x_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_slice_eq_x!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_slice_eq_x.html\n",
+                "     a label: `a`,\n",
+                " a inner len: `4`,\n",
+                "     b label: `b`,\n",
+                "       b len: `4`,\n",
+                "       index: `2`,\n",
+                "      a item: `Some(3)`,\n",
+                "      b item: `Some(9)`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_len_differs() {
+        let a: Result<Vec<i8>, i8> = Ok(vec![1, 2, 3]);
+        let b = [1, 2, 3, 4];
+        let result = assert_ok_slice_eq_x_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_slice_eq_x!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_slice_eq_x.html\n",
+                "     a label: `a`,\n",
+                " a inner len: `3`,\n",
+                "     b label: `b`,\n",
+                "       b len: `4`,\n",
+                "       index: `3`,\n",
+                "      a item: `None`,\n",
+                "      b item: `Some(4)`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_err() {
+        let a: Result<Vec<i8>, i8> = Err(1);
+        let b = [1];
+        let result = assert_ok_slice_eq_x_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_slice_eq_x!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_slice_eq_x.html\n",
+                " a label: `a`,\n",
+                " a debug: `Err(1)`,\n",
+                " b label: `b`,\n",
+                " b debug: `[1]`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Ok(vec) and its slice is equal to a slice expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1[..]) = b[..]
+///
+/// * If true, return `a1`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<Vec<i8>, i8> = Ok(vec![1, 2, 3]);
+/// assert_ok_slice_eq_x!(a, &[1, 2, 3]);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<Vec<i8>, i8> = Ok(vec![1, 2, 3, 4]);
+/// let b = [1, 2, 9, 4];
+/// assert_ok_slice_eq_x!(a, b);
+/// # });
+/// // assertion failed: `assert_ok_slice_eq_x!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_slice_eq_x.html
+/// //      a label: `a`,
+/// //  a inner len: `4`,
+/// //      b label: `b`,
+/// //        b len: `4`,
+/// //        index: `2`,
+/// //       a item: `Some(3)`,
+/// //       b item: `Some(9)`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ok_slice_eq_x!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_slice_eq_x.html\n",
+/// #     "     a label: `a`,\n",
+/// #     " a inner len: `4`,\n",
+/// #     "     b label: `b`,\n",
+/// #     "       b len: `4`,\n",
+/// #     "       index: `2`,\n",
+/// #     "      a item: `Some(3)`,\n",
+/// #     "      b item: `Some(9)`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ok_slice_eq_x`](macro@crate::assert_ok_slice_eq_x)
+/// * [`assert_ok_slice_eq_x_as_result`](macro@crate::assert_ok_slice_eq_x_as_result)
+/// * [`debug_assert_ok_slice_eq_x`](macro@crate::debug_assert_ok_slice_eq_x)
+///
+#[macro_export]
+macro_rules! assert_ok_slice_eq_x {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_ok_slice_eq_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_slice_eq_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Ok(vec) and its slice is equal to a slice expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1[..]) = b[..]
+///
+/// This macro provides the same statements as [`assert_ok_slice_eq_x`](macro.assert_ok_slice_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_slice_eq_x`](macro@crate::assert_ok_slice_eq_x)
+/// * [`assert_ok_slice_eq_x_as_result`](macro@crate::assert_ok_slice_eq_x_as_result)
+/// * [`debug_assert_ok_slice_eq_x`](macro@crate::debug_assert_ok_slice_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_slice_eq_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ok_slice_eq_x!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,269 @@
+//! Assert a closure returns Ok(…) within a maximum number of attempts.
+//!
+//! Pseudocode:<br>
+//! closure() is Ok(value) within max attempts ⇒ (value, attempts)
+//!
+//! This calls the closure up to `max` times, and stops at the first `Ok(value)`.
+//! This is for testing retry logic and flaky operations, where an operation
+//! may fail a few times before it succeeds.
+//!
+//! If every attempt returns `Err(err)`, then the message shows the number of
+//! failed attempts and the last error.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let mut count = 0;
+//! let (value, attempts) = assert_ok_within_attempts!(3, || {
+//!     count += 1;
+//!     if count < 2 { Err("busy") } else { Ok(count * 10) }
+//! });
+//! assert_eq!(value, 20);
+//! assert_eq!(attempts, 2);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_within_attempts`](macro@crate::assert_ok_within_attempts)
+//! * [`assert_ok_within_attempts_as_result`](macro@crate::assert_ok_within_attempts_as_result)
+//! * [`debug_assert_ok_within_attempts`](macro@crate::debug_assert_ok_within_attempts)
+
+/// Assert a closure returns Ok(…) within a maximum number of attempts.
+///
+/// Pseudocode:<br>
+/// closure() is Ok(value) within max attempts ⇒ (value, attempts)
+///
+/// * If true, return Result `Ok((value, attempts))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The max is a `usize`. The closure is any `FnMut() -> Result<T, E>`
+/// where `E` implements `Debug`, and may capture mutable state, such as a
+/// counter or a mock server.
+///
+/// This macro provides the same statements as [`assert_ok_within_attempts`](macro.assert_ok_within_attempts.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_within_attempts`](macro@crate::assert_ok_within_attempts)
+/// * [`assert_ok_within_attempts_as_result`](macro@crate::assert_ok_within_attempts_as_result)
+/// * [`debug_assert_ok_within_attempts`](macro@crate::debug_assert_ok_within_attempts)
+///
+#[macro_export]
+macro_rules! assert_ok_within_attempts_as_result {
+    ($max:expr, $closure:expr $(,)?) => {{
+        match (&$max) {
+            max => {
+                let max: usize = *max;
+                #[allow(unused_mut)]
+                let mut closure = $closure;
+                let mut attempts: usize = 0;
+                let mut last_err = None;
+                loop {
+                    if attempts >= max {
+                        break Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_ok_within_attempts!(max, closure)`\n",
+                                    "{}macro.assert_ok_within_attempts.html\n",
+                                    "       max label: `{}`,\n",
+                                    "       max debug: `{:?}`,\n",
+                                    "   closure label: `{}`,\n",
+                                    " failed attempts: `{}`,\n",
+                                    "        last err: `{}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($max),
+                                max,
+                                stringify!($closure),
+                                attempts,
+                                match &last_err {
+                                    Some(err) => format!("{:?}", err),
+                                    None => String::from("none, because max is 0"),
+                                }
+                            )
+                        );
+                    }
+                    attempts += 1;
+                    match closure() {
+                        Ok(value) => break Ok((value, attempts)),
+                        Err(err) => last_err = Some(err),
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let result = assert_ok_within_attempts_as_result!(3, || Ok::<i32, String>(1));
+        assert_eq!(result, Ok((1, 1)));
+    }
+
+    #[test]
+    fn success_after_failures() {
+        let mut count = 0;
+        let result = assert_ok_within_attempts_as_result!(3, || {
+            count += 1;
+            if count < 3 { Err(count) } else { Ok("alfa") }
+        });
+        assert_eq!(result, Ok(("alfa", 3)));
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn failure() {
+        let max = 3;
+        let mut count = 0;
+        let result = assert_ok_within_attempts_as_result!(max, || { count += 1; Err::<i32, String>(format!("fail {}", count)) });
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ok_within_attempts!(max, closure)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_within_attempts.html\n",
+                "       max label: `max`,\n",
+                "       max debug: `3`,\n",
+                "   closure label: `|| { count += 1; Err::<i32, String>(format!(\"fail {}\", count)) }`,\n",
+                " failed attempts: `3`,\n",
+                "        last err: `\"fail 3\"`"
+            )
+        );
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn failure_because_max_is_zero() {
+        let max = 0;
+        let mut count = 0;
+        let result = assert_ok_within_attempts_as_result!(max, || { count += 1; Ok::<i32, String>(1) });
+        let actual = result.unwrap_err();
+        assert!(actual.contains(" failed attempts: `0`,\n"));
+        assert!(actual.ends_with("        last err: `none, because max is 0`"));
+        assert_eq!(count, 0);
+    }
+}
+
+/// Assert a closure returns Ok(…) within a maximum number of attempts.
+///
+/// Pseudocode:<br>
+/// closure() is Ok(value) within max attempts ⇒ (value, attempts)
+///
+/// * If true, return `(value, attempts)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let mut count = 0;
+/// let (value, attempts) = assert_ok_within_attempts!(3, || {
+///     count += 1;
+///     if count < 2 { Err("busy") } else { Ok(count * 10) }
+/// });
+/// assert_eq!(value, 20);
+/// assert_eq!(attempts, 2);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let max = 3;
+/// assert_ok_within_attempts!(max, || Err::<i32, &str>("busy"));
+/// # });
+/// // assertion failed: `assert_ok_within_attempts!(max, closure)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_within_attempts.html
+/// //        max label: `max`,
+/// //        max debug: `3`,
+/// //    closure label: `|| Err::<i32, &str>(\"busy\")`,
+/// //  failed attempts: `3`,
+/// //         last err: `\"busy\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ok_within_attempts!(max, closure)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ok_within_attempts.html\n",
+/// #     "       max label: `max`,\n",
+/// #     "       max debug: `3`,\n",
+/// #     "   closure label: `|| Err::<i32, &str>(\"busy\")`,\n",
+/// #     " failed attempts: `3`,\n",
+/// #     "        last err: `\"busy\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ok_within_attempts`](macro@crate::assert_ok_within_attempts)
+/// * [`assert_ok_within_attempts_as_result`](macro@crate::assert_ok_within_attempts_as_result)
+/// * [`debug_assert_ok_within_attempts`](macro@crate::debug_assert_ok_within_attempts)
+///
+#[macro_export]
+macro_rules! assert_ok_within_attempts {
+    ($max:expr, $closure:expr $(,)?) => {{
+        match $crate::assert_ok_within_attempts_as_result!($max, $closure) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($max:expr, $closure:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_within_attempts_as_result!($max, $closure) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a closure returns Ok(…) within a maximum number of attempts.
+///
+/// Pseudocode:<br>
+/// closure() is Ok(value) within max attempts ⇒ (value, attempts)
+///
+/// This macro provides the same statements as [`assert_ok_within_attempts`](macro.assert_ok_within_attempts.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_within_attempts`](macro@crate::assert_ok_within_attempts)
+/// * [`assert_ok_within_attempts_as_result`](macro@crate::assert_ok_within_attempts_as_result)
+/// * [`debug_assert_ok_within_attempts`](macro@crate::debug_assert_ok_within_attempts)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_within_attempts {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ok_within_attempts!($($arg)*);
+        }
+    };
+}
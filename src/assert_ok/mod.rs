@@ -6,6 +6,7 @@
 //!
 //! * [`assert_ok!(a)`](macro@crate::assert_ok)
 //!   ≈ a is Ok.
+//! * [`assert_is_ok!(a)`](macro@crate::assert_is_ok) ≈ a.is_ok(), without formatting the payload
 //!
 //! Compare Ok(…) to another Ok(…):
 //!
@@ -16,6 +17,32 @@
 //!
 //! * [`assert_ok_eq_x!(a, expr)`](macro@crate::assert_ok_eq_x) ≈ (a ⇒ Ok(a1) ⇒ a1) = expr
 //! * [`assert_ok_ne_x!(a, expr)`](macro@crate::assert_ok_ne_x) ≈ (a ⇒ Ok(a1) ⇒ a1) ≠ expr
+//! * [`assert_ok_eq_into_x!(a, expr)`](macro@crate::assert_ok_eq_into_x) ≈ (a ⇒ Ok(a1) ⇒ a1) = (expr ⇒ try_into)
+//! * [`assert_ok_display_eq_x!(a, expr)`](macro@crate::assert_ok_display_eq_x) ≈ (a ⇒ Ok(a1) ⇒ a1.to_string()) = expr
+//! * [`assert_ok_map_eq!(a, f, expr)`](macro@crate::assert_ok_map_eq) ≈ (a ⇒ Ok(a1) ⇒ f(a1)) = expr
+//! * [`assert_ok_in_delta_x!(a, expr, delta)`](macro@crate::assert_ok_in_delta_x) ≈ (a ⇒ Ok(a1) ⇒ a1) ∧ | a1 - expr | ≤ Δ
+//! * [`assert_ok_slice_eq_x!(a, expr)`](macro@crate::assert_ok_slice_eq_x) ≈ (a ⇒ Ok(a1) ⇒ a1[..]) = expr[..], with the first difference
+//!
+//! Assert expression is Ok(Option), such as `Result<Option<T>, E>`:
+//!
+//! * [`assert_ok_none!(a)`](macro@crate::assert_ok_none) ≈ a is Ok(None)
+//! * [`assert_ok_some_eq_x!(a, expr)`](macro@crate::assert_ok_some_eq_x) ≈ (a ⇒ Ok(Some(a1)) ⇒ a1) = expr
+//!
+//! Assert expression is Ok(Result), such as `Result<Result<T, E2>, E1>`:
+//!
+//! * [`assert_ok_ok_eq_x!(a, expr)`](macro@crate::assert_ok_ok_eq_x) ≈ (a ⇒ Ok(Ok(a1)) ⇒ a1) = expr
+//!
+//! Assert a closure returns Ok(…) within a maximum number of attempts:
+//!
+//! * [`assert_ok_within_attempts!(max, closure)`](macro@crate::assert_ok_within_attempts) ≈ closure() is Ok(value) within max attempts ⇒ (value, attempts)
+//!
+//! Compare Ok(…) or the default to an expression:
+//!
+//! * [`assert_ok_or_default_eq!(a, expr)`](macro@crate::assert_ok_or_default_eq) ≈ (a ⇒ Ok(a1) ⇒ a1, or Err(_) ⇒ default) = expr
+//!
+//! Compare Ok(reader) read_to_string() to an expression:
+//!
+//! * [`assert_ok_read_to_string_eq_x!(a, expr)`](macro@crate::assert_ok_read_to_string_eq_x) ≈ (a ⇒ Ok(a_reader) ⇒ a_reader.read_to_string()) = expr
 //!
 //! # Example
 //!
@@ -29,6 +56,7 @@
 //! ```
 
 // Verify Ok(_)
+pub mod assert_is_ok;
 pub mod assert_ok;
 
 // Compare another
@@ -36,5 +64,44 @@ pub mod assert_ok_eq;
 pub mod assert_ok_ne;
 
 // Compare expression
+pub mod assert_ok_display_eq_x;
+pub mod assert_ok_eq_into_x;
 pub mod assert_ok_eq_x;
+pub mod assert_ok_in_delta_x;
+pub mod assert_ok_map_eq;
 pub mod assert_ok_ne_x;
+pub mod assert_ok_slice_eq_x;
+
+// Compare Ok(Option)
+pub mod assert_ok_none;
+pub mod assert_ok_some_eq_x;
+
+// Compare Ok(Result)
+pub mod assert_ok_ok_eq_x;
+
+// Retry closure
+pub mod assert_ok_within_attempts;
+
+// Compare expression or default
+pub mod assert_ok_or_default_eq;
+
+// Compare reader
+pub mod assert_ok_read_to_string_eq_x;
+
+/// Convert a value into the type of another value, via `TryInto`.
+///
+/// The first argument is only for type inference, so a macro can convert an
+/// expression into the type of an inner value without naming the type.
+#[doc(hidden)]
+pub fn try_into_type_of<T, U: TryInto<T>>(_like: &T, value: U) -> Result<T, U::Error> {
+    value.try_into()
+}
+
+/// Call a function with a value.
+///
+/// A macro calls a closure via this function, rather than directly, so the
+/// compiler infers the closure parameter type from the value.
+#[doc(hidden)]
+pub fn call_with<T, U>(value: T, f: impl FnOnce(T) -> U) -> U {
+    f(value)
+}
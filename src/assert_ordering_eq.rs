@@ -0,0 +1,276 @@
+//! Assert the ordering of two values, by `Ord::cmp`, is equal to an expected ordering.
+//!
+//! Pseudocode:<br>
+//! a.cmp(&b) = ordering
+//!
+//! This calls `Ord::cmp` directly, rather than the operators of `PartialOrd`,
+//! so it tests a custom `Ord` implementation, including `Ordering::Equal`.
+//! This matters because a buggy implementation can have `PartialOrd` and
+//! `Ord::cmp` diverge, and then [`assert_lt`](macro@crate::assert_lt) and
+//! similar macros do not test `Ord::cmp`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::cmp::Ordering;
+//!
+//! # fn main() {
+//! let a = 1;
+//! let b = 2;
+//! assert_ordering_eq!(a, b, Ordering::Less);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_ordering_eq`](macro@crate::assert_ordering_eq)
+//! * [`assert_ordering_eq_as_result`](macro@crate::assert_ordering_eq_as_result)
+//! * [`debug_assert_ordering_eq`](macro@crate::debug_assert_ordering_eq)
+
+/// Assert the ordering of two values, by `Ord::cmp`, is equal to an expected ordering.
+///
+/// Pseudocode:<br>
+/// a.cmp(&b) = ordering
+///
+/// * If true, return Result `Ok(ordering)`.
+///
+/// * Otherwise, return Result `Err(message)` with the actual ordering.
+///
+/// This macro provides the same statements as [`assert_ordering_eq`](macro.assert_ordering_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ordering_eq`](macro@crate::assert_ordering_eq)
+/// * [`assert_ordering_eq_as_result`](macro@crate::assert_ordering_eq_as_result)
+/// * [`debug_assert_ordering_eq`](macro@crate::debug_assert_ordering_eq)
+///
+#[macro_export]
+macro_rules! assert_ordering_eq_as_result {
+    ($a:expr, $b:expr, $ordering:expr $(,)?) => {{
+        match (&$a, &$b, &$ordering) {
+            (a, b, ordering) => {
+                let ordering: ::std::cmp::Ordering = *ordering;
+                let actual = ::std::cmp::Ord::cmp(a, b);
+                if actual == ordering {
+                    Ok(actual)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_ordering_eq!(a, b, ordering)`\n",
+                                "{}macro.assert_ordering_eq.html\n",
+                                "        a label: `{}`,\n",
+                                "        a debug: `{:?}`,\n",
+                                "        b label: `{}`,\n",
+                                "        b debug: `{:?}`,\n",
+                                " ordering label: `{}`,\n",
+                                " ordering debug: `{:?}`,\n",
+                                "      a.cmp(&b): `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($ordering),
+                            ordering,
+                            actual
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    /// A version number that compares by major, then minor, in `Ord::cmp`,
+    /// but has a buggy `PartialOrd` that compares only by major.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Version {
+        major: u8,
+        minor: u8,
+    }
+
+    impl Ord for Version {
+        fn cmp(&self, other: &Self) -> Ordering {
+            (self.major, self.minor).cmp(&(other.major, other.minor))
+        }
+    }
+
+    #[allow(clippy::non_canonical_partial_ord_impl)]
+    impl PartialOrd for Version {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.major.partial_cmp(&other.major)
+        }
+    }
+
+    #[test]
+    fn success_less() {
+        let a = 1;
+        let b = 2;
+        let result = assert_ordering_eq_as_result!(a, b, Ordering::Less);
+        assert_eq!(result, Ok(Ordering::Less));
+    }
+
+    #[test]
+    fn success_equal() {
+        let a = "alfa";
+        let b = "alfa";
+        let result = assert_ordering_eq_as_result!(a, b, Ordering::Equal);
+        assert_eq!(result, Ok(Ordering::Equal));
+    }
+
+    #[test]
+    fn success_with_custom_cmp() {
+        let a = Version { major: 1, minor: 2 };
+        let b = Version { major: 1, minor: 3 };
+        assert_ne!(a.partial_cmp(&b), Some(Ordering::Less));
+        let result = assert_ordering_eq_as_result!(a, b, Ordering::Less);
+        assert_eq!(result, Ok(Ordering::Less));
+    }
+
+    #[test]
+    fn failure() {
+        let a = 2;
+        let b = 1;
+        let result = assert_ordering_eq_as_result!(a, b, Ordering::Less);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_ordering_eq!(a, b, ordering)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ordering_eq.html\n",
+                "        a label: `a`,\n",
+                "        a debug: `2`,\n",
+                "        b label: `b`,\n",
+                "        b debug: `1`,\n",
+                " ordering label: `Ordering::Less`,\n",
+                " ordering debug: `Less`,\n",
+                "      a.cmp(&b): `Greater`"
+            )
+        );
+    }
+}
+
+/// Assert the ordering of two values, by `Ord::cmp`, is equal to an expected ordering.
+///
+/// Pseudocode:<br>
+/// a.cmp(&b) = ordering
+///
+/// * If true, return `ordering`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// use std::cmp::Ordering;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = 1;
+/// let b = 2;
+/// assert_ordering_eq!(a, b, Ordering::Less);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = 2;
+/// let b = 1;
+/// assert_ordering_eq!(a, b, Ordering::Less);
+/// # });
+/// // assertion failed: `assert_ordering_eq!(a, b, ordering)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_ordering_eq.html
+/// //         a label: `a`,
+/// //         a debug: `2`,
+/// //         b label: `b`,
+/// //         b debug: `1`,
+/// //  ordering label: `Ordering::Less`,
+/// //  ordering debug: `Less`,
+/// //       a.cmp(&b): `Greater`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_ordering_eq!(a, b, ordering)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ordering_eq.html\n",
+/// #     "        a label: `a`,\n",
+/// #     "        a debug: `2`,\n",
+/// #     "        b label: `b`,\n",
+/// #     "        b debug: `1`,\n",
+/// #     " ordering label: `Ordering::Less`,\n",
+/// #     " ordering debug: `Less`,\n",
+/// #     "      a.cmp(&b): `Greater`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_ordering_eq`](macro@crate::assert_ordering_eq)
+/// * [`assert_ordering_eq_as_result`](macro@crate::assert_ordering_eq_as_result)
+/// * [`debug_assert_ordering_eq`](macro@crate::debug_assert_ordering_eq)
+///
+#[macro_export]
+macro_rules! assert_ordering_eq {
+    ($a:expr, $b:expr, $ordering:expr $(,)?) => {{
+        match $crate::assert_ordering_eq_as_result!($a, $b, $ordering) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $ordering:expr, $($message:tt)+) => {{
+        match $crate::assert_ordering_eq_as_result!($a, $b, $ordering) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert the ordering of two values, by `Ord::cmp`, is equal to an expected ordering.
+///
+/// Pseudocode:<br>
+/// a.cmp(&b) = ordering
+///
+/// This macro provides the same statements as [`assert_ordering_eq`](macro.assert_ordering_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ordering_eq`](macro@crate::assert_ordering_eq)
+/// * [`assert_ordering_eq_as_result`](macro@crate::assert_ordering_eq_as_result)
+/// * [`debug_assert_ordering_eq`](macro@crate::debug_assert_ordering_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_ordering_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_ordering_eq!($($arg)*);
+        }
+    };
+}
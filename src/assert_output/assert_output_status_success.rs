@@ -0,0 +1,211 @@
+//! Assert a command output has a success status.
+//!
+//! Pseudocode:<br>
+//! output.status.success()
+//!
+//! The output is a `::std::process::Output` that is already captured, such as
+//! by [`assert_command_output`](macro@crate::assert_command_output), so the
+//! command does not run again. The message shows the stderr, which usually
+//! explains why the command failed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let output = Command::new("bin/exit-with-arg").arg("0").output().unwrap();
+//! assert_output_status_success!(output);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_output_status_success`](macro@crate::assert_output_status_success)
+//! * [`assert_output_status_success_as_result`](macro@crate::assert_output_status_success_as_result)
+//! * [`debug_assert_output_status_success`](macro@crate::debug_assert_output_status_success)
+
+/// Assert a command output has a success status.
+///
+/// Pseudocode:<br>
+/// output.status.success()
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_output_status_success`](macro.assert_output_status_success.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_output_status_success`](macro@crate::assert_output_status_success)
+/// * [`assert_output_status_success_as_result`](macro@crate::assert_output_status_success_as_result)
+/// * [`debug_assert_output_status_success`](macro@crate::debug_assert_output_status_success)
+///
+#[macro_export]
+macro_rules! assert_output_status_success_as_result {
+    ($output:expr $(,)?) => {{
+        match (&$output) {
+            output => {
+                if output.status.success() {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_output_status_success!(output)`\n",
+                                "{}macro.assert_output_status_success.html\n",
+                                "  output label: `{}`,\n",
+                                "   status code: `{:?}`,\n",
+                                " output stderr: `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($output),
+                            output.status.code(),
+                            String::from_utf8_lossy(&output.stderr)
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let output = Command::new("bin/exit-with-arg").arg("0").output().unwrap();
+        let result = assert_output_status_success_as_result!(output);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure() {
+        let output = Command::new("bin/exit-with-arg").arg("1").output().unwrap();
+        let result = assert_output_status_success_as_result!(output);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_output_status_success!(output)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_output_status_success.html\n",
+                "  output label: `output`,\n",
+                "   status code: `Some(1)`,\n",
+                " output stderr: `\"\"`"
+            )
+        );
+    }
+}
+
+/// Assert a command output has a success status.
+///
+/// Pseudocode:<br>
+/// output.status.success()
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let output = Command::new("bin/exit-with-arg").arg("0").output().unwrap();
+/// assert_output_status_success!(output);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let output = Command::new("bin/exit-with-arg").arg("1").output().unwrap();
+/// assert_output_status_success!(output);
+/// # });
+/// // assertion failed: `assert_output_status_success!(output)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_output_status_success.html
+/// //   output label: `output`,
+/// //    status code: `Some(1)`,
+/// //  output stderr: `\"\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_output_status_success!(output)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_output_status_success.html\n",
+/// #     "  output label: `output`,\n",
+/// #     "   status code: `Some(1)`,\n",
+/// #     " output stderr: `\"\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_output_status_success`](macro@crate::assert_output_status_success)
+/// * [`assert_output_status_success_as_result`](macro@crate::assert_output_status_success_as_result)
+/// * [`debug_assert_output_status_success`](macro@crate::debug_assert_output_status_success)
+///
+#[macro_export]
+macro_rules! assert_output_status_success {
+    ($output:expr $(,)?) => {{
+        match $crate::assert_output_status_success_as_result!($output) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($output:expr, $($message:tt)+) => {{
+        match $crate::assert_output_status_success_as_result!($output) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command output has a success status.
+///
+/// Pseudocode:<br>
+/// output.status.success()
+///
+/// This macro provides the same statements as [`assert_output_status_success`](macro.assert_output_status_success.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_output_status_success`](macro@crate::assert_output_status_success)
+/// * [`assert_output_status_success_as_result`](macro@crate::assert_output_status_success_as_result)
+/// * [`debug_assert_output_status_success`](macro@crate::debug_assert_output_status_success)
+///
+#[macro_export]
+macro_rules! debug_assert_output_status_success {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_output_status_success!($($arg)*);
+        }
+    };
+}
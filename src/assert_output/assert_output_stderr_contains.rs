@@ -0,0 +1,231 @@
+//! Assert a command output stderr string contains a given containee.
+//!
+//! Pseudocode:<br>
+//! (output.stderr ⇒ string) contains containee
+//!
+//! The output is a `::std::process::Output` that is already captured, such as
+//! by [`assert_command_output`](macro@crate::assert_command_output), so the
+//! command does not run again. The stderr converts to a string lossily, so
+//! invalid UTF-8 does not panic.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let output = Command::new("bin/printf-stderr").args(["%s", "alfa"]).output().unwrap();
+//! assert_output_stderr_contains!(output, "lf");
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_output_stderr_contains`](macro@crate::assert_output_stderr_contains)
+//! * [`assert_output_stderr_contains_as_result`](macro@crate::assert_output_stderr_contains_as_result)
+//! * [`debug_assert_output_stderr_contains`](macro@crate::debug_assert_output_stderr_contains)
+
+/// Assert a command output stderr string contains a given containee.
+///
+/// Pseudocode:<br>
+/// (output.stderr ⇒ string) contains containee
+///
+/// * If true, return Result `Ok(string)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The containee can be any type that implements `AsRef<str>`, such as
+/// `&str` or `String`.
+///
+/// This macro provides the same statements as [`assert_output_stderr_contains`](macro.assert_output_stderr_contains.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_output_stderr_contains`](macro@crate::assert_output_stderr_contains)
+/// * [`assert_output_stderr_contains_as_result`](macro@crate::assert_output_stderr_contains_as_result)
+/// * [`debug_assert_output_stderr_contains`](macro@crate::debug_assert_output_stderr_contains)
+///
+#[macro_export]
+macro_rules! assert_output_stderr_contains_as_result {
+    ($output:expr, $containee:expr $(,)?) => {{
+        match (&$output, &$containee) {
+            (output, containee) => {
+                let string = String::from_utf8_lossy(&output.stderr);
+                if string.contains(AsRef::<str>::as_ref(containee)) {
+                    Ok(string.into_owned())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_output_stderr_contains!(output, containee)`\n",
+                                "{}macro.assert_output_stderr_contains.html\n",
+                                "    output label: `{}`,\n",
+                                " containee label: `{}`,\n",
+                                " containee debug: `{:?}`,\n",
+                                "   output stderr: `{:?}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($output),
+                            stringify!($containee),
+                            containee,
+                            string
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let output = Command::new("bin/printf-stderr").args(["%s", "alfa"]).output().unwrap();
+        let containee = "lf";
+        let result = assert_output_stderr_contains_as_result!(output, containee);
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn success_with_string() {
+        let output = Command::new("bin/printf-stderr").args(["%s", "alfa"]).output().unwrap();
+        let containee = String::from("alfa");
+        let result = assert_output_stderr_contains_as_result!(output, containee);
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn failure() {
+        let output = Command::new("bin/printf-stderr").args(["%s", "alfa"]).output().unwrap();
+        let containee = "zz";
+        let result = assert_output_stderr_contains_as_result!(output, containee);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_output_stderr_contains!(output, containee)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_output_stderr_contains.html\n",
+                "    output label: `output`,\n",
+                " containee label: `containee`,\n",
+                " containee debug: `\"zz\"`,\n",
+                "   output stderr: `\"alfa\"`"
+            )
+        );
+    }
+}
+
+/// Assert a command output stderr string contains a given containee.
+///
+/// Pseudocode:<br>
+/// (output.stderr ⇒ string) contains containee
+///
+/// * If true, return `string`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let output = Command::new("bin/printf-stderr").args(["%s", "alfa"]).output().unwrap();
+/// assert_output_stderr_contains!(output, "lf");
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let output = Command::new("bin/printf-stderr").args(["%s", "alfa"]).output().unwrap();
+/// let containee = "zz";
+/// assert_output_stderr_contains!(output, containee);
+/// # });
+/// // assertion failed: `assert_output_stderr_contains!(output, containee)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_output_stderr_contains.html
+/// //     output label: `output`,
+/// //  containee label: `containee`,
+/// //  containee debug: `\"zz\"`,
+/// //    output stderr: `\"alfa\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_output_stderr_contains!(output, containee)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_output_stderr_contains.html\n",
+/// #     "    output label: `output`,\n",
+/// #     " containee label: `containee`,\n",
+/// #     " containee debug: `\"zz\"`,\n",
+/// #     "   output stderr: `\"alfa\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_output_stderr_contains`](macro@crate::assert_output_stderr_contains)
+/// * [`assert_output_stderr_contains_as_result`](macro@crate::assert_output_stderr_contains_as_result)
+/// * [`debug_assert_output_stderr_contains`](macro@crate::debug_assert_output_stderr_contains)
+///
+#[macro_export]
+macro_rules! assert_output_stderr_contains {
+    ($output:expr, $containee:expr $(,)?) => {{
+        match $crate::assert_output_stderr_contains_as_result!($output, $containee) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($output:expr, $containee:expr, $($message:tt)+) => {{
+        match $crate::assert_output_stderr_contains_as_result!($output, $containee) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command output stderr string contains a given containee.
+///
+/// Pseudocode:<br>
+/// (output.stderr ⇒ string) contains containee
+///
+/// This macro provides the same statements as [`assert_output_stderr_contains`](macro.assert_output_stderr_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_output_stderr_contains`](macro@crate::assert_output_stderr_contains)
+/// * [`assert_output_stderr_contains_as_result`](macro@crate::assert_output_stderr_contains_as_result)
+/// * [`debug_assert_output_stderr_contains`](macro@crate::debug_assert_output_stderr_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_output_stderr_contains {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_output_stderr_contains!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,241 @@
+//! Assert a command output stdout is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! output.stdout = expr
+//!
+//! The output is a `::std::process::Output` that is already captured, such as
+//! by [`assert_command_output`](macro@crate::assert_command_output), so the
+//! command does not run again, and the macro only borrows the output.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let output = Command::new("bin/printf-stdout").args(["%s", "alfa"]).output().unwrap();
+//! let bytes = vec![b'a', b'l', b'f', b'a'];
+//! assert_output_stdout_eq_x!(output, bytes);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_output_stdout_eq_x`](macro@crate::assert_output_stdout_eq_x)
+//! * [`assert_output_stdout_eq_x_as_result`](macro@crate::assert_output_stdout_eq_x_as_result)
+//! * [`debug_assert_output_stdout_eq_x`](macro@crate::debug_assert_output_stdout_eq_x)
+
+/// Assert a command output stdout is equal to an expression.
+///
+/// Pseudocode:<br>
+/// output.stdout = expr
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_output_stdout_eq_x`](macro.assert_output_stdout_eq_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_output_stdout_eq_x`](macro@crate::assert_output_stdout_eq_x)
+/// * [`assert_output_stdout_eq_x_as_result`](macro@crate::assert_output_stdout_eq_x_as_result)
+/// * [`debug_assert_output_stdout_eq_x`](macro@crate::debug_assert_output_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_output_stdout_eq_x_as_result {
+    ($output:expr, $expr:expr $(,)?) => {{
+        match (&$output, &$expr) {
+            (output, expr) => {
+                if output.stdout.eq(expr) {
+                    Ok(output.stdout.clone())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_output_stdout_eq_x!(output, expr)`\n",
+                                "{}macro.assert_output_stdout_eq_x.html\n",
+                                "  output label: `{}`,\n",
+                                "    expr label: `{}`,\n",
+                                "    expr debug: `{:?}`,\n",
+                                " output stdout: `{:?}`,\n",
+                                "       hexdump:\n{}"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($output),
+                            stringify!($expr),
+                            expr,
+                            output.stdout,
+                            $crate::hexdump::hexdump_diff(&output.stdout, &expr[..])
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let output = Command::new("bin/printf-stdout").args(["%s", "alfa"]).output().unwrap();
+        let expr = vec![b'a', b'l', b'f', b'a'];
+        let result = assert_output_stdout_eq_x_as_result!(output, expr);
+        assert_eq!(result.unwrap(), vec![b'a', b'l', b'f', b'a']);
+    }
+
+    #[test]
+    fn success_with_reuse() {
+        let output = Command::new("bin/printf-stdout").args(["%s", "alfa"]).output().unwrap();
+        let result = assert_output_stdout_eq_x_as_result!(output, b"alfa");
+        assert!(result.is_ok());
+        let result = assert_output_stdout_eq_x_as_result!(&output, b"alfa");
+        assert!(result.is_ok());
+        assert!(output.status.success());
+    }
+
+    #[test]
+    fn failure() {
+        let output = Command::new("bin/printf-stdout").args(["%s", "alfa"]).output().unwrap();
+        let expr = vec![b'z', b'z'];
+        let result = assert_output_stdout_eq_x_as_result!(output, expr);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_output_stdout_eq_x!(output, expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_output_stdout_eq_x.html\n",
+                "  output label: `output`,\n",
+                "    expr label: `expr`,\n",
+                "    expr debug: `[122, 122]`,\n",
+                " output stdout: `[97, 108, 102, 97]`,\n",
+                "       hexdump:\n",
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
+            )
+        );
+    }
+}
+
+/// Assert a command output stdout is equal to an expression.
+///
+/// Pseudocode:<br>
+/// output.stdout = expr
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let output = Command::new("bin/printf-stdout").args(["%s", "alfa"]).output().unwrap();
+/// let bytes = vec![b'a', b'l', b'f', b'a'];
+/// assert_output_stdout_eq_x!(output, bytes);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let output = Command::new("bin/printf-stdout").args(["%s", "alfa"]).output().unwrap();
+/// let bytes = vec![b'z', b'z'];
+/// assert_output_stdout_eq_x!(output, bytes);
+/// # });
+/// // assertion failed: `assert_output_stdout_eq_x!(output, expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_output_stdout_eq_x.html
+/// //   output label: `output`,
+/// //     expr label: `bytes`,
+/// //     expr debug: `[122, 122]`,
+/// //  output stdout: `[97, 108, 102, 97]`,
+/// //        hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 61              alfa      7a 7a                    zz
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_output_stdout_eq_x!(output, expr)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_output_stdout_eq_x.html\n",
+/// #     "  output label: `output`,\n",
+/// #     "    expr label: `bytes`,\n",
+/// #     "    expr debug: `[122, 122]`,\n",
+/// #     " output stdout: `[97, 108, 102, 97]`,\n",
+/// #     "       hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 61              alfa      7a 7a                    zz"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_output_stdout_eq_x`](macro@crate::assert_output_stdout_eq_x)
+/// * [`assert_output_stdout_eq_x_as_result`](macro@crate::assert_output_stdout_eq_x_as_result)
+/// * [`debug_assert_output_stdout_eq_x`](macro@crate::debug_assert_output_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_output_stdout_eq_x {
+    ($output:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_output_stdout_eq_x_as_result!($output, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($output:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_output_stdout_eq_x_as_result!($output, $expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command output stdout is equal to an expression.
+///
+/// Pseudocode:<br>
+/// output.stdout = expr
+///
+/// This macro provides the same statements as [`assert_output_stdout_eq_x`](macro.assert_output_stdout_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_output_stdout_eq_x`](macro@crate::assert_output_stdout_eq_x)
+/// * [`assert_output_stdout_eq_x_as_result`](macro@crate::assert_output_stdout_eq_x_as_result)
+/// * [`debug_assert_output_stdout_eq_x`](macro@crate::debug_assert_output_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_output_stdout_eq_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_output_stdout_eq_x!($($arg)*);
+        }
+    };
+}
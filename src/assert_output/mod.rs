@@ -0,0 +1,30 @@
+//! Assert for checking a command output that is already captured.
+//!
+//! These macros take a `::std::process::Output`, rather than a
+//! `::std::process::Command`, and only inspect its fields. A test can run a
+//! command once, such as with [`assert_command_output`](macro@crate::assert_command_output),
+//! then make many assertions without running the command again, and without
+//! borrowing the command as mutable.
+//!
+//! * [`assert_output_status_success!(output)`](macro@crate::assert_output_status_success) ≈ output.status.success()
+//! * [`assert_output_stdout_eq_x!(output, expr)`](macro@crate::assert_output_stdout_eq_x) ≈ output.stdout = expr
+//! * [`assert_output_stderr_contains!(output, containee)`](macro@crate::assert_output_stderr_contains) ≈ (output.stderr ⇒ string) contains containee
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! # fn main() {
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let output = assert_command_output!(command);
+//! assert_output_status_success!(output);
+//! assert_output_stdout_eq_x!(output, b"alfa");
+//! # }
+//! ```
+
+pub mod assert_output_status_success;
+pub mod assert_output_stderr_contains;
+pub mod assert_output_stdout_eq_x;
@@ -0,0 +1,227 @@
+//! Assert a closure does not panic.
+//!
+//! Pseudocode:<br>
+//! catch_unwind(closure) is Ok(value)
+//!
+//! This is useful for a regression test, such as an input that used to
+//! cause a panic. If the closure panics, the message shows the panic
+//! payload, rather than the test harness showing a generic panic.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let value = assert_no_panic!(|| "1".parse::<i32>().unwrap());
+//! assert_eq!(value, 1);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_no_panic`](macro@crate::assert_no_panic)
+//! * [`assert_no_panic_as_result`](macro@crate::assert_no_panic_as_result)
+//! * [`debug_assert_no_panic`](macro@crate::debug_assert_no_panic)
+
+/// Assert a closure does not panic.
+///
+/// Pseudocode:<br>
+/// catch_unwind(closure) is Ok(value)
+///
+/// * If true, return Result `Ok(value)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The macro wraps the closure in `::std::panic::AssertUnwindSafe`, so the
+/// closure can capture mutable references, which `catch_unwind` otherwise
+/// rejects. The caller must not rely on captured state after a panic, because
+/// the closure may have left that state half-updated.
+///
+/// The panic hook still runs when the closure panics, so the test output may
+/// include the usual panic line, in addition to this macro's message.
+///
+/// This macro provides the same statements as [`assert_no_panic`](macro.assert_no_panic.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_no_panic`](macro@crate::assert_no_panic)
+/// * [`assert_no_panic_as_result`](macro@crate::assert_no_panic_as_result)
+/// * [`debug_assert_no_panic`](macro@crate::debug_assert_no_panic)
+///
+#[macro_export]
+macro_rules! assert_no_panic_as_result {
+    ($closure:expr $(,)?) => {{
+        match (::std::panic::catch_unwind(::std::panic::AssertUnwindSafe($closure))) {
+            Ok(value) => Ok(value),
+            Err(payload) => {
+                let (payload_type, payload_debug) =
+                    $crate::assert_panics::describe_payload(&*payload);
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_no_panic!(closure)`\n",
+                            "{}macro.assert_no_panic.html\n",
+                            " closure label: `{}`,\n",
+                            "  payload type: `{}`,\n",
+                            " payload debug: `{}`"
+                        ),
+                        $crate::DOC_URL_BASE,
+                        stringify!($closure),
+                        payload_type,
+                        payload_debug
+                    )
+                )
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let result = assert_no_panic_as_result!(|| 1);
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[test]
+    fn success_with_mutable_capture() {
+        let mut count = 0;
+        let result = assert_no_panic_as_result!(|| count += 1);
+        assert_eq!(result, Ok(()));
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn failure_because_str_payload() {
+        let result = assert_no_panic_as_result!(|| -> i32 { panic!("boom") });
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_no_panic!(closure)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_panic.html\n",
+                " closure label: `|| -> i32 { panic!(\"boom\") }`,\n",
+                "  payload type: `&str`,\n",
+                " payload debug: `\"boom\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_string_payload() {
+        let n = 1;
+        let result = assert_no_panic_as_result!(|| -> i32 { panic!("boom {}", n) });
+        let actual = result.unwrap_err();
+        assert!(actual.contains("  payload type: `String`,\n"));
+        assert!(actual.ends_with(" payload debug: `\"boom 1\"`"));
+    }
+}
+
+/// Assert a closure does not panic.
+///
+/// Pseudocode:<br>
+/// catch_unwind(closure) is Ok(value)
+///
+/// * If true, return `value`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let value = assert_no_panic!(|| "1".parse::<i32>().unwrap());
+/// assert_eq!(value, 1);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// assert_no_panic!(|| -> i32 { panic!("boom") });
+/// # });
+/// // assertion failed: `assert_no_panic!(closure)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_panic.html
+/// //  closure label: `|| -> i32 { panic!("boom") }`,
+/// //   payload type: `&str`,
+/// //  payload debug: `"boom"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_no_panic!(closure)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_no_panic.html\n",
+/// #     " closure label: `|| -> i32 { panic!(\"boom\") }`,\n",
+/// #     "  payload type: `&str`,\n",
+/// #     " payload debug: `\"boom\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_no_panic`](macro@crate::assert_no_panic)
+/// * [`assert_no_panic_as_result`](macro@crate::assert_no_panic_as_result)
+/// * [`debug_assert_no_panic`](macro@crate::debug_assert_no_panic)
+///
+#[macro_export]
+macro_rules! assert_no_panic {
+    ($closure:expr $(,)?) => {{
+        match $crate::assert_no_panic_as_result!($closure) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($closure:expr, $($message:tt)+) => {{
+        match $crate::assert_no_panic_as_result!($closure) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a closure does not panic.
+///
+/// Pseudocode:<br>
+/// catch_unwind(closure) is Ok(value)
+///
+/// This macro provides the same statements as [`assert_no_panic`](macro.assert_no_panic.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_no_panic`](macro@crate::assert_no_panic)
+/// * [`assert_no_panic_as_result`](macro@crate::assert_no_panic_as_result)
+/// * [`debug_assert_no_panic`](macro@crate::debug_assert_no_panic)
+///
+#[macro_export]
+macro_rules! debug_assert_no_panic {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_no_panic!($($arg)*);
+        }
+    };
+}
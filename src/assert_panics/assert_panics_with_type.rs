@@ -0,0 +1,281 @@
+//! Assert a closure panics with a payload of a given type.
+//!
+//! Pseudocode:<br>
+//! catch_unwind(closure) is Err(payload) ⇒ payload.downcast::<T>() is Ok(value)
+//!
+//! This is useful for code that panics with a custom payload type,
+//! such as via `::std::panic::panic_any`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let code: i32 = assert_panics_with_type!(|| std::panic::panic_any(7_i32), i32);
+//! assert_eq!(code, 7);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_panics_with_type`](macro@crate::assert_panics_with_type)
+//! * [`assert_panics_with_type_as_result`](macro@crate::assert_panics_with_type_as_result)
+//! * [`debug_assert_panics_with_type`](macro@crate::debug_assert_panics_with_type)
+
+/// Assert a closure panics with a payload of a given type.
+///
+/// Pseudocode:<br>
+/// catch_unwind(closure) is Err(payload) ⇒ payload.downcast::<T>() is Ok(value)
+///
+/// * If true, return Result `Ok(value)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// When the closure panics with a payload of another type, the message
+/// describes the payload on a best-effort basis: a `&str` or `String`
+/// payload shows its text, and any other payload shows its `TypeId`.
+///
+/// This macro provides the same statements as [`assert_panics_with_type`](macro.assert_panics_with_type.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_panics_with_type`](macro@crate::assert_panics_with_type)
+/// * [`assert_panics_with_type_as_result`](macro@crate::assert_panics_with_type_as_result)
+/// * [`debug_assert_panics_with_type`](macro@crate::debug_assert_panics_with_type)
+///
+#[macro_export]
+macro_rules! assert_panics_with_type_as_result {
+    ($closure:expr, $type:ty $(,)?) => {{
+        match (::std::panic::catch_unwind($closure)) {
+            Ok(_) => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_panics_with_type!(closure, type)`\n",
+                            "{}macro.assert_panics_with_type.html\n",
+                            " closure label: `{}`,\n",
+                            "    type label: `{}`,\n",
+                            "       outcome: `did not panic`"
+                        ),
+                        $crate::DOC_URL_BASE,
+                        stringify!($closure),
+                        stringify!($type)
+                    )
+                )
+            },
+            Err(payload) => {
+                match (payload.downcast::<$type>()) {
+                    Ok(value) => Ok(*value),
+                    Err(payload) => {
+                        let (payload_type, payload_debug) =
+                            $crate::assert_panics::describe_payload(&*payload);
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_panics_with_type!(closure, type)`\n",
+                                    "{}macro.assert_panics_with_type.html\n",
+                                    " closure label: `{}`,\n",
+                                    "    type label: `{}`,\n",
+                                    "  payload type: `{}`,\n",
+                                    " payload debug: `{}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($closure),
+                                stringify!($type),
+                                payload_type,
+                                payload_debug
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[derive(Debug, PartialEq)]
+    struct Code(i32);
+
+    #[test]
+    fn success_with_custom_payload() {
+        let result = assert_panics_with_type_as_result!(|| std::panic::panic_any(Code(7)), Code);
+        assert_eq!(result.unwrap(), Code(7));
+    }
+
+    #[test]
+    fn success_with_str_payload() {
+        let result = assert_panics_with_type_as_result!(|| panic!("boom"), &str);
+        assert_eq!(result.unwrap(), "boom");
+    }
+
+    #[test]
+    fn failure_because_no_panic() {
+        let result = assert_panics_with_type_as_result!(|| 1, Code);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_panics_with_type!(closure, type)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_panics_with_type.html\n",
+                " closure label: `|| 1`,\n",
+                "    type label: `Code`,\n",
+                "       outcome: `did not panic`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_str_payload() {
+        let result = assert_panics_with_type_as_result!(|| panic!("boom"), Code);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_panics_with_type!(closure, type)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_panics_with_type.html\n",
+                " closure label: `|| panic!(\"boom\")`,\n",
+                "    type label: `Code`,\n",
+                "  payload type: `&str`,\n",
+                " payload debug: `\"boom\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_string_payload() {
+        let result = assert_panics_with_type_as_result!(|| std::panic::panic_any(String::from("boom")), Code);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_panics_with_type!(closure, type)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_panics_with_type.html\n",
+                " closure label: `|| std::panic::panic_any(String::from(\"boom\"))`,\n",
+                "    type label: `Code`,\n",
+                "  payload type: `String`,\n",
+                " payload debug: `\"boom\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_unknown_payload() {
+        let result = assert_panics_with_type_as_result!(|| std::panic::panic_any(7_u8), Code);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("  payload type: `unknown`,\n"));
+        assert!(actual.contains(&format!(
+            " payload debug: `{:?}`",
+            std::any::TypeId::of::<u8>()
+        )));
+    }
+}
+
+/// Assert a closure panics with a payload of a given type.
+///
+/// Pseudocode:<br>
+/// catch_unwind(closure) is Err(payload) ⇒ payload.downcast::<T>() is Ok(value)
+///
+/// * If true, return `value`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let code: i32 = assert_panics_with_type!(|| std::panic::panic_any(7_i32), i32);
+/// assert_eq!(code, 7);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// assert_panics_with_type!(|| panic!("boom"), i32);
+/// # });
+/// // assertion failed: `assert_panics_with_type!(closure, type)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_panics_with_type.html
+/// //  closure label: `|| panic!("boom")`,
+/// //     type label: `i32`,
+/// //   payload type: `&str`,
+/// //  payload debug: `"boom"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_panics_with_type!(closure, type)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_panics_with_type.html\n",
+/// #     " closure label: `|| panic!(\"boom\")`,\n",
+/// #     "    type label: `i32`,\n",
+/// #     "  payload type: `&str`,\n",
+/// #     " payload debug: `\"boom\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_panics_with_type`](macro@crate::assert_panics_with_type)
+/// * [`assert_panics_with_type_as_result`](macro@crate::assert_panics_with_type_as_result)
+/// * [`debug_assert_panics_with_type`](macro@crate::debug_assert_panics_with_type)
+///
+#[macro_export]
+macro_rules! assert_panics_with_type {
+    ($closure:expr, $type:ty $(,)?) => {{
+        match $crate::assert_panics_with_type_as_result!($closure, $type) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($closure:expr, $type:ty, $($message:tt)+) => {{
+        match $crate::assert_panics_with_type_as_result!($closure, $type) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a closure panics with a payload of a given type.
+///
+/// Pseudocode:<br>
+/// catch_unwind(closure) is Err(payload) ⇒ payload.downcast::<T>() is Ok(value)
+///
+/// This macro provides the same statements as [`assert_panics_with_type`](macro.assert_panics_with_type.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_panics_with_type`](macro@crate::assert_panics_with_type)
+/// * [`assert_panics_with_type_as_result`](macro@crate::assert_panics_with_type_as_result)
+/// * [`debug_assert_panics_with_type`](macro@crate::debug_assert_panics_with_type)
+///
+#[macro_export]
+macro_rules! debug_assert_panics_with_type {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_panics_with_type!($($arg)*);
+        }
+    };
+}
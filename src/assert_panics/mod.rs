@@ -0,0 +1,47 @@
+//! Assert for panics.
+//!
+//! These macros help with code that panics, such as a closure that calls
+//! `panic!` with a message, or `::std::panic::panic_any` with a custom payload.
+//! The macros run the closure with `::std::panic::catch_unwind`, then check
+//! the outcome.
+//!
+//! Assert a closure panics with a payload of a given type:
+//!
+//! * [`assert_panics_with_type!(closure, T)`](macro@crate::assert_panics_with_type) ≈ catch_unwind(closure) is Err(payload) ⇒ payload.downcast::<T>() is Ok
+//!
+//! Assert a closure does not panic:
+//!
+//! * [`assert_no_panic!(closure)`](macro@crate::assert_no_panic) ≈ catch_unwind(closure) is Ok(value)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let code: i32 = assert_panics_with_type!(|| std::panic::panic_any(7_i32), i32);
+//! assert_eq!(code, 7);
+//! # }
+//! ```
+
+// Verify no panic
+pub mod assert_no_panic;
+
+// Verify payload type
+pub mod assert_panics_with_type;
+
+/// Describe a panic payload, as a type name and a debug string.
+///
+/// A `&str` or `String` payload, such as from `panic!` with a message, shows
+/// its text. Any other payload shows its `TypeId`, because `Any` has no way
+/// to format an arbitrary value.
+#[doc(hidden)]
+pub fn describe_payload(payload: &(dyn ::std::any::Any + Send)) -> (&'static str, String) {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        ("&str", format!("{:?}", s))
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        ("String", format!("{:?}", s))
+    } else {
+        ("unknown", format!("{:?}", payload.type_id()))
+    }
+}
@@ -22,7 +22,7 @@
 //! * [`assert_pending_as_result`](macro@crate::assert_pending_as_result)
 //! * [`debug_assert_pending`](macro@crate::debug_assert_pending)
 
-/// Assert an expression.is_pending() is true.
+/// Assert an expression is Pending.
 ///
 /// Pseudocode:<br>
 /// a is Pending
@@ -52,17 +52,20 @@ macro_rules! assert_pending_as_result {
                     Pending => {
                         Ok(())
                     },
-                    _ => {
+                    Ready(a1) => {
                         Err(
                             format!(
                                 concat!(
                                     "assertion failed: `assert_pending!(a)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_pending.html\n",
+                                    "{}macro.assert_pending.html\n",
                                     " a label: `{}`,\n",
-                                    " a debug: `{:?}`",
+                                    " a debug: `{:?}`,\n",
+                                    " a inner: `{:?}`",
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a),
-                                a
+                                a,
+                                a1
                             )
                         )
                     }
@@ -94,7 +97,8 @@ mod tests {
                 "assertion failed: `assert_pending!(a)`\n",
                 "https://docs.rs/assertables/9.2.0/assertables/macro.assert_pending.html\n",
                 " a label: `a`,\n",
-                " a debug: `Ready(1)`"
+                " a debug: `Ready(1)`,\n",
+                " a inner: `1`"
             )
         );
     }
@@ -129,13 +133,15 @@ mod tests {
 /// // assertion failed: `assert_pending!(a)`
 /// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_pending.html
 /// //  a label: `a`,
-/// //  a debug: `Ready(1)`
+/// //  a debug: `Ready(1)`,
+/// //  a inner: `1`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let expect = concat!(
 /// #     "assertion failed: `assert_pending!(a)`\n",
 /// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_pending.html\n",
 /// #     " a label: `a`,\n",
-/// #     " a debug: `Ready(1)`",
+/// #     " a debug: `Ready(1)`,\n",
+/// #     " a inner: `1`",
 /// # );
 /// # assert_eq!(actual, expect);
 /// # }
@@ -191,7 +197,7 @@ macro_rules! assert_pending {
 /// # Module macros
 ///
 /// * [`assert_pending`](macro@crate::assert_pending)
-/// * [`assert_pending`](macro@crate::assert_pending)
+/// * [`assert_pending_as_result`](macro@crate::assert_pending_as_result)
 /// * [`debug_assert_pending`](macro@crate::debug_assert_pending)
 ///
 #[macro_export]
@@ -0,0 +1,314 @@
+//! Assert a pipeline of programs with args has stdout equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (program1 + args1 | program2 + args2 | … ⇒ stdout) = expr
+//!
+//! Each stage standard output is the next stage standard input, like a
+//! shell pipeline. The failure message shows which stage failed to spawn,
+//! or which stage exited unsuccessfully.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let stages = [
+//!     ("bin/printf-stdout", vec!["%s", "alfa"]),
+//!     ("tr", vec!["a-z", "A-Z"]),
+//! ];
+//! let expr = vec![b'A', b'L', b'F', b'A'];
+//! assert_pipeline_stdout_eq_x!(&stages, expr);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_pipeline_stdout_eq_x`](macro@crate::assert_pipeline_stdout_eq_x)
+//! * [`assert_pipeline_stdout_eq_x_as_result`](macro@crate::assert_pipeline_stdout_eq_x_as_result)
+//! * [`debug_assert_pipeline_stdout_eq_x`](macro@crate::debug_assert_pipeline_stdout_eq_x)
+
+/// Assert a pipeline of programs with args has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// (program1 + args1 | program2 + args2 | … ⇒ stdout) = expr
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * If a stage fails to spawn, or exits unsuccessfully, return Result
+///   `Err(message)` with the stage index and program.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_pipeline_stdout_eq_x`](macro.assert_pipeline_stdout_eq_x.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_pipeline_stdout_eq_x`](macro@crate::assert_pipeline_stdout_eq_x)
+/// * [`assert_pipeline_stdout_eq_x_as_result`](macro@crate::assert_pipeline_stdout_eq_x_as_result)
+/// * [`debug_assert_pipeline_stdout_eq_x`](macro@crate::debug_assert_pipeline_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_pipeline_stdout_eq_x_as_result {
+    ($stages:expr, $expr:expr $(,)?) => {{
+        match ($stages, &$expr) {
+            (stages, expr) => {
+                match $crate::assert_pipeline::pipeline_stdout(stages) {
+                    Ok(a) => {
+                        if a.eq(expr) {
+                            Ok(a)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_pipeline_stdout_eq_x!(stages, expr)`\n",
+                                        "{}macro.assert_pipeline_stdout_eq_x.html\n",
+                                        "        stages label: `{}`,\n",
+                                        "        stages debug: `{:?}`,\n",
+                                        "          expr label: `{}`,\n",
+                                        "          expr debug: `{:?}`,\n",
+                                        "        stdout value: `{:?}`,\n",
+                                        "          expr value: `{:?}`,\n",
+                                        "             hexdump:\n{}"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($stages),
+                                    stages,
+                                    stringify!($expr),
+                                    expr,
+                                    a,
+                                    expr,
+                                    $crate::hexdump::hexdump_diff(&a, &expr[..])
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_pipeline_stdout_eq_x!(stages, expr)`\n",
+                                    "{}macro.assert_pipeline_stdout_eq_x.html\n",
+                                    "        stages label: `{}`,\n",
+                                    "        stages debug: `{:?}`,\n",
+                                    "          expr label: `{}`,\n",
+                                    "          expr debug: `{:?}`,\n",
+                                    "{}"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($stages),
+                                stages,
+                                stringify!($expr),
+                                expr,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let stages = [
+            ("bin/printf-stdout", vec!["%s", "alfa"]),
+            ("tr", vec!["a-z", "A-Z"]),
+        ];
+        let expr = vec![b'A', b'L', b'F', b'A'];
+        let result = assert_pipeline_stdout_eq_x_as_result!(&stages, expr);
+        assert_eq!(result.unwrap(), vec![b'A', b'L', b'F', b'A']);
+    }
+
+    #[test]
+    fn failure() {
+        let stages = [
+            ("bin/printf-stdout", vec!["%s", "alfa"]),
+            ("tr", vec!["a-z", "A-Z"]),
+        ];
+        let expr = vec![b'a', b'l', b'f', b'a'];
+        let result = assert_pipeline_stdout_eq_x_as_result!(&stages, expr);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_pipeline_stdout_eq_x!(stages, expr)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_pipeline_stdout_eq_x.html\n",
+                "        stages label: `&stages`,\n",
+                "        stages debug: `[(\"bin/printf-stdout\", [\"%s\", \"alfa\"]), (\"tr\", [\"a-z\", \"A-Z\"])]`,\n",
+                "          expr label: `expr`,\n",
+                "          expr debug: `[97, 108, 102, 97]`,\n",
+                "        stdout value: `[65, 76, 70, 65]`,\n",
+                "          expr value: `[97, 108, 102, 97]`,\n",
+                "             hexdump:\n",
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                ">00000000  41 4c 46 41              ALFA      61 6c 66 61              alfa"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_stage_cannot_spawn() {
+        let stages = [
+            ("bin/printf-stdout", vec!["%s", "alfa"]),
+            ("bin/no-such-program", vec![]),
+        ];
+        let expr = vec![b'a'];
+        let result = assert_pipeline_stdout_eq_x_as_result!(&stages, expr);
+        let actual = result.unwrap_err();
+        assert!(actual.contains(concat!(
+            "          expr debug: `[97]`,\n",
+            "         stage index: `1`,\n",
+            "       stage program: `\"bin/no-such-program\"`,\n",
+            "     stage spawn err: `"
+        )));
+    }
+
+    #[test]
+    fn failure_because_stage_exits_unsuccessfully() {
+        let stages = [
+            ("bin/printf-stdout", vec!["%s", "alfa"]),
+            ("bin/exit-with-arg", vec!["1"]),
+        ];
+        let expr = vec![b'a'];
+        let result = assert_pipeline_stdout_eq_x_as_result!(&stages, expr);
+        let actual = result.unwrap_err();
+        assert!(actual.contains(concat!(
+            "          expr debug: `[97]`,\n",
+            "         stage index: `1`,\n",
+            "       stage program: `\"bin/exit-with-arg\"`,\n",
+            "        stage status: `"
+        )));
+    }
+}
+
+/// Assert a pipeline of programs with args has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// (program1 + args1 | program2 + args2 | … ⇒ stdout) = expr
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let stages = [
+///     ("bin/printf-stdout", vec!["%s", "alfa"]),
+///     ("tr", vec!["a-z", "A-Z"]),
+/// ];
+/// let expr = vec![b'A', b'L', b'F', b'A'];
+/// assert_pipeline_stdout_eq_x!(&stages, expr);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let stages = [
+///     ("bin/printf-stdout", vec!["%s", "alfa"]),
+///     ("tr", vec!["a-z", "A-Z"]),
+/// ];
+/// let expr = vec![b'a', b'l', b'f', b'a'];
+/// assert_pipeline_stdout_eq_x!(&stages, expr);
+/// # });
+/// // assertion failed: `assert_pipeline_stdout_eq_x!(stages, expr)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_pipeline_stdout_eq_x.html
+/// //         stages label: `&stages`,
+/// //         stages debug: `[(\"bin/printf-stdout\", [\"%s\", \"alfa\"]), (\"tr\", [\"a-z\", \"A-Z\"])]`,
+/// //           expr label: `expr`,
+/// //           expr debug: `[97, 108, 102, 97]`,
+/// //         stdout value: `[65, 76, 70, 65]`,
+/// //           expr value: `[97, 108, 102, 97]`,
+/// //              hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  41 4c 46 41              ALFA      61 6c 66 61              alfa
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_pipeline_stdout_eq_x!(stages, expr)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_pipeline_stdout_eq_x.html\n",
+/// #     "        stages label: `&stages`,\n",
+/// #     "        stages debug: `[(\"bin/printf-stdout\", [\"%s\", \"alfa\"]), (\"tr\", [\"a-z\", \"A-Z\"])]`,\n",
+/// #     "          expr label: `expr`,\n",
+/// #     "          expr debug: `[97, 108, 102, 97]`,\n",
+/// #     "        stdout value: `[65, 76, 70, 65]`,\n",
+/// #     "          expr value: `[97, 108, 102, 97]`,\n",
+/// #     "             hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  41 4c 46 41              ALFA      61 6c 66 61              alfa"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_pipeline_stdout_eq_x`](macro@crate::assert_pipeline_stdout_eq_x)
+/// * [`assert_pipeline_stdout_eq_x_as_result`](macro@crate::assert_pipeline_stdout_eq_x_as_result)
+/// * [`debug_assert_pipeline_stdout_eq_x`](macro@crate::debug_assert_pipeline_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_pipeline_stdout_eq_x {
+    ($stages:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_pipeline_stdout_eq_x_as_result!($stages, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($stages:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_pipeline_stdout_eq_x_as_result!($stages, $expr) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a pipeline of programs with args has stdout equal to an expression.
+///
+/// Pseudocode:<br>
+/// (program1 + args1 | program2 + args2 | … ⇒ stdout) = expr
+///
+/// This macro provides the same statements as [`assert_pipeline_stdout_eq_x`](macro.assert_pipeline_stdout_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_pipeline_stdout_eq_x`](macro@crate::assert_pipeline_stdout_eq_x)
+/// * [`assert_pipeline_stdout_eq_x_as_result`](macro@crate::assert_pipeline_stdout_eq_x_as_result)
+/// * [`debug_assert_pipeline_stdout_eq_x`](macro@crate::debug_assert_pipeline_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_pipeline_stdout_eq_x {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_pipeline_stdout_eq_x!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,201 @@
+//! Assert for pipelines of programs with arguments.
+//!
+//! These macros help with calling a sequence of external programs with
+//! arguments, like a shell pipeline, where each program standard output is
+//! the next program standard input, then capturing the last standard output.
+//!
+//! Each stage is a tuple of a program and its args, in the same way as the
+//! macros in the module [`assert_program_args`](module@crate::assert_program_args).
+//!
+//! Compare pipeline standard output to an expression:
+//!
+//! * [`assert_pipeline_stdout_eq_x!(stages, expr)`](macro@crate::assert_pipeline_stdout_eq_x) ≈ stage1 | stage2 | … ⇒ stdout = expr
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let stages = [
+//!     ("bin/printf-stdout", vec!["%s", "alfa"]),
+//!     ("tr", vec!["a-z", "A-Z"]),
+//! ];
+//! assert_pipeline_stdout_eq_x!(&stages, b"ALFA".to_vec());
+//! # }
+//! ```
+
+pub mod assert_pipeline_stdout_eq_x;
+
+/// Run a pipeline of programs with arguments, and capture the last stdout.
+///
+/// The first stage reads standard input from null. Each next stage reads
+/// standard input from the previous stage standard output. The standard
+/// error of each stage is discarded.
+///
+/// * If every stage spawns and exits successfully, return Result `Ok(stdout)`.
+///
+/// * Otherwise, return Result `Err(message)`, with message lines that show
+///   the index and program of the stage that failed to spawn, or the last
+///   stage that exited unsuccessfully, like the shell `set -o pipefail`.
+///   If a stage fails to spawn, then the stages that are already running
+///   are killed and waited on.
+///
+/// The last stage that exited unsuccessfully is the most useful one to show,
+/// because when a stage exits early, the stage before it can fail too,
+/// such as by a broken pipe, depending on timing.
+///
+/// The macros use this function, then add their own message lines.
+#[doc(hidden)]
+pub fn pipeline_stdout<P, A, S>(stages: &[(P, A)]) -> Result<Vec<u8>, String>
+where
+    P: AsRef<::std::ffi::OsStr>,
+    A: AsRef<[S]>,
+    S: AsRef<::std::ffi::OsStr>,
+{
+    use ::std::process::{Child, Command, Stdio};
+
+    fn kill_all(children: &mut [(usize, Child)]) {
+        for (_, child) in children.iter_mut() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+
+    if stages.is_empty() {
+        return Err(String::from("     stages is empty: `true`"));
+    }
+    let mut children: Vec<(usize, Child)> = Vec::with_capacity(stages.len());
+    let mut stdin: Option<Stdio> = None;
+    for (index, (program, args)) in stages.iter().enumerate() {
+        let mut command = Command::new(program);
+        command
+            .args(args.as_ref())
+            .stdin(stdin.take().unwrap_or_else(Stdio::null))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+        match command.spawn() {
+            Ok(mut child) => {
+                if index + 1 < stages.len() {
+                    stdin = child.stdout.take().map(Stdio::from);
+                }
+                children.push((index, child));
+            }
+            Err(err) => {
+                kill_all(&mut children);
+                return Err(format!(
+                    concat!(
+                        "         stage index: `{}`,\n",
+                        "       stage program: `{:?}`,\n",
+                        "     stage spawn err: `{:?}`"
+                    ),
+                    index,
+                    program.as_ref(),
+                    err
+                ));
+            }
+        }
+    }
+
+    // The last stage is waited on with its output, which reads its stdout
+    // until the end; then the earlier stages are waited on, in order.
+    let (last_index, last_child) = children.pop().expect("stages is not empty");
+    let last = last_child.wait_with_output();
+    let mut statuses = Vec::with_capacity(stages.len());
+    for (index, mut child) in children {
+        statuses.push((index, child.wait()));
+    }
+    let stdout = match last {
+        Ok(output) => {
+            statuses.push((last_index, Ok(output.status)));
+            output.stdout
+        }
+        Err(err) => {
+            statuses.push((last_index, Err(err)));
+            Vec::new()
+        }
+    };
+    for (index, status) in statuses.into_iter().rev() {
+        match status {
+            Ok(status) if status.success() => {}
+            Ok(status) => {
+                return Err(format!(
+                    concat!(
+                        "         stage index: `{}`,\n",
+                        "       stage program: `{:?}`,\n",
+                        "        stage status: `{:?}`"
+                    ),
+                    index,
+                    stages[index].0.as_ref(),
+                    status
+                ));
+            }
+            Err(err) => {
+                return Err(format!(
+                    concat!(
+                        "         stage index: `{}`,\n",
+                        "       stage program: `{:?}`,\n",
+                        "      stage wait err: `{:?}`"
+                    ),
+                    index,
+                    stages[index].0.as_ref(),
+                    err
+                ));
+            }
+        }
+    }
+    Ok(stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pipeline_stdout;
+
+    #[test]
+    fn success() {
+        let stages = [
+            ("bin/printf-stdout", vec!["%s", "alfa"]),
+            ("tr", vec!["a-z", "A-Z"]),
+            ("rev", vec![]),
+        ];
+        assert_eq!(pipeline_stdout(&stages).unwrap(), b"AFLA".to_vec());
+    }
+
+    #[test]
+    fn failure_because_stages_is_empty() {
+        let stages: [(&str, Vec<&str>); 0] = [];
+        assert_eq!(
+            pipeline_stdout(&stages).unwrap_err(),
+            "     stages is empty: `true`"
+        );
+    }
+
+    #[test]
+    fn failure_because_stage_cannot_spawn() {
+        let stages = [
+            ("bin/printf-stdout", vec!["%s", "alfa"]),
+            ("bin/no-such-program", vec![]),
+        ];
+        let actual = pipeline_stdout(&stages).unwrap_err();
+        assert!(actual.starts_with(concat!(
+            "         stage index: `1`,\n",
+            "       stage program: `\"bin/no-such-program\"`,\n",
+            "     stage spawn err: `"
+        )));
+    }
+
+    #[test]
+    fn failure_because_stage_exits_unsuccessfully() {
+        let stages = [
+            ("bin/printf-stdout", vec!["%s", "alfa"]),
+            ("bin/exit-with-arg", vec!["2"]),
+            ("cat", vec![]),
+        ];
+        let actual = pipeline_stdout(&stages).unwrap_err();
+        assert!(actual.starts_with(concat!(
+            "         stage index: `1`,\n",
+            "       stage program: `\"bin/exit-with-arg\"`,\n",
+            "        stage status: `"
+        )));
+    }
+}
@@ -1,6 +1,6 @@
 //! Assert an expression is Pending.
 //!
-//! Deprecated. Please rename from `assert_poll_pending` into `assert_pending_as_result` because more developers prefer the shorter name.
+//! Deprecated. Please rename from `assert_poll_pending` into `assert_pending` because more developers prefer the shorter name.
 
 /// Assert an expression.is_pending() is true.
 ///
@@ -40,6 +40,20 @@ macro_rules! assert_poll_pending {
 #[macro_export]
 macro_rules! debug_assert_poll_pending {
     ($($arg:tt)*) => {
-        $crate::debug_assert_pending_as_result!($($arg)*)
+        $crate::debug_assert_pending!($($arg)*)
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use std::task::Poll;
+    use std::task::Poll::*;
+
+    #[test]
+    fn test_assert_poll_pending_forwards_to_assert_pending() {
+        let a: Poll<i8> = Pending;
+        assert_eq!(assert_poll_pending_as_result!(a), Ok(()));
+        assert_poll_pending!(a);
     }
 }
@@ -43,3 +43,28 @@ macro_rules! debug_assert_poll_ready_eq {
         $crate::debug_assert_ready_eq!($($arg)*)
     }
 }
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use std::task::Poll;
+    use std::task::Poll::*;
+
+    #[test]
+    fn test_assert_poll_ready_eq_forwards_to_assert_ready_eq_success() {
+        let a: Poll<i8> = Ready(1);
+        let b: Poll<i8> = Ready(1);
+        assert_eq!(assert_poll_ready_eq_as_result!(a, b), crate::assert_ready_eq_as_result!(a, b));
+        assert_eq!(assert_poll_ready_eq!(a, b), (1, 1));
+    }
+
+    #[test]
+    fn test_assert_poll_ready_eq_forwards_to_assert_ready_eq_failure() {
+        let a: Poll<i8> = Ready(1);
+        let b: Poll<i8> = Ready(2);
+        assert_eq!(assert_poll_ready_eq_as_result!(a, b), crate::assert_ready_eq_as_result!(a, b));
+        let a: Poll<i8> = Pending;
+        let b: Poll<i8> = Ready(1);
+        assert_eq!(assert_poll_ready_eq_as_result!(a, b), crate::assert_ready_eq_as_result!(a, b));
+    }
+}
@@ -57,13 +57,14 @@ macro_rules! assert_process_status_code_value_ge_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_process_status_code_value_ge_x!(a, b)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_process_status_code_value_ge_x.html\n",
+                                        "{}macro.assert_process_status_code_value_ge_x.html\n",
                                         " a label: `{}`,\n",
                                         " a debug: `{:?}`,\n",
                                         " a value: `{:?}`,\n",
                                         " b label: `{}`,\n",
                                         " b debug: `{:?}`"
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_process),
                                     $a_process,
                                     a2,
@@ -78,13 +79,14 @@ macro_rules! assert_process_status_code_value_ge_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_process_status_code_value_ge_x!(a, b)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_process_status_code_value_ge_x.html\n",
+                                    "{}macro.assert_process_status_code_value_ge_x.html\n",
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`,\n",
                                     "  a code: `{:?}`,\n",
                                     " b label: `{}`,\n",
                                     " b debug: `{:?}`",
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_process),
                                 $a_process,
                                 a_code,
@@ -100,13 +102,14 @@ macro_rules! assert_process_status_code_value_ge_x_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_process_status_code_value_ge_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_process_status_code_value_ge_x.html\n",
+                            "{}macro.assert_process_status_code_value_ge_x.html\n",
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             " a status: `{:?}`,\n",
                             "  b label: `{}`,\n",
                             "  b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a_process),
                         $a_process,
                         a_status,
@@ -57,7 +57,7 @@ macro_rules! assert_process_status_code_value_lt_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_process_status_code_value_lt!(a, b)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_process_status_code_value_lt.html\n",
+                                        "{}macro.assert_process_status_code_value_lt.html\n",
                                         " a label: `{}`,\n",
                                         " a debug: `{:?}`,\n",
                                         " a value: `{:?}`,\n",
@@ -65,6 +65,7 @@ macro_rules! assert_process_status_code_value_lt_as_result {
                                         " b debug: `{:?}`\n",
                                         " b value: `{:?}`",
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_process),
                                     $a_process,
                                     a2,
@@ -80,7 +81,7 @@ macro_rules! assert_process_status_code_value_lt_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_process_status_code_value_lt!(a, b)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_process_status_code_value_lt.html\n",
+                                    "{}macro.assert_process_status_code_value_lt.html\n",
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`,\n",
                                     "  a code: `{:?}`,\n",
@@ -88,6 +89,7 @@ macro_rules! assert_process_status_code_value_lt_as_result {
                                     " b debug: `{:?}`\n",
                                     "  b code: `{:?}`",
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_process),
                                 $a_process,
                                 a_code,
@@ -104,7 +106,7 @@ macro_rules! assert_process_status_code_value_lt_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_process_status_code_value_lt!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_process_status_code_value_lt.html\n",
+                            "{}macro.assert_process_status_code_value_lt.html\n",
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             " a status: `{:?}`,\n",
@@ -112,6 +114,7 @@ macro_rules! assert_process_status_code_value_lt_as_result {
                             "  b debug: `{:?}`\n",
                             " b status: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a_process),
                         $a_process,
                         a_status,
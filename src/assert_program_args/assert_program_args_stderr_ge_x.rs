@@ -58,7 +58,7 @@ macro_rules! assert_program_args_stderr_ge_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stderr_ge_x!(a_program, a_args, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stderr_ge_x.html\n",
+                                        "{}macro.assert_program_args_stderr_ge_x.html\n",
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -68,6 +68,7 @@ macro_rules! assert_program_args_stderr_ge_x_as_result {
                                         "               a: `{:?}`,\n",
                                         "               b: `{:?}`"
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_program),
                                     a_program,
                                     stringify!($a_args),
@@ -85,7 +86,7 @@ macro_rules! assert_program_args_stderr_ge_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stderr_ge_x!(a_program, a_args, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stderr_ge_x.html\n",
+                                    "{}macro.assert_program_args_stderr_ge_x.html\n",
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -94,6 +95,7 @@ macro_rules! assert_program_args_stderr_ge_x_as_result {
                                     "    b_expr debug: `{:?}`,\n",
                                     "             err: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_program),
                                 a_program,
                                 stringify!($a_args),
@@ -63,7 +63,7 @@ macro_rules! assert_program_args_stderr_lt_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stderr_lt!(a_program, a_args, b_program, b_args)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stderr_lt.html\n",
+                                        "{}macro.assert_program_args_stderr_lt.html\n",
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -75,6 +75,7 @@ macro_rules! assert_program_args_stderr_lt_as_result {
                                         "               a: `{:?}`,\n",
                                         "               b: `{:?}`"
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_program),
                                     a_program,
                                     stringify!($a_args),
@@ -94,7 +95,7 @@ macro_rules! assert_program_args_stderr_lt_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stderr_lt!(a_program, a_args, b_program, b_args)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stderr_lt.html\n",
+                                    "{}macro.assert_program_args_stderr_lt.html\n",
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -106,6 +107,7 @@ macro_rules! assert_program_args_stderr_lt_as_result {
                                     "               a: `{:?}`,\n",
                                     "               b: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_program),
                                 a_program,
                                 stringify!($a_args),
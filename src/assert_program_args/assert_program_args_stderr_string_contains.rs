@@ -63,7 +63,7 @@ macro_rules! assert_program_args_stderr_string_contains_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stderr_string_contains!(a_program, a_args, containee)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stderr_string_contains.html\n",
+                                        "{}macro.assert_program_args_stderr_string_contains.html\n",
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -73,6 +73,7 @@ macro_rules! assert_program_args_stderr_string_contains_as_result {
                                         "               a: `{:?}`,\n",
                                         "       containee: `{:?}`"
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_program),
                                     a_program,
                                     stringify!($a_args),
@@ -90,7 +91,7 @@ macro_rules! assert_program_args_stderr_string_contains_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stderr_string_contains!(a_program, a_args, containee)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stderr_string_contains.html\n",
+                                    "{}macro.assert_program_args_stderr_string_contains.html\n",
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -99,6 +100,7 @@ macro_rules! assert_program_args_stderr_string_contains_as_result {
                                     " containee debug: `{:?}`,\n",
                                     "             err: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_program),
                                 a_program,
                                 stringify!($a_args),
@@ -0,0 +1,355 @@
+//! Assert a command (built with program and args) stdout is equal to the contents of a file.
+//!
+//! Pseudocode:<br>
+//! (program + args ⇒ command ⇒ stdout) = std::fs::read(path)
+//!
+//! This is useful for golden-file testing, where the expected output of a
+//! program is kept in a file next to the tests. It corresponds to
+//! [`assert_command_stdout_eq_file`](macro@crate::assert_command_stdout_eq_file).
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let program = "bin/printf-stdout";
+//! let args = ["%s\\n", "alfa"];
+//! let path = "alfa.txt";
+//! assert_program_args_stdout_eq_file!(&program, &args, path);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_program_args_stdout_eq_file`](macro@crate::assert_program_args_stdout_eq_file)
+//! * [`assert_program_args_stdout_eq_file_as_result`](macro@crate::assert_program_args_stdout_eq_file_as_result)
+//! * [`debug_assert_program_args_stdout_eq_file`](macro@crate::debug_assert_program_args_stdout_eq_file)
+
+/// Assert a command (built with program and args) stdout is equal to the contents of a file.
+///
+/// Pseudocode:<br>
+/// (program + args ⇒ command ⇒ stdout) = std::fs::read(path)
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// When the bytes differ, the message shows both lengths, the first differing
+/// offset, and a hexdump. When one value is a prefix of the other, the first
+/// differing offset is the length of the shorter value. When the file cannot
+/// be read, the message shows the read error instead.
+///
+/// This macro provides the same statements as [`assert_program_args_stdout_eq_file`](macro.assert_program_args_stdout_eq_file.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_program_args_stdout_eq_file`](macro@crate::assert_program_args_stdout_eq_file)
+/// * [`assert_program_args_stdout_eq_file_as_result`](macro@crate::assert_program_args_stdout_eq_file_as_result)
+/// * [`debug_assert_program_args_stdout_eq_file`](macro@crate::debug_assert_program_args_stdout_eq_file)
+///
+#[macro_export]
+macro_rules! assert_program_args_stdout_eq_file_as_result {
+    ($a_program:expr, $a_args:expr, $path:expr $(,)?) => {{
+        match ($a_program, $a_args, &$path) {
+            (a_program, a_args, path) => {
+                match $crate::assert_program_args_impl_prep!(a_program, a_args) {
+                    Ok(a_output) => {
+                        let a = a_output.stdout;
+                        match (::std::fs::read(path)) {
+                            Ok(b) => {
+                                if a == b {
+                                    Ok(a)
+                                } else {
+                                    let offset = $crate::assert_slice::first_difference(&a, &b).unwrap_or(0);
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_program_args_stdout_eq_file!(a_program, a_args, path)`\n",
+                                                "{}macro.assert_program_args_stdout_eq_file.html\n",
+                                                "   a_program label: `{}`,\n",
+                                                "   a_program debug: `{:?}`,\n",
+                                                "      a_args label: `{}`,\n",
+                                                "      a_args debug: `{:?}`,\n",
+                                                "        path label: `{}`,\n",
+                                                "        path debug: `{:?}`,\n",
+                                                "           a value: `{:?}`,\n",
+                                                "        file value: `{:?}`,\n",
+                                                "             a len: `{}`,\n",
+                                                "          file len: `{}`,\n",
+                                                " first diff offset: `{}`,\n",
+                                                "           hexdump:\n{}"
+                                            ),
+                                            $crate::DOC_URL_BASE,
+                                            stringify!($a_program),
+                                            a_program,
+                                            stringify!($a_args),
+                                            a_args,
+                                            stringify!($path),
+                                            path,
+                                            a,
+                                            b,
+                                            a.len(),
+                                            b.len(),
+                                            offset,
+                                            $crate::hexdump::hexdump_diff(&a, &b)
+                                        )
+                                    )
+                                }
+                            },
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_program_args_stdout_eq_file!(a_program, a_args, path)`\n",
+                                            "{}macro.assert_program_args_stdout_eq_file.html\n",
+                                            " a_program label: `{}`,\n",
+                                            " a_program debug: `{:?}`,\n",
+                                            "    a_args label: `{}`,\n",
+                                            "    a_args debug: `{:?}`,\n",
+                                            "      path label: `{}`,\n",
+                                            "      path debug: `{:?}`,\n",
+                                            "     read is err: `{:?}`"
+                                        ),
+                                        $crate::DOC_URL_BASE,
+                                        stringify!($a_program),
+                                        a_program,
+                                        stringify!($a_args),
+                                        a_args,
+                                        stringify!($path),
+                                        path,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_program_args_stdout_eq_file!(a_program, a_args, path)`\n",
+                                        "{}macro.assert_program_args_stdout_eq_file.html\n",
+                                        " a_program label: `{}`,\n",
+                                        " a_program debug: `{:?}`,\n",
+                                        "    a_args label: `{}`,\n",
+                                        "    a_args debug: `{:?}`,\n",
+                                        "      path label: `{}`,\n",
+                                        "      path debug: `{:?}`,\n",
+                                    "   output is err: `{:?}`"
+                                ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a_program),
+                                    a_program,
+                                    stringify!($a_args),
+                                    a_args,
+                                    stringify!($path),
+                                    path,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn eq() {
+        let a_program = "bin/printf-stdout";
+        let a_args = ["%s\\n", "alfa"];
+        let path = "alfa.txt";
+        let result = assert_program_args_stdout_eq_file_as_result!(&a_program, &a_args, path);
+        assert_eq!(result.unwrap(), b"alfa\n".to_vec());
+    }
+
+    #[test]
+    fn ne() {
+        let a_program = "bin/printf-stdout";
+        let a_args = ["%s\\n", "alfxyz"];
+        let path = "alfa.txt";
+        let result = assert_program_args_stdout_eq_file_as_result!(&a_program, &a_args, path);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_program_args_stdout_eq_file!(a_program, a_args, path)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stdout_eq_file.html\n",
+                "   a_program label: `&a_program`,\n",
+                "   a_program debug: `\"bin/printf-stdout\"`,\n",
+                "      a_args label: `&a_args`,\n",
+                "      a_args debug: `[\"%s\\\\n\", \"alfxyz\"]`,\n",
+                "        path label: `path`,\n",
+                "        path debug: `\"alfa.txt\"`,\n",
+                "           a value: `[97, 108, 102, 120, 121, 122, 10]`,\n",
+                "        file value: `[97, 108, 102, 97, 10]`,\n",
+                "             a len: `7`,\n",
+                "          file len: `5`,\n",
+                " first diff offset: `3`,\n",
+                "           hexdump:\n",
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                ">00000000  61 6c 66 78 79 7a 0a     alfxyz.   61 6c 66 61 0a           alfa."
+            )
+        );
+    }
+
+    #[test]
+    fn ne_because_prefix() {
+        let a_program = "bin/printf-stdout";
+        let a_args = ["%s", "alfa"];
+        let path = "alfa.txt";
+        let result = assert_program_args_stdout_eq_file_as_result!(&a_program, &a_args, path);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("             a len: `4`,\n"));
+        assert!(actual.contains("          file len: `5`,\n"));
+        assert!(actual.contains(" first diff offset: `4`,\n"));
+    }
+
+    #[test]
+    fn read_is_err() {
+        let a_program = "bin/printf-stdout";
+        let a_args = ["%s\\n", "alfa"];
+        let path = "missing.txt";
+        let result = assert_program_args_stdout_eq_file_as_result!(&a_program, &a_args, path);
+        let actual = result.unwrap_err();
+        assert!(actual.contains("      path label: `path`,\n"));
+        assert!(actual.contains("     read is err: `Os {"));
+    }
+}
+
+/// Assert a command (built with program and args) stdout is equal to the contents of a file.
+///
+/// Pseudocode:<br>
+/// (program + args ⇒ command ⇒ stdout) = std::fs::read(path)
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let program = "bin/printf-stdout";
+/// let args = ["%s\\n", "alfa"];
+/// let path = "alfa.txt";
+/// assert_program_args_stdout_eq_file!(&program, &args, path);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a_program = "bin/printf-stdout";
+/// let a_args = ["%s\\n", "alfxyz"];
+/// let path = "alfa.txt";
+/// assert_program_args_stdout_eq_file!(&a_program, &a_args, path);
+/// # });
+/// // assertion failed: `assert_program_args_stdout_eq_file!(a_program, a_args, path)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stdout_eq_file.html
+/// //    a_program label: `&a_program`,
+/// //    a_program debug: `\"bin/printf-stdout\"`,
+/// //       a_args label: `&a_args`,
+/// //       a_args debug: `[\"%s\\\\n\", \"alfxyz\"]`,
+/// //         path label: `path`,
+/// //         path debug: `\"alfa.txt\"`,
+/// //            a value: `[97, 108, 102, 120, 121, 122, 10]`,
+/// //         file value: `[97, 108, 102, 97, 10]`,
+/// //              a len: `7`,
+/// //           file len: `5`,
+/// //  first diff offset: `3`,
+/// //            hexdump:
+/// //  offset    a hex                    a ascii   b hex                    b ascii
+/// // >00000000  61 6c 66 78 79 7a 0a     alfxyz.   61 6c 66 61 0a           alfa.
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_program_args_stdout_eq_file!(a_program, a_args, path)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stdout_eq_file.html\n",
+/// #     "   a_program label: `&a_program`,\n",
+/// #     "   a_program debug: `\"bin/printf-stdout\"`,\n",
+/// #     "      a_args label: `&a_args`,\n",
+/// #     "      a_args debug: `[\"%s\\\\n\", \"alfxyz\"]`,\n",
+/// #     "        path label: `path`,\n",
+/// #     "        path debug: `\"alfa.txt\"`,\n",
+/// #     "           a value: `[97, 108, 102, 120, 121, 122, 10]`,\n",
+/// #     "        file value: `[97, 108, 102, 97, 10]`,\n",
+/// #     "             a len: `7`,\n",
+/// #     "          file len: `5`,\n",
+/// #     " first diff offset: `3`,\n",
+/// #     "           hexdump:\n",
+/// #     " offset    a hex                    a ascii   b hex                    b ascii\n",
+/// #     ">00000000  61 6c 66 78 79 7a 0a     alfxyz.   61 6c 66 61 0a           alfa."
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_program_args_stdout_eq_file`](macro@crate::assert_program_args_stdout_eq_file)
+/// * [`assert_program_args_stdout_eq_file_as_result`](macro@crate::assert_program_args_stdout_eq_file_as_result)
+/// * [`debug_assert_program_args_stdout_eq_file`](macro@crate::debug_assert_program_args_stdout_eq_file)
+///
+#[macro_export]
+macro_rules! assert_program_args_stdout_eq_file {
+    ($a_program:expr, $a_args:expr, $path:expr $(,)?) => {{
+        match $crate::assert_program_args_stdout_eq_file_as_result!($a_program, $a_args, $path) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a_program:expr, $a_args:expr, $path:expr, $($message:tt)+) => {{
+        match $crate::assert_program_args_stdout_eq_file_as_result!($a_program, $a_args, $path) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a command (built with program and args) stdout is equal to the contents of a file.
+///
+/// Pseudocode:<br>
+/// (program + args ⇒ command ⇒ stdout) = std::fs::read(path)
+///
+/// This macro provides the same statements as [`assert_program_args_stdout_eq_file`](macro.assert_program_args_stdout_eq_file.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_program_args_stdout_eq_file`](macro@crate::assert_program_args_stdout_eq_file)
+/// * [`assert_program_args_stdout_eq_file_as_result`](macro@crate::assert_program_args_stdout_eq_file_as_result)
+/// * [`debug_assert_program_args_stdout_eq_file`](macro@crate::debug_assert_program_args_stdout_eq_file)
+///
+#[macro_export]
+macro_rules! debug_assert_program_args_stdout_eq_file {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_program_args_stdout_eq_file!($($arg)*);
+        }
+    };
+}
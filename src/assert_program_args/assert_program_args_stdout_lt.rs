@@ -64,7 +64,7 @@ macro_rules! assert_program_args_stdout_lt_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stdout_lt!(a_program, a_args, b_program, b_args)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stdout_lt.html\n",
+                                        "{}macro.assert_program_args_stdout_lt.html\n",
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -76,6 +76,7 @@ macro_rules! assert_program_args_stdout_lt_as_result {
                                         "               a: `{:?}`,\n",
                                         "               b: `{:?}`"
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_program),
                                     a_program,
                                     stringify!($a_args),
@@ -95,7 +96,7 @@ macro_rules! assert_program_args_stdout_lt_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stdout_lt!(a_program, a_args, b_program, b_args)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stdout_lt.html\n",
+                                    "{}macro.assert_program_args_stdout_lt.html\n",
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -107,6 +108,7 @@ macro_rules! assert_program_args_stdout_lt_as_result {
                                     "        a output: `{:?}`,\n",
                                     "        b output: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_program),
                                 a_program,
                                 stringify!($a_args),
@@ -59,7 +59,7 @@ macro_rules! assert_program_args_stdout_string_is_match_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stdout_string_is_match!(a_program, b_matcher)`\n",
-                                        "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stdout_string_is_match.html\n",
+                                        "{}macro.assert_program_args_stdout_string_is_match.html\n",
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -69,6 +69,7 @@ macro_rules! assert_program_args_stdout_string_is_match_as_result {
                                         "               a: `{:?}`,\n",
                                         "               b: `{:?}`"
                                     ),
+                                    $crate::DOC_URL_BASE,
                                     stringify!($a_program),
                                     a_program,
                                     stringify!($a_args),
@@ -86,7 +87,7 @@ macro_rules! assert_program_args_stdout_string_is_match_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stdout_string_is_match!(a_program, b_matcher)`\n",
-                                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_program_args_stdout_string_is_match.html\n",
+                                    "{}macro.assert_program_args_stdout_string_is_match.html\n",
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -95,6 +96,7 @@ macro_rules! assert_program_args_stdout_string_is_match_as_result {
                                     " b_matcher debug: `{:?}`,\n",
                                     "        a output: `{:?}`"
                                 ),
+                                $crate::DOC_URL_BASE,
                                 stringify!($a_program),
                                 a_program,
                                 stringify!($a_args),
@@ -25,10 +25,15 @@
 //! * [`assert_program_args_stdout_gt_x!(program, args, expr)`](macro@crate::assert_program_args_stdout_gt_x) ≈ command using program and args to stdout > expr
 //! * [`assert_program_args_stdout_ge_x!(program, args, expr)`](macro@crate::assert_program_args_stdout_ge_x) ≈ command using program and args to stdout ≥ expr
 //!
+//! Compare program and arguments standard output to a file:
+//!
+//! * [`assert_program_args_stdout_eq_file!(program, args, path)`](macro@crate::assert_program_args_stdout_eq_file) ≈ command using program and args to stdout = std::fs::read(path)
+//!
 //! Assert program and arguments standard output as a string:
 //!
 //! * [`assert_program_args_stdout_string_contains!(program, args, containee)`](macro@crate::assert_program_args_stdout_string_contains) ≈ command using program and args to stdout string contains containee
 //! * [`assert_program_args_stdout_string_is_match!(program, args, matcher)`](macro@crate::assert_program_args_stdout_string_is_match) ≈ matcher is match with command using program and args
+//! * [`assert_program_args_stdout_string_is_not_match!(program, args, matcher)`](macro@crate::assert_program_args_stdout_string_is_not_match) ≈ matcher is not match with command using program and args
 //!
 //! ## Program args stderr
 //!
@@ -54,6 +59,7 @@
 //!
 //! * [`assert_program_args_stderr_string_contains!(program, args, containee)`](macro@crate::assert_program_args_stderr_string_contains) ≈ command using program and args to stderr string contains containee
 //! * [`assert_program_args_stderr_string_is_match!(program, args, matcher)`](macro@crate::assert_program_args_stderr_string_is_match) ≈ matcher is match with command using program and args
+//! * [`assert_program_args_stderr_string_is_not_match!(program, args, matcher)`](macro@crate::assert_program_args_stderr_string_is_not_match) ≈ matcher is not match with command using program and args
 //!
 //! # Example
 //!
@@ -95,11 +101,15 @@ pub mod assert_program_args_stdout_le_x;
 pub mod assert_program_args_stdout_lt_x;
 pub mod assert_program_args_stdout_ne_x;
 
+// stdout file
+pub mod assert_program_args_stdout_eq_file;
+
 // stdout string
 pub mod assert_program_args_stdout_contains;
 pub mod assert_program_args_stdout_is_match;
 pub mod assert_program_args_stdout_string_contains;
 pub mod assert_program_args_stdout_string_is_match;
+pub mod assert_program_args_stdout_string_is_not_match;
 
 // stderr
 pub mod assert_program_args_stderr_eq;
@@ -121,3 +131,4 @@ pub mod assert_program_args_stderr_contains;
 pub mod assert_program_args_stderr_is_match;
 pub mod assert_program_args_stderr_string_contains;
 pub mod assert_program_args_stderr_string_is_match;
+pub mod assert_program_args_stderr_string_is_not_match;
@@ -51,10 +51,11 @@ macro_rules! assert_ready_as_result {
             _ => Err(format!(
                 concat!(
                     "assertion failed: `assert_ready!(a)`\n",
-                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ready.html\n",
+                    "{}macro.assert_ready.html\n",
                     " a label: `{}`,\n",
                     " a debug: `{:?}`",
                 ),
+                $crate::DOC_URL_BASE,
                 stringify!($a),
                 $a,
             )),
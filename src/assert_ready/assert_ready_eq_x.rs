@@ -32,7 +32,7 @@
 /// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro provides the same statements as [`assert_ready_eq_x`](macro.assert_ready_eq_x.html),
-/// except this macro returns a Option, rather than doing a panic.
+/// except this macro returns a Result, rather than doing a panic.
 ///
 /// This macro is useful for runtime checks, such as checking parameters,
 /// or sanitizing inputs, or handling different results in different ways.
@@ -55,13 +55,14 @@ macro_rules! assert_ready_eq_x_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_ready_eq_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ready_eq_x.html\n",
+                                "{}macro.assert_ready_eq_x.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a inner: `{:?}`,\n",
                                 " b label: `{}`,\n",
                                 " b debug: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             $a,
                             a1,
@@ -76,12 +77,13 @@ macro_rules! assert_ready_eq_x_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_ready_eq_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ready_eq_x.html\n",
+                            "{}macro.assert_ready_eq_x.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
                         $a,
                         stringify!($b),
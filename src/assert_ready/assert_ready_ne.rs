@@ -32,7 +32,7 @@
 /// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro provides the same statements as [`assert_ready_ne`](macro.assert_ready_ne.html),
-/// except this macro returns a Option, rather than doing a panic.
+/// except this macro returns a Result, rather than doing a panic.
 ///
 /// This macro is useful for runtime checks, such as checking parameters,
 /// or sanitizing inputs, or handling different results in different ways.
@@ -47,7 +47,7 @@
 macro_rules! assert_ready_ne_as_result {
     ($a:expr, $b:expr $(,)?) => {
         match ($a, $b) {
-            (Ready(a1), Ready(b1)) => {
+            (::std::task::Poll::Ready(a1), ::std::task::Poll::Ready(b1)) => {
                 if a1 != b1 {
                     Ok((a1, b1))
                 } else {
@@ -55,7 +55,7 @@ macro_rules! assert_ready_ne_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_ready_ne!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ready_ne.html\n",
+                                "{}macro.assert_ready_ne.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a inner: `{:?}`,\n",
@@ -63,31 +63,33 @@ macro_rules! assert_ready_ne_as_result {
                                 " b debug: `{:?}`,\n",
                                 " b inner: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
-                            $a,
+                            ::std::task::Poll::Ready(&a1),
                             a1,
                             stringify!($b),
-                            $b,
+                            ::std::task::Poll::Ready(&b1),
                             b1
                         )
                     )
                 }
             },
-            _ => {
+            (a, b) => {
                 Err(
                     format!(
                         concat!(
                             "assertion failed: `assert_ready_ne!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ready_ne.html\n",
+                            "{}macro.assert_ready_ne.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
-                        $a,
+                        a,
                         stringify!($b),
-                        $b
+                        b
                     )
                 )
             }
@@ -108,6 +110,14 @@ mod tests {
         assert_eq!(result.unwrap(), (1, 2));
     }
 
+    #[test]
+    fn success_with_owned_inner_values() {
+        let a: Poll<String> = Poll::Ready(String::from("alfa"));
+        let b: Poll<String> = Poll::Ready(String::from("bravo"));
+        let result = assert_ready_ne_as_result!(a, b);
+        assert_eq!(result.unwrap(), (String::from("alfa"), String::from("bravo")));
+    }
+
     #[test]
     fn ne() {
         let a: Poll<i8> = Ready(1);
@@ -32,7 +32,7 @@
 /// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro provides the same statements as [`assert_ready_ne_x`](macro.assert_ready_ne_x.html),
-/// except this macro returns a Option, rather than doing a panic.
+/// except this macro returns a Result, rather than doing a panic.
 ///
 /// This macro is useful for runtime checks, such as checking parameters,
 /// or sanitizing inputs, or handling different results in different ways.
@@ -46,46 +46,48 @@
 #[macro_export]
 macro_rules! assert_ready_ne_x_as_result {
     ($a:expr, $b:expr $(,)?) => {
-        match ($a) {
-            Ready(a1) => {
-                if a1 != $b {
+        match ($a, $b) {
+            (::std::task::Poll::Ready(a1), b) => {
+                if a1 != b {
                     Ok(a1)
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_ready_ne_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ready_ne_x.html\n",
+                                "{}macro.assert_ready_ne_x.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a inner: `{:?}`,\n",
                                 " b label: `{}`,\n",
                                 " b debug: `{:?}`",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
-                            $a,
+                            ::std::task::Poll::Ready(&a1),
                             a1,
                             stringify!($b),
-                            $b
+                            b
                         )
                     )
                 }
             },
-            _ => {
+            (a, b) => {
                 Err(
                     format!(
                         concat!(
                             "assertion failed: `assert_ready_ne_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_ready_ne_x.html\n",
+                            "{}macro.assert_ready_ne_x.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
-                        $a,
+                        a,
                         stringify!($b),
-                        $b
+                        b
                     )
                 )
             }
@@ -106,6 +108,14 @@ mod tests {
         assert_eq!(result.unwrap(), 1);
     }
 
+    #[test]
+    fn success_with_owned_inner_value() {
+        let a: Poll<String> = Poll::Ready(String::from("alfa"));
+        let b = String::from("bravo");
+        let result = assert_ready_ne_x_as_result!(a, b);
+        assert_eq!(result.unwrap(), String::from("alfa"));
+    }
+
     #[test]
     fn ne() {
         let a: Poll<i8> = Ready(1);
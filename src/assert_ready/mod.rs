@@ -11,11 +11,19 @@
 //!
 //! * [`assert_ready_eq!(a, b)`](macro@crate::assert_ready_eq) ≈ (a ⇒ Ready(a1) ⇒ a1) = (b ⇒ Ready(b1) ⇒ b1)
 //! * [`assert_ready_ne!(a, b)`](macro@crate::assert_ready_ne) ≈ (a ⇒ Ready(a1) ⇒ a1) ≠ (b ⇒ Ready(b1) ⇒ b1)
+//! * [`assert_ready_lt!(a, b)`](macro@crate::assert_ready_lt) ≈ (a ⇒ Ready(a1) ⇒ a1) < (b ⇒ Ready(b1) ⇒ b1)
+//! * [`assert_ready_le!(a, b)`](macro@crate::assert_ready_le) ≈ (a ⇒ Ready(a1) ⇒ a1) ≤ (b ⇒ Ready(b1) ⇒ b1)
+//! * [`assert_ready_gt!(a, b)`](macro@crate::assert_ready_gt) ≈ (a ⇒ Ready(a1) ⇒ a1) > (b ⇒ Ready(b1) ⇒ b1)
+//! * [`assert_ready_ge!(a, b)`](macro@crate::assert_ready_ge) ≈ (a ⇒ Ready(a1) ⇒ a1) ≥ (b ⇒ Ready(b1) ⇒ b1)
 //!
 //! Compare Ready(…) to an expression:
 //!
 //! * [`assert_ready_eq_x!(a, expr)`](macro@crate::assert_ready_eq_x) ≈ (a ⇒ Ready(a1) ⇒ a1) = expr
 //! * [`assert_ready_ne_x!(a, expr)`](macro@crate::assert_ready_ne_x) ≈ (a ⇒ Ready(a1) ⇒ a1) ≠ expr
+//! * [`assert_ready_lt_x!(a, expr)`](macro@crate::assert_ready_lt_x) ≈ (a ⇒ Ready(a1) ⇒ a1) < expr
+//! * [`assert_ready_le_x!(a, expr)`](macro@crate::assert_ready_le_x) ≈ (a ⇒ Ready(a1) ⇒ a1) ≤ expr
+//! * [`assert_ready_gt_x!(a, expr)`](macro@crate::assert_ready_gt_x) ≈ (a ⇒ Ready(a1) ⇒ a1) > expr
+//! * [`assert_ready_ge_x!(a, expr)`](macro@crate::assert_ready_ge_x) ≈ (a ⇒ Ready(a1) ⇒ a1) ≥ expr
 //!
 //! # Example
 //!
@@ -37,7 +45,15 @@ pub mod assert_ready;
 // Compare another
 pub mod assert_ready_eq;
 pub mod assert_ready_ne;
+pub mod assert_ready_lt;
+pub mod assert_ready_le;
+pub mod assert_ready_gt;
+pub mod assert_ready_ge;
 
 // Compare expression
 pub mod assert_ready_eq_x;
 pub mod assert_ready_ne_x;
+pub mod assert_ready_lt_x;
+pub mod assert_ready_le_x;
+pub mod assert_ready_gt_x;
+pub mod assert_ready_ge_x;
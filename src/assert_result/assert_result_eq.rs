@@ -0,0 +1,295 @@
+//! Assert two expressions are Results that are equal, by variant and payload.
+//!
+//! Pseudocode:<br>
+//! (a is Ok(a1) and b is Ok(b1) and a1 = b1) or (a is Err(a1) and b is Err(b1) and a1 = b1)
+//!
+//! This compares the same as [`assert_eq!(a, b)`](https://doc.rust-lang.org/std/macro.assert_eq.html),
+//! and the failure message says whether the variants are different, or
+//! the variants are the same and the payloads are different. The message
+//! shows each variant and its payload, rather than each whole Result.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Result<i8, i8> = Ok(1);
+//! let b: Result<i8, i8> = Ok(1);
+//! assert_result_eq!(a, b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_result_eq`](macro@crate::assert_result_eq)
+//! * [`assert_result_eq_as_result`](macro@crate::assert_result_eq_as_result)
+//! * [`debug_assert_result_eq`](macro@crate::debug_assert_result_eq)
+
+/// Assert two expressions are Results that are equal, by variant and payload.
+///
+/// Pseudocode:<br>
+/// (a is Ok(a1) and b is Ok(b1) and a1 = b1) or (a is Err(a1) and b is Err(b1) and a1 = b1)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_result_eq`](macro.assert_result_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_result_eq`](macro@crate::assert_result_eq)
+/// * [`assert_result_eq_as_result`](macro@crate::assert_result_eq_as_result)
+/// * [`debug_assert_result_eq`](macro@crate::debug_assert_result_eq)
+///
+#[macro_export]
+macro_rules! assert_result_eq_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                if a == b {
+                    Ok(())
+                } else {
+                    let (a_variant, a_inner) = match a {
+                        Ok(a1) => ("Ok", format!("{:?}", a1)),
+                        Err(a1) => ("Err", format!("{:?}", a1)),
+                    };
+                    let (b_variant, b_inner) = match b {
+                        Ok(b1) => ("Ok", format!("{:?}", b1)),
+                        Err(b1) => ("Err", format!("{:?}", b1)),
+                    };
+                    let mismatch = if a_variant == b_variant {
+                        "same variant, different payloads"
+                    } else {
+                        "different variants"
+                    };
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_result_eq!(a, b)`\n",
+                                "{}macro.assert_result_eq.html\n",
+                                "   a label: `{}`,\n",
+                                "   b label: `{}`,\n",
+                                "  mismatch: `{}`,\n",
+                                " a variant: `{}`,\n",
+                                "   a inner: `{}`,\n",
+                                " b variant: `{}`,\n",
+                                "   b inner: `{}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            stringify!($b),
+                            mismatch,
+                            a_variant,
+                            a_inner,
+                            b_variant,
+                            b_inner
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success_with_ok() {
+        let a: Result<i8, i8> = Ok(1);
+        let b: Result<i8, i8> = Ok(1);
+        let result = assert_result_eq_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn success_with_err() {
+        let a: Result<i8, &str> = Err("alfa");
+        let b: Result<i8, &str> = Err("alfa");
+        let result = assert_result_eq_as_result!(a, b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_because_ok_payloads_differ() {
+        let a: Result<i8, i8> = Ok(1);
+        let b: Result<i8, i8> = Ok(2);
+        let result = assert_result_eq_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_result_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_result_eq.html\n",
+                "   a label: `a`,\n",
+                "   b label: `b`,\n",
+                "  mismatch: `same variant, different payloads`,\n",
+                " a variant: `Ok`,\n",
+                "   a inner: `1`,\n",
+                " b variant: `Ok`,\n",
+                "   b inner: `2`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_err_payloads_differ() {
+        let a: Result<i8, &str> = Err("alfa");
+        let b: Result<i8, &str> = Err("bravo");
+        let result = assert_result_eq_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_result_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_result_eq.html\n",
+                "   a label: `a`,\n",
+                "   b label: `b`,\n",
+                "  mismatch: `same variant, different payloads`,\n",
+                " a variant: `Err`,\n",
+                "   a inner: `\"alfa\"`,\n",
+                " b variant: `Err`,\n",
+                "   b inner: `\"bravo\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_variants_differ() {
+        let a: Result<i8, &str> = Ok(1);
+        let b: Result<i8, &str> = Err("alfa");
+        let result = assert_result_eq_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_result_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_result_eq.html\n",
+                "   a label: `a`,\n",
+                "   b label: `b`,\n",
+                "  mismatch: `different variants`,\n",
+                " a variant: `Ok`,\n",
+                "   a inner: `1`,\n",
+                " b variant: `Err`,\n",
+                "   b inner: `\"alfa\"`"
+            )
+        );
+    }
+}
+
+/// Assert two expressions are Results that are equal, by variant and payload.
+///
+/// Pseudocode:<br>
+/// (a is Ok(a1) and b is Ok(b1) and a1 = b1) or (a is Err(a1) and b is Err(b1) and a1 = b1)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<i8, i8> = Ok(1);
+/// let b: Result<i8, i8> = Ok(1);
+/// assert_result_eq!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<i8, i8> = Ok(1);
+/// let b: Result<i8, i8> = Err(1);
+/// assert_result_eq!(a, b);
+/// # });
+/// // assertion failed: `assert_result_eq!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_result_eq.html
+/// //    a label: `a`,
+/// //    b label: `b`,
+/// //   mismatch: `different variants`,
+/// //  a variant: `Ok`,
+/// //    a inner: `1`,
+/// //  b variant: `Err`,
+/// //    b inner: `1`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_result_eq!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_result_eq.html\n",
+/// #     "   a label: `a`,\n",
+/// #     "   b label: `b`,\n",
+/// #     "  mismatch: `different variants`,\n",
+/// #     " a variant: `Ok`,\n",
+/// #     "   a inner: `1`,\n",
+/// #     " b variant: `Err`,\n",
+/// #     "   b inner: `1`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_result_eq`](macro@crate::assert_result_eq)
+/// * [`assert_result_eq_as_result`](macro@crate::assert_result_eq_as_result)
+/// * [`debug_assert_result_eq`](macro@crate::debug_assert_result_eq)
+///
+#[macro_export]
+macro_rules! assert_result_eq {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_result_eq_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_result_eq_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert two expressions are Results that are equal, by variant and payload.
+///
+/// Pseudocode:<br>
+/// (a is Ok(a1) and b is Ok(b1) and a1 = b1) or (a is Err(a1) and b is Err(b1) and a1 = b1)
+///
+/// This macro provides the same statements as [`assert_result_eq`](macro.assert_result_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_result_eq`](macro@crate::assert_result_eq)
+/// * [`assert_result_eq_as_result`](macro@crate::assert_result_eq_as_result)
+/// * [`debug_assert_result_eq`](macro@crate::debug_assert_result_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_result_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_result_eq!($($arg)*);
+        }
+    };
+}
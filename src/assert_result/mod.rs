@@ -1,11 +1,18 @@
 //! Assert for `Result` {`Ok`, `Err`}
 //!
+//! Compare a Result to another Result, by variant and payload:
+//!
+//! * [`assert_result_eq!(a, b)`](macro@crate::assert_result_eq) ≈ a = b, with a message that says whether the variants or the payloads differ
+//!
 //! Deprecated.
 //!
 //! Please rename from `assert_result_ok*` into `assert_ok*`.
 //!
 //! Please rename from `assert_result_err` into `assert_err`.
 
+// Compare another
+pub mod assert_result_eq;
+
 // Assert for Result
 pub mod assert_result_err;
 pub mod assert_result_ok;
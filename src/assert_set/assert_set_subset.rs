@@ -58,7 +58,7 @@ macro_rules! assert_set_subset_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_set_subset!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_set_subset.html\n",
+                                "{}macro.assert_set_subset.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -66,6 +66,7 @@ macro_rules! assert_set_subset_as_result {
                                 "       a: `{:?}`,\n",
                                 "       b: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a_collection),
                             a_collection,
                             stringify!($b_collection),
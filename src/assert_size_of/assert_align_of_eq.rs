@@ -0,0 +1,217 @@
+//! Assert the alignment of a type, in bytes, is equal to a number.
+//!
+//! Pseudocode:<br>
+//! align_of::<T>() = n
+//!
+//! This is useful for unsafe code that casts a pointer, because the
+//! pointer must be aligned for the target type.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! assert_align_of_eq!(u32, 4);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_align_of_eq`](macro@crate::assert_align_of_eq)
+//! * [`assert_align_of_eq_as_result`](macro@crate::assert_align_of_eq_as_result)
+//! * [`debug_assert_align_of_eq`](macro@crate::debug_assert_align_of_eq)
+
+/// Assert the alignment of a type, in bytes, is equal to a number.
+///
+/// Pseudocode:<br>
+/// align_of::<T>() = n
+///
+/// * If true, return Result `Ok(align_of::<T>())`.
+///
+/// * Otherwise, return Result `Err(message)` with the actual alignment.
+///
+/// This macro provides the same statements as [`assert_align_of_eq`](macro.assert_align_of_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_align_of_eq`](macro@crate::assert_align_of_eq)
+/// * [`assert_align_of_eq_as_result`](macro@crate::assert_align_of_eq_as_result)
+/// * [`debug_assert_align_of_eq`](macro@crate::debug_assert_align_of_eq)
+///
+#[macro_export]
+macro_rules! assert_align_of_eq_as_result {
+    ($type:ty, $n:expr $(,)?) => {{
+        match (&$n) {
+            n => {
+                let actual: usize = ::std::mem::align_of::<$type>();
+                if actual == *n {
+                    Ok(actual)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_align_of_eq!(T, n)`\n",
+                                "{}macro.assert_align_of_eq.html\n",
+                                "         T label: `{}`,\n",
+                                "         n label: `{}`,\n",
+                                "         n debug: `{:?}`,\n",
+                                " align_of::<T>(): `{}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($type),
+                            stringify!($n),
+                            n,
+                            actual
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let n: usize = 4;
+        let result = assert_align_of_eq_as_result!(u32, n);
+        assert_eq!(result.unwrap(), 4);
+    }
+
+    #[test]
+    fn success_with_repr_align() {
+        #[repr(C, align(16))]
+        struct Aligned(u8);
+        let result = assert_align_of_eq_as_result!(Aligned, 16);
+        assert_eq!(result.unwrap(), 16);
+    }
+
+    #[test]
+    fn failure() {
+        let n: usize = 8;
+        let result = assert_align_of_eq_as_result!(u32, n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_align_of_eq!(T, n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_align_of_eq.html\n",
+                "         T label: `u32`,\n",
+                "         n label: `n`,\n",
+                "         n debug: `8`,\n",
+                " align_of::<T>(): `4`"
+            )
+        );
+    }
+}
+
+/// Assert the alignment of a type, in bytes, is equal to a number.
+///
+/// Pseudocode:<br>
+/// align_of::<T>() = n
+///
+/// * If true, return `align_of::<T>()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// assert_align_of_eq!(u32, 4);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let n: usize = 8;
+/// assert_align_of_eq!(u32, n);
+/// # });
+/// // assertion failed: `assert_align_of_eq!(T, n)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_align_of_eq.html
+/// //          T label: `u32`,
+/// //          n label: `n`,
+/// //          n debug: `8`,
+/// //  align_of::<T>(): `4`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_align_of_eq!(T, n)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_align_of_eq.html\n",
+/// #     "         T label: `u32`,\n",
+/// #     "         n label: `n`,\n",
+/// #     "         n debug: `8`,\n",
+/// #     " align_of::<T>(): `4`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_align_of_eq`](macro@crate::assert_align_of_eq)
+/// * [`assert_align_of_eq_as_result`](macro@crate::assert_align_of_eq_as_result)
+/// * [`debug_assert_align_of_eq`](macro@crate::debug_assert_align_of_eq)
+///
+#[macro_export]
+macro_rules! assert_align_of_eq {
+    ($type:ty, $n:expr $(,)?) => {{
+        match $crate::assert_align_of_eq_as_result!($type, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($type:ty, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_align_of_eq_as_result!($type, $n) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert the alignment of a type, in bytes, is equal to a number.
+///
+/// Pseudocode:<br>
+/// align_of::<T>() = n
+///
+/// This macro provides the same statements as [`assert_align_of_eq`](macro.assert_align_of_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_align_of_eq`](macro@crate::assert_align_of_eq)
+/// * [`assert_align_of_eq_as_result`](macro@crate::assert_align_of_eq_as_result)
+/// * [`debug_assert_align_of_eq`](macro@crate::debug_assert_align_of_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_align_of_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_align_of_eq!($($arg)*);
+        }
+    };
+}
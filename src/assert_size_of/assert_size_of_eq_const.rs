@@ -0,0 +1,103 @@
+//! Assert the size of a type, in bytes, is equal to a number, at compile time.
+//!
+//! Pseudocode:<br>
+//! size_of::<T>() = n
+//!
+//! This macro is a compile-time check, not a run-time check. It expands to
+//! an anonymous constant, and the compiler evaluates the constant, so if the
+//! size differs, then the program fails to compile, with an error at the
+//! macro site. The macro never panics at run time and returns nothing.
+//!
+//! The number must be a constant expression, such as a literal or a `const`.
+//! For a run-time check that reports the actual size, see
+//! [`assert_size_of_eq`](macro@crate::assert_size_of_eq).
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! #[repr(C)]
+//! struct Header {
+//!     tag: u8,
+//!     len: u32,
+//! }
+//!
+//! assert_size_of_eq_const!(Header, 8);
+//! # fn main() {
+//! # }
+//! ```
+//!
+//! A type with a different size is a compile error:
+//!
+//! ```compile_fail
+//! use assertables::*;
+//!
+//! assert_size_of_eq_const!(u64, 4);
+//! # fn main() {
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_size_of_eq_const`](macro@crate::assert_size_of_eq_const)
+
+/// Assert the size of a type, in bytes, is equal to a number, at compile time.
+///
+/// Pseudocode:<br>
+/// size_of::<T>() = n
+///
+/// * If true, compile.
+///
+/// * Otherwise, fail to compile, with an error at the macro site, such as
+///   "evaluation of constant value failed".
+///
+/// This macro has no `_as_result` form and no `debug_` form, because
+/// it has no run-time behavior.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+///
+/// # fn main() {
+/// assert_size_of_eq_const!(u64, 8);
+/// assert_size_of_eq_const!([u16; 3], 6);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_size_of_eq_const`](macro@crate::assert_size_of_eq_const)
+///
+#[macro_export]
+macro_rules! assert_size_of_eq_const {
+    ($type:ty, $n:expr $(,)?) => {
+        const _: () = ::std::assert!(
+            ::std::mem::size_of::<$type>() == $n,
+            "assertion failed: `assert_size_of_eq_const!(T, n)`"
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct Pair {
+        a: u8,
+        b: u32,
+    }
+
+    const PAIR_SIZE: usize = 8;
+
+    assert_size_of_eq_const!(Pair, PAIR_SIZE);
+    assert_size_of_eq_const!((), 0);
+
+    #[test]
+    fn success_in_function() {
+        assert_size_of_eq_const!([u8; 3], 3);
+        assert_eq!(std::mem::size_of::<Pair>(), PAIR_SIZE);
+    }
+}
@@ -0,0 +1,221 @@
+//! Assert the size of a type, in bytes, is greater than or equal to a number.
+//!
+//! Pseudocode:<br>
+//! size_of::<T>() ≥ n
+//!
+//! This is useful to bound the size of a type, such as a buffer type that
+//! must hold at least a minimum number of bytes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! assert_size_of_ge!(u32, 4);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_size_of_ge`](macro@crate::assert_size_of_ge)
+//! * [`assert_size_of_ge_as_result`](macro@crate::assert_size_of_ge_as_result)
+//! * [`debug_assert_size_of_ge`](macro@crate::debug_assert_size_of_ge)
+
+/// Assert the size of a type, in bytes, is greater than or equal to a number.
+///
+/// Pseudocode:<br>
+/// size_of::<T>() ≥ n
+///
+/// * If true, return Result `Ok(size_of::<T>())`.
+///
+/// * Otherwise, return Result `Err(message)` with the actual size.
+///
+/// This macro provides the same statements as [`assert_size_of_ge`](macro.assert_size_of_ge.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_size_of_ge`](macro@crate::assert_size_of_ge)
+/// * [`assert_size_of_ge_as_result`](macro@crate::assert_size_of_ge_as_result)
+/// * [`debug_assert_size_of_ge`](macro@crate::debug_assert_size_of_ge)
+///
+#[macro_export]
+macro_rules! assert_size_of_ge_as_result {
+    ($type:ty, $n:expr $(,)?) => {{
+        match (&$n) {
+            n => {
+                let actual: usize = ::std::mem::size_of::<$type>();
+                if actual >= *n {
+                    Ok(actual)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_size_of_ge!(T, n)`\n",
+                                "{}macro.assert_size_of_ge.html\n",
+                                "        T label: `{}`,\n",
+                                "        n label: `{}`,\n",
+                                "        n debug: `{:?}`,\n",
+                                " size_of::<T>(): `{}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($type),
+                            stringify!($n),
+                            n,
+                            actual
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let n: usize = 4;
+        let result = assert_size_of_ge_as_result!(u32, n);
+        assert_eq!(result.unwrap(), 4);
+    }
+
+    #[test]
+    fn success_with_repr_c() {
+        #[allow(dead_code)]
+        #[repr(C)]
+        struct Pair {
+            a: u8,
+            b: u32,
+        }
+        let result = assert_size_of_ge_as_result!(Pair, 8);
+        assert_eq!(result.unwrap(), 8);
+    }
+
+    #[test]
+    fn failure() {
+        let n: usize = 16;
+        let result = assert_size_of_ge_as_result!(u32, n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_size_of_ge!(T, n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_size_of_ge.html\n",
+                "        T label: `u32`,\n",
+                "        n label: `n`,\n",
+                "        n debug: `16`,\n",
+                " size_of::<T>(): `4`"
+            )
+        );
+    }
+}
+
+/// Assert the size of a type, in bytes, is greater than or equal to a number.
+///
+/// Pseudocode:<br>
+/// size_of::<T>() ≥ n
+///
+/// * If true, return `size_of::<T>()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// assert_size_of_ge!(u32, 4);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let n: usize = 16;
+/// assert_size_of_ge!(u32, n);
+/// # });
+/// // assertion failed: `assert_size_of_ge!(T, n)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_size_of_ge.html
+/// //         T label: `u32`,
+/// //         n label: `n`,
+/// //         n debug: `16`,
+/// //  size_of::<T>(): `4`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_size_of_ge!(T, n)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_size_of_ge.html\n",
+/// #     "        T label: `u32`,\n",
+/// #     "        n label: `n`,\n",
+/// #     "        n debug: `16`,\n",
+/// #     " size_of::<T>(): `4`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_size_of_ge`](macro@crate::assert_size_of_ge)
+/// * [`assert_size_of_ge_as_result`](macro@crate::assert_size_of_ge_as_result)
+/// * [`debug_assert_size_of_ge`](macro@crate::debug_assert_size_of_ge)
+///
+#[macro_export]
+macro_rules! assert_size_of_ge {
+    ($type:ty, $n:expr $(,)?) => {{
+        match $crate::assert_size_of_ge_as_result!($type, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($type:ty, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_size_of_ge_as_result!($type, $n) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert the size of a type, in bytes, is greater than or equal to a number.
+///
+/// Pseudocode:<br>
+/// size_of::<T>() ≥ n
+///
+/// This macro provides the same statements as [`assert_size_of_ge`](macro.assert_size_of_ge.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_size_of_ge`](macro@crate::assert_size_of_ge)
+/// * [`assert_size_of_ge_as_result`](macro@crate::assert_size_of_ge_as_result)
+/// * [`debug_assert_size_of_ge`](macro@crate::debug_assert_size_of_ge)
+///
+#[macro_export]
+macro_rules! debug_assert_size_of_ge {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_size_of_ge!($($arg)*);
+        }
+    };
+}
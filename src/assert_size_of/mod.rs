@@ -0,0 +1,44 @@
+//! Assert for the size and the alignment of a type.
+//!
+//! These macros help with FFI and unsafe code, where a type layout must match
+//! a layout that is defined elsewhere, such as by a C header. Each macro takes
+//! a type, then a number of bytes.
+//!
+//! Run-time checks, which return the actual size or alignment:
+//!
+//! * [`assert_size_of_eq!(T, n)`](macro@crate::assert_size_of_eq) ≈ size_of::<T>() = n
+//! * [`assert_size_of_le!(T, n)`](macro@crate::assert_size_of_le) ≈ size_of::<T>() ≤ n
+//! * [`assert_size_of_ge!(T, n)`](macro@crate::assert_size_of_ge) ≈ size_of::<T>() ≥ n
+//! * [`assert_align_of_eq!(T, n)`](macro@crate::assert_align_of_eq) ≈ align_of::<T>() = n
+//!
+//! Compile-time checks:
+//!
+//! * [`assert_size_of_eq_const!(T, n)`](macro@crate::assert_size_of_eq_const) ≈ size_of::<T>() = n, else a compile error
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! #[repr(C)]
+//! struct Header {
+//!     tag: u8,
+//!     len: u32,
+//! }
+//!
+//! assert_size_of_eq!(Header, 8);
+//! assert_align_of_eq!(Header, 4);
+//! # }
+//! ```
+
+// Size
+pub mod assert_size_of_eq;
+pub mod assert_size_of_ge;
+pub mod assert_size_of_le;
+
+// Size at compile time
+pub mod assert_size_of_eq_const;
+
+// Alignment
+pub mod assert_align_of_eq;
@@ -0,0 +1,257 @@
+//! Assert a slice is equal to another, and report the first difference.
+//!
+//! Pseudocode:<br>
+//! a[..] = b[..]
+//!
+//! The failure message shows the first index where the slices differ, and
+//! the items at that index, rather than the entire slices, so it stays
+//! readable for large arrays. When one slice is a prefix of the other,
+//! the index is the length of the shorter slice, and its item is `None`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = [1, 2, 3, 4];
+//! let b = vec![1, 2, 3, 4];
+//! assert_slice_eq!(&a, &b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_slice_eq`](macro@crate::assert_slice_eq)
+//! * [`assert_slice_eq_as_result`](macro@crate::assert_slice_eq_as_result)
+//! * [`debug_assert_slice_eq`](macro@crate::debug_assert_slice_eq)
+
+/// Assert a slice is equal to another, and report the first difference.
+///
+/// Pseudocode:<br>
+/// a[..] = b[..]
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_slice_eq`](macro.assert_slice_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_slice_eq`](macro@crate::assert_slice_eq)
+/// * [`assert_slice_eq_as_result`](macro@crate::assert_slice_eq_as_result)
+/// * [`debug_assert_slice_eq`](macro@crate::debug_assert_slice_eq)
+///
+#[macro_export]
+macro_rules! assert_slice_eq_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let (a_slice, b_slice) = (&a[..], &b[..]);
+                match $crate::assert_slice::first_difference(a_slice, b_slice) {
+                    None => Ok(()),
+                    Some(index) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_slice_eq!(a, b)`\n",
+                                    "{}macro.assert_slice_eq.html\n",
+                                    " a label: `{}`,\n",
+                                    "   a len: `{}`,\n",
+                                    " b label: `{}`,\n",
+                                    "   b len: `{}`,\n",
+                                    "   index: `{}`,\n",
+                                    "  a item: `{:?}`,\n",
+                                    "  b item: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a_slice.len(),
+                                stringify!($b),
+                                b_slice.len(),
+                                index,
+                                a_slice.get(index),
+                                b_slice.get(index)
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3, 4];
+        let b = vec![1, 2, 3, 4];
+        let result = assert_slice_eq_as_result!(&a, &b);
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn failure_because_item_differs() {
+        let a = [1, 2, 3, 4];
+        let b = [1, 2, 9, 4];
+        let result = assert_slice_eq_as_result!(&a, &b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_slice_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_eq.html\n",
+                " a label: `&a`,\n",
+                "   a len: `4`,\n",
+                " b label: `&b`,\n",
+                "   b len: `4`,\n",
+                "   index: `2`,\n",
+                "  a item: `Some(3)`,\n",
+                "  b item: `Some(9)`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_len_differs() {
+        let a = [1, 2, 3];
+        let b = [1, 2, 3, 4];
+        let result = assert_slice_eq_as_result!(&a, &b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_slice_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_eq.html\n",
+                " a label: `&a`,\n",
+                "   a len: `3`,\n",
+                " b label: `&b`,\n",
+                "   b len: `4`,\n",
+                "   index: `3`,\n",
+                "  a item: `None`,\n",
+                "  b item: `Some(4)`"
+            )
+        );
+    }
+}
+
+/// Assert a slice is equal to another, and report the first difference.
+///
+/// Pseudocode:<br>
+/// a[..] = b[..]
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3, 4];
+/// let b = vec![1, 2, 3, 4];
+/// assert_slice_eq!(&a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3, 4];
+/// let b = [1, 2, 9, 4];
+/// assert_slice_eq!(&a, &b);
+/// # });
+/// // assertion failed: `assert_slice_eq!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_eq.html
+/// //  a label: `&a`,
+/// //    a len: `4`,
+/// //  b label: `&b`,
+/// //    b len: `4`,
+/// //    index: `2`,
+/// //   a item: `Some(3)`,
+/// //   b item: `Some(9)`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_slice_eq!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_eq.html\n",
+/// #     " a label: `&a`,\n",
+/// #     "   a len: `4`,\n",
+/// #     " b label: `&b`,\n",
+/// #     "   b len: `4`,\n",
+/// #     "   index: `2`,\n",
+/// #     "  a item: `Some(3)`,\n",
+/// #     "  b item: `Some(9)`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_slice_eq`](macro@crate::assert_slice_eq)
+/// * [`assert_slice_eq_as_result`](macro@crate::assert_slice_eq_as_result)
+/// * [`debug_assert_slice_eq`](macro@crate::debug_assert_slice_eq)
+///
+#[macro_export]
+macro_rules! assert_slice_eq {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_slice_eq_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_slice_eq_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a slice is equal to another, and report the first difference.
+///
+/// Pseudocode:<br>
+/// a[..] = b[..]
+///
+/// This macro provides the same statements as [`assert_slice_eq`](macro.assert_slice_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_slice_eq`](macro@crate::assert_slice_eq)
+/// * [`assert_slice_eq_as_result`](macro@crate::assert_slice_eq_as_result)
+/// * [`debug_assert_slice_eq`](macro@crate::debug_assert_slice_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_slice_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_slice_eq!($($arg)*);
+        }
+    };
+}
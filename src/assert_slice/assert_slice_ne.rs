@@ -0,0 +1,230 @@
+//! Assert a slice is not equal to another, and return the first difference.
+//!
+//! Pseudocode:<br>
+//! a[..] ≠ b[..]
+//!
+//! On success, this returns the first index where the slices differ. When
+//! one slice is a prefix of the other, the index is the length of the
+//! shorter slice.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = [1, 2, 3];
+//! let b = [1, 9, 3];
+//! let index = assert_slice_ne!(&a, &b);
+//! assert_eq!(index, 1);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_slice_ne`](macro@crate::assert_slice_ne)
+//! * [`assert_slice_ne_as_result`](macro@crate::assert_slice_ne_as_result)
+//! * [`debug_assert_slice_ne`](macro@crate::debug_assert_slice_ne)
+
+/// Assert a slice is not equal to another, and return the first difference.
+///
+/// Pseudocode:<br>
+/// a[..] ≠ b[..]
+///
+/// * If true, return Result `Ok(index)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_slice_ne`](macro.assert_slice_ne.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_slice_ne`](macro@crate::assert_slice_ne)
+/// * [`assert_slice_ne_as_result`](macro@crate::assert_slice_ne_as_result)
+/// * [`debug_assert_slice_ne`](macro@crate::debug_assert_slice_ne)
+///
+#[macro_export]
+macro_rules! assert_slice_ne_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let (a_slice, b_slice) = (&a[..], &b[..]);
+                match $crate::assert_slice::first_difference(a_slice, b_slice) {
+                    Some(index) => Ok(index),
+                    None => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_slice_ne!(a, b)`\n",
+                                    "{}macro.assert_slice_ne.html\n",
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " b label: `{}`,\n",
+                                    " b debug: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a_slice,
+                                stringify!($b),
+                                b_slice
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let b = [1, 9, 3];
+        let result = assert_slice_ne_as_result!(&a, &b);
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn success_because_len_differs() {
+        let a = vec![1, 2, 3];
+        let b = vec![1, 2];
+        let result = assert_slice_ne_as_result!(&a, &b);
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 2, 3];
+        let b = [1, 2, 3];
+        let result = assert_slice_ne_as_result!(&a, &b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_slice_ne!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_ne.html\n",
+                " a label: `&a`,\n",
+                " a debug: `[1, 2, 3]`,\n",
+                " b label: `&b`,\n",
+                " b debug: `[1, 2, 3]`"
+            )
+        );
+    }
+}
+
+/// Assert a slice is not equal to another, and return the first difference.
+///
+/// Pseudocode:<br>
+/// a[..] ≠ b[..]
+///
+/// * If true, return `index`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// let b = [1, 9, 3];
+/// let index = assert_slice_ne!(&a, &b);
+/// assert_eq!(index, 1);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3];
+/// let b = [1, 2, 3];
+/// assert_slice_ne!(&a, &b);
+/// # });
+/// // assertion failed: `assert_slice_ne!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_ne.html
+/// //  a label: `&a`,
+/// //  a debug: `[1, 2, 3]`,
+/// //  b label: `&b`,
+/// //  b debug: `[1, 2, 3]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_slice_ne!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_ne.html\n",
+/// #     " a label: `&a`,\n",
+/// #     " a debug: `[1, 2, 3]`,\n",
+/// #     " b label: `&b`,\n",
+/// #     " b debug: `[1, 2, 3]`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_slice_ne`](macro@crate::assert_slice_ne)
+/// * [`assert_slice_ne_as_result`](macro@crate::assert_slice_ne_as_result)
+/// * [`debug_assert_slice_ne`](macro@crate::debug_assert_slice_ne)
+///
+#[macro_export]
+macro_rules! assert_slice_ne {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_slice_ne_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_slice_ne_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a slice is not equal to another, and return the first difference.
+///
+/// Pseudocode:<br>
+/// a[..] ≠ b[..]
+///
+/// This macro provides the same statements as [`assert_slice_ne`](macro.assert_slice_ne.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_slice_ne`](macro@crate::assert_slice_ne)
+/// * [`assert_slice_ne_as_result`](macro@crate::assert_slice_ne_as_result)
+/// * [`debug_assert_slice_ne`](macro@crate::debug_assert_slice_ne)
+///
+#[macro_export]
+macro_rules! debug_assert_slice_ne {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_slice_ne!($($arg)*);
+        }
+    };
+}
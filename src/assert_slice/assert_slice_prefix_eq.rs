@@ -0,0 +1,282 @@
+//! Assert a slice starts with a prefix, and report the first difference.
+//!
+//! Pseudocode:<br>
+//! a[..prefix.len()] = prefix[..]
+//!
+//! This compares only the first `prefix.len()` items of `a`, and ignores the
+//! rest, so it is useful for pinning the header of a stream of output. Unlike
+//! [`slice::starts_with`](https://doc.rust-lang.org/std/primitive.slice.html#method.starts_with),
+//! the failure message shows the first index where the items differ, and the
+//! items at that index. When `a` is shorter than the prefix, the index is the
+//! length of `a`, and its item is `None`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = vec![1, 2, 3, 4];
+//! let prefix = [1, 2];
+//! assert_slice_prefix_eq!(&a, &prefix);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_slice_prefix_eq`](macro@crate::assert_slice_prefix_eq)
+//! * [`assert_slice_prefix_eq_as_result`](macro@crate::assert_slice_prefix_eq_as_result)
+//! * [`debug_assert_slice_prefix_eq`](macro@crate::debug_assert_slice_prefix_eq)
+
+/// Assert a slice starts with a prefix, and report the first difference.
+///
+/// Pseudocode:<br>
+/// a[..prefix.len()] = prefix[..]
+///
+/// * If true, return Result `Ok(prefix.len())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_slice_prefix_eq`](macro.assert_slice_prefix_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_slice_prefix_eq`](macro@crate::assert_slice_prefix_eq)
+/// * [`assert_slice_prefix_eq_as_result`](macro@crate::assert_slice_prefix_eq_as_result)
+/// * [`debug_assert_slice_prefix_eq`](macro@crate::debug_assert_slice_prefix_eq)
+///
+#[macro_export]
+macro_rules! assert_slice_prefix_eq_as_result {
+    ($a:expr, $prefix:expr $(,)?) => {{
+        match (&$a, &$prefix) {
+            (a, prefix) => {
+                let (a_slice, prefix_slice) = (&a[..], &prefix[..]);
+                let index = a_slice.iter().zip(prefix_slice.iter()).position(|(a_item, prefix_item)| a_item != prefix_item);
+                let index = match index {
+                    Some(index) => Some(index),
+                    None if a_slice.len() < prefix_slice.len() => Some(a_slice.len()),
+                    None => None,
+                };
+                match index {
+                    None => Ok(prefix_slice.len()),
+                    Some(index) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_slice_prefix_eq!(a, prefix)`\n",
+                                    "{}macro.assert_slice_prefix_eq.html\n",
+                                    "      a label: `{}`,\n",
+                                    "        a len: `{}`,\n",
+                                    " prefix label: `{}`,\n",
+                                    "   prefix len: `{}`,\n",
+                                    "        index: `{}`,\n",
+                                    "       a item: `{:?}`,\n",
+                                    "  prefix item: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a_slice.len(),
+                                stringify!($prefix),
+                                prefix_slice.len(),
+                                index,
+                                a_slice.get(index),
+                                prefix_slice.get(index)
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = vec![1, 2, 3, 4];
+        let prefix = [1, 2];
+        let result = assert_slice_prefix_eq_as_result!(&a, &prefix);
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn success_with_equal_len() {
+        let a = [1, 2];
+        let prefix = vec![1, 2];
+        let result = assert_slice_prefix_eq_as_result!(&a, &prefix);
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn success_with_empty_prefix() {
+        let a = [1, 2];
+        let prefix: [i32; 0] = [];
+        let result = assert_slice_prefix_eq_as_result!(&a, &prefix);
+        assert_eq!(result, Ok(0));
+    }
+
+    #[test]
+    fn failure_because_item_differs() {
+        let a = [1, 2, 3, 4];
+        let prefix = [1, 9];
+        let result = assert_slice_prefix_eq_as_result!(&a, &prefix);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_slice_prefix_eq!(a, prefix)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_prefix_eq.html\n",
+                "      a label: `&a`,\n",
+                "        a len: `4`,\n",
+                " prefix label: `&prefix`,\n",
+                "   prefix len: `2`,\n",
+                "        index: `1`,\n",
+                "       a item: `Some(2)`,\n",
+                "  prefix item: `Some(9)`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_a_is_too_short() {
+        let a = [1, 2];
+        let prefix = [1, 2, 3];
+        let result = assert_slice_prefix_eq_as_result!(&a, &prefix);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_slice_prefix_eq!(a, prefix)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_prefix_eq.html\n",
+                "      a label: `&a`,\n",
+                "        a len: `2`,\n",
+                " prefix label: `&prefix`,\n",
+                "   prefix len: `3`,\n",
+                "        index: `2`,\n",
+                "       a item: `None`,\n",
+                "  prefix item: `Some(3)`"
+            )
+        );
+    }
+}
+
+/// Assert a slice starts with a prefix, and report the first difference.
+///
+/// Pseudocode:<br>
+/// a[..prefix.len()] = prefix[..]
+///
+/// * If true, return `prefix.len()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = vec![1, 2, 3, 4];
+/// let prefix = [1, 2];
+/// let len = assert_slice_prefix_eq!(&a, &prefix);
+/// assert_eq!(len, 2);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3, 4];
+/// let prefix = [1, 9];
+/// assert_slice_prefix_eq!(&a, &prefix);
+/// # });
+/// // assertion failed: `assert_slice_prefix_eq!(a, prefix)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_prefix_eq.html
+/// //       a label: `&a`,
+/// //         a len: `4`,
+/// //  prefix label: `&prefix`,
+/// //    prefix len: `2`,
+/// //         index: `1`,
+/// //        a item: `Some(2)`,
+/// //   prefix item: `Some(9)`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_slice_prefix_eq!(a, prefix)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_slice_prefix_eq.html\n",
+/// #     "      a label: `&a`,\n",
+/// #     "        a len: `4`,\n",
+/// #     " prefix label: `&prefix`,\n",
+/// #     "   prefix len: `2`,\n",
+/// #     "        index: `1`,\n",
+/// #     "       a item: `Some(2)`,\n",
+/// #     "  prefix item: `Some(9)`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_slice_prefix_eq`](macro@crate::assert_slice_prefix_eq)
+/// * [`assert_slice_prefix_eq_as_result`](macro@crate::assert_slice_prefix_eq_as_result)
+/// * [`debug_assert_slice_prefix_eq`](macro@crate::debug_assert_slice_prefix_eq)
+///
+#[macro_export]
+macro_rules! assert_slice_prefix_eq {
+    ($a:expr, $prefix:expr $(,)?) => {{
+        match $crate::assert_slice_prefix_eq_as_result!($a, $prefix) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $prefix:expr, $($message:tt)+) => {{
+        match $crate::assert_slice_prefix_eq_as_result!($a, $prefix) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a slice starts with a prefix, and report the first difference.
+///
+/// Pseudocode:<br>
+/// a[..prefix.len()] = prefix[..]
+///
+/// This macro provides the same statements as [`assert_slice_prefix_eq`](macro.assert_slice_prefix_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_slice_prefix_eq`](macro@crate::assert_slice_prefix_eq)
+/// * [`assert_slice_prefix_eq_as_result`](macro@crate::assert_slice_prefix_eq_as_result)
+/// * [`debug_assert_slice_prefix_eq`](macro@crate::debug_assert_slice_prefix_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_slice_prefix_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_slice_prefix_eq!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,60 @@
+//! Assert for comparing slices, with the first difference.
+//!
+//! These macros help with comparison of large arrays and vectors. Rather than
+//! show every item, the failure message shows the first index where the
+//! slices differ, and the items at that index.
+//!
+//! * [`assert_slice_eq!(a, b)`](macro@crate::assert_slice_eq) ≈ a[..] = b[..]
+//! * [`assert_slice_ne!(a, b)`](macro@crate::assert_slice_ne) ≈ a[..] ≠ b[..]
+//! * [`assert_slice_prefix_eq!(a, prefix)`](macro@crate::assert_slice_prefix_eq) ≈ a[..prefix.len()] = prefix[..]
+//!
+//! For a slice inside Ok(…), see [`assert_ok_slice_eq_x`](macro@crate::assert_ok_slice_eq_x).
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = [1, 2, 3];
+//! let b = vec![1, 2, 3];
+//! assert_slice_eq!(&a, &b);
+//! # }
+//! ```
+
+pub mod assert_slice_eq;
+pub mod assert_slice_ne;
+pub mod assert_slice_prefix_eq;
+
+/// Return the first index where two slices differ, or `None` if they are equal.
+///
+/// If one slice is a prefix of the other, then the index is the length of
+/// the shorter slice, which is where the longer slice has an extra item.
+#[doc(hidden)]
+pub fn first_difference<T: PartialEq>(a: &[T], b: &[T]) -> Option<usize> {
+    match a.iter().zip(b.iter()).position(|(a_item, b_item)| a_item != b_item) {
+        Some(index) => Some(index),
+        None if a.len() != b.len() => Some(::std::cmp::min(a.len(), b.len())),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::first_difference;
+
+    #[test]
+    fn first_difference_with_equal() {
+        assert_eq!(first_difference(b"alfa", b"alfa"), None);
+    }
+
+    #[test]
+    fn first_difference_with_differ() {
+        assert_eq!(first_difference(b"alfa", b"alxa"), Some(2));
+    }
+
+    #[test]
+    fn first_difference_with_prefix() {
+        assert_eq!(first_difference(b"alfa", b"al"), Some(2));
+    }
+}
@@ -49,10 +49,11 @@ macro_rules! assert_some_as_result {
             _ => Err(format!(
                 concat!(
                     "assertion failed: `assert_some!(a)`\n",
-                    "https://docs.rs/assertables/9.2.0/assertables/macro.assert_some.html\n",
+                    "{}macro.assert_some.html\n",
                     " option label: `{}`,\n",
                     " option debug: `{:?}`",
                 ),
+                $crate::DOC_URL_BASE,
                 stringify!($a),
                 $a
             )),
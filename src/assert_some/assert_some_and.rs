@@ -0,0 +1,270 @@
+//! Assert an expression is Some and its value satisfies a predicate.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Some(a1) ⇒ predicate(&a1)) is true
+//!
+//! The predicate is a closure that receives a reference to the inner value,
+//! so the inner value can be returned after the check.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Option<i8> = Option::Some(1);
+//! assert_some_and!(a, |x: &i8| *x > 0);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_some_and`](macro@crate::assert_some_and)
+//! * [`assert_some_and_as_result`](macro@crate::assert_some_and_as_result)
+//! * [`debug_assert_some_and`](macro@crate::debug_assert_some_and)
+
+/// Assert an expression is Some and its value satisfies a predicate.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Some(a1) ⇒ predicate(&a1)) is true
+///
+/// * If true, return Result `Ok(a1)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message distinguishes an expression that is None from an inner
+/// value that does not satisfy the predicate; the latter shows the inner value.
+///
+/// This macro provides the same statements as [`assert_some_and`](macro.assert_some_and.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_some_and`](macro@crate::assert_some_and)
+/// * [`assert_some_and_as_result`](macro@crate::assert_some_and_as_result)
+/// * [`debug_assert_some_and`](macro@crate::debug_assert_some_and)
+///
+#[macro_export]
+macro_rules! assert_some_and_as_result {
+    ($a:expr, $predicate:expr $(,)?) => {
+        match ($a) {
+            Some(a1) => {
+                if ($predicate)(&a1) {
+                    Ok(a1)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_some_and!(a, predicate)`\n",
+                                "{}macro.assert_some_and.html\n",
+                                "         a label: `{}`,\n",
+                                "         a debug: `{:?}`,\n",
+                                "         a inner: `{:?}`,\n",
+                                " predicate label: `{}`,\n",
+                                "  predicate call: `false`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            Some(&a1),
+                            a1,
+                            stringify!($predicate)
+                        )
+                    )
+                }
+            },
+            a => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_some_and!(a, predicate)`\n",
+                            "{}macro.assert_some_and.html\n",
+                            "         a label: `{}`,\n",
+                            "         a debug: `{:?}`,\n",
+                            " predicate label: `{}`"
+                        ),
+                        $crate::DOC_URL_BASE,
+                        stringify!($a),
+                        a,
+                        stringify!($predicate)
+                    )
+                )
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: Option<i8> = Option::Some(1);
+        let result = assert_some_and_as_result!(a, |x: &i8| *x > 0);
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[test]
+    fn success_with_non_copy_value() {
+        let a: Option<String> = Option::Some(String::from("alfa"));
+        let result = assert_some_and_as_result!(a, |x: &String| x.starts_with('a'));
+        assert_eq!(result.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn failure_because_predicate_is_false() {
+        let a: Option<i8> = Option::Some(1);
+        let result = assert_some_and_as_result!(a, |x: &i8| *x > 1);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_some_and!(a, predicate)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_and.html\n",
+                "         a label: `a`,\n",
+                "         a debug: `Some(1)`,\n",
+                "         a inner: `1`,\n",
+                " predicate label: `|x: &i8| *x > 1`,\n",
+                "  predicate call: `false`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_not_some() {
+        let a: Option<i8> = Option::None;
+        let result = assert_some_and_as_result!(a, |x: &i8| *x > 0);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_some_and!(a, predicate)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_and.html\n",
+                "         a label: `a`,\n",
+                "         a debug: `None`,\n",
+                " predicate label: `|x: &i8| *x > 0`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_not_some_evaluates_a_once() {
+        let count = std::cell::Cell::new(0);
+        let a = || {
+            count.set(count.get() + 1);
+            Option::<String>::None
+        };
+        let result = assert_some_and_as_result!(a(), |x: &String| x.is_empty());
+        assert!(result.unwrap_err().contains("         a debug: `None`,\n"));
+        assert_eq!(count.get(), 1);
+    }
+}
+
+/// Assert an expression is Some and its value satisfies a predicate.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Some(a1) ⇒ predicate(&a1)) is true
+///
+/// * If true, return `a1`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Option<i8> = Option::Some(1);
+/// let a1 = assert_some_and!(a, |x: &i8| *x > 0);
+/// assert_eq!(a1, 1);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Option<i8> = Option::Some(1);
+/// assert_some_and!(a, |x: &i8| *x > 1);
+/// # });
+/// // assertion failed: `assert_some_and!(a, predicate)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_and.html
+/// //          a label: `a`,
+/// //          a debug: `Some(1)`,
+/// //          a inner: `1`,
+/// //  predicate label: `|x: &i8| *x > 1`,
+/// //   predicate call: `false`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_some_and!(a, predicate)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_and.html\n",
+/// #     "         a label: `a`,\n",
+/// #     "         a debug: `Some(1)`,\n",
+/// #     "         a inner: `1`,\n",
+/// #     " predicate label: `|x: &i8| *x > 1`,\n",
+/// #     "  predicate call: `false`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_some_and`](macro@crate::assert_some_and)
+/// * [`assert_some_and_as_result`](macro@crate::assert_some_and_as_result)
+/// * [`debug_assert_some_and`](macro@crate::debug_assert_some_and)
+///
+#[macro_export]
+macro_rules! assert_some_and {
+    ($a:expr, $predicate:expr $(,)?) => {{
+        match $crate::assert_some_and_as_result!($a, $predicate) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $predicate:expr, $($message:tt)+) => {{
+        match $crate::assert_some_and_as_result!($a, $predicate) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Some and its value satisfies a predicate.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Some(a1) ⇒ predicate(&a1)) is true
+///
+/// This macro provides the same statements as [`assert_some_and`](macro.assert_some_and.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_some_and`](macro@crate::assert_some_and)
+/// * [`assert_some_and_as_result`](macro@crate::assert_some_and_as_result)
+/// * [`debug_assert_some_and`](macro@crate::debug_assert_some_and)
+///
+#[macro_export]
+macro_rules! debug_assert_some_and {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_some_and!($($arg)*);
+        }
+    };
+}
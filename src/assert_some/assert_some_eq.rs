@@ -31,7 +31,7 @@
 /// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro provides the same statements as [`assert_some_eq`](macro.assert_some_eq.html),
-/// except this macro returns a Option, rather than doing a panic.
+/// except this macro returns a Result, rather than doing a panic.
 ///
 /// This macro is useful for runtime checks, such as checking parameters,
 /// or sanitizing inputs, or handling different results in different ways.
@@ -54,7 +54,7 @@ macro_rules! assert_some_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_some_eq!(a, b)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_eq.html\n",
+                                "{}macro.assert_some_eq.html\n",
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a inner: `{:?}`,\n",
@@ -62,6 +62,7 @@ macro_rules! assert_some_eq_as_result {
                                 " b debug: `{:?}`,\n",
                                 " b inner: `{:?}`"
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($a),
                             $a,
                             a1,
@@ -77,12 +78,13 @@ macro_rules! assert_some_eq_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_some_eq!(a, b)`\n",
-                            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_eq.html\n",
+                            "{}macro.assert_some_eq.html\n",
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
                             " b debug: `{:?}`",
                         ),
+                        $crate::DOC_URL_BASE,
                         stringify!($a),
                         $a,
                         stringify!($b),
@@ -0,0 +1,273 @@
+//! Assert an expression is Some and its value has a length equal to n.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Some(a1) ⇒ a1.len()) = n
+//!
+//! The inner value can be any type with a `.len()` method, such as a `Vec`,
+//! a `String`, a `&str`, or a `HashMap`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a: Option<Vec<i8>> = Option::Some(vec![1, 2]);
+//! let n: usize = 2;
+//! assert_some_len_eq!(a, n);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_some_len_eq`](macro@crate::assert_some_len_eq)
+//! * [`assert_some_len_eq_as_result`](macro@crate::assert_some_len_eq_as_result)
+//! * [`debug_assert_some_len_eq`](macro@crate::debug_assert_some_len_eq)
+
+/// Assert an expression is Some and its value has a length equal to n.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Some(a1) ⇒ a1.len()) = n
+///
+/// * If true, return Result `Ok(len)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The message distinguishes an expression that is None from an inner
+/// value that has a different length.
+///
+/// This macro provides the same statements as [`assert_some_len_eq`](macro.assert_some_len_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_some_len_eq`](macro@crate::assert_some_len_eq)
+/// * [`assert_some_len_eq_as_result`](macro@crate::assert_some_len_eq_as_result)
+/// * [`debug_assert_some_len_eq`](macro@crate::debug_assert_some_len_eq)
+///
+#[macro_export]
+macro_rules! assert_some_len_eq_as_result {
+    ($a:expr, $n:expr $(,)?) => {{
+        match (&$a, &$n) {
+            (a, n) => {
+                match a {
+                    Some(a1) => {
+                        let a1_len = a1.len();
+                        if a1_len == *n {
+                            Ok(a1_len)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_some_len_eq!(a, n)`\n",
+                                        "{}macro.assert_some_len_eq.html\n",
+                                        "     a label: `{}`,\n",
+                                        "     a debug: `{:?}`,\n",
+                                        " a inner len: `{:?}`,\n",
+                                        "     n label: `{}`,\n",
+                                        "     n debug: `{:?}`"
+                                    ),
+                                    $crate::DOC_URL_BASE,
+                                    stringify!($a),
+                                    a,
+                                    a1_len,
+                                    stringify!($n),
+                                    n
+                                )
+                            )
+                        }
+                    },
+                    None => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_some_len_eq!(a, n)`\n",
+                                    "{}macro.assert_some_len_eq.html\n",
+                                    "     a label: `{}`,\n",
+                                    "     a debug: `{:?}`,\n",
+                                    "     n label: `{}`,\n",
+                                    "     n debug: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($a),
+                                a,
+                                stringify!($n),
+                                n
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a: Option<Vec<i8>> = Option::Some(vec![1, 2]);
+        let n: usize = 2;
+        let result = assert_some_len_eq_as_result!(a, n);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn success_with_str() {
+        let a: Option<&str> = Option::Some("alfa");
+        let n: usize = 4;
+        let result = assert_some_len_eq_as_result!(a, n);
+        assert_eq!(result.unwrap(), 4);
+    }
+
+    #[test]
+    fn failure_because_len_differs() {
+        let a: Option<Vec<i8>> = Option::Some(vec![1, 2]);
+        let n: usize = 3;
+        let result = assert_some_len_eq_as_result!(a, n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_some_len_eq!(a, n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_len_eq.html\n",
+                "     a label: `a`,\n",
+                "     a debug: `Some([1, 2])`,\n",
+                " a inner len: `2`,\n",
+                "     n label: `n`,\n",
+                "     n debug: `3`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_not_some() {
+        let a: Option<Vec<i8>> = Option::None;
+        let n: usize = 3;
+        let result = assert_some_len_eq_as_result!(a, n);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_some_len_eq!(a, n)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_len_eq.html\n",
+                "     a label: `a`,\n",
+                "     a debug: `None`,\n",
+                "     n label: `n`,\n",
+                "     n debug: `3`"
+            )
+        );
+    }
+}
+
+/// Assert an expression is Some and its value has a length equal to n.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Some(a1) ⇒ a1.len()) = n
+///
+/// * If true, return `len`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Option<Vec<i8>> = Option::Some(vec![1, 2]);
+/// let n: usize = 2;
+/// assert_some_len_eq!(a, n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Option<Vec<i8>> = Option::Some(vec![1, 2]);
+/// let n: usize = 3;
+/// assert_some_len_eq!(a, n);
+/// # });
+/// // assertion failed: `assert_some_len_eq!(a, n)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_len_eq.html
+/// //      a label: `a`,
+/// //      a debug: `Some([1, 2])`,
+/// //  a inner len: `2`,
+/// //      n label: `n`,
+/// //      n debug: `3`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_some_len_eq!(a, n)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_some_len_eq.html\n",
+/// #     "     a label: `a`,\n",
+/// #     "     a debug: `Some([1, 2])`,\n",
+/// #     " a inner len: `2`,\n",
+/// #     "     n label: `n`,\n",
+/// #     "     n debug: `3`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_some_len_eq`](macro@crate::assert_some_len_eq)
+/// * [`assert_some_len_eq_as_result`](macro@crate::assert_some_len_eq_as_result)
+/// * [`debug_assert_some_len_eq`](macro@crate::debug_assert_some_len_eq)
+///
+#[macro_export]
+macro_rules! assert_some_len_eq {
+    ($a:expr, $n:expr $(,)?) => {{
+        match $crate::assert_some_len_eq_as_result!($a, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_some_len_eq_as_result!($a, $n) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression is Some and its value has a length equal to n.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Some(a1) ⇒ a1.len()) = n
+///
+/// This macro provides the same statements as [`assert_some_len_eq`](macro.assert_some_len_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_some_len_eq`](macro@crate::assert_some_len_eq)
+/// * [`assert_some_len_eq_as_result`](macro@crate::assert_some_len_eq_as_result)
+/// * [`debug_assert_some_len_eq`](macro@crate::debug_assert_some_len_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_some_len_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_some_len_eq!($($arg)*);
+        }
+    };
+}
@@ -7,6 +7,14 @@
 //! * [`assert_some!(a)`](macro@crate::assert_some)
 //!   ≈ a is Some
 //!
+//! Assert expression is Some and its value satisfies a predicate:
+//!
+//! * [`assert_some_and!(a, predicate)`](macro@crate::assert_some_and) ≈ (a ⇒ Some(a1) ⇒ predicate(&a1)) is true
+//!
+//! Assert expression is Some and its value has a length:
+//!
+//! * [`assert_some_len_eq!(a, n)`](macro@crate::assert_some_len_eq) ≈ (a ⇒ Some(a1) ⇒ a1.len()) = n
+//!
 //! Compare Some(…) to another Some(…):
 //!
 //! * [`assert_some_eq!(a, b)`](macro@crate::assert_some_eq) ≈ (a ⇒ Some(a1) ⇒ a1) = (b ⇒ Some(b1) ⇒ b1)
@@ -31,6 +39,12 @@
 // Verify Some(_)
 pub mod assert_some;
 
+// Verify Some(_) with predicate
+pub mod assert_some_and;
+
+// Verify Some(_) with length
+pub mod assert_some_len_eq;
+
 // Compare another
 pub mod assert_some_eq;
 pub mod assert_some_ne;
@@ -12,12 +12,12 @@
 //! // String starts with substring?
 //! let whole: &str = "alfa";
 //! let part: &str = "al";
-//! assert_starts_with!(sequence, x);
+//! assert_starts_with!(whole, part);
 //!
 //! // Vector starts with element?
 //! let whole = vec![1, 2, 3];
 //! let part = [1];
-//! assert_starts_with!(sequence, x);
+//! assert_starts_with!(whole, part);
 //! # }
 //! ```
 //!
@@ -36,6 +36,10 @@
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
+/// If the whole and the part are both strings, such as `&str` or `String`,
+/// then the message also shows their longest common prefix, which is where
+/// they diverge.
+///
 /// This macro provides the same statements as [`assert_starts_with`](macro.assert_starts_with.html),
 /// except this macro returns a Result, rather than doing a panic.
 ///
@@ -52,24 +56,28 @@
 macro_rules! assert_starts_with_as_result {
     ($whole:expr, $part:expr $(,)?) => {{
         match (&$whole, &$part) {
-            (sequence, x) => {
-                if sequence.starts_with(x) {
+            (whole, part) => {
+                if whole.starts_with(part) {
                     Ok(())
                 } else {
+                    #[allow(unused_imports)]
+                    use $crate::assert_starts_with::{CommonPrefixOther as _, CommonPrefixStr as _};
                     Err(
                         format!(
                             concat!(
-                                "assertion failed: `assert_starts_with!(sequence, x)`\n",
-                                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with.html\n",
-                                " whole label: `{}`,\n",
-                                " whole debug: `{:?}`,\n",
-                                "  part label: `{}`,\n",
-                                "  part debug: `{:?}`",
+                                "assertion failed: `assert_starts_with!(whole, part)`\n",
+                                "{}macro.assert_starts_with.html\n",
+                                "   whole label: `{}`,\n",
+                                "   whole debug: `{:?}`,\n",
+                                "    part label: `{}`,\n",
+                                "    part debug: `{:?}`{}",
                             ),
+                            $crate::DOC_URL_BASE,
                             stringify!($whole),
                             whole,
                             stringify!($part),
                             part,
+                            (&$crate::assert_starts_with::CommonPrefix(whole, part)).common_prefix_line()
                         )
                     )
                 }
@@ -85,7 +93,7 @@ mod tests {
     fn test_assert_starts_with_as_result_success() {
         let whole = "alfa";
         let part = "al";
-        let result = assert_starts_with_as_result!(sequence, x);
+        let result = assert_starts_with_as_result!(whole, part);
         assert_eq!(result.unwrap(), ());
     }
 
@@ -93,15 +101,51 @@ mod tests {
     fn test_assert_starts_with_as_result_x_failure() {
         let whole = "alfa";
         let part = "fa";
-        let result = assert_starts_with_as_result!(sequence, x);
+        let result = assert_starts_with_as_result!(whole, part);
+        let actual = result.unwrap_err();
+        let expect = concat!(
+            "assertion failed: `assert_starts_with!(whole, part)`\n",
+            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with.html\n",
+            "   whole label: `whole`,\n",
+            "   whole debug: `\"alfa\"`,\n",
+            "    part label: `part`,\n",
+            "    part debug: `\"fa\"`,\n",
+            " common prefix: `\"\"`"
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn failure_shows_common_prefix() {
+        let whole = String::from("fn main() {}");
+        let part = "fn main(x)";
+        let result = assert_starts_with_as_result!(whole, part);
+        let actual = result.unwrap_err();
+        let expect = concat!(
+            "assertion failed: `assert_starts_with!(whole, part)`\n",
+            "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with.html\n",
+            "   whole label: `whole`,\n",
+            "   whole debug: `\"fn main() {}\"`,\n",
+            "    part label: `part`,\n",
+            "    part debug: `\"fn main(x)\"`,\n",
+            " common prefix: `\"fn main(\"`"
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn failure_with_vector_has_no_common_prefix() {
+        let whole = vec![1, 2, 3];
+        let part = [1, 3];
+        let result = assert_starts_with_as_result!(whole, part);
         let actual = result.unwrap_err();
         let expect = concat!(
-            "assertion failed: `assert_starts_with!(sequence, x)`\n",
+            "assertion failed: `assert_starts_with!(whole, part)`\n",
             "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with.html\n",
-            " whole label: `whole`,\n",
-            " whole debug: `\"alfa\"`,\n",
-            "  part label: `part`,\n",
-            "  part debug: `\"fa\"`"
+            "   whole label: `whole`,\n",
+            "   whole debug: `[1, 2, 3]`,\n",
+            "    part label: `part`,\n",
+            "    part debug: `[1, 3]`"
         );
         assert_eq!(actual, expect);
     }
@@ -127,33 +171,35 @@ mod tests {
 /// // String starts with substring?
 /// let whole: &str = "alfa";
 /// let part: &str = "al";
-/// assert_starts_with!(sequence, x);
+/// assert_starts_with!(whole, part);
 ///
 /// // Vector starts with element?
 /// let whole = vec![1, 2, 3];
 /// let part = [1];
-/// assert_starts_with!(sequence, x);
+/// assert_starts_with!(whole, part);
 ///
 /// # let result = panic::catch_unwind(|| {
 /// // This will panic
 /// let whole = "alfa";
 /// let part = "fa";
-/// assert_starts_with!(sequence, x);
-/// // assertion failed: `assert_starts_with!(sequence, x)`
+/// assert_starts_with!(whole, part);
+/// // assertion failed: `assert_starts_with!(whole, part)`
 /// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with.html
-/// //  whole label: `whole`,
-/// //  whole debug: `\"alfa\"`,
-/// //   part label: `part`,
-/// //   part debug: `\"fa\"`
+/// //    whole label: `whole`,
+/// //    whole debug: `\"alfa\"`,
+/// //     part label: `part`,
+/// //     part debug: `\"fa\"`,
+/// //  common prefix: `\"\"`
 /// # });
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let expect = concat!(
-/// #     "assertion failed: `assert_starts_with!(sequence, x)`\n",
+/// #     "assertion failed: `assert_starts_with!(whole, part)`\n",
 /// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with.html\n",
-/// #     " whole label: `whole`,\n",
-/// #     " whole debug: `\"alfa\"`,\n",
-/// #     "  part label: `part`,\n",
-/// #     "  part debug: `\"fa\"`"
+/// #     "   whole label: `whole`,\n",
+/// #     "   whole debug: `\"alfa\"`,\n",
+/// #     "    part label: `part`,\n",
+/// #     "    part debug: `\"fa\"`,\n",
+/// #     " common prefix: `\"\"`"
 /// # );
 /// # assert_eq!(actual, expect);
 /// # }
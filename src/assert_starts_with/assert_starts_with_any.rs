@@ -0,0 +1,243 @@
+//! Assert an expression (such as a string) starts with any of several expressions (such as strings).
+//!
+//! Pseudocode:<br>
+//! parts.iter().any(|part| whole.starts_with(part))
+//!
+//! This is useful for parsing, where several leading tokens are valid,
+//! such as the HTTP methods `"GET "` and `"POST "`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let whole: &str = "POST /index.html";
+//! let parts = ["GET ", "POST "];
+//! assert_starts_with_any!(whole, &parts);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_starts_with_any`](macro@crate::assert_starts_with_any)
+//! * [`assert_starts_with_any_as_result`](macro@crate::assert_starts_with_any_as_result)
+//! * [`debug_assert_starts_with_any`](macro@crate::debug_assert_starts_with_any)
+
+/// Assert an expression (such as a string) starts with any of several expressions (such as strings).
+///
+/// Pseudocode:<br>
+/// parts.iter().any(|part| whole.starts_with(part))
+///
+/// * If true, return Result `Ok(part)`, where `part` is the first part that matches.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The parts are checked in order. The message shows every part, and the
+/// start of the whole, as long as the longest part.
+///
+/// This macro provides the same statements as [`assert_starts_with_any`](macro.assert_starts_with_any.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_starts_with_any`](macro@crate::assert_starts_with_any)
+/// * [`assert_starts_with_any_as_result`](macro@crate::assert_starts_with_any_as_result)
+/// * [`debug_assert_starts_with_any`](macro@crate::debug_assert_starts_with_any)
+///
+#[macro_export]
+macro_rules! assert_starts_with_any_as_result {
+    ($whole:expr, $parts:expr $(,)?) => {{
+        match (&$whole, &$parts) {
+            (whole, parts) => {
+                match parts.iter().find(|part| whole.starts_with(&**part)) {
+                    Some(part) => Ok(part.clone()),
+                    None => {
+                        let n = parts.iter().map(|part| part.chars().count()).max().unwrap_or(0);
+                        let whole_start: String = whole.chars().take(n).collect();
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_starts_with_any!(whole, parts)`\n",
+                                    "{}macro.assert_starts_with_any.html\n",
+                                    " whole label: `{}`,\n",
+                                    " whole debug: `{:?}`,\n",
+                                    " parts label: `{}`,\n",
+                                    " parts debug: `{:?}`,\n",
+                                    " whole start: `{:?}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($whole),
+                                whole,
+                                stringify!($parts),
+                                parts,
+                                whole_start
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let whole = "POST /index.html";
+        let parts = ["GET ", "POST "];
+        let result = assert_starts_with_any_as_result!(whole, &parts);
+        assert_eq!(result.unwrap(), "POST ");
+    }
+
+    #[test]
+    fn success_returns_first_matching_part() {
+        let whole = "alfa";
+        let parts = ["al", "a"];
+        let result = assert_starts_with_any_as_result!(whole, &parts);
+        assert_eq!(result.unwrap(), "al");
+    }
+
+    #[test]
+    fn success_with_strings() {
+        let whole = String::from("POST /index.html");
+        let parts = vec![String::from("GET "), String::from("POST ")];
+        let result = assert_starts_with_any_as_result!(whole, parts);
+        assert_eq!(result.unwrap(), "POST ");
+    }
+
+    #[test]
+    fn failure() {
+        let whole = "PUT /index.html";
+        let parts = ["GET ", "POST "];
+        let result = assert_starts_with_any_as_result!(whole, &parts);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_starts_with_any!(whole, parts)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with_any.html\n",
+                " whole label: `whole`,\n",
+                " whole debug: `\"PUT /index.html\"`,\n",
+                " parts label: `&parts`,\n",
+                " parts debug: `[\"GET \", \"POST \"]`,\n",
+                " whole start: `\"PUT /\"`"
+            )
+        );
+    }
+}
+
+/// Assert an expression (such as a string) starts with any of several expressions (such as strings).
+///
+/// Pseudocode:<br>
+/// parts.iter().any(|part| whole.starts_with(part))
+///
+/// * If true, return `part`, where `part` is the first part that matches.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let whole: &str = "POST /index.html";
+/// let parts = ["GET ", "POST "];
+/// let part = assert_starts_with_any!(whole, &parts);
+/// assert_eq!(part, "POST ");
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let whole: &str = "PUT /index.html";
+/// let parts = ["GET ", "POST "];
+/// assert_starts_with_any!(whole, &parts);
+/// # });
+/// // assertion failed: `assert_starts_with_any!(whole, parts)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with_any.html
+/// //  whole label: `whole`,
+/// //  whole debug: `\"PUT /index.html\"`,
+/// //  parts label: `&parts`,
+/// //  parts debug: `[\"GET \", \"POST \"]`,
+/// //  whole start: `\"PUT /\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_starts_with_any!(whole, parts)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with_any.html\n",
+/// #     " whole label: `whole`,\n",
+/// #     " whole debug: `\"PUT /index.html\"`,\n",
+/// #     " parts label: `&parts`,\n",
+/// #     " parts debug: `[\"GET \", \"POST \"]`,\n",
+/// #     " whole start: `\"PUT /\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_starts_with_any`](macro@crate::assert_starts_with_any)
+/// * [`assert_starts_with_any_as_result`](macro@crate::assert_starts_with_any_as_result)
+/// * [`debug_assert_starts_with_any`](macro@crate::debug_assert_starts_with_any)
+///
+#[macro_export]
+macro_rules! assert_starts_with_any {
+    ($whole:expr, $parts:expr $(,)?) => {{
+        match $crate::assert_starts_with_any_as_result!($whole, $parts) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($whole:expr, $parts:expr, $($message:tt)+) => {{
+        match $crate::assert_starts_with_any_as_result!($whole, $parts) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert an expression (such as a string) starts with any of several expressions (such as strings).
+///
+/// Pseudocode:<br>
+/// parts.iter().any(|part| whole.starts_with(part))
+///
+/// This macro provides the same statements as [`assert_starts_with_any`](macro.assert_starts_with_any.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_starts_with_any`](macro@crate::assert_starts_with_any)
+/// * [`assert_starts_with_any_as_result`](macro@crate::assert_starts_with_any_as_result)
+/// * [`debug_assert_starts_with_any`](macro@crate::debug_assert_starts_with_any)
+///
+#[macro_export]
+macro_rules! debug_assert_starts_with_any {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_starts_with_any!($($arg)*);
+        }
+    };
+}
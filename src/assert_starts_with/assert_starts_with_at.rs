@@ -0,0 +1,358 @@
+//! Assert a string starts with a prefix at a byte offset.
+//!
+//! Pseudocode:<br>
+//! s[offset..].starts_with(prefix)
+//!
+//! This is useful for parsing framed text, where a prefix is expected at a
+//! computed position, such as after a known header.
+//!
+//! The offset is in bytes. The failure message distinguishes an offset that
+//! is out of bounds, an offset that is not on a char boundary, and a string
+//! that does not start with the prefix at the offset. The debug representation
+//! of the string from the offset is truncated to its first 64 chars.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let s = "HEAD:alfa";
+//! let offset: usize = 5;
+//! let prefix = "al";
+//! assert_starts_with_at!(s, offset, prefix);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_starts_with_at`](macro@crate::assert_starts_with_at)
+//! * [`assert_starts_with_at_as_result`](macro@crate::assert_starts_with_at_as_result)
+//! * [`debug_assert_starts_with_at`](macro@crate::debug_assert_starts_with_at)
+
+/// Assert a string starts with a prefix at a byte offset.
+///
+/// Pseudocode:<br>
+/// s[offset..].starts_with(prefix)
+///
+/// * If true, return Result `Ok(end)`, where `end` is the byte offset after the prefix.
+///
+/// * If the offset is out of bounds, or is not on a char boundary, return Result `Err(message)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_starts_with_at`](macro.assert_starts_with_at.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_starts_with_at`](macro@crate::assert_starts_with_at)
+/// * [`assert_starts_with_at_as_result`](macro@crate::assert_starts_with_at_as_result)
+/// * [`debug_assert_starts_with_at`](macro@crate::debug_assert_starts_with_at)
+///
+#[macro_export]
+macro_rules! assert_starts_with_at_as_result {
+    ($s:expr, $offset:expr, $prefix:expr $(,)?) => {{
+        match (&$s, &$offset, &$prefix) {
+            (s, offset, prefix) => {
+                if *offset > s.len() {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_starts_with_at!(s, offset, prefix)`\n",
+                                "{}macro.assert_starts_with_at.html\n",
+                                "       s label: `{}`,\n",
+                                "       s debug: `{:?}`,\n",
+                                "  offset label: `{}`,\n",
+                                "  offset debug: `{:?}`,\n",
+                                "  prefix label: `{}`,\n",
+                                "  prefix debug: `{:?}`,\n",
+                                "    s byte len: `{:?}`,\n",
+                                "  offset check: `out of bounds`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($s),
+                            s,
+                            stringify!($offset),
+                            offset,
+                            stringify!($prefix),
+                            prefix,
+                            s.len()
+                        )
+                    )
+                } else if !s.is_char_boundary(*offset) {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_starts_with_at!(s, offset, prefix)`\n",
+                                "{}macro.assert_starts_with_at.html\n",
+                                "       s label: `{}`,\n",
+                                "       s debug: `{:?}`,\n",
+                                "  offset label: `{}`,\n",
+                                "  offset debug: `{:?}`,\n",
+                                "  prefix label: `{}`,\n",
+                                "  prefix debug: `{:?}`,\n",
+                                "  offset check: `not a char boundary`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($s),
+                            s,
+                            stringify!($offset),
+                            offset,
+                            stringify!($prefix),
+                            prefix
+                        )
+                    )
+                } else {
+                    let s_from_offset = &s[*offset..];
+                    if s_from_offset.starts_with(&**prefix) {
+                        Ok(*offset + prefix.len())
+                    } else {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_starts_with_at!(s, offset, prefix)`\n",
+                                    "{}macro.assert_starts_with_at.html\n",
+                                    "       s label: `{}`,\n",
+                                    "       s debug: `{:?}`,\n",
+                                    "  offset label: `{}`,\n",
+                                    "  offset debug: `{:?}`,\n",
+                                    "  prefix label: `{}`,\n",
+                                    "  prefix debug: `{:?}`,\n",
+                                    " s from offset: `{}`"
+                                ),
+                                $crate::DOC_URL_BASE,
+                                stringify!($s),
+                                s,
+                                stringify!($offset),
+                                offset,
+                                stringify!($prefix),
+                                prefix,
+                                $crate::assert_len::debug_truncated(s_from_offset)
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let s = "HEAD:alfa";
+        let offset: usize = 5;
+        let prefix = "al";
+        let result = assert_starts_with_at_as_result!(s, offset, prefix);
+        assert_eq!(result.unwrap(), 7);
+    }
+
+    #[test]
+    fn success_with_strings_at_end() {
+        let s = String::from("HEAD:");
+        let offset: usize = 5;
+        let prefix = String::new();
+        let result = assert_starts_with_at_as_result!(s, offset, prefix);
+        assert_eq!(result.unwrap(), 5);
+    }
+
+    #[test]
+    fn failure() {
+        let s = "HEAD:alfa";
+        let offset: usize = 5;
+        let prefix = "fa";
+        let result = assert_starts_with_at_as_result!(s, offset, prefix);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_starts_with_at!(s, offset, prefix)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with_at.html\n",
+                "       s label: `s`,\n",
+                "       s debug: `\"HEAD:alfa\"`,\n",
+                "  offset label: `offset`,\n",
+                "  offset debug: `5`,\n",
+                "  prefix label: `prefix`,\n",
+                "  prefix debug: `\"fa\"`,\n",
+                " s from offset: `\"alfa\"`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_with_long_string_is_truncated() {
+        let s = format!("HEAD:{}", "x".repeat(100));
+        let offset: usize = 5;
+        let prefix = "y";
+        let result = assert_starts_with_at_as_result!(s, offset, prefix);
+        let actual = result.unwrap_err();
+        assert!(actual.ends_with(&format!(" s from offset: `\"{}…`", "x".repeat(63))));
+    }
+
+    #[test]
+    fn failure_because_offset_is_out_of_bounds() {
+        let s = "HEAD:";
+        let offset: usize = 6;
+        let prefix = "al";
+        let result = assert_starts_with_at_as_result!(s, offset, prefix);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_starts_with_at!(s, offset, prefix)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with_at.html\n",
+                "       s label: `s`,\n",
+                "       s debug: `\"HEAD:\"`,\n",
+                "  offset label: `offset`,\n",
+                "  offset debug: `6`,\n",
+                "  prefix label: `prefix`,\n",
+                "  prefix debug: `\"al\"`,\n",
+                "    s byte len: `5`,\n",
+                "  offset check: `out of bounds`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_offset_is_not_char_boundary() {
+        let s = "café";
+        let offset: usize = 4;
+        let prefix = "é";
+        let result = assert_starts_with_at_as_result!(s, offset, prefix);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_starts_with_at!(s, offset, prefix)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with_at.html\n",
+                "       s label: `s`,\n",
+                "       s debug: `\"café\"`,\n",
+                "  offset label: `offset`,\n",
+                "  offset debug: `4`,\n",
+                "  prefix label: `prefix`,\n",
+                "  prefix debug: `\"é\"`,\n",
+                "  offset check: `not a char boundary`"
+            )
+        );
+    }
+}
+
+/// Assert a string starts with a prefix at a byte offset.
+///
+/// Pseudocode:<br>
+/// s[offset..].starts_with(prefix)
+///
+/// * If true, return `end`, where `end` is the byte offset after the prefix.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let s = "HEAD:alfa";
+/// let offset: usize = 5;
+/// let prefix = "al";
+/// let end = assert_starts_with_at!(s, offset, prefix);
+/// assert_eq!(end, 7);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let s = "HEAD:alfa";
+/// let offset: usize = 5;
+/// let prefix = "fa";
+/// assert_starts_with_at!(s, offset, prefix);
+/// # });
+/// // assertion failed: `assert_starts_with_at!(s, offset, prefix)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with_at.html
+/// //        s label: `s`,
+/// //        s debug: `\"HEAD:alfa\"`,
+/// //   offset label: `offset`,
+/// //   offset debug: `5`,
+/// //   prefix label: `prefix`,
+/// //   prefix debug: `\"fa\"`,
+/// //  s from offset: `\"alfa\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_starts_with_at!(s, offset, prefix)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_starts_with_at.html\n",
+/// #     "       s label: `s`,\n",
+/// #     "       s debug: `\"HEAD:alfa\"`,\n",
+/// #     "  offset label: `offset`,\n",
+/// #     "  offset debug: `5`,\n",
+/// #     "  prefix label: `prefix`,\n",
+/// #     "  prefix debug: `\"fa\"`,\n",
+/// #     " s from offset: `\"alfa\"`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_starts_with_at`](macro@crate::assert_starts_with_at)
+/// * [`assert_starts_with_at_as_result`](macro@crate::assert_starts_with_at_as_result)
+/// * [`debug_assert_starts_with_at`](macro@crate::debug_assert_starts_with_at)
+///
+#[macro_export]
+macro_rules! assert_starts_with_at {
+    ($s:expr, $offset:expr, $prefix:expr $(,)?) => {{
+        match $crate::assert_starts_with_at_as_result!($s, $offset, $prefix) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($s:expr, $offset:expr, $prefix:expr, $($message:tt)+) => {{
+        match $crate::assert_starts_with_at_as_result!($s, $offset, $prefix) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a string starts with a prefix at a byte offset.
+///
+/// Pseudocode:<br>
+/// s[offset..].starts_with(prefix)
+///
+/// This macro provides the same statements as [`assert_starts_with_at`](macro.assert_starts_with_at.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_starts_with_at`](macro@crate::assert_starts_with_at)
+/// * [`assert_starts_with_at_as_result`](macro@crate::assert_starts_with_at_as_result)
+/// * [`debug_assert_starts_with_at`](macro@crate::debug_assert_starts_with_at)
+///
+#[macro_export]
+macro_rules! debug_assert_starts_with_at {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_starts_with_at!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,311 @@
+//! Assert a string starts with an open delimiter and ends with a close delimiter.
+//!
+//! Pseudocode:<br>
+//! s.starts_with(open) ∧ s.ends_with(close) ∧ s.len() ≥ open.len() + close.len()
+//!
+//! This is for delimited strings, such as `"[…]"`, `"{…}"`, or a quoted string.
+//! The length check guards against a short string where the delimiters
+//! overlap, such as `"\""` with the open `"\""` and the close `"\""`.
+//!
+//! On success, the macro returns the inner slice between the delimiters.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let s = "[alfa]";
+//! let inner = assert_wrapped_with!(s, "[", "]");
+//! assert_eq!(inner, "alfa");
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_wrapped_with`](macro@crate::assert_wrapped_with)
+//! * [`assert_wrapped_with_as_result`](macro@crate::assert_wrapped_with_as_result)
+//! * [`debug_assert_wrapped_with`](macro@crate::debug_assert_wrapped_with)
+
+/// Assert a string starts with an open delimiter and ends with a close delimiter.
+///
+/// Pseudocode:<br>
+/// s.starts_with(open) ∧ s.ends_with(close) ∧ s.len() ≥ open.len() + close.len()
+///
+/// * If true, return Result `Ok(inner)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// The string and the delimiters can be any type that implements `AsRef<str>`,
+/// such as `&str` or `String`. The inner slice borrows from the string.
+///
+/// The message shows which side failed, and the lengths, which matter when
+/// the string is shorter than both delimiters together.
+///
+/// This macro provides the same statements as [`assert_wrapped_with`](macro.assert_wrapped_with.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_wrapped_with`](macro@crate::assert_wrapped_with)
+/// * [`assert_wrapped_with_as_result`](macro@crate::assert_wrapped_with_as_result)
+/// * [`debug_assert_wrapped_with`](macro@crate::debug_assert_wrapped_with)
+///
+#[macro_export]
+macro_rules! assert_wrapped_with_as_result {
+    ($s:expr, $open:expr, $close:expr $(,)?) => {{
+        match (&$s, &$open, &$close) {
+            (s, open, close) => {
+                let (s, open, close): (&str, &str, &str) = (
+                    AsRef::<str>::as_ref(s),
+                    AsRef::<str>::as_ref(open),
+                    AsRef::<str>::as_ref(close),
+                );
+                let starts_with_open = s.starts_with(open);
+                let ends_with_close = s.ends_with(close);
+                if starts_with_open && ends_with_close && s.len() >= open.len() + close.len() {
+                    Ok(&s[open.len()..s.len() - close.len()])
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_wrapped_with!(s, open, close)`\n",
+                                "{}macro.assert_wrapped_with.html\n",
+                                "               s label: `{}`,\n",
+                                "               s debug: `{:?}`,\n",
+                                "            open label: `{}`,\n",
+                                "            open debug: `{:?}`,\n",
+                                "           close label: `{}`,\n",
+                                "           close debug: `{:?}`,\n",
+                                "      starts with open: `{}`,\n",
+                                "       ends with close: `{}`,\n",
+                                "                 s len: `{}`,\n",
+                                "  open len + close len: `{}`"
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($s),
+                            s,
+                            stringify!($open),
+                            open,
+                            stringify!($close),
+                            close,
+                            starts_with_open,
+                            ends_with_close,
+                            s.len(),
+                            open.len() + close.len()
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let s = "[alfa]";
+        let result = assert_wrapped_with_as_result!(s, "[", "]");
+        assert_eq!(result, Ok("alfa"));
+    }
+
+    #[test]
+    fn success_with_empty_inner() {
+        let s = String::from("{}");
+        let open = String::from("{");
+        let result = assert_wrapped_with_as_result!(s, open, "}");
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn success_with_multibyte_delimiters() {
+        let s = "«alfa»";
+        let result = assert_wrapped_with_as_result!(s, "«", "»");
+        assert_eq!(result, Ok("alfa"));
+    }
+
+    #[test]
+    fn failure() {
+        let s = "[alfa";
+        let open = "[";
+        let close = "]";
+        let result = assert_wrapped_with_as_result!(s, open, close);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_wrapped_with!(s, open, close)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_wrapped_with.html\n",
+                "               s label: `s`,\n",
+                "               s debug: `\"[alfa\"`,\n",
+                "            open label: `open`,\n",
+                "            open debug: `\"[\"`,\n",
+                "           close label: `close`,\n",
+                "           close debug: `\"]\"`,\n",
+                "      starts with open: `true`,\n",
+                "       ends with close: `false`,\n",
+                "                 s len: `5`,\n",
+                "  open len + close len: `2`"
+            )
+        );
+    }
+
+    #[test]
+    fn failure_because_open() {
+        let s = "alfa]";
+        let result = assert_wrapped_with_as_result!(s, "[", "]");
+        let actual = result.unwrap_err();
+        assert!(actual.contains("     starts with open: `false`,\n"));
+        assert!(actual.contains("      ends with close: `true`,\n"));
+    }
+
+    #[test]
+    fn failure_because_overlap() {
+        let s = "\"";
+        let open = "\"";
+        let close = "\"";
+        let result = assert_wrapped_with_as_result!(s, open, close);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_wrapped_with!(s, open, close)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_wrapped_with.html\n",
+                "               s label: `s`,\n",
+                "               s debug: `\"\\\"\"`,\n",
+                "            open label: `open`,\n",
+                "            open debug: `\"\\\"\"`,\n",
+                "           close label: `close`,\n",
+                "           close debug: `\"\\\"\"`,\n",
+                "      starts with open: `true`,\n",
+                "       ends with close: `true`,\n",
+                "                 s len: `1`,\n",
+                "  open len + close len: `2`"
+            )
+        );
+    }
+}
+
+/// Assert a string starts with an open delimiter and ends with a close delimiter.
+///
+/// Pseudocode:<br>
+/// s.starts_with(open) ∧ s.ends_with(close) ∧ s.len() ≥ open.len() + close.len()
+///
+/// * If true, return `inner`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let s = "[alfa]";
+/// let inner = assert_wrapped_with!(s, "[", "]");
+/// assert_eq!(inner, "alfa");
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let s = "[alfa";
+/// let open = "[";
+/// let close = "]";
+/// assert_wrapped_with!(s, open, close);
+/// # });
+/// // assertion failed: `assert_wrapped_with!(s, open, close)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_wrapped_with.html
+/// //                s label: `s`,
+/// //                s debug: `\"[alfa\"`,
+/// //             open label: `open`,
+/// //             open debug: `\"[\"`,
+/// //            close label: `close`,
+/// //            close debug: `\"]\"`,
+/// //       starts with open: `true`,
+/// //        ends with close: `false`,
+/// //                  s len: `5`,
+/// //   open len + close len: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_wrapped_with!(s, open, close)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_wrapped_with.html\n",
+/// #     "               s label: `s`,\n",
+/// #     "               s debug: `\"[alfa\"`,\n",
+/// #     "            open label: `open`,\n",
+/// #     "            open debug: `\"[\"`,\n",
+/// #     "           close label: `close`,\n",
+/// #     "           close debug: `\"]\"`,\n",
+/// #     "      starts with open: `true`,\n",
+/// #     "       ends with close: `false`,\n",
+/// #     "                 s len: `5`,\n",
+/// #     "  open len + close len: `2`"
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_wrapped_with`](macro@crate::assert_wrapped_with)
+/// * [`assert_wrapped_with_as_result`](macro@crate::assert_wrapped_with_as_result)
+/// * [`debug_assert_wrapped_with`](macro@crate::debug_assert_wrapped_with)
+///
+#[macro_export]
+macro_rules! assert_wrapped_with {
+    ($s:expr, $open:expr, $close:expr $(,)?) => {{
+        match $crate::assert_wrapped_with_as_result!($s, $open, $close) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($s:expr, $open:expr, $close:expr, $($message:tt)+) => {{
+        match $crate::assert_wrapped_with_as_result!($s, $open, $close) {
+            Ok(x) => x,
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a string starts with an open delimiter and ends with a close delimiter.
+///
+/// Pseudocode:<br>
+/// s.starts_with(open) ∧ s.ends_with(close) ∧ s.len() ≥ open.len() + close.len()
+///
+/// This macro provides the same statements as [`assert_wrapped_with`](macro.assert_wrapped_with.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_wrapped_with`](macro@crate::assert_wrapped_with)
+/// * [`assert_wrapped_with_as_result`](macro@crate::assert_wrapped_with_as_result)
+/// * [`debug_assert_wrapped_with`](macro@crate::debug_assert_wrapped_with)
+///
+#[macro_export]
+macro_rules! debug_assert_wrapped_with {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_wrapped_with!($($arg)*);
+        }
+    };
+}
@@ -3,9 +3,15 @@
 //! These macros help with comparison of a whole (such as a string, vector, range)
 //! and a part (such as a string substring, an array element, a range value).
 //!
-//! * [`assert_starts_with(sequence, x)`](macro@crate::assert_starts_with) ≈ container.contains(containee)
+//! * [`assert_starts_with!(whole, part)`](macro@crate::assert_starts_with) ≈ whole.starts_with(part)
 //!
-//! * [`assert_not_starts_with!(sequence, x)`](macro@crate::assert_not_starts_with) ≈ !container.contains(containee)
+//! * [`assert_not_starts_with!(whole, part)`](macro@crate::assert_not_starts_with) ≈ !whole.starts_with(part)
+//!
+//! * [`assert_starts_with_any!(whole, parts)`](macro@crate::assert_starts_with_any) ≈ parts.iter().any(|part| whole.starts_with(part))
+//!
+//! * [`assert_starts_with_at!(s, offset, prefix)`](macro@crate::assert_starts_with_at) ≈ s[offset..].starts_with(prefix)
+//!
+//! * [`assert_wrapped_with!(s, open, close)`](macro@crate::assert_wrapped_with) ≈ s.starts_with(open) ∧ s.ends_with(close), without overlap ⇒ inner
 //!
 //!
 //! # Example
@@ -17,14 +23,89 @@
 //! // String starts with substring?
 //! let whole: &str = "alfa";
 //! let part: &str = "al";
-//! assert_starts_with!(sequence, x);
+//! assert_starts_with!(whole, part);
 //!
 //! // Vector starts with element?
 //! let whole = vec![1, 2, 3];
 //! let part = [1];
-//! assert_starts_with!(sequence, x);
+//! assert_starts_with!(whole, part);
 //! # }
 //! ```
 
 pub mod assert_not_starts_with;
 pub mod assert_starts_with;
+pub mod assert_starts_with_any;
+pub mod assert_starts_with_at;
+pub mod assert_wrapped_with;
+
+/// Return the longest common prefix of two strings, on a char boundary.
+#[doc(hidden)]
+pub fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let end = a
+        .char_indices()
+        .zip(b.chars())
+        .find(|((_, a_char), b_char)| a_char != b_char)
+        .map(|((index, _), _)| index)
+        .unwrap_or_else(|| a.len().min(b.len()));
+    &a[..end]
+}
+
+/// A pair of a whole and a part, for a failure message line that shows their
+/// longest common prefix.
+///
+/// The macros call `(&CommonPrefix(whole, part)).common_prefix_line()`.
+/// If the whole and the part are both strings, then method resolution picks
+/// [`CommonPrefixStr`], which returns the line. Otherwise method resolution
+/// picks [`CommonPrefixOther`], which returns an empty string.
+#[doc(hidden)]
+pub struct CommonPrefix<'a, A: ?Sized, B: ?Sized>(pub &'a A, pub &'a B);
+
+#[doc(hidden)]
+pub trait CommonPrefixStr {
+    fn common_prefix_line(&self) -> String;
+}
+
+impl<A: AsRef<str> + ?Sized, B: AsRef<str> + ?Sized> CommonPrefixStr for CommonPrefix<'_, A, B> {
+    fn common_prefix_line(&self) -> String {
+        format!(
+            ",\n common prefix: `{:?}`",
+            common_prefix(self.0.as_ref(), self.1.as_ref())
+        )
+    }
+}
+
+#[doc(hidden)]
+pub trait CommonPrefixOther {
+    fn common_prefix_line(&self) -> String;
+}
+
+impl<A: ?Sized, B: ?Sized> CommonPrefixOther for &CommonPrefix<'_, A, B> {
+    fn common_prefix_line(&self) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::common_prefix;
+
+    #[test]
+    fn common_prefix_with_divergence() {
+        assert_eq!(common_prefix("fn main() {}", "fn main(x)"), "fn main(");
+    }
+
+    #[test]
+    fn common_prefix_with_none_shared() {
+        assert_eq!(common_prefix("alfa", "bravo"), "");
+    }
+
+    #[test]
+    fn common_prefix_with_shorter_whole() {
+        assert_eq!(common_prefix("al", "alfa"), "al");
+    }
+
+    #[test]
+    fn common_prefix_with_multibyte_chars() {
+        assert_eq!(common_prefix("αβγ", "αβδ"), "αβ");
+    }
+}
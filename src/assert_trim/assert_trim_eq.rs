@@ -0,0 +1,235 @@
+//! Assert a string trimmed is equal to another string trimmed.
+//!
+//! Pseudocode:<br>
+//! a.trim() = b.trim()
+//!
+//! This is useful for comparing text that may or may not have surrounding
+//! whitespace, such as a trailing newline from a file or from command output.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = "alfa\n";
+//! let b = "  alfa";
+//! assert_trim_eq!(a, b);
+//! # }
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_trim_eq`](macro@crate::assert_trim_eq)
+//! * [`assert_trim_eq_as_result`](macro@crate::assert_trim_eq_as_result)
+//! * [`debug_assert_trim_eq`](macro@crate::debug_assert_trim_eq)
+
+/// Assert a string trimmed is equal to another string trimmed.
+///
+/// Pseudocode:<br>
+/// a.trim() = b.trim()
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro provides the same statements as [`assert_trim_eq`](macro.assert_trim_eq.html),
+/// except this macro returns a Result, rather than doing a panic.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_trim_eq`](macro@crate::assert_trim_eq)
+/// * [`assert_trim_eq_as_result`](macro@crate::assert_trim_eq_as_result)
+/// * [`debug_assert_trim_eq`](macro@crate::debug_assert_trim_eq)
+///
+#[macro_export]
+macro_rules! assert_trim_eq_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let a_trim = a.trim();
+                let b_trim = b.trim();
+                if a_trim == b_trim {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_trim_eq!(a, b)`\n",
+                                "{}macro.assert_trim_eq.html\n",
+                                "  a label: `{}`,\n",
+                                "  a debug: `{:?}`,\n",
+                                " a.trim(): `{:?}`,\n",
+                                "  b label: `{}`,\n",
+                                "  b debug: `{:?}`,\n",
+                                " b.trim(): `{:?}`",
+                            ),
+                            $crate::DOC_URL_BASE,
+                            stringify!($a),
+                            a,
+                            a_trim,
+                            stringify!($b),
+                            b,
+                            b_trim
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn success() {
+        let a = "alfa\n";
+        let b = "  alfa";
+        let result = assert_trim_eq_as_result!(a, b);
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_string() {
+        let a = String::from("alfa\n");
+        let b = String::from("  alfa");
+        let result = assert_trim_eq_as_result!(a, b);
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = "alfa\n";
+        let b = "  bravo";
+        let result = assert_trim_eq_as_result!(a, b);
+        assert_eq!(
+            result.unwrap_err(),
+            concat!(
+                "assertion failed: `assert_trim_eq!(a, b)`\n",
+                "https://docs.rs/assertables/9.2.0/assertables/macro.assert_trim_eq.html\n",
+                "  a label: `a`,\n",
+                "  a debug: `\"alfa\\n\"`,\n",
+                " a.trim(): `\"alfa\"`,\n",
+                "  b label: `b`,\n",
+                "  b debug: `\"  bravo\"`,\n",
+                " b.trim(): `\"bravo\"`",
+            )
+        );
+    }
+}
+
+/// Assert a string trimmed is equal to another string trimmed.
+///
+/// Pseudocode:<br>
+/// a.trim() = b.trim()
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa\n";
+/// let b = "  alfa";
+/// assert_trim_eq!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa\n";
+/// let b = "  bravo";
+/// assert_trim_eq!(a, b);
+/// # });
+/// // assertion failed: `assert_trim_eq!(a, b)`
+/// // https://docs.rs/assertables/9.2.0/assertables/macro.assert_trim_eq.html
+/// //   a label: `a`,
+/// //   a debug: `\"alfa\\n\"`,
+/// //  a.trim(): `\"alfa\"`,
+/// //   b label: `b`,
+/// //   b debug: `\"  bravo\"`,
+/// //  b.trim(): `\"bravo\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let expect = concat!(
+/// #     "assertion failed: `assert_trim_eq!(a, b)`\n",
+/// #     "https://docs.rs/assertables/9.2.0/assertables/macro.assert_trim_eq.html\n",
+/// #     "  a label: `a`,\n",
+/// #     "  a debug: `\"alfa\\n\"`,\n",
+/// #     " a.trim(): `\"alfa\"`,\n",
+/// #     "  b label: `b`,\n",
+/// #     "  b debug: `\"  bravo\"`,\n",
+/// #     " b.trim(): `\"bravo\"`",
+/// # );
+/// # assert_eq!(actual, expect);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_trim_eq`](macro@crate::assert_trim_eq)
+/// * [`assert_trim_eq_as_result`](macro@crate::assert_trim_eq_as_result)
+/// * [`debug_assert_trim_eq`](macro@crate::debug_assert_trim_eq)
+///
+#[macro_export]
+macro_rules! assert_trim_eq {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_trim_eq_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", err),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_trim_eq_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(_err) => panic!("{}", $($message)+),
+        }
+    }};
+}
+
+/// Assert a string trimmed is equal to another string trimmed.
+///
+/// Pseudocode:<br>
+/// a.trim() = b.trim()
+///
+/// This macro provides the same statements as [`assert_trim_eq`](macro.assert_trim_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_trim_eq`](macro@crate::assert_trim_eq)
+/// * [`assert_trim_eq_as_result`](macro@crate::assert_trim_eq_as_result)
+/// * [`debug_assert_trim_eq`](macro@crate::debug_assert_trim_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_trim_eq {
+    ($($arg:tt)*) => {
+        if $crate::cfg!(debug_assertions) {
+            $crate::assert_trim_eq!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,21 @@
+//! Assert for comparing strings with surrounding whitespace trimmed.
+//!
+//! These macros help compare text that may or may not have surrounding
+//! whitespace, such as a trailing newline from a file or command output.
+//!
+//! * [`assert_trim_eq!(a, b)`](macro@crate::assert_trim_eq) ≈ a.trim() = b.trim()
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! # fn main() {
+//! let a = "alfa\n";
+//! let b = "alfa";
+//! assert_trim_eq!(a, b);
+//! # }
+//! ```
+
+// Compare another
+pub mod assert_trim_eq;
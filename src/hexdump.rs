@@ -0,0 +1,122 @@
+//! Hexdump helpers for byte comparison messages.
+//!
+//! These functions are used by macros that compare bytes, such as
+//! [`assert_bytes_eq`](macro@crate::assert_bytes_eq), so a failure message
+//! can show two buffers side by side, rather than as decimal arrays.
+//!
+//! Each row shows a marker, the offset, then the hex and ASCII of each buffer.
+//! The marker `>` is on the row that has the first differing offset.
+//!
+//! ```text
+//!  offset    a hex                    a ascii   b hex                    b ascii
+//! >00000000  61 6c 66 61 0a           alfa.     62 72 61 76 6f           bravo
+//! ```
+
+/// The number of bytes per row.
+const ROW_LEN: usize = 8;
+
+/// The maximum number of rows to show, around the first difference.
+const MAX_ROWS: usize = 16;
+
+/// Render two buffers as a side by side hexdump.
+///
+/// When there are many rows, this shows the rows around the first
+/// difference, and shows `…` for rows that are left out.
+pub fn hexdump_diff(a: &[u8], b: &[u8]) -> String {
+    let rows = std::cmp::max(a.len(), b.len()).div_ceil(ROW_LEN);
+    let diff_row = crate::assert_slice::first_difference(a, b).map(|offset| offset / ROW_LEN);
+    let start = match diff_row {
+        Some(diff_row) if rows > MAX_ROWS => std::cmp::min(diff_row.saturating_sub(4), rows - MAX_ROWS),
+        _ => 0,
+    };
+    let end = std::cmp::min(rows, start + MAX_ROWS);
+    let mut lines = vec![format!(
+        "{:<9}  {:<23}  {:<8}  {:<23}  {}",
+        " offset", "a hex", "a ascii", "b hex", "b ascii"
+    )];
+    if start > 0 {
+        lines.push(String::from(" …"));
+    }
+    for row in start..end {
+        let marker = if diff_row == Some(row) { '>' } else { ' ' };
+        let a_row = row_slice(a, row);
+        let b_row = row_slice(b, row);
+        let line = format!(
+            "{}{:08x}  {:<23}  {:<8}  {:<23}  {}",
+            marker,
+            row * ROW_LEN,
+            hex(a_row),
+            ascii(a_row),
+            hex(b_row),
+            ascii(b_row)
+        );
+        lines.push(String::from(line.trim_end()));
+    }
+    if end < rows {
+        lines.push(String::from(" …"));
+    }
+    lines.join("\n")
+}
+
+/// Get the bytes of a row, which may be short or empty at the end.
+fn row_slice(bytes: &[u8], row: usize) -> &[u8] {
+    let start = std::cmp::min(bytes.len(), row * ROW_LEN);
+    let end = std::cmp::min(bytes.len(), start + ROW_LEN);
+    &bytes[start..end]
+}
+
+/// Format bytes as lowercase hex pairs separated by spaces.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect::<Vec<String>>().join(" ")
+}
+
+/// Format bytes as ASCII, with `.` for a byte that is not printable.
+fn ascii(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&byte| if (0x20..=0x7e).contains(&byte) { byte as char } else { '.' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hexdump_diff_x_short() {
+        assert_eq!(
+            hexdump_diff(b"alfa\n", b"bravo"),
+            concat!(
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                ">00000000  61 6c 66 61 0a           alfa.     62 72 61 76 6f           bravo"
+            )
+        );
+    }
+
+    #[test]
+    fn hexdump_diff_x_marks_row_of_first_difference() {
+        assert_eq!(
+            hexdump_diff(b"alfa bravo charlie", b"alfa bravo xharlie"),
+            concat!(
+                " offset    a hex                    a ascii   b hex                    b ascii\n",
+                " 00000000  61 6c 66 61 20 62 72 61  alfa bra  61 6c 66 61 20 62 72 61  alfa bra\n",
+                ">00000008  76 6f 20 63 68 61 72 6c  vo charl  76 6f 20 78 68 61 72 6c  vo xharl\n",
+                " 00000010  69 65                    ie        69 65                    ie"
+            )
+        );
+    }
+
+    #[test]
+    fn hexdump_diff_x_many_rows_shows_window() {
+        let a = vec![0u8; 400];
+        let mut b = a.clone();
+        b[200] = 1;
+        let actual = hexdump_diff(&a, &b);
+        let lines: Vec<&str> = actual.lines().collect();
+        assert_eq!(lines.len(), 1 + 1 + MAX_ROWS + 1);
+        assert_eq!(lines[1], " …");
+        assert!(lines[2].starts_with(" 000000a8"));
+        assert!(lines[6].starts_with(">000000c8"));
+        assert_eq!(lines[lines.len() - 1], " …");
+    }
+}
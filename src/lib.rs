@@ -81,13 +81,24 @@
 //! * [`assert_le!(a, b)`](module@crate::assert_le) ≈ a ≤ b
 //! * [`assert_gt!(a, b)`](module@crate::assert_gt) ≈ a > b
 //! * [`assert_ge!(a, b)`](module@crate::assert_ge) ≈ a ≥ b
+//! * [`assert_ordering_eq!(a, b, ordering)`](module@crate::assert_ordering_eq) ≈ a.cmp(&b) = ordering
+//! * [`assert_between!(lo, x, hi)`](module@crate::assert_between) ≈ lo ≤ x ≤ hi
+//! * [`assert_is_default!(a)`](module@crate::assert_default) ≈ a = T::default()
+//! * [`assert_is_power_of_two!(n)`](module@crate::assert_int) ≈ n is a power of two, for any integer type
 //!
 //! Differences:
 //!
 //! * [`assert_approx_eq!(a, b)`](module@crate::assert_approx::assert_approx_eq) ≈ |a-b| ≤ 1e-6
 //! * [`assert_abs_diff_eq!(a, b, delta)`](module@crate::assert_abs_diff::assert_abs_diff_eq) ≈ |a-b| = Δ
 //! * [`assert_in_delta!(a, b, delta)`](module@crate::assert_in::assert_in_delta) ≈ |a-b| ≤ Δ
-//! * [`assert_in_epsilon!(a, b, epsilon)`](module@crate::assert_in::assert_in_epsilon) ≈ |a-b| ≤ ε min(a,b)
+//! * [`assert_struct_in_delta!(a, b, delta, [fields])`](module@crate::assert_in::assert_struct_in_delta) ≈ |a.field-b.field| ≤ Δ for each field
+//! * [`assert_in_epsilon!(a, b, epsilon)`](module@crate::assert_in::assert_in_epsilon) ≈ |a-b| ≤ ε |b|
+//! * [`assert_within_percent!(a, b, pct)`](module@crate::assert_in::assert_within_percent) ≈ |a-b| ≤ |b| pct / 100
+//! * [`assert_f64_eq!(a, b)`](module@crate::assert_in::assert_f64_eq) ≈ |a-b| ≤ f64::EPSILON max(|a|,|b|,1)
+//! * [`assert_f64_eq!(a, b, ε)`](module@crate::assert_in::assert_f64_eq) ≈ |a-b| ≤ ε max(|a|,|b|,1)
+//! * [`assert_f32_eq!(a, b)`](module@crate::assert_in::assert_f32_eq) ≈ |a-b| ≤ f32::EPSILON max(|a|,|b|,1)
+//! * [`assert_f32_eq!(a, b, ε)`](module@crate::assert_in::assert_f32_eq) ≈ |a-b| ≤ ε max(|a|,|b|,1)
+//! * [`assert_duration_in_delta!(a, b, delta)`](module@crate::assert_duration::assert_duration_in_delta) ≈ |a-b| ≤ Δ for durations
 //!
 //! Groups:
 //!
@@ -96,14 +107,17 @@
 //! * [`assert_is_empty!(group)`](module@crate::assert_is_empty::assert_is_empty) ≈ a.is_empty()
 //! * [`assert_len_eq!(a, b)`](module@crate::assert_len::assert_len_eq) ≈ a.len() = b.len()
 //! * [`assert_count_eq!(a, b)`](module@crate::assert_count::assert_count_eq) ≈ a.count() = b.count()
+//! * [`assert_char_count_eq!(s, n)`](module@crate::assert_char_count::assert_char_count_eq) ≈ s.chars().count() = n
 //!
 //! Matching:
 //!
-//! * [`assert_starts_with!(sequence, x)`](module@crate::assert_starts_with) ≈ sequence.starts_with(x)
-//! * [`assert_ends_with!(sequence, x)`](module@crate::assert_ends_with) ≈ sequence.ends_with(x)
+//! * [`assert_starts_with!(whole, part)`](module@crate::assert_starts_with) ≈ whole.starts_with(part)
+//! * [`assert_ends_with!(whole, part)`](module@crate::assert_ends_with) ≈ whole.ends_with(part)
 //! * [`assert_contains!(container, x)`](module@crate::assert_contains) ≈ container.contains(x)
 //! * [`assert_is_match!(matcher, x)`](module@crate::assert_is_match) ≈ matcher.is_match(x)
 //! * [`assert_matches!(expr, pattern)`](module@crate::assert_matches) ≈ matches!(expr, pattern)
+//! * [`assert_matches_glob!(string, pattern)`](module@crate::assert_matches::assert_matches_glob) ≈ string matches glob pattern with * and ?
+//! * [`assert_path_starts_with!(path, base)`](module@crate::assert_fs_path::assert_path_starts_with) ≈ path.starts_with(base) by path components
 //!
 //! Results:
 //!
@@ -131,8 +145,17 @@
 //! Collections:
 //!
 //! * [`assert_iter_eq!(a, b)`](module@crate::assert_iter) ≈ a into iter = b into iter
+//! * [`assert_iter_eq_unordered!(a, b)`](module@crate::assert_iter) ≈ a into iter counts = b into iter counts
 //! * [`assert_set_eq!(a, b)`](module@crate::assert_set) ≈ a into set = b into set
 //! * [`assert_bag_eq!(a, b)`](module@crate::assert_bag) ≈ a into bag = = b into bag
+//! * [`assert_get_eq!(container, key, expected)`](module@crate::assert_map) ≈ container.get(key) = expected
+//! * [`assert_slice_eq!(a, b)`](module@crate::assert_slice) ≈ a[..] = b[..], with the first difference
+//! * [`assert_bytes_eq!(a, b)`](module@crate::assert_bytes) ≈ a[..] = b[..], with a hexdump
+//!
+//! Types, at compile time:
+//!
+//! * [`assert_impl!(T: Send + Sync)`](module@crate::assert_impl) ≈ T implements the bounds, else a compile error
+//! * [`assert_size_of_eq_const!(T, n)`](module@crate::assert_size_of) ≈ size_of::<T>() = n, else a compile error
 //!
 //! Infix notation:
 //!
@@ -157,6 +180,27 @@
 //! * [`assert_gt_as_result!(1, 2)`](macro@crate::assert_gt_as_result) ≈ Result Err
 //! * [`debug_assert_gt!(1, 2)`](macro@crate::debug_assert_gt) ≈ panic in debug mode
 //!
+//! Every `_as_result` macro returns `Result<T, String>`, where the error is the
+//! same message that the panic form prints. This means you can use the `?`
+//! operator to chain macros from any module in a function that returns
+//! `Result<_, String>`:
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! fn validate(s: &str) -> Result<(), String> {
+//!     assert_contains_as_result!(s, "@")?;
+//!     assert_len_gt_x_as_result!(s, 3)?;
+//!     assert_ok_as_result!(s.parse::<String>())?;
+//!     Ok(())
+//! }
+//!
+//! # fn main() {
+//! assert!(validate("alice@example.com").is_ok());
+//! assert!(validate("alfa").is_err());
+//! # }
+//! ```
+//!
 //! Many of the macros have a form "compare left item to right item" that compares
 //! items of the same kind, and a form "compare left item to right expression" that
 //! compares one item to any arbitrary expression:
@@ -179,20 +223,47 @@
 //! * License: MIT or Apache-2.0 or GPL-2.0 or GPL-3.0 or contact us for more
 //! * Contact: Joel Parker Henderson (joel@joelparkerhenderson.com)
 
+/// Documentation URL base for this crate version, used in assertion messages.
+///
+/// The macros append a page such as `macro.assert_lt.html`, so every failure
+/// message links to the docs for the version of the crate that is compiled.
+#[doc(hidden)]
+pub const DOC_URL_BASE: &str = concat!(
+    "https://docs.rs/assertables/",
+    env!("CARGO_PKG_VERSION"),
+    "/assertables/"
+);
+
 // Assert truth
 pub mod assert; // (in addition to what's provided by Rust `std`)
 
+// Assert type implements traits, at compile time
+pub mod assert_impl;
+
+// Assert type size and alignment
+pub mod assert_size_of;
+
 // Assert value comparison
+pub mod assert_between;
+pub mod assert_between_exclusive;
+pub mod assert_default;
 pub mod assert_eq; // (in addition to what's provided by Rust `std`)
 pub mod assert_ge;
+pub mod assert_ge_within;
 pub mod assert_gt;
 pub mod assert_le;
+pub mod assert_le_within;
 pub mod assert_lt;
 pub mod assert_ne; // (in addition to what's provided by Rust `std`)
+pub mod assert_ordering_eq;
+
+// Assert integer properties
+pub mod assert_int;
 
 // Assert difference
 pub mod assert_abs_diff;
 pub mod assert_approx;
+pub mod assert_duration;
 pub mod assert_in;
 
 // Assert all/any
@@ -203,19 +274,22 @@ pub mod assert_any;
 pub mod assert_infix;
 
 // Matching
+pub mod assert_char_count;
 pub mod assert_contains;
 pub mod assert_count;
 pub mod assert_ends_with;
 pub mod assert_is_empty;
 pub mod assert_is_match;
 pub mod assert_len;
+pub mod assert_lines_count;
 pub mod assert_matches;
 pub mod assert_starts_with;
+pub mod assert_trim;
 
 // For Result Ok & Err
 pub mod assert_err;
 pub mod assert_ok;
-pub mod assert_result; // Deprecated
+pub mod assert_result; // Deprecated, except assert_result_eq
 
 // For Option Some & None
 pub mod assert_none;
@@ -229,19 +303,60 @@ pub mod assert_ready; // Deprecated
 
 // For collections
 pub mod assert_bag;
+pub mod assert_bytes;
 pub mod assert_iter;
+pub mod assert_map;
 pub mod assert_set;
+pub mod assert_slice;
 
 // For functions
 pub mod assert_fn;
 pub mod assert_fn_err;
 pub mod assert_fn_ok;
 
+// For panics
+pub mod assert_panics;
+
+// For paths
+pub mod assert_fs_path;
+
 // For reading
 pub mod assert_fs_read_to_string;
 pub mod assert_io_read_to_string;
 
+// For JSON, with the crate feature `json`
+#[cfg(feature = "json")]
+pub mod assert_json;
+
 // For externals
 pub mod assert_command;
+pub mod assert_output;
+pub mod assert_pipeline;
 pub mod assert_process;
 pub mod assert_program_args;
+
+// Helpers for messages
+#[doc(hidden)]
+pub mod hexdump;
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn doc_url_base() {
+        assert_eq!(
+            crate::DOC_URL_BASE,
+            format!(
+                "https://docs.rs/assertables/{}/assertables/",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+
+    #[test]
+    fn doc_url_base_in_message() {
+        let result = crate::assert_lt_as_result!(2, 1);
+        let expect = format!("{}macro.assert_lt.html\n", crate::DOC_URL_BASE);
+        assert!(result.unwrap_err().contains(&expect));
+    }
+}
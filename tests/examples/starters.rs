@@ -235,10 +235,14 @@ fn examples_with_assert_ready() {
     // Compare another
     assert_ready_eq!(a, a);
     assert_ready_ne!(a, b);
+    assert_ready_lt!(a, b);
+    assert_ready_ge!(b, a);
 
     // Compare expression
     assert_ready_eq_x!(a, 1);
     assert_ready_ne_x!(a, 2);
+    assert_ready_lt_x!(a, 2);
+    assert_ready_ge_x!(a, 1);
 }
 
 /// Examples with assert_pending.
@@ -581,8 +585,8 @@ fn examples_of_success_return_with_results() {
     let a_result: Result<i8, i8> = Ok(1);
     let b_result: Result<i8, i8> = Ok(2);
     let (a_inner, b_inner) = assert_ok_ne!(a_result, b_result);
-    assert_eq!(a_inner, 1);
-    assert_eq!(b_inner, 2);
+    assert_eq!(*a_inner, 1);
+    assert_eq!(*b_inner, 2);
 
     // Compare expression
     let result: Result<i8, i8> = Ok(2);
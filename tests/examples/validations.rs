@@ -82,4 +82,43 @@ fn validate_words() {
 	let result = validate_words("this text contains alfa");
 	assert_err!(result);
 
-}
\ No newline at end of file
+}
+
+/// Validate results from many modules, with the `?` operator.
+/// Each `_as_result` macro returns `Result<T, String>`, so the macros compose.
+#[test]
+fn validate_with_many_modules() {
+
+	fn string_err<T>(result: Result<T, String>) -> Result<T, String> {
+		result
+	}
+
+	fn validate(a: &[i32], s: &str) -> Result<(), String> {
+		string_err(assert_lt_as_result!(a[0], a[1]))?;
+		string_err(assert_in_delta_as_result!(a[0], a[1], 1))?;
+		string_err(assert_len_eq_x_as_result!(a, 2))?;
+		string_err(assert_contains_as_result!(s, "alfa"))?;
+		string_err(assert_starts_with_as_result!(s, "al"))?;
+		string_err(assert_some_as_result!(a.first()))?;
+		string_err(assert_ok_as_result!(s.parse::<String>()))?;
+		string_err(assert_err_as_result!(s.parse::<i32>()))?;
+		string_err(assert_iter_eq_as_result!(a, [1, 2]))?;
+		string_err(assert_set_subset_as_result!(a, [1, 2, 3]))?;
+		string_err(assert_bag_eq_as_result!(a, [2, 1]))?;
+		string_err(assert_fs_read_to_string_eq_x_as_result!("alfa.txt", "alfa\n"))?;
+		Ok(())
+	}
+
+	// Success
+	let result = validate(&[1, 2], "alfa");
+	assert_ok!(result);
+
+	// Failure because the numbers are not less than
+	let result = validate(&[2, 1], "alfa");
+	assert_err!(result);
+
+	// Failure because the string does not contain "alfa"
+	let result = validate(&[1, 2], "bravo");
+	assert_err!(result);
+
+}